@@ -1,11 +1,13 @@
 use anyhow::Result;
-use std::fs;
+use std::{env, fs, path::PathBuf};
 fn main() -> Result<()> {
     // 如果目录不存在则创建
     fs::create_dir_all("src/pb")?;
     let builder = tonic_build::configure();
     builder
         .out_dir("src/pb")
+        // reflection 使用的 FileDescriptorSet
+        .file_descriptor_set_path(PathBuf::from(env::var("OUT_DIR")?).join("crm.bin"))
         .compile_protos(&["../protos/crm/crm.proto"], &["../protos"])?;
     Ok(())
 }
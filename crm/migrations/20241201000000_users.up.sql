@@ -0,0 +1,9 @@
+-- Add up migration script here
+create table users (
+    id bigserial primary key,
+    name varchar(64) not null,
+    email varchar(128) not null unique,
+    created_at timestamptz not null default current_timestamp
+);
+
+create index users_created_at_idx on users (created_at);
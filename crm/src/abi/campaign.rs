@@ -0,0 +1,370 @@
+use crate::{
+    pb::{CampaignResult, RecallRequest, RemindRequest, WelcomeRequest},
+    CrmService, ResponseStream, ServiceResult,
+};
+use chrono::{DateTime, Duration, Utc};
+use crm_core::{auth::AUTHORIZATION, telemetry};
+use crm_metadata::pb::{Content, MaterializeRequest};
+use crm_send::pb::{SendRequest, SendResponse, SendStatus};
+use futures::{stream, StreamExt};
+use prost_types::FieldMask;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{metadata::AsciiMetadataValue, Request, Response, Status};
+use tracing::{info, info_span, warn, Instrument};
+use user_stat::pb::{Filter, PageRequest, QueryRequest, UserStat};
+
+// 每次从 UserStats 拉取的用户数量
+const PAGE_SIZE: u32 = 500;
+// 每个用户最多推荐的内容数量
+const MAX_CONTENTS: usize = 10;
+const CHANNEL_SIZE: usize = 1024;
+
+/// 一次营销活动 对查询到的每个用户发送一封邮件
+struct Campaign {
+    subject: &'static str,
+    query: QueryRequest,
+    recommend: Recommend,
+    // 转发给下游服务的 authorization
+    auth: Option<AsciiMetadataValue>,
+}
+
+/// 推荐给用户的内容
+enum Recommend {
+    // 所有用户推荐相同的内容
+    Fixed(Vec<u32>),
+    // 浏览过但未开始观看的内容
+    Viewed,
+    // 开始观看但未看完的内容
+    Started,
+}
+
+type ResultSender = mpsc::Sender<Result<CampaignResult, Status>>;
+
+impl CrmService {
+    // 欢迎 interval 天前注册的用户
+    pub async fn welcome(
+        &self,
+        req: WelcomeRequest,
+        auth: Option<AsciiMetadataValue>,
+    ) -> ServiceResult<ResponseStream> {
+        if req.content_ids.is_empty() {
+            return Err(Status::invalid_argument("Content ids are required"));
+        }
+        let (lower, upper) = day_window(req.interval);
+        self.run(Campaign {
+            subject: "Welcome",
+            query: QueryRequest::new_with_dt("created_at", lower, upper),
+            recommend: Recommend::Fixed(req.content_ids),
+            auth,
+        })
+        .await
+    }
+
+    // 召回 last_visit_interval 天前最后访问的用户
+    pub async fn recall(
+        &self,
+        req: RecallRequest,
+        auth: Option<AsciiMetadataValue>,
+    ) -> ServiceResult<ResponseStream> {
+        let (lower, upper) = day_window(req.last_visit_interval);
+        let mut query = QueryRequest::new_with_dt("last_visited_at", lower, upper);
+        query.filter = Some(Filter::length("viewed_but_not_started", Some(1), None));
+        self.run(Campaign {
+            subject: "We miss you",
+            query,
+            recommend: Recommend::Viewed,
+            auth,
+        })
+        .await
+    }
+
+    // 提醒 last_visit_interval 天前最后访问 且有未看完内容的用户
+    pub async fn remind(
+        &self,
+        req: RemindRequest,
+        auth: Option<AsciiMetadataValue>,
+    ) -> ServiceResult<ResponseStream> {
+        let (lower, upper) = day_window(req.last_visit_interval);
+        let mut query = QueryRequest::new_with_dt("last_visited_at", lower, upper);
+        query.filter = Some(Filter::length("started_but_not_finished", Some(1), None));
+        self.run(Campaign {
+            subject: "Continue watching",
+            query,
+            recommend: Recommend::Started,
+            auth,
+        })
+        .await
+    }
+
+    // 在后台逐页处理用户 每个用户的结果通过 stream 返回
+    async fn run(&self, campaign: Campaign) -> ServiceResult<ResponseStream> {
+        let (tx, rx) = mpsc::channel(CHANNEL_SIZE);
+        let svc = self.clone();
+        tokio::spawn(
+            async move {
+                if let Err(e) = svc.deliver(&campaign, &tx).await {
+                    warn!("Campaign {} failed: {:?}", campaign.subject, e);
+                    let _ = tx.send(Err(e)).await;
+                }
+            }
+            .in_current_span(),
+        );
+
+        let stream = ReceiverStream::new(rx);
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    async fn deliver(&self, campaign: &Campaign, tx: &ResultSender) -> Result<(), Status> {
+        let mut contents = HashMap::new();
+        let mut cursor = String::new();
+        let mut page = 0;
+        let mut total = 0;
+        loop {
+            let span = info_span!("campaign.page", campaign = campaign.subject, page);
+            let ret = self
+                .deliver_page(campaign, cursor, &mut contents, tx)
+                .instrument(span)
+                .await?;
+            let Some((count, next_cursor)) = ret else {
+                warn!("Client disconnected, stop campaign {}", campaign.subject);
+                return Ok(());
+            };
+            total += count;
+            if next_cursor.is_empty() {
+                break;
+            }
+            cursor = next_cursor;
+            page += 1;
+        }
+
+        info!("Campaign {} sent to {} users", campaign.subject, total);
+        Ok(())
+    }
+
+    // 处理一页用户 返回用户数和下一页的 cursor 客户端断开时返回 None
+    async fn deliver_page(
+        &self,
+        campaign: &Campaign,
+        cursor: String,
+        contents: &mut HashMap<u32, Content>,
+        tx: &ResultSender,
+    ) -> Result<Option<(usize, String)>, Status> {
+        let page = self
+            .user_stats
+            .clone()
+            .query_page(campaign.request(campaign.page_request(cursor)))
+            .await?
+            .into_inner();
+        let users: Vec<_> = page
+            .users
+            .into_iter()
+            .map(|user| {
+                let ids = campaign.recommend.content_ids(&user);
+                (user.email, ids)
+            })
+            .collect();
+        if users.is_empty() {
+            return Ok(Some((0, String::new())));
+        }
+        let count = users.len();
+
+        let ids = users.iter().flat_map(|(_, ids)| ids);
+        self.materialize(campaign, ids, contents).await?;
+        for result in self.send(campaign, users, contents).await? {
+            if tx.send(Ok(result)).await.is_err() {
+                return Ok(None);
+            }
+        }
+        Ok(Some((count, page.next_cursor)))
+    }
+
+    // 获取尚未缓存的内容
+    async fn materialize(
+        &self,
+        campaign: &Campaign,
+        ids: impl Iterator<Item = &u32>,
+        contents: &mut HashMap<u32, Content>,
+    ) -> Result<(), Status> {
+        // 同一页的用户常有相同的内容 如 welcome 中所有用户的内容都相同
+        let mut ids: Vec<_> = ids
+            .filter(|id| !contents.contains_key(id))
+            .copied()
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut stream = self
+            .metadata
+            .clone()
+            .materialize(campaign.request(MaterializeRequest::new_with_ids(&ids)))
+            .await?
+            .into_inner();
+        while let Some(content) = stream.next().await {
+            let content = content?;
+            contents.insert(content.id, content);
+        }
+        Ok(())
+    }
+
+    // 发送一页用户的通知 根据 message_id 将回执对应到用户
+    async fn send(
+        &self,
+        campaign: &Campaign,
+        users: Vec<(String, Vec<u32>)>,
+        contents: &HashMap<u32, Content>,
+    ) -> Result<Vec<CampaignResult>, Status> {
+        let sender = &self.config.server.sender_email;
+        let mut pending = HashMap::with_capacity(users.len());
+        let reqs: Vec<_> = users
+            .into_iter()
+            .map(|(email, ids)| {
+                let contents: Vec<_> = ids
+                    .iter()
+                    .filter_map(|id| contents.get(id).cloned())
+                    .collect();
+                let req = SendRequest::new(
+                    campaign.subject.to_string(),
+                    sender.clone(),
+                    std::slice::from_ref(&email),
+                    &contents,
+                );
+                pending.insert(req.message_id().to_string(), email);
+                req
+            })
+            .collect();
+
+        let mut stream = self
+            .notification
+            .clone()
+            .send(campaign.request(stream::iter(reqs)))
+            .await?
+            .into_inner();
+        let mut results = Vec::with_capacity(pending.len());
+        let mut error = "No response from notification service".to_string();
+        // 每条消息收到入队回执后即返回 不等待投递完成
+        while !pending.is_empty() {
+            let Some(res) = stream.next().await else {
+                break;
+            };
+            match res {
+                Ok(res) => {
+                    if let Some(email) = pending.remove(&res.message_id) {
+                        results.push(CampaignResult {
+                            email,
+                            error: send_error(&res),
+                            message_id: res.message_id,
+                        });
+                    }
+                }
+                Err(e) => {
+                    error = e.message().to_string();
+                    break;
+                }
+            }
+        }
+
+        // 没有收到回执的消息视为发送失败
+        results.extend(
+            pending
+                .into_iter()
+                .map(|(message_id, email)| CampaignResult {
+                    email,
+                    message_id,
+                    error: error.clone(),
+                }),
+        );
+        Ok(results)
+    }
+}
+
+impl Campaign {
+    // 创建下游请求 带上调用方的 authorization 和当前的 trace 上下文
+    fn request<T>(&self, msg: T) -> Request<T> {
+        let mut req = Request::new(msg);
+        telemetry::inject(req.metadata_mut());
+        if let Some(auth) = &self.auth {
+            req.metadata_mut().insert(AUTHORIZATION, auth.clone());
+        }
+        req
+    }
+
+    fn page_request(&self, cursor: String) -> PageRequest {
+        PageRequest {
+            query: Some(self.query.clone()),
+            order_by: None,
+            limit: PAGE_SIZE,
+            cursor,
+            fields: Some(FieldMask {
+                paths: vec![self.recommend.column().to_string()],
+            }),
+        }
+    }
+}
+
+impl Recommend {
+    // 需要从 UserStats 查询的列
+    fn column(&self) -> &'static str {
+        match self {
+            Recommend::Fixed(_) => "name",
+            Recommend::Viewed => "viewed_but_not_started",
+            Recommend::Started => "started_but_not_finished",
+        }
+    }
+
+    fn content_ids(&self, user: &UserStat) -> Vec<u32> {
+        let ids = match self {
+            Recommend::Fixed(ids) => ids,
+            Recommend::Viewed => &user.viewed_but_not_started,
+            Recommend::Started => &user.started_but_not_finished,
+        };
+        ids.iter().take(MAX_CONTENTS).copied().collect()
+    }
+}
+
+// interval 天前的一整天
+fn day_window(interval: u32) -> (DateTime<Utc>, DateTime<Utc>) {
+    let upper = Utc::now() - Duration::days(interval as i64);
+    (upper - Duration::days(1), upper)
+}
+
+// 只有 ACCEPTED 视为成功 其他状态没有 reason 时使用状态名
+fn send_error(res: &SendResponse) -> String {
+    match res.status() {
+        SendStatus::Accepted => String::new(),
+        _ if !res.reason.is_empty() => res.reason.clone(),
+        status => status.as_str_name().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_accepted_should_succeed() {
+        let res = |status: SendStatus, reason: &str| SendResponse {
+            status: status as i32,
+            reason: reason.to_string(),
+            ..Default::default()
+        };
+        assert_eq!(send_error(&res(SendStatus::Accepted, "")), "");
+        assert_eq!(send_error(&res(SendStatus::Rejected, "bad")), "bad");
+        assert_eq!(
+            send_error(&res(SendStatus::Failed, "")),
+            "SEND_STATUS_FAILED"
+        );
+        // 未设置或未知的状态同样视为失败
+        assert_eq!(
+            send_error(&SendResponse::default()),
+            "SEND_STATUS_UNSPECIFIED"
+        );
+        let mut unknown = res(SendStatus::Accepted, "");
+        unknown.status = 42;
+        assert_eq!(send_error(&unknown), "SEND_STATUS_UNSPECIFIED");
+    }
+}
@@ -1 +1,52 @@
+mod campaign;
 mod crm;
+mod user;
+
+use crate::{pb::crm_server::CrmServer, AppConfig, CrmService, CrmServiceInner};
+use anyhow::Result;
+use crm_core::{tls::connect, Downstream, HealthCheck};
+use crm_metadata::pb::metadata_client::MetadataClient;
+use crm_send::pb::notification_client::NotificationClient;
+use futures::future::try_join_all;
+use std::sync::Arc;
+use user_stat::pb::user_stats_client::UserStatsClient;
+
+impl CrmService {
+    // 连接下游服务 创建一个新的Service实例
+    pub async fn try_new(config: AppConfig) -> Result<Self> {
+        let server = &config.server;
+        let tls = server.client_tls.as_ref();
+        let user_stats = connect(server.user_stats.clone(), tls).await?;
+        let metadata = connect(server.metadata.clone(), tls).await?;
+        let notification = connect(server.notification.clone(), tls).await?;
+        let downstream = vec![
+            Downstream::new("user_stats", user_stats.clone()),
+            Downstream::new("metadata", metadata.clone()),
+            Downstream::new("notification", notification.clone()),
+        ];
+        let inner = CrmServiceInner {
+            config,
+            user_stats: UserStatsClient::new(user_stats),
+            metadata: MetadataClient::new(metadata),
+            notification: NotificationClient::new(notification),
+            downstream,
+        };
+
+        Ok(Self {
+            inner: Arc::new(inner),
+        })
+    }
+
+    // 将 Service 转换为 RPC Server
+    pub fn into_server(self) -> CrmServer<Self> {
+        CrmServer::new(self)
+    }
+}
+
+// 所有下游服务可用时才可用
+impl HealthCheck for CrmService {
+    async fn check(&self) -> Result<()> {
+        try_join_all(self.downstream.iter().map(|d| d.check())).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,357 @@
+use crate::{
+    pb::{
+        user_service_server::UserServiceServer, CreateUserRequest, DeleteUserRequest,
+        GetUserRequest, ListUsersRequest, ListUsersResponse, UpdateUserRequest, User,
+    },
+    AppConfig, ServiceResult, UserServer,
+};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use crm_core::{HealthCheck, ToDateTime, ToTimestamp};
+use sqlx::{FromRow, PgPool};
+use tonic::{Response, Status};
+use tracing::warn;
+
+const DEFAULT_PAGE_SIZE: u32 = 100;
+const MAX_PAGE_SIZE: u32 = 1000;
+const USER_COLUMNS: &str = "id, name, email, created_at";
+
+/// users 表中的一行
+#[derive(Debug, FromRow)]
+struct UserRow {
+    id: i64,
+    name: String,
+    email: String,
+    created_at: DateTime<Utc>,
+}
+
+impl UserServer {
+    // 连接数据库 创建一个新的Service实例
+    pub async fn try_new(config: &AppConfig) -> Result<Self> {
+        let pool = PgPool::connect(&config.server.db_url).await?;
+        crm_core::metrics::record_pool("crm", pool.clone());
+        Ok(Self::new(pool))
+    }
+
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // 将 Service 转换为 RPC Server
+    pub fn into_server(self) -> UserServiceServer<Self> {
+        UserServiceServer::new(self)
+    }
+
+    pub async fn get_user(&self, req: GetUserRequest) -> ServiceResult<User> {
+        let row: Option<UserRow> =
+            sqlx::query_as(&format!("SELECT {} FROM users WHERE id = $1", USER_COLUMNS))
+                .bind(to_db_id(req.id)?)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(db_error)?;
+        found(row, req.id)
+    }
+
+    pub async fn create_user(&self, req: CreateUserRequest) -> ServiceResult<User> {
+        validate_name(&req.name)?;
+        validate_email(&req.email)?;
+        let row: UserRow = sqlx::query_as(&format!(
+            "INSERT INTO users (name, email) VALUES ($1, $2) RETURNING {}",
+            USER_COLUMNS
+        ))
+        .bind(&req.name)
+        .bind(&req.email)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(db_error)?;
+        Ok(Response::new(row.into()))
+    }
+
+    pub async fn update_user(&self, req: UpdateUserRequest) -> ServiceResult<User> {
+        if let Some(name) = &req.name {
+            validate_name(name)?;
+        }
+        if let Some(email) = &req.email {
+            validate_email(email)?;
+        }
+        let row: Option<UserRow> = sqlx::query_as(&format!(
+            "UPDATE users SET name = coalesce($2, name), email = coalesce($3, email) WHERE id = $1 RETURNING {}",
+            USER_COLUMNS
+        ))
+        .bind(to_db_id(req.id)?)
+        .bind(&req.name)
+        .bind(&req.email)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+        found(row, req.id)
+    }
+
+    pub async fn delete_user(&self, req: DeleteUserRequest) -> ServiceResult<User> {
+        let row: Option<UserRow> = sqlx::query_as(&format!(
+            "DELETE FROM users WHERE id = $1 RETURNING {}",
+            USER_COLUMNS
+        ))
+        .bind(to_db_id(req.id)?)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(db_error)?;
+        found(row, req.id)
+    }
+
+    // 按 id 做 keyset 分页 page_token 为上一页最后一个用户的 id
+    pub async fn list_users(&self, req: ListUsersRequest) -> ServiceResult<ListUsersResponse> {
+        let page_size = match req.page_size {
+            0 => DEFAULT_PAGE_SIZE,
+            n => n.min(MAX_PAGE_SIZE),
+        };
+        let after_id: i64 = match req.page_token.as_str() {
+            "" => 0,
+            token => token
+                .parse()
+                .map_err(|_| Status::invalid_argument("Invalid page token"))?,
+        };
+        let created_after = req
+            .created_after
+            .as_ref()
+            .map(ToDateTime::to_datetime)
+            .transpose()?;
+        let created_before = req
+            .created_before
+            .as_ref()
+            .map(ToDateTime::to_datetime)
+            .transpose()?;
+
+        // 多查询一行 用于判断是否还有下一页
+        let mut rows: Vec<UserRow> = sqlx::query_as(&format!(
+            "SELECT {} FROM users WHERE id > $1 AND ($2::timestamptz IS NULL OR created_at >= $2) AND ($3::timestamptz IS NULL OR created_at < $3) ORDER BY id LIMIT $4",
+            USER_COLUMNS
+        ))
+        .bind(after_id)
+        .bind(created_after)
+        .bind(created_before)
+        .bind(page_size as i64 + 1)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(db_error)?;
+
+        let next_page_token = if rows.len() > page_size as usize {
+            rows.truncate(page_size as usize);
+            rows.last().map(|r| r.id.to_string()).unwrap_or_default()
+        } else {
+            String::new()
+        };
+
+        Ok(Response::new(ListUsersResponse {
+            users: rows.into_iter().map(User::from).collect(),
+            next_page_token,
+        }))
+    }
+}
+
+// 能从连接池取得连接时可用
+impl HealthCheck for UserServer {
+    async fn check(&self) -> Result<()> {
+        self.pool.acquire().await?;
+        Ok(())
+    }
+}
+
+impl From<UserRow> for User {
+    fn from(row: UserRow) -> Self {
+        Self {
+            id: row.id as u64,
+            name: row.name,
+            email: row.email,
+            created_at: Some(row.created_at.to_timestamp()),
+        }
+    }
+}
+
+fn found(row: Option<UserRow>, id: u64) -> ServiceResult<User> {
+    row.map(|row| Response::new(row.into()))
+        .ok_or_else(|| Status::not_found(format!("User not found: {}", id)))
+}
+
+fn to_db_id(id: u64) -> Result<i64, Status> {
+    i64::try_from(id).map_err(|_| Status::invalid_argument(format!("Invalid user id: {}", id)))
+}
+
+fn validate_name(name: &str) -> Result<(), Status> {
+    if name.is_empty() || name.chars().count() > 64 {
+        return Err(Status::invalid_argument(
+            "Name must be between 1 and 64 characters",
+        ));
+    }
+    Ok(())
+}
+
+fn validate_email(email: &str) -> Result<(), Status> {
+    if email.len() > 128 || !email.contains('@') {
+        return Err(Status::invalid_argument(format!(
+            "Invalid email: {}",
+            email
+        )));
+    }
+    Ok(())
+}
+
+fn db_error(e: sqlx::Error) -> Status {
+    let code = e
+        .as_database_error()
+        .and_then(|e| e.code())
+        .map(|c| c.into_owned());
+    match code.as_deref() {
+        // unique_violation: email 已被使用
+        Some("23505") => Status::already_exists("Email already exists"),
+        _ => {
+            warn!("Failed to access users: {:?}", e);
+            Status::internal("Failed to access users")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crm_core::ConfigLoader;
+    use sqlx_db_tester::TestPg;
+    use std::path::Path;
+    use tonic::Code;
+
+    async fn new_for_test() -> (TestPg, UserServer) {
+        let config = AppConfig::load().expect("Failed Load config");
+        let post = config.server.db_url.rfind('/').expect("invalid db_url");
+        let server_url = config.server.db_url[..post].to_string();
+        let migrations = Path::new(env!("CARGO_MANIFEST_DIR")).join("migrations");
+        let tdb = TestPg::new(server_url, migrations);
+        let pool = tdb.get_pool().await;
+        (tdb, UserServer::new(pool))
+    }
+
+    fn create(name: &str, email: &str) -> CreateUserRequest {
+        CreateUserRequest {
+            name: name.to_string(),
+            email: email.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn user_crud_should_work() -> anyhow::Result<()> {
+        let (_tdb, svc) = new_for_test().await;
+        let user = svc
+            .create_user(create("Tom", "tom@163.com"))
+            .await?
+            .into_inner();
+        assert!(user.id > 0);
+        assert!(user.created_at.is_some());
+
+        let err = svc
+            .create_user(create("Tom2", "tom@163.com"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Code::AlreadyExists);
+
+        let got = svc
+            .get_user(GetUserRequest { id: user.id })
+            .await?
+            .into_inner();
+        assert_eq!(got, user);
+
+        let updated = svc
+            .update_user(UpdateUserRequest {
+                id: user.id,
+                name: Some("Tommy".to_string()),
+                email: None,
+            })
+            .await?
+            .into_inner();
+        assert_eq!(updated.name, "Tommy");
+        assert_eq!(updated.email, user.email);
+
+        let deleted = svc
+            .delete_user(DeleteUserRequest { id: user.id })
+            .await?
+            .into_inner();
+        assert_eq!(deleted, updated);
+
+        let err = svc
+            .get_user(GetUserRequest { id: user.id })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Code::NotFound);
+        let err = svc
+            .delete_user(DeleteUserRequest { id: user.id })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Code::NotFound);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_user_should_fail() -> anyhow::Result<()> {
+        let (_tdb, svc) = new_for_test().await;
+        for req in [create("", "a@b.c"), create("Tom", "invalid")] {
+            let err = svc.create_user(req).await.unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+        }
+        let err = svc
+            .get_user(GetUserRequest { id: u64::MAX })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Code::InvalidArgument);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn list_users_should_work() -> anyhow::Result<()> {
+        let (_tdb, svc) = new_for_test().await;
+        for i in 0..5 {
+            svc.create_user(create(&format!("user{}", i), &format!("user{}@a.com", i)))
+                .await?;
+        }
+
+        let first = svc
+            .list_users(ListUsersRequest {
+                page_size: 3,
+                ..Default::default()
+            })
+            .await?
+            .into_inner();
+        assert_eq!(first.users.len(), 3);
+        assert!(!first.next_page_token.is_empty());
+
+        let second = svc
+            .list_users(ListUsersRequest {
+                page_size: 3,
+                page_token: first.next_page_token,
+                ..Default::default()
+            })
+            .await?
+            .into_inner();
+        assert_eq!(second.users.len(), 2);
+        assert!(second.next_page_token.is_empty());
+        assert!(first.users[2].id < second.users[0].id);
+
+        // 所有用户都在当前时间之前注册
+        let before = first.users[0].created_at;
+        let empty = svc
+            .list_users(ListUsersRequest {
+                created_before: before,
+                ..Default::default()
+            })
+            .await?
+            .into_inner();
+        assert!(empty.users.is_empty());
+
+        let err = svc
+            .list_users(ListUsersRequest {
+                page_token: "invalid".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Code::InvalidArgument);
+        Ok(())
+    }
+}
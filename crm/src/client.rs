@@ -1,16 +1,61 @@
 use anyhow::Result;
+use crm::pb::crm_client::CrmClient;
 use crm::pb::user_service_client::UserServiceClient;
-use crm::pb::CreateUserRequest;
+use crm::pb::{CreateUserRequest, WelcomeRequest};
+use crm_core::{
+    auth::{bearer, AUTHORIZATION},
+    tls::connect,
+    ClientTls,
+};
+use futures::StreamExt;
+use std::env;
 use tonic::Request;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let mut client = UserServiceClient::connect("http://[::1]:50051").await?;
-    let request = Request::new(CreateUserRequest {
-        name: "Tom".to_string(),
-        email: "tom@163.com".to_string(),
+    // 服务端需要 EdDSA 签名的 token
+    let token = env::var("CRM_TOKEN")?;
+    // 设置 CRM_TLS_CA 时使用 TLS 同时设置 CRM_TLS_CERT 与 CRM_TLS_KEY 时使用 mTLS
+    let tls = env::var("CRM_TLS_CA").ok().map(|ca| ClientTls {
+        ca: ca.into(),
+        cert: env::var("CRM_TLS_CERT").ok().map(Into::into),
+        key: env::var("CRM_TLS_KEY").ok().map(Into::into),
+        domain: None,
     });
+    let url = match tls {
+        Some(_) => "https://[::1]:50000",
+        None => "http://[::1]:50000",
+    };
+    let channel = connect(url, tls.as_ref()).await?;
+
+    let mut client = UserServiceClient::new(channel.clone());
+    let request = with_auth(
+        CreateUserRequest {
+            name: "Tom".to_string(),
+            email: "tom@163.com".to_string(),
+        },
+        &token,
+    )?;
     let user = client.create_user(request).await?.into_inner();
     println!("Returned user={:?}", user);
+
+    let mut client = CrmClient::new(channel);
+    let request = with_auth(
+        WelcomeRequest {
+            interval: 90,
+            content_ids: vec![1, 2, 3],
+        },
+        &token,
+    )?;
+    let mut stream = client.welcome(request).await?.into_inner();
+    while let Some(res) = stream.next().await {
+        println!("Welcome result={:?}", res?);
+    }
     Ok(())
 }
+
+fn with_auth<T>(msg: T, token: &str) -> Result<Request<T>> {
+    let mut req = Request::new(msg);
+    req.metadata_mut().insert(AUTHORIZATION, bearer(token)?);
+    Ok(req)
+}
@@ -0,0 +1,102 @@
+use crm_core::{
+    config::{field, Issues, Validate},
+    AuthConfig, ClientTls, ConfigLoader, MetricsConfig, PolicyConfig, ServerTls, TracingConfig,
+};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+
+/// 服务配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppConfig {
+    // 服务器相关
+    pub server: ServerConfig,
+    // 身份认证相关
+    pub auth: AuthConfig,
+    // 按方法授权相关
+    #[serde(default)]
+    pub policy: PolicyConfig,
+    // 配置后提供 /metrics
+    #[serde(default)]
+    pub metrics: Option<MetricsConfig>,
+    // 配置后通过 OTLP 导出 trace
+    #[serde(default)]
+    pub tracing: Option<TracingConfig>,
+}
+
+// 服务配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerConfig {
+    // 监听地址
+    #[serde(default = "crm_core::server::default_host")]
+    pub host: IpAddr,
+    // 监听端口
+    pub port: u16,
+    // 收到退出信号后 等待进行中请求完成的最长秒数
+    #[serde(default = "crm_core::server::default_drain_timeout")]
+    pub drain_timeout: u64,
+    // 未配置时使用明文
+    pub tls: Option<ServerTls>,
+    // 用户数据库
+    pub db_url: String,
+    // 通知的发件人
+    pub sender_email: String,
+    // 下游服务地址
+    pub metadata: String,
+    pub user_stats: String,
+    pub notification: String,
+    // 连接下游服务的 TLS 配置
+    pub client_tls: Option<ClientTls>,
+}
+
+impl ConfigLoader for AppConfig {
+    const FILE: &'static str = "crm.yml";
+    const ENV: &'static str = "CRM_CONFIG";
+    const PREFIX: &'static str = "CRM";
+}
+
+impl Validate for AppConfig {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        self.server.validate(&field(path, "server"), issues);
+        self.auth.validate(&field(path, "auth"), issues);
+        self.policy.validate(&field(path, "policy"), issues);
+        if let Some(metrics) = &self.metrics {
+            metrics.validate(&field(path, "metrics"), issues);
+        }
+        if let Some(tracing) = &self.tracing {
+            tracing.validate(&field(path, "tracing"), issues);
+        }
+    }
+}
+
+impl Validate for ServerConfig {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        issues.check(self.port != 0, field(path, "port"), "must not be 0");
+        if let Some(tls) = &self.tls {
+            tls.validate(&field(path, "tls"), issues);
+        }
+        issues.check(
+            self.db_url.starts_with("postgres://") || self.db_url.starts_with("postgresql://"),
+            field(path, "db_url"),
+            "must be a postgres:// url",
+        );
+        issues.check(
+            self.sender_email.contains('@'),
+            field(path, "sender_email"),
+            "must be an email address",
+        );
+        for (key, url) in [
+            ("metadata", &self.metadata),
+            ("user_stats", &self.user_stats),
+            ("notification", &self.notification),
+        ] {
+            issues.check(
+                url.starts_with("http://") || url.starts_with("https://"),
+                field(path, key),
+                "must be an http:// or https:// url",
+            );
+        }
+        if let Some(tls) = &self.client_tls {
+            tls.validate(&field(path, "client_tls"), issues);
+        }
+    }
+}
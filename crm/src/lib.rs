@@ -1,26 +1,117 @@
+#![allow(clippy::result_large_err)]
+
 mod abi;
+mod config;
 pub mod pb;
-use crate::pb::{user_service_server::UserService, CreateUserRequest, GetUserRequest, User};
+
+pub use config::AppConfig;
+
+use crate::pb::{
+    crm_server::Crm, user_service_server::UserService, CampaignResult, CreateUserRequest,
+    DeleteUserRequest, GetUserRequest, ListUsersRequest, ListUsersResponse, RecallRequest,
+    RemindRequest, UpdateUserRequest, User, WelcomeRequest,
+};
 use anyhow::Result;
-use tonic::{Request, Response, Status};
+use crm_core::{auth::AUTHORIZATION, Downstream};
+use crm_metadata::pb::metadata_client::MetadataClient;
+use crm_send::pb::notification_client::NotificationClient;
+use futures::Stream;
+use sqlx::PgPool;
+use std::{ops::Deref, pin::Pin, sync::Arc};
+use tonic::{metadata::AsciiMetadataValue, transport::Channel, Request, Response, Status};
+use user_stat::pb::user_stats_client::UserStatsClient;
+
+type ServiceResult<T> = Result<Response<T>, Status>;
+type ResponseStream = Pin<Box<dyn Stream<Item = Result<CampaignResult, Status>> + Send>>;
+
+/// 基于 Postgres 的用户服务
+#[derive(Clone)]
+pub struct UserServer {
+    pool: PgPool,
+}
 
-#[derive(Default)]
-pub struct UserServer {}
 #[tonic::async_trait]
 impl UserService for UserServer {
-    async fn get_user(&self, request: Request<GetUserRequest>) -> Result<Response<User>, Status> {
-        let input = request.into_inner();
-        println!("Got a request: {:?}", input);
-        Ok(Response::new(User::default()))
+    async fn get_user(&self, request: Request<GetUserRequest>) -> ServiceResult<User> {
+        let req = request.into_inner();
+        self.get_user(req).await
+    }
+
+    async fn create_user(&self, request: Request<CreateUserRequest>) -> ServiceResult<User> {
+        let req = request.into_inner();
+        self.create_user(req).await
     }
 
-    async fn create_user(
+    async fn update_user(&self, request: Request<UpdateUserRequest>) -> ServiceResult<User> {
+        let req = request.into_inner();
+        self.update_user(req).await
+    }
+
+    async fn delete_user(&self, request: Request<DeleteUserRequest>) -> ServiceResult<User> {
+        let req = request.into_inner();
+        self.delete_user(req).await
+    }
+
+    async fn list_users(
         &self,
-        request: Request<CreateUserRequest>,
-    ) -> Result<Response<User>, Status> {
-        let input = request.into_inner();
-        println!("create_user: {:?}", input);
-        let user = User::new(666, &input.name, &input.email);
-        Ok(Response::new(user))
+        request: Request<ListUsersRequest>,
+    ) -> ServiceResult<ListUsersResponse> {
+        let req = request.into_inner();
+        self.list_users(req).await
+    }
+}
+
+/// 组合 UserStats、Metadata 与 Notification 的营销服务
+#[derive(Clone)]
+pub struct CrmService {
+    inner: Arc<CrmServiceInner>,
+}
+
+pub struct CrmServiceInner {
+    config: AppConfig,
+    user_stats: UserStatsClient<Channel>,
+    metadata: MetadataClient<Channel>,
+    notification: NotificationClient<Channel>,
+    // 用于检查下游服务的健康状态
+    downstream: Vec<Downstream>,
+}
+
+#[tonic::async_trait]
+impl Crm for CrmService {
+    type WelcomeStream = ResponseStream;
+    // Welcome
+    async fn welcome(&self, request: Request<WelcomeRequest>) -> ServiceResult<ResponseStream> {
+        let auth = forward_auth(&request);
+        let req = request.into_inner();
+        self.welcome(req, auth).await
+    }
+
+    type RecallStream = ResponseStream;
+    // Recall
+    async fn recall(&self, request: Request<RecallRequest>) -> ServiceResult<ResponseStream> {
+        let auth = forward_auth(&request);
+        let req = request.into_inner();
+        self.recall(req, auth).await
+    }
+
+    type RemindStream = ResponseStream;
+    // Remind
+    async fn remind(&self, request: Request<RemindRequest>) -> ServiceResult<ResponseStream> {
+        let auth = forward_auth(&request);
+        let req = request.into_inner();
+        self.remind(req, auth).await
+    }
+}
+
+// 调用下游服务时沿用调用方的 token
+fn forward_auth<T>(request: &Request<T>) -> Option<AsciiMetadataValue> {
+    request.metadata().get(AUTHORIZATION).cloned()
+}
+
+impl Deref for CrmService {
+    type Target = CrmServiceInner;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
     }
 }
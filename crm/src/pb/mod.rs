@@ -1,2 +1,5 @@
+#[rustfmt::skip]
 mod crm;
-pub use crm::*;
+pub use self::crm::*;
+
+pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("crm");
@@ -1,16 +1,36 @@
 use anyhow::Result;
-use crm::pb::user_service_server::UserServiceServer;
-use crm::UserServer;
-use tonic::transport::Server;
+use crm::{
+    pb::{crm_server::CrmServer, user_service_server::UserServiceServer, FILE_DESCRIPTOR_SET},
+    AppConfig, CrmService, UserServer,
+};
+use crm_core::{init_tracing, metrics, Bootstrap, ConfigLoader};
+use std::{env, net::SocketAddr, time::Duration};
+use tonic::{server::NamedService, service::Routes};
+use tracing::info;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let addr = "[::1]:50051".parse()?;
-    let svc = UserServer::default();
-    println!("UserServer listening on {}", addr);
-    Server::builder()
-        .add_service(UserServiceServer::new(svc))
-        .serve(addr)
-        .await?;
-    Ok(())
+    let config = AppConfig::load_with_args(env::args().skip(1))?;
+    let _guard = init_tracing("crm", config.tracing.as_ref())?;
+    if let Some(config) = &config.metrics {
+        metrics::install(config)?;
+    }
+    let addr = SocketAddr::new(config.server.host, config.server.port);
+    let server = Bootstrap::try_new(
+        addr,
+        config.server.tls.as_ref(),
+        &config.auth,
+        &config.policy,
+    )?
+    .drain_timeout(Duration::from_secs(config.server.drain_timeout));
+    info!("CrmServer listening on {}", server.addr());
+
+    let user = UserServer::try_new(&config).await?;
+    let crm = CrmService::try_new(config).await?;
+    let server = server
+        .health(&[UserServiceServer::<UserServer>::NAME], user.clone())
+        .health(&[CrmServer::<CrmService>::NAME], crm.clone())
+        .reflection(FILE_DESCRIPTOR_SET);
+    let routes = Routes::new(user.into_server()).add_service(crm.into_server());
+    server.serve(routes).await
 }
@@ -0,0 +1,202 @@
+use anyhow::Result;
+use chrono::Utc;
+use crm::{
+    pb::{RecallRequest, RemindRequest, WelcomeRequest},
+    AppConfig, CrmService,
+};
+use crm_core::{
+    auth::{bearer, test_utils::TestKey},
+    AuthConfig, Bootstrap, ConfigLoader, HealthCheck, PolicyConfig,
+};
+use crm_metadata::MetadataService;
+use crm_send::NotificationService;
+use futures::StreamExt;
+use std::env;
+use tonic::{service::Routes, transport::server::TcpIncoming, Code};
+use user_stat::{
+    pb::{Filter, OrderBy, PageRequest, QueryRequest},
+    UserStatsService,
+};
+
+const PORT_BASE: u16 = 61000;
+
+#[tokio::test]
+async fn campaigns_should_work() -> Result<()> {
+    // 下游服务的配置文件位于各自的 crate 目录
+    env::set_var("USER_STAT_CONFIG", "../user_stat/user_stat.yml");
+    env::set_var("METADATA_CONFIG", "../crm_metadata/metadata.yml");
+    env::set_var("SEND_CONFIG", "../crm_send/send.yml");
+
+    let (_tdb, stats) = UserStatsService::new_for_test().await?;
+    // 下游服务校验 token crm 转发调用方的 token
+    let key = TestKey::new();
+    let svc = start_crm(stats.clone(), &key, PORT_BASE).await?;
+    // 下游服务均可用
+    svc.check().await?;
+    let auth = Some(bearer(&key.token_with_roles("crm", &["marketer"]))?);
+
+    let (email, interval) = pick(&stats, "created_at", None).await?;
+    let results = svc
+        .welcome(
+            WelcomeRequest {
+                interval,
+                content_ids: vec![1, 2, 3],
+            },
+            auth.clone(),
+        )
+        .await?
+        .into_inner()
+        .collect::<Vec<_>>()
+        .await;
+    let results = results.into_iter().collect::<Result<Vec<_>, _>>()?;
+    assert!(results.iter().any(|r| r.email == email));
+    assert!(results
+        .iter()
+        .all(|r| r.error.is_empty() && !r.message_id.is_empty()));
+
+    let viewed = Filter::length("viewed_but_not_started", Some(1), None);
+    let (email, interval) = pick(&stats, "last_visited_at", Some(viewed)).await?;
+    let results = svc
+        .recall(
+            RecallRequest {
+                last_visit_interval: interval,
+            },
+            auth.clone(),
+        )
+        .await?
+        .into_inner()
+        .collect::<Vec<_>>()
+        .await;
+    assert!(results.iter().any(|r| r.as_ref().unwrap().email == email));
+
+    let started = Filter::length("started_but_not_finished", Some(1), None);
+    let (email, interval) = pick(&stats, "last_visited_at", Some(started)).await?;
+    let results = svc
+        .remind(
+            RemindRequest {
+                last_visit_interval: interval,
+            },
+            auth.clone(),
+        )
+        .await?
+        .into_inner()
+        .collect::<Vec<_>>()
+        .await;
+    assert!(results.iter().any(|r| r.as_ref().unwrap().email == email));
+
+    let err = svc
+        .welcome(WelcomeRequest::default(), auth)
+        .await
+        .err()
+        .unwrap();
+    assert_eq!(err.code(), Code::InvalidArgument);
+
+    // 没有 token 时下游服务拒绝请求
+    let req = RemindRequest {
+        last_visit_interval: interval,
+    };
+    let results = svc
+        .remind(req, None)
+        .await?
+        .into_inner()
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(results.len(), 1);
+    assert_eq!(
+        results[0].as_ref().unwrap_err().code(),
+        Code::Unauthenticated
+    );
+
+    // 没有 marketer 角色时 Notification 拒绝发送
+    let req = RemindRequest {
+        last_visit_interval: interval,
+    };
+    let results = svc
+        .remind(req, Some(bearer(&key.token("crm"))?))
+        .await?
+        .into_inner()
+        .collect::<Vec<_>>()
+        .await;
+    assert_eq!(results.len(), 1);
+    let err = results[0].as_ref().unwrap_err();
+    assert_eq!(err.code(), Code::PermissionDenied);
+    assert_eq!(err.message(), "Missing permission: notification.send");
+    Ok(())
+}
+
+// 选出一个满足条件的用户 并计算其对应列距今的天数
+async fn pick(
+    stats: &UserStatsService,
+    column: &str,
+    filter: Option<Filter>,
+) -> Result<(String, u32)> {
+    let page = stats
+        .query_page(PageRequest {
+            query: Some(QueryRequest {
+                filter,
+                ..Default::default()
+            }),
+            order_by: Some(OrderBy {
+                column: column.to_string(),
+                desc: true,
+            }),
+            limit: 1,
+            ..Default::default()
+        })
+        .await?
+        .into_inner();
+    let user = &page.users[0];
+    let ts = match column {
+        "created_at" => user.created_at,
+        _ => user.last_visited_at,
+    }
+    .unwrap();
+    let days = (Utc::now().timestamp() - ts.seconds) / 86400;
+    Ok((user.email.clone(), days as u32))
+}
+
+// 启动下游服务 并创建连接它们的 CrmService
+async fn start_crm(stats: UserStatsService, key: &TestKey, port: u16) -> Result<CrmService> {
+    let auth = key.config();
+    let stats_policy = user_stat::AppConfig::load()?.policy;
+    let metadata_config = crm_metadata::config::AppConfig::load()?;
+    let metadata_policy = metadata_config.policy.clone();
+    let metadata = MetadataService::new(metadata_config);
+    let send_config = crm_send::AppConfig::load()?;
+    let send_policy = send_config.policy.clone();
+    let notification = NotificationService::try_new(send_config)?;
+
+    let mut config = AppConfig::load()?;
+    config.server.user_stats = serve(
+        Routes::new(stats.into_server()),
+        &auth,
+        &stats_policy,
+        port + 1,
+    )?;
+    config.server.metadata = serve(
+        Routes::new(metadata.into_service()),
+        &auth,
+        &metadata_policy,
+        port + 2,
+    )?;
+    config.server.notification = serve(
+        Routes::new(notification.into_server()),
+        &auth,
+        &send_policy,
+        port + 3,
+    )?;
+
+    CrmService::try_new(config).await
+}
+
+fn serve(routes: Routes, auth: &AuthConfig, policy: &PolicyConfig, port: u16) -> Result<String> {
+    let addr = format!("[::1]:{}", port).parse()?;
+    let server = Bootstrap::try_new(addr, None, auth, policy)?;
+    let addr = server.addr();
+    // 先绑定端口 再启动服务 避免客户端连接时服务尚未就绪
+    let incoming = TcpIncoming::new(addr, true, None).map_err(|e| anyhow::anyhow!(e))?;
+    tokio::spawn(async move {
+        server.serve_with_incoming(routes, incoming).await.unwrap();
+    });
+    Ok(format!("http://{}", addr))
+}
@@ -0,0 +1,385 @@
+use crate::{
+    config::{field, Issues, Validate},
+    server::split_method,
+};
+use anyhow::Result;
+use futures::future::{ready, Either, Ready};
+use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tonic::{
+    metadata::{AsciiMetadataValue, MetadataMap},
+    service::Interceptor,
+    Request, Status,
+};
+use tower::{Layer, Service};
+use tracing::warn;
+
+// 携带 token 的 metadata 键
+pub const AUTHORIZATION: &str = "authorization";
+const BEARER: &str = "Bearer ";
+// 无需认证的服务 供负载均衡器探活以及 grpcurl 等工具使用
+const PUBLIC_SERVICES: &[&str] = &[
+    "grpc.health.v1.Health",
+    "grpc.reflection.v1.ServerReflection",
+    "grpc.reflection.v1alpha.ServerReflection",
+];
+
+/// 身份认证配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthConfig {
+    // Ed25519 公钥 PEM 用于校验 token 签名
+    pub pk: String,
+    // token 的 aud 必须包含此值
+    #[serde(default = "default_aud")]
+    pub aud: String,
+}
+
+/// token 中的声明 校验通过后放入 request extensions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Claims {
+    // 调用方身份
+    pub sub: String,
+    // 单个字符串或字符串数组 都转换为数组
+    #[serde(deserialize_with = "one_or_many")]
+    pub aud: Vec<String>,
+    // 过期时间 unix 秒
+    pub exp: u64,
+    // 调用方的角色 用于按方法授权
+    #[serde(default)]
+    pub roles: Vec<String>,
+}
+
+/// 校验 EdDSA 签名的 JWT
+#[derive(Clone)]
+pub struct AuthInterceptor {
+    inner: Arc<AuthInterceptorInner>,
+}
+
+struct AuthInterceptorInner {
+    key: DecodingKey,
+    validation: Validation,
+}
+
+#[derive(Clone)]
+pub struct AuthService<S> {
+    inner: S,
+    auth: AuthInterceptor,
+}
+
+impl AuthInterceptor {
+    pub fn try_new(config: &AuthConfig) -> Result<Self> {
+        let key = DecodingKey::from_ed_pem(config.pk.as_bytes())?;
+        let mut validation = Validation::new(Algorithm::EdDSA);
+        validation.set_audience(&[&config.aud]);
+        validation.set_required_spec_claims(&["exp", "aud", "sub"]);
+
+        Ok(Self {
+            inner: Arc::new(AuthInterceptorInner { key, validation }),
+        })
+    }
+
+    // 校验 token 返回其中的声明
+    pub fn verify(&self, token: &str) -> Result<Claims, Status> {
+        decode::<Claims>(token, &self.inner.key, &self.inner.validation)
+            .map(|data| data.claims)
+            .map_err(|e| {
+                warn!("Failed to verify token: {}", e);
+                Status::unauthenticated(format!("Invalid token: {}", e))
+            })
+    }
+}
+
+impl Validate for AuthConfig {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        if let Err(e) = DecodingKey::from_ed_pem(self.pk.as_bytes()) {
+            issues.push(
+                field(path, "pk"),
+                format!("invalid Ed25519 public key: {}", e),
+            );
+        }
+        issues.check(
+            !self.aud.is_empty(),
+            field(path, "aud"),
+            "must not be empty",
+        );
+    }
+}
+
+impl Interceptor for AuthInterceptor {
+    fn call(&mut self, mut req: Request<()>) -> Result<Request<()>, Status> {
+        let token = bearer_token(req.metadata())?;
+        let claims = self.verify(token)?;
+        req.extensions_mut().insert(claims);
+        Ok(req)
+    }
+}
+
+// 除 PUBLIC_SERVICES 外的请求都需要携带有效的 token
+impl<S> Layer<S> for AuthInterceptor {
+    type Service = AuthService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        AuthService {
+            inner,
+            auth: self.clone(),
+        }
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for AuthService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+    ResBody: Default,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Either<Ready<Result<S::Response, S::Error>>, S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: http::Request<ReqBody>) -> Self::Future {
+        if is_public(req.uri().path()) {
+            return Either::Right(self.inner.call(req));
+        }
+        let token = strip_bearer(req.headers().get(AUTHORIZATION).map(|v| v.to_str()));
+        match token.and_then(|token| self.auth.verify(token)) {
+            Ok(claims) => {
+                req.extensions_mut().insert(claims);
+                Either::Right(self.inner.call(req))
+            }
+            Err(status) => {
+                let res = status.into_http().map(|_| ResBody::default());
+                Either::Left(ready(Ok(res)))
+            }
+        }
+    }
+}
+
+/// 从 metadata 中取出 Bearer token
+pub fn bearer_token(metadata: &MetadataMap) -> Result<&str, Status> {
+    strip_bearer(metadata.get(AUTHORIZATION).map(|v| v.to_str()))
+}
+
+fn strip_bearer<E>(value: Option<Result<&str, E>>) -> Result<&str, Status> {
+    value
+        .and_then(|v| v.ok())
+        .and_then(|v| v.strip_prefix(BEARER))
+        .ok_or_else(|| Status::unauthenticated("Missing bearer token"))
+}
+
+fn is_public(path: &str) -> bool {
+    split_method(path).is_some_and(|(service, _)| PUBLIC_SERVICES.contains(&service))
+}
+
+/// 生成 authorization metadata 的值
+pub fn bearer(token: &str) -> Result<AsciiMetadataValue, Status> {
+    format!("{}{}", BEARER, token)
+        .parse()
+        .map_err(|_| Status::invalid_argument("Invalid token"))
+}
+
+fn default_aud() -> String {
+    "crm".to_string()
+}
+
+fn one_or_many<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(one) => vec![one],
+        OneOrMany::Many(many) => many,
+    })
+}
+
+#[cfg(feature = "test_utils")]
+pub mod test_utils {
+    use super::{AuthConfig, Claims};
+    use ed25519_dalek::{
+        pkcs8::{spki::der::pem::LineEnding, EncodePrivateKey, EncodePublicKey},
+        SigningKey,
+    };
+    use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+    use serde::Serialize;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    /// 测试使用的临时密钥对
+    pub struct TestKey {
+        key: EncodingKey,
+        pk: String,
+    }
+
+    impl TestKey {
+        pub fn new() -> Self {
+            let signing = SigningKey::generate(&mut rand::rngs::OsRng);
+            let sk = signing
+                .to_pkcs8_pem(LineEnding::LF)
+                .expect("encode private key");
+            let pk = signing
+                .verifying_key()
+                .to_public_key_pem(LineEnding::LF)
+                .expect("encode public key");
+            Self {
+                key: EncodingKey::from_ed_pem(sk.as_bytes()).expect("load private key"),
+                pk,
+            }
+        }
+
+        // 使用此公钥校验的配置
+        pub fn config(&self) -> AuthConfig {
+            AuthConfig {
+                pk: self.pk.clone(),
+                aud: "crm".to_string(),
+            }
+        }
+
+        pub fn sign(&self, claims: &impl Serialize) -> String {
+            encode(&Header::new(Algorithm::EdDSA), claims, &self.key).expect("sign token")
+        }
+
+        // 签发一个一小时后过期的 token
+        pub fn token(&self, sub: &str) -> String {
+            self.token_with_roles(sub, &[])
+        }
+
+        pub fn token_with_roles(&self, sub: &str, roles: &[&str]) -> String {
+            self.sign(&Claims {
+                sub: sub.to_string(),
+                aud: vec!["crm".to_string()],
+                exp: now() + 3600,
+                roles: roles.iter().map(|r| r.to_string()).collect(),
+            })
+        }
+    }
+
+    impl Default for TestKey {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    pub fn now() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{test_utils::*, *};
+    use tonic::Code;
+
+    fn request(token: Option<&str>) -> Request<()> {
+        let mut req = Request::new(());
+        if let Some(token) = token {
+            req.metadata_mut()
+                .insert(AUTHORIZATION, bearer(token).unwrap());
+        }
+        req
+    }
+
+    #[test]
+    fn valid_token_should_pass() {
+        let key = TestKey::new();
+        let mut auth = AuthInterceptor::try_new(&key.config()).unwrap();
+        let token = key.token_with_roles("alice", &["admin"]);
+        let req = auth.call(request(Some(&token))).unwrap();
+        let claims = req.extensions().get::<Claims>().unwrap();
+        assert_eq!(claims.sub, "alice");
+        assert_eq!(claims.roles, ["admin"]);
+    }
+
+    #[test]
+    fn aud_should_be_string_or_array() {
+        let key = TestKey::new();
+        let auth = AuthInterceptor::try_new(&key.config()).unwrap();
+
+        let many = key.sign(&Claims {
+            sub: "alice".to_string(),
+            aud: vec!["other".to_string(), "crm".to_string()],
+            exp: now() + 3600,
+            roles: vec![],
+        });
+        assert_eq!(auth.verify(&many).unwrap().aud, ["other", "crm"]);
+
+        #[derive(Serialize)]
+        struct One {
+            sub: &'static str,
+            aud: &'static str,
+            exp: u64,
+        }
+        let one = key.sign(&One {
+            sub: "alice",
+            aud: "crm",
+            exp: now() + 3600,
+        });
+        assert_eq!(auth.verify(&one).unwrap().aud, ["crm"]);
+    }
+
+    #[test]
+    fn invalid_token_should_fail() {
+        let key = TestKey::new();
+        let mut auth = AuthInterceptor::try_new(&key.config()).unwrap();
+
+        let expired = key.sign(&Claims {
+            sub: "alice".to_string(),
+            aud: vec!["crm".to_string()],
+            exp: now() - 3600,
+            roles: vec![],
+        });
+        let wrong_aud = key.sign(&Claims {
+            sub: "alice".to_string(),
+            aud: vec!["other".to_string()],
+            exp: now() + 3600,
+            roles: vec![],
+        });
+        let other_key = TestKey::new().token("alice");
+        for token in [
+            None,
+            Some("not a jwt"),
+            Some(expired.as_str()),
+            Some(wrong_aud.as_str()),
+            Some(other_key.as_str()),
+        ] {
+            let err = auth.call(request(token)).unwrap_err();
+            assert_eq!(err.code(), Code::Unauthenticated);
+        }
+
+        let mut req = Request::new(());
+        req.metadata_mut()
+            .insert(AUTHORIZATION, key.token("alice").parse().unwrap());
+        let err = auth.call(req).unwrap_err();
+        assert_eq!(err.code(), Code::Unauthenticated);
+    }
+
+    #[test]
+    fn only_probe_services_should_be_public() {
+        assert!(is_public("/grpc.health.v1.Health/Check"));
+        assert!(is_public(
+            "/grpc.reflection.v1.ServerReflection/ServerReflectionInfo"
+        ));
+        assert!(!is_public("/metadata.Metadata/Materialize"));
+        assert!(!is_public("/grpc.health.v1.Health.Fake/Check"));
+    }
+
+    #[test]
+    fn configured_key_should_load() {
+        let config = AuthConfig {
+            pk: "-----BEGIN PUBLIC KEY-----\nMCowBQYDK2VwAyEAfM+lwNHj6TRJ3EGP38lIJcOo9Dlt2u2JzcwWMbu7jQY=\n-----END PUBLIC KEY-----\n".to_string(),
+            aud: default_aud(),
+        };
+        assert!(AuthInterceptor::try_new(&config).is_ok());
+    }
+}
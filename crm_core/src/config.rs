@@ -0,0 +1,468 @@
+use anyhow::{bail, Result};
+use serde::{
+    de::{
+        value::{MapDeserializer, SeqDeserializer},
+        DeserializeOwned, IntoDeserializer, Visitor,
+    },
+    forward_to_deserialize_any, Deserializer,
+};
+use serde_yaml::{Mapping, Value};
+use std::{collections::HashMap, env, fmt::Write as _, fs, path::Path};
+
+// 部署时挂载配置文件的目录
+const CONFIG_DIR: &str = "/etc/config";
+// 环境变量中嵌套字段的分隔符 如 USER_STAT__SERVER__PORT
+const ENV_SEPARATOR: &str = "__";
+
+/// 分层加载配置 后面的层覆盖前面的层
+/// 默认值 < 配置文件 < 带前缀的环境变量 < 命令行参数
+pub trait ConfigLoader: DeserializeOwned + Validate {
+    // 配置文件名 依次查找当前目录、/etc/config 以及 ENV 指定的路径
+    const FILE: &'static str;
+    // 指定配置文件路径的环境变量
+    const ENV: &'static str;
+    // 覆盖单个字段的环境变量前缀
+    const PREFIX: &'static str;
+
+    fn load() -> Result<Self> {
+        Self::load_from(env::vars(), Vec::new())
+    }
+
+    // 在 load 的基础上使用命令行参数覆盖 如 --server.port=50001
+    fn load_with_args(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        Self::load_from(env::vars(), args)
+    }
+
+    fn load_from(
+        vars: impl IntoIterator<Item = (String, String)>,
+        args: impl IntoIterator<Item = String>,
+    ) -> Result<Self> {
+        let vars: Vec<_> = vars.into_iter().collect();
+        let mut layers = Layers::default();
+        layers.file(Self::FILE, Self::ENV, &vars)?;
+        layers.env(Self::PREFIX, &vars);
+        layers.args(args)?;
+        layers.build()
+    }
+}
+
+/// 校验反序列化后的配置 将发现的问题全部写入 issues
+pub trait Validate {
+    // path 为当前字段在配置中的路径 如 server.tls
+    fn validate(&self, path: &str, issues: &mut Issues);
+}
+
+/// 配置中的问题 以字段路径标识
+#[derive(Debug, Default)]
+pub struct Issues(Vec<(String, String)>);
+
+impl Issues {
+    pub fn push(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.0.push((path.into(), message.into()));
+    }
+
+    pub fn check(&mut self, ok: bool, path: impl Into<String>, message: impl Into<String>) {
+        if !ok {
+            self.push(path, message);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+// 拼接字段路径
+pub fn field(path: &str, key: &str) -> String {
+    match path {
+        "" => key.to_string(),
+        _ => format!("{}.{}", path, key),
+    }
+}
+
+/// 合并后的配置 以及每个字段的来源
+#[derive(Default)]
+struct Layers {
+    value: Value,
+    sources: HashMap<String, String>,
+    // 找到的配置文件
+    file: Option<String>,
+    // 未找到配置文件时查找过的位置
+    searched: Vec<String>,
+}
+
+impl Layers {
+    fn file(&mut self, name: &str, env: &str, vars: &[(String, String)]) -> Result<()> {
+        let mut candidates = vec![
+            name.to_string(),
+            Path::new(CONFIG_DIR).join(name).display().to_string(),
+        ];
+        match vars.iter().find(|(k, _)| k == env) {
+            Some((_, path)) => candidates.push(path.clone()),
+            None => self.searched.push(format!("${} (unset)", env)),
+        }
+
+        let Some(path) = candidates.iter().find(|p| Path::new(p).is_file()) else {
+            self.searched.splice(0..0, candidates);
+            return Ok(());
+        };
+        let value: Value = match serde_yaml::from_str(&fs::read_to_string(path)?) {
+            Ok(value) => value,
+            Err(e) => bail!("Failed to parse config file {}: {}", path, e),
+        };
+        self.set(&[], value, &format!("file {}", path));
+        self.file = Some(path.clone());
+        Ok(())
+    }
+
+    fn env(&mut self, prefix: &str, vars: &[(String, String)]) {
+        let prefix = format!("{}{}", prefix, ENV_SEPARATOR);
+        for (key, value) in vars {
+            let Some(name) = key.strip_prefix(&prefix) else {
+                continue;
+            };
+            let path: Vec<_> = name
+                .split(ENV_SEPARATOR)
+                .map(|s| s.to_lowercase())
+                .collect();
+            self.set(&path, Value::String(value.clone()), &format!("env {}", key));
+        }
+    }
+
+    fn args(&mut self, args: impl IntoIterator<Item = String>) -> Result<()> {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let Some(flag) = arg.strip_prefix("--") else {
+                bail!("Unexpected argument: {}", arg);
+            };
+            let (key, value) = match flag.split_once('=') {
+                Some((key, value)) => (key.to_string(), value.to_string()),
+                None => match args.next() {
+                    Some(value) => (flag.to_string(), value),
+                    None => bail!("Missing value for argument: {}", arg),
+                },
+            };
+            let path: Vec<_> = key.split('.').map(|s| s.to_string()).collect();
+            self.set(&path, Value::String(value), &format!("flag --{}", key));
+        }
+        Ok(())
+    }
+
+    // 将 value 写入 path 并记录其中每个字段的来源
+    fn set(&mut self, path: &[String], value: Value, source: &str) {
+        let key = path.join(".");
+        self.sources
+            .retain(|k, _| !(key.is_empty() || *k == key || k.starts_with(&format!("{}.", key))));
+        record(&key, &value, source, &mut self.sources);
+
+        let mut node = &mut self.value;
+        for segment in path {
+            if !node.is_mapping() {
+                *node = Value::Mapping(Mapping::new());
+            }
+            let map = node.as_mapping_mut().expect("mapping");
+            node = map
+                .entry(Value::String(segment.clone()))
+                .or_insert(Value::Null);
+        }
+        *node = value;
+    }
+
+    fn build<T: DeserializeOwned + Validate>(mut self) -> Result<T> {
+        let mut issues = Issues::default();
+        let mut unknown = Vec::new();
+        let value = match std::mem::take(&mut self.value) {
+            Value::Null => Value::Mapping(Mapping::new()),
+            value => value,
+        };
+        let mut track = |path: serde_ignored::Path| unknown.push(path.to_string());
+        let de = serde_ignored::Deserializer::new(Coerce(value), &mut track);
+        let ret: Result<T, _> = serde_path_to_error::deserialize(de);
+        for path in unknown {
+            issues.push(path, "unknown key");
+        }
+        match ret {
+            Ok(config) => {
+                config.validate("", &mut issues);
+                if issues.is_empty() {
+                    return Ok(config);
+                }
+            }
+            Err(e) => issues.push(e.path().to_string(), e.into_inner().to_string()),
+        }
+
+        let mut report = String::from("Invalid config:");
+        for (path, message) in &issues.0 {
+            let _ = write!(report, "\n  {} ({}): {}", path, self.source(path), message);
+        }
+        if self.file.is_none() {
+            let _ = write!(
+                report,
+                "\n  no config file found in {}",
+                self.searched.join(", ")
+            );
+        }
+        bail!(report)
+    }
+
+    // 字段的来源 取自该字段或最近的上级字段 都没有时为配置文件或默认值
+    fn source(&self, path: &str) -> String {
+        let mut key = path;
+        loop {
+            if let Some(source) = self.sources.get(key) {
+                return source.clone();
+            }
+            match key.rsplit_once('.') {
+                Some((parent, _)) => key = parent,
+                None => break,
+            }
+        }
+        match &self.file {
+            Some(file) => format!("file {}", file),
+            None => "default".to_string(),
+        }
+    }
+}
+
+// 记录 value 中每个叶子字段的来源
+fn record(path: &str, value: &Value, source: &str, sources: &mut HashMap<String, String>) {
+    match value.as_mapping() {
+        Some(map) if !map.is_empty() => {
+            for (k, v) in map {
+                if let Some(k) = k.as_str() {
+                    record(&field(path, k), v, source, sources);
+                }
+            }
+        }
+        _ => {
+            sources.insert(path.to_string(), source.to_string());
+        }
+    }
+}
+
+/// 环境变量与命令行参数的值都是字符串 保持原样
+/// 仅在目标类型为数字或布尔值时按 yaml 标量解析
+struct Coerce(Value);
+
+impl Coerce {
+    // 解析失败时保持字符串 由 serde 报告类型错误
+    fn scalar(self) -> Value {
+        match self.0 {
+            Value::String(s) => match serde_yaml::from_str(&s) {
+                Ok(v @ (Value::Bool(_) | Value::Number(_))) => v,
+                _ => Value::String(s),
+            },
+            v => v,
+        }
+    }
+}
+
+impl<'de> IntoDeserializer<'de, serde_yaml::Error> for Coerce {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+macro_rules! coerce_scalar {
+    ($($method:ident)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                self.scalar().$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for Coerce {
+    type Error = serde_yaml::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Mapping(map) => {
+                let mut map =
+                    MapDeserializer::new(map.into_iter().map(|(k, v)| (Coerce(k), Coerce(v))));
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+            Value::Sequence(seq) => {
+                let mut seq = SeqDeserializer::new(seq.into_iter().map(Coerce));
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            value => visitor.visit_some(Coerce(value)),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0.deserialize_enum(name, variants, visitor)
+    }
+
+    coerce_scalar! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_u128 deserialize_f32 deserialize_f64
+    }
+
+    forward_to_deserialize_any! {
+        char str string bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::io::Write as _;
+
+    #[derive(Debug, Deserialize)]
+    struct TestConfig {
+        server: TestServer,
+        #[serde(default = "default_name")]
+        name: String,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct TestServer {
+        port: u16,
+        host: String,
+        #[serde(default)]
+        debug: bool,
+    }
+
+    impl ConfigLoader for TestConfig {
+        const FILE: &'static str = "not_exists.yml";
+        const ENV: &'static str = "TEST_CONFIG";
+        const PREFIX: &'static str = "TEST";
+    }
+
+    impl Validate for TestConfig {
+        fn validate(&self, path: &str, issues: &mut Issues) {
+            let server = field(path, "server");
+            issues.check(
+                self.server.port != 0,
+                field(&server, "port"),
+                "must not be 0",
+            );
+            issues.check(
+                !self.server.host.is_empty(),
+                field(&server, "host"),
+                "must not be empty",
+            );
+        }
+    }
+
+    fn default_name() -> String {
+        "default".to_string()
+    }
+
+    fn config_file(content: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        file
+    }
+
+    fn env_vars(file: &tempfile::NamedTempFile, vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        let path = file.path().display().to_string();
+        vars.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .chain([("TEST_CONFIG".to_string(), path)])
+            .collect()
+    }
+
+    #[test]
+    fn layers_should_override_in_order() {
+        let file = config_file("server:\n  port: 1000\n  host: file\n");
+        let vars = env_vars(
+            &file,
+            &[
+                ("TEST__SERVER__PORT", "2000"),
+                ("TEST__SERVER__HOST", "env"),
+                ("TEST__SERVER__DEBUG", "true"),
+                ("OTHER__SERVER__PORT", "4000"),
+            ],
+        );
+        let args = ["--server.port=3000"].map(String::from);
+        let config = TestConfig::load_from(vars, args).unwrap();
+
+        assert_eq!(config.name, "default");
+        assert_eq!(config.server.port, 3000);
+        assert_eq!(config.server.host, "env");
+        assert!(config.server.debug);
+
+        let args = ["--name", "cli"].map(String::from);
+        let config = TestConfig::load_from(env_vars(&file, &[]), args).unwrap();
+        assert_eq!(config.name, "cli");
+        assert_eq!(config.server.port, 1000);
+    }
+
+    #[test]
+    fn string_values_should_be_kept_as_is() {
+        let file = config_file("server:\n  port: 1000\n  host: file\n");
+        // 数字形式的字符串 以及 yaml 中表示注释的 #
+        let vars = env_vars(&file, &[("TEST__SERVER__HOST", "123456")]);
+        let args = ["--name", "a #b"].map(String::from);
+        let config = TestConfig::load_from(vars, args).unwrap();
+
+        assert_eq!(config.server.host, "123456");
+        assert_eq!(config.name, "a #b");
+    }
+
+    #[test]
+    fn invalid_config_should_report_all_issues() {
+        let file = config_file("server:\n  port: 0\n  host: file\n  unknown: 1\nextra: 2\n");
+        let vars = env_vars(&file, &[("TEST__SERVER__HOST", "")]);
+        let err = TestConfig::load_from(vars, Vec::new())
+            .unwrap_err()
+            .to_string();
+        let path = file.path().display();
+
+        assert!(err.contains(&format!("server.unknown (file {}): unknown key", path)));
+        assert!(err.contains(&format!("extra (file {}): unknown key", path)));
+        assert!(err.contains(&format!("server.port (file {}): must not be 0", path)));
+        assert!(err.contains("server.host (env TEST__SERVER__HOST): must not be empty"));
+    }
+
+    #[test]
+    fn type_error_should_report_source() {
+        let file = config_file("server:\n  port: 1000\n  host: file\n");
+        let args = ["--server.port", "abc"].map(String::from);
+        let err = TestConfig::load_from(env_vars(&file, &[]), args)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("server.port (flag --server.port): invalid type"));
+
+        let err = TestConfig::load_from(Vec::new(), Vec::new())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("missing field `server`"));
+        assert!(err.contains("no config file found in not_exists.yml"));
+
+        let err = TestConfig::load_from(Vec::new(), ["server.port".to_string()]).unwrap_err();
+        assert_eq!(err.to_string(), "Unexpected argument: server.port");
+    }
+}
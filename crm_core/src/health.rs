@@ -0,0 +1,229 @@
+use anyhow::{bail, Result};
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use tokio::{
+    sync::Mutex as AsyncMutex,
+    time::{sleep, timeout},
+};
+use tonic::transport::Channel;
+use tonic_health::{
+    pb::{
+        health_check_response::ServingStatus as RemoteStatus,
+        health_client::HealthClient,
+        health_server::{Health, HealthServer},
+        HealthCheckRequest,
+    },
+    server::{health_reporter, HealthReporter},
+    ServingStatus,
+};
+use tracing::{info, warn};
+
+// 两次依赖检查之间的间隔
+const CHECK_INTERVAL: Duration = Duration::from_secs(5);
+// 单次检查的超时时间 超时视为不可用
+const CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+// 整体状态使用空的服务名
+const OVERALL: &str = "";
+
+/// 检查服务的依赖是否可用 结果通过 grpc.health.v1.Health 对外提供
+pub trait HealthCheck: Send + Sync + 'static {
+    // 依赖不可用时返回原因
+    fn check(&self) -> impl Future<Output = Result<()>> + Send;
+}
+
+// 没有外部依赖的服务始终可用
+impl HealthCheck for () {
+    async fn check(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// 下游服务 根据其 Health 服务报告的整体状态判断是否可用
+#[derive(Debug, Clone)]
+pub struct Downstream {
+    name: &'static str,
+    client: HealthClient<Channel>,
+}
+
+impl Downstream {
+    pub fn new(name: &'static str, channel: Channel) -> Self {
+        Self {
+            name,
+            client: HealthClient::new(channel),
+        }
+    }
+}
+
+impl HealthCheck for Downstream {
+    async fn check(&self) -> Result<()> {
+        let req = HealthCheckRequest {
+            service: OVERALL.to_string(),
+        };
+        let status = match self.client.clone().check(req).await {
+            Ok(res) => res.into_inner().status(),
+            Err(e) => bail!("{} is unreachable: {}", self.name, e.message()),
+        };
+        if status != RemoteStatus::Serving {
+            bail!("{} is {}", self.name, status.as_str_name());
+        }
+        Ok(())
+    }
+}
+
+/// 各服务的健康状态 全部可用时整体状态才为 SERVING
+#[derive(Clone)]
+pub struct HealthState {
+    reporter: HealthReporter,
+    statuses: Arc<Mutex<HashMap<&'static str, ServingStatus>>>,
+    // 是否已经开始退出 更新状态时持有 保证退出后不再变回 SERVING
+    stopped: Arc<AsyncMutex<bool>>,
+}
+
+impl HealthState {
+    pub fn new() -> (Self, HealthServer<impl Health>) {
+        let (reporter, server) = health_reporter();
+        let state = Self {
+            reporter,
+            statuses: Default::default(),
+            stopped: Default::default(),
+        };
+        (state, server)
+    }
+
+    // 注册服务 首次检查通过前为 NOT_SERVING
+    pub async fn register(&self, services: &[&'static str]) {
+        self.set(services, ServingStatus::NotServing).await;
+    }
+
+    // 退出后不再更新
+    pub async fn set(&self, services: &[&'static str], status: ServingStatus) {
+        let stopped = self.stopped.lock().await;
+        if !*stopped {
+            self.update(services, status).await;
+        }
+    }
+
+    // 退出时将所有服务标记为 NOT_SERVING 让负载均衡器不再转发新请求
+    // 此后 watch 中仍在进行的检查不会再改变状态
+    pub async fn shutdown(&self) {
+        let mut stopped = self.stopped.lock().await;
+        *stopped = true;
+        let services: Vec<_> = self.statuses.lock().unwrap().keys().copied().collect();
+        self.update(&services, ServingStatus::NotServing).await;
+    }
+
+    async fn update(&self, services: &[&'static str], status: ServingStatus) {
+        let overall = {
+            let mut statuses = self.statuses.lock().unwrap();
+            for service in services {
+                statuses.insert(service, status);
+            }
+            overall(&statuses)
+        };
+
+        let mut reporter = self.reporter.clone();
+        for service in services {
+            reporter.set_service_status(service, status).await;
+        }
+        reporter.set_service_status(OVERALL, overall).await;
+    }
+
+    // 定期检查依赖 状态变化时更新 services
+    pub async fn watch(self, services: Vec<&'static str>, check: impl HealthCheck) {
+        let mut last = None;
+        loop {
+            let status = match timeout(CHECK_TIMEOUT, check.check()).await {
+                Ok(Ok(())) => ServingStatus::Serving,
+                Ok(Err(e)) => {
+                    warn!("Health check of {:?} failed: {}", services, e);
+                    ServingStatus::NotServing
+                }
+                Err(_) => {
+                    warn!("Health check of {:?} timed out", services);
+                    ServingStatus::NotServing
+                }
+            };
+            if last != Some(status) {
+                info!("{:?} is now {}", services, status);
+                self.set(&services, status).await;
+                last = Some(status);
+            }
+            sleep(CHECK_INTERVAL).await;
+        }
+    }
+}
+
+fn overall(statuses: &HashMap<&'static str, ServingStatus>) -> ServingStatus {
+    match statuses.values().all(|s| *s == ServingStatus::Serving) {
+        true => ServingStatus::Serving,
+        false => ServingStatus::NotServing,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::mpsc;
+
+    fn status(state: &HealthState, service: &str) -> ServingStatus {
+        let statuses = state.statuses.lock().unwrap();
+        match service {
+            OVERALL => overall(&statuses),
+            _ => statuses[service],
+        }
+    }
+
+    #[tokio::test]
+    async fn overall_status_should_require_all_services() {
+        let (state, _server) = HealthState::new();
+        state.register(&["a", "b"]).await;
+        assert_eq!(status(&state, OVERALL), ServingStatus::NotServing);
+
+        state.set(&["a"], ServingStatus::Serving).await;
+        assert_eq!(status(&state, "a"), ServingStatus::Serving);
+        assert_eq!(status(&state, OVERALL), ServingStatus::NotServing);
+
+        state.set(&["b"], ServingStatus::Serving).await;
+        assert_eq!(status(&state, OVERALL), ServingStatus::Serving);
+
+        state.shutdown().await;
+        assert_eq!(status(&state, "a"), ServingStatus::NotServing);
+        assert_eq!(status(&state, OVERALL), ServingStatus::NotServing);
+    }
+
+    // 由测试决定每次检查的结果
+    struct Scripted(AsyncMutex<mpsc::Receiver<bool>>);
+
+    impl HealthCheck for Scripted {
+        async fn check(&self) -> Result<()> {
+            match self.0.lock().await.recv().await {
+                Some(true) => Ok(()),
+                _ => bail!("down"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn shutdown_should_not_be_overridden_by_watch() {
+        let (state, _server) = HealthState::new();
+        state.register(&["a"]).await;
+        let (tx, rx) = mpsc::channel(1);
+        let watcher = tokio::spawn(
+            state
+                .clone()
+                .watch(vec!["a"], Scripted(AsyncMutex::new(rx))),
+        );
+
+        // 检查进行中开始退出 之后检查才返回 SERVING
+        state.shutdown().await;
+        tx.send(true).await.unwrap();
+        sleep(Duration::from_millis(100)).await;
+        assert_eq!(status(&state, "a"), ServingStatus::NotServing);
+        assert_eq!(status(&state, OVERALL), ServingStatus::NotServing);
+        watcher.abort();
+    }
+}
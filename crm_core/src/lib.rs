@@ -0,0 +1,21 @@
+#![allow(clippy::result_large_err)]
+
+pub mod auth;
+pub mod config;
+pub mod health;
+pub mod metrics;
+pub mod policy;
+pub mod server;
+pub mod telemetry;
+pub mod time;
+pub mod tls;
+
+pub use auth::{AuthConfig, AuthInterceptor, Claims};
+pub use config::{ConfigLoader, Issues, Validate};
+pub use health::{Downstream, HealthCheck};
+pub use metrics::MetricsConfig;
+pub use policy::{Policy, PolicyConfig};
+pub use server::Bootstrap;
+pub use telemetry::{init_tracing, TracingConfig};
+pub use time::{ToDateTime, ToTimestamp};
+pub use tls::{ClientTls, ServerTls};
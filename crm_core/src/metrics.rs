@@ -0,0 +1,301 @@
+use crate::{
+    config::{field, Issues, Validate},
+    server::{default_host, split_method},
+};
+use ::metrics::{counter, gauge, histogram};
+use anyhow::Result;
+use http_body::{Body, Frame, SizeHint};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
+use pin_project_lite::pin_project;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use std::{
+    future::Future,
+    net::{IpAddr, SocketAddr},
+    pin::Pin,
+    task::{ready, Context, Poll},
+    time::{Duration, Instant},
+};
+use tokio::time::sleep;
+use tonic::Code;
+use tower::{Layer, Service};
+use tracing::info;
+
+// 请求耗时直方图的桶 单位秒
+const LATENCY_BUCKETS: &[f64] = &[
+    0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+];
+// 连接池状态的采集间隔
+const POOL_INTERVAL: Duration = Duration::from_secs(5);
+const GRPC_STATUS: &str = "grpc-status";
+// 无法识别的服务与方法使用的标签值
+const UNKNOWN: &str = "unknown";
+
+/// /metrics 的监听地址 未配置时不提供
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricsConfig {
+    #[serde(default = "default_host")]
+    pub host: IpAddr,
+    pub port: u16,
+}
+
+impl Validate for MetricsConfig {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        issues.check(self.port != 0, field(path, "port"), "must not be 0");
+    }
+}
+
+// 安装全局的 Prometheus recorder 并在 host:port 上提供 /metrics
+pub fn install(config: &MetricsConfig) -> Result<()> {
+    let addr = SocketAddr::new(config.host, config.port);
+    PrometheusBuilder::new()
+        .with_http_listener(addr)
+        .set_buckets_for_metric(Matcher::Suffix("_seconds".to_string()), LATENCY_BUCKETS)?
+        .install()?;
+    info!("Serving metrics on http://{}/metrics", addr);
+    Ok(())
+}
+
+// 定期记录连接池的连接数
+pub fn record_pool(name: &'static str, pool: PgPool) {
+    gauge!("db_pool_max_connections", "pool" => name).set(pool.options().get_max_connections());
+    tokio::spawn(async move {
+        loop {
+            let idle = pool.num_idle() as f64;
+            let size = pool.size() as f64;
+            gauge!("db_pool_connections", "pool" => name, "state" => "idle").set(idle);
+            gauge!("db_pool_connections", "pool" => name, "state" => "active").set(size - idle);
+            sleep(POOL_INTERVAL).await;
+        }
+    });
+}
+
+/// 记录每个 RPC 的调用次数与耗时 流式调用的耗时到最后一条消息发送完为止
+#[derive(Debug, Clone, Default)]
+pub struct MetricsLayer;
+
+#[derive(Debug, Clone)]
+pub struct MetricsService<S> {
+    inner: S,
+}
+
+impl<S> Layer<S> for MetricsLayer {
+    type Service = MetricsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        MetricsService { inner }
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for MetricsService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+{
+    type Response = http::Response<MetricsBody<ResBody>>;
+    type Error = S::Error;
+    type Future = MetricsFuture<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<ReqBody>) -> Self::Future {
+        let call = Call::new(req.uri().path());
+        MetricsFuture {
+            inner: self.inner.call(req),
+            call: Some(call),
+        }
+    }
+}
+
+pin_project! {
+    pub struct MetricsFuture<F> {
+        #[pin]
+        inner: F,
+        call: Option<Call>,
+    }
+}
+
+impl<F, ResBody, E> Future for MetricsFuture<F>
+where
+    F: Future<Output = Result<http::Response<ResBody>, E>>,
+{
+    type Output = Result<http::Response<MetricsBody<ResBody>>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let res = ready!(this.inner.poll(cx));
+        let mut call = this.call.take().expect("polled after completion");
+        Poll::Ready(match res {
+            Ok(res) => {
+                // 出错时状态直接放在 header 中
+                call.code = status(res.headers());
+                Ok(res.map(|inner| MetricsBody { inner, call }))
+            }
+            Err(e) => {
+                call.code = Some(Code::Internal);
+                Err(e)
+            }
+        })
+    }
+}
+
+pin_project! {
+    pub struct MetricsBody<B> {
+        #[pin]
+        inner: B,
+        call: Call,
+    }
+}
+
+impl<B: Body> Body for MetricsBody<B> {
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        let frame = ready!(this.inner.poll_frame(cx));
+        if let Some(Ok(frame)) = &frame {
+            if let Some(code) = frame.trailers_ref().and_then(status) {
+                this.call.code = Some(code);
+            }
+        }
+        Poll::Ready(frame)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+/// 一次 RPC 调用 在响应结束或被丢弃时记录
+struct Call {
+    service: String,
+    method: String,
+    start: Instant,
+    code: Option<Code>,
+}
+
+impl Call {
+    fn new(path: &str) -> Self {
+        let (service, method) = split_method(path).unwrap_or((UNKNOWN, UNKNOWN));
+        Self {
+            service: service.to_string(),
+            method: method.to_string(),
+            start: Instant::now(),
+            code: None,
+        }
+    }
+}
+
+impl Drop for Call {
+    fn drop(&mut self) {
+        // 没有收到状态就结束 说明客户端取消了调用
+        let code = self.code.unwrap_or(Code::Cancelled);
+        // 路由不到的路径由客户端任意构造 合并为 unknown 避免标签无限增长
+        let (service, method) = match code {
+            Code::Unimplemented => (UNKNOWN.to_string(), UNKNOWN.to_string()),
+            _ => (self.service.clone(), self.method.clone()),
+        };
+        let code = format!("{:?}", code);
+        let labels = [("grpc_service", service), ("grpc_method", method)];
+        histogram!("grpc_server_handling_seconds", &labels)
+            .record(self.start.elapsed().as_secs_f64());
+        let [service, method] = labels;
+        counter!(
+            "grpc_server_handled_total",
+            &[service, method, ("grpc_code", code)]
+        )
+        .increment(1);
+    }
+}
+
+fn status(headers: &http::HeaderMap) -> Option<Code> {
+    headers
+        .get(GRPC_STATUS)
+        .map(|v| Code::from_bytes(v.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::metrics::with_local_recorder;
+    use futures::executor::block_on;
+    use http::{HeaderMap, HeaderValue};
+    use http_body_util::{BodyExt, Empty, StreamBody};
+    use tower::{service_fn, ServiceExt};
+
+    #[test]
+    fn rpc_metrics_should_be_recorded() {
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+
+        with_local_recorder(&recorder, || {
+            block_on(async {
+                // trailers-only 的错误响应
+                let svc = MetricsLayer.layer(service_fn(|req: http::Request<()>| async move {
+                    let code = match req.uri().path() {
+                        "/pkg.Svc/Denied" => "16",
+                        _ => "12",
+                    };
+                    let res = http::Response::builder()
+                        .header(GRPC_STATUS, code)
+                        .body(Empty::<bytes::Bytes>::new())
+                        .unwrap();
+                    Ok::<_, std::convert::Infallible>(res)
+                }));
+                let req = http::Request::builder()
+                    .uri("/pkg.Svc/Denied")
+                    .body(())
+                    .unwrap();
+                let res = svc.clone().oneshot(req).await.unwrap();
+                res.into_body().collect().await.unwrap();
+
+                // 没有注册的服务 路由返回 UNIMPLEMENTED
+                let req = http::Request::builder()
+                    .uri("/random.Probe/Method")
+                    .body(())
+                    .unwrap();
+                let res = svc.oneshot(req).await.unwrap();
+                res.into_body().collect().await.unwrap();
+
+                // 状态位于 trailers 中的成功响应
+                let svc = MetricsLayer.layer(service_fn(|_req: http::Request<()>| async {
+                    let mut trailers = HeaderMap::new();
+                    trailers.insert(GRPC_STATUS, HeaderValue::from_static("0"));
+                    let frames = futures::stream::iter([
+                        Ok::<_, std::convert::Infallible>(Frame::data(bytes::Bytes::new())),
+                        Ok(Frame::trailers(trailers)),
+                    ]);
+                    Ok::<_, std::convert::Infallible>(http::Response::new(StreamBody::new(frames)))
+                }));
+                let req = http::Request::builder()
+                    .uri("/pkg.Svc/Stream")
+                    .body(())
+                    .unwrap();
+                let res = svc.oneshot(req).await.unwrap();
+                res.into_body().collect().await.unwrap();
+            })
+        });
+
+        let output = handle.render();
+        assert!(output.contains(
+            r#"grpc_server_handled_total{grpc_service="pkg.Svc",grpc_method="Denied",grpc_code="Unauthenticated"} 1"#
+        ));
+        assert!(output.contains(
+            r#"grpc_server_handled_total{grpc_service="pkg.Svc",grpc_method="Stream",grpc_code="Ok"} 1"#
+        ));
+        assert!(output.contains(
+            r#"grpc_server_handled_total{grpc_service="unknown",grpc_method="unknown",grpc_code="Unimplemented"} 1"#
+        ));
+        assert!(!output.contains("random.Probe"));
+        assert!(output.contains("grpc_server_handling_seconds"));
+    }
+}
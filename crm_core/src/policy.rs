@@ -0,0 +1,188 @@
+use crate::{
+    config::{field, Issues, Validate},
+    server::split_method,
+    Claims,
+};
+use futures::future::{ready, Either, Ready};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tonic::Status;
+use tower::{Layer, Service};
+use tracing::warn;
+
+// 拥有全部权限的通配符
+const ANY: &str = "*";
+
+/// 按方法的访问控制策略
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PolicyConfig {
+    // 方法路径 -> 所需权限 未列出的方法只要求通过身份认证
+    #[serde(default)]
+    pub methods: HashMap<String, String>,
+    // 角色 -> 拥有的权限
+    #[serde(default)]
+    pub roles: HashMap<String, Vec<String>>,
+}
+
+/// 根据 token 中的角色校验方法权限 需放在 AuthInterceptor 之后
+#[derive(Debug, Clone)]
+pub struct Policy {
+    inner: Arc<PolicyConfig>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PolicyService<S> {
+    inner: S,
+    policy: Policy,
+}
+
+impl Policy {
+    pub fn new(config: PolicyConfig) -> Self {
+        Self {
+            inner: Arc::new(config),
+        }
+    }
+
+    // 校验调用方是否拥有 path 对应方法所需的权限
+    pub fn check(&self, path: &str, claims: Option<&Claims>) -> Result<(), Status> {
+        let Some(permission) = self.inner.methods.get(path) else {
+            return Ok(());
+        };
+        let claims = claims.ok_or_else(|| Status::unauthenticated("Missing claims"))?;
+        if self.inner.grants(claims, permission) {
+            return Ok(());
+        }
+
+        warn!("{} is not allowed to call {}", claims.sub, path);
+        Err(Status::permission_denied(format!(
+            "Missing permission: {}",
+            permission
+        )))
+    }
+}
+
+impl PolicyConfig {
+    // 调用方的任一角色拥有 permission 或通配符
+    pub fn grants(&self, claims: &Claims, permission: &str) -> bool {
+        claims
+            .roles
+            .iter()
+            .filter_map(|role| self.roles.get(role))
+            .flatten()
+            .any(|p| p == permission || p == ANY)
+    }
+}
+
+impl Validate for PolicyConfig {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        for (method, permission) in &self.methods {
+            // 方法路径形如 /package.Service/Method
+            let valid = split_method(method)
+                .is_some_and(|(svc, m)| !svc.is_empty() && !m.is_empty() && !m.contains('/'));
+            let path = field(&field(path, "methods"), method);
+            issues.check(
+                valid,
+                &path,
+                "method must look like /package.Service/Method",
+            );
+            issues.check(!permission.is_empty(), path, "permission must not be empty");
+        }
+    }
+}
+
+impl<S> Layer<S> for Policy {
+    type Service = PolicyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        PolicyService {
+            inner,
+            policy: self.clone(),
+        }
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<http::Request<ReqBody>> for PolicyService<S>
+where
+    S: Service<http::Request<ReqBody>, Response = http::Response<ResBody>>,
+    ResBody: Default,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Either<Ready<Result<S::Response, S::Error>>, S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<ReqBody>) -> Self::Future {
+        let path = req.uri().path();
+        match self.policy.check(path, req.extensions().get::<Claims>()) {
+            Ok(()) => Either::Right(self.inner.call(req)),
+            Err(status) => {
+                let res = status.into_http().map(|_| ResBody::default());
+                Either::Left(ready(Ok(res)))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::Code;
+
+    const RAW_QUERY: &str = "/user_stats.UserStats/RawQuery";
+
+    fn policy() -> Policy {
+        let config: PolicyConfig = serde_yaml::from_str(
+            r#"
+            methods:
+              /user_stats.UserStats/RawQuery: user_stats.raw_query
+            roles:
+              admin: ["*"]
+              analyst: [user_stats.raw_query]
+              reader: [metadata.read]
+            "#,
+        )
+        .unwrap();
+        Policy::new(config)
+    }
+
+    fn claims(roles: &[&str]) -> Claims {
+        Claims {
+            sub: "alice".to_string(),
+            aud: vec!["crm".to_string()],
+            exp: 0,
+            roles: roles.iter().map(|r| r.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn granted_roles_should_pass() {
+        let policy = policy();
+        assert!(policy.check(RAW_QUERY, Some(&claims(&["admin"]))).is_ok());
+        assert!(policy
+            .check(RAW_QUERY, Some(&claims(&["reader", "analyst"])))
+            .is_ok());
+        // 未列出的方法对所有已认证的调用方开放
+        let path = "/metadata.Metadata/Materialize";
+        assert!(policy.check(path, Some(&claims(&[]))).is_ok());
+    }
+
+    #[test]
+    fn missing_permission_should_fail() {
+        let policy = policy();
+        for roles in [&[][..], &["reader"], &["unknown"]] {
+            let err = policy.check(RAW_QUERY, Some(&claims(roles))).unwrap_err();
+            assert_eq!(err.code(), Code::PermissionDenied);
+            assert_eq!(err.message(), "Missing permission: user_stats.raw_query");
+        }
+
+        let err = policy.check(RAW_QUERY, None).unwrap_err();
+        assert_eq!(err.code(), Code::Unauthenticated);
+    }
+}
@@ -0,0 +1,194 @@
+use crate::{
+    health::HealthState, metrics::MetricsLayer, telemetry::TraceLayer, tls, AuthConfig,
+    AuthInterceptor, HealthCheck, Policy, PolicyConfig, ServerTls,
+};
+use anyhow::Result;
+use futures::future::BoxFuture;
+use std::{
+    future::{pending, Future},
+    net::{IpAddr, Ipv6Addr, SocketAddr},
+    time::Duration,
+};
+use tokio::{sync::oneshot, time::sleep};
+use tonic::{service::Routes, transport::server::TcpIncoming};
+use tonic_reflection::server::Builder as ReflectionBuilder;
+use tracing::{info, warn};
+
+// 默认只监听本机 容器中需配置为 0.0.0.0 或 ::
+pub fn default_host() -> IpAddr {
+    Ipv6Addr::LOCALHOST.into()
+}
+
+// 默认等待进行中请求完成的秒数
+pub fn default_drain_timeout() -> u64 {
+    30
+}
+
+// 拆分方法路径 /package.Service/Method 为服务名与方法名
+pub(crate) fn split_method(path: &str) -> Option<(&str, &str)> {
+    path.strip_prefix('/').and_then(|p| p.split_once('/'))
+}
+
+// 等待 SIGINT 或 SIGTERM
+pub async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("Failed to listen for SIGINT");
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Failed to listen for SIGTERM")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => info!("Received SIGINT"),
+        _ = terminate => info!("Received SIGTERM"),
+    }
+}
+
+type Watcher = Box<dyn FnOnce(HealthState) -> BoxFuture<'static, ()> + Send>;
+
+/// 各服务共用的启动流程 依次经过 TLS、链路追踪、调用指标、身份认证与按方法授权
+/// 同时提供 grpc.health.v1.Health 与 server reflection
+pub struct Bootstrap {
+    addr: SocketAddr,
+    tls: Option<ServerTls>,
+    auth: AuthInterceptor,
+    policy: Policy,
+    drain_timeout: Duration,
+    // 需要报告健康状态的服务 以及检查它们依赖的任务
+    services: Vec<&'static str>,
+    watchers: Vec<Watcher>,
+    // 编码后的 FileDescriptorSet 用于 reflection
+    descriptors: Vec<&'static [u8]>,
+}
+
+impl Bootstrap {
+    pub fn try_new(
+        addr: SocketAddr,
+        tls: Option<&ServerTls>,
+        auth: &AuthConfig,
+        policy: &PolicyConfig,
+    ) -> Result<Self> {
+        Ok(Self {
+            addr,
+            tls: tls.cloned(),
+            auth: AuthInterceptor::try_new(auth)?,
+            policy: Policy::new(policy.clone()),
+            drain_timeout: Duration::from_secs(default_drain_timeout()),
+            services: Vec::new(),
+            watchers: Vec::new(),
+            descriptors: Vec::new(),
+        })
+    }
+
+    // services 的健康状态由 check 的结果决定 服务名形如 package.Service
+    pub fn health(mut self, services: &[&'static str], check: impl HealthCheck) -> Self {
+        let services = services.to_vec();
+        self.services.extend(&services);
+        self.watchers.push(Box::new(move |state| {
+            Box::pin(state.watch(services, check))
+        }));
+        self
+    }
+
+    // 通过 reflection 公开 descriptor 中的服务
+    pub fn reflection(mut self, descriptor: &'static [u8]) -> Self {
+        self.descriptors.push(descriptor);
+        self
+    }
+
+    // 收到退出信号后 等待进行中请求完成的最长时间
+    pub fn drain_timeout(mut self, timeout: Duration) -> Self {
+        self.drain_timeout = timeout;
+        self
+    }
+
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    // 服务直到收到 SIGINT 或 SIGTERM
+    pub async fn serve(self, routes: Routes) -> Result<()> {
+        let incoming = TcpIncoming::new(self.addr, true, None).map_err(|e| anyhow::anyhow!(e))?;
+        self.serve_with_shutdown(routes, incoming, shutdown_signal())
+            .await
+    }
+
+    // 使用已绑定的端口 测试中可在启动服务前先绑定
+    pub async fn serve_with_incoming(self, routes: Routes, incoming: TcpIncoming) -> Result<()> {
+        self.serve_with_shutdown(routes, incoming, pending()).await
+    }
+
+    // signal 完成后停止接受新连接 进行中的请求最多再处理 drain_timeout
+    pub async fn serve_with_shutdown(
+        self,
+        routes: Routes,
+        incoming: TcpIncoming,
+        signal: impl Future<Output = ()>,
+    ) -> Result<()> {
+        let (health, health_server) = HealthState::new();
+        health.register(&self.services).await;
+        let watchers: Vec<_> = self
+            .watchers
+            .into_iter()
+            .map(|watch| tokio::spawn(watch(health.clone())))
+            .collect();
+        let routes = add_reflection(routes.add_service(health_server), &self.descriptors)?;
+
+        let (tx, rx) = oneshot::channel();
+        let signal = async move {
+            signal.await;
+            info!("Shutting down, draining in-flight requests");
+            health.shutdown().await;
+            let _ = tx.send(());
+        };
+        let drain_timeout = self.drain_timeout;
+        let deadline = async move {
+            match rx.await {
+                Ok(()) => sleep(drain_timeout).await,
+                // 服务已经退出
+                Err(_) => pending().await,
+            }
+        };
+
+        let server = tls::server(self.tls.as_ref())?
+            .layer(TraceLayer)
+            .layer(MetricsLayer)
+            .layer(self.auth)
+            .layer(self.policy)
+            .add_routes(routes)
+            .serve_with_incoming_shutdown(incoming, signal);
+        let ret = tokio::select! {
+            ret = server => ret.map_err(Into::into),
+            _ = deadline => {
+                warn!("Drain timeout exceeded, dropping in-flight requests");
+                Ok(())
+            }
+        };
+        for watcher in watchers {
+            watcher.abort();
+        }
+        ret
+    }
+}
+
+// reflection 同时提供 v1 与 grpcurl 等旧工具使用的 v1alpha
+fn add_reflection(routes: Routes, descriptors: &[&'static [u8]]) -> Result<Routes> {
+    let builder = || {
+        descriptors.iter().fold(
+            ReflectionBuilder::configure()
+                .register_encoded_file_descriptor_set(tonic_health::pb::FILE_DESCRIPTOR_SET),
+            |builder, descriptor| builder.register_encoded_file_descriptor_set(descriptor),
+        )
+    };
+    Ok(routes
+        .add_service(builder().build_v1()?)
+        .add_service(builder().build_v1alpha()?))
+}
@@ -0,0 +1,271 @@
+use crate::{
+    config::{field, Issues, Validate},
+    server::split_method,
+};
+use anyhow::Result;
+use opentelemetry::{
+    global,
+    propagation::{Extractor, Injector},
+    trace::TracerProvider as _,
+    KeyValue,
+};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{
+    propagation::TraceContextPropagator,
+    runtime,
+    trace::{Sampler, TracerProvider},
+    Resource,
+};
+use serde::{Deserialize, Serialize};
+use std::task::{Context, Poll};
+use tonic::metadata::{MetadataKey, MetadataMap};
+use tower::{Layer, Service};
+use tracing::{
+    info_span, instrument::Instrumented, level_filters::LevelFilter, warn, Instrument, Span,
+};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, Layer as _};
+
+/// 通过 OTLP 导出 trace
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TracingConfig {
+    // OTLP gRPC 地址 如 http://localhost:4317
+    pub endpoint: String,
+    // 新 trace 的采样比例 上游已采样的请求总是采样
+    #[serde(default = "default_sample_ratio")]
+    pub sample_ratio: f64,
+}
+
+fn default_sample_ratio() -> f64 {
+    1.0
+}
+
+impl Validate for TracingConfig {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        issues.check(
+            self.endpoint.starts_with("http://") || self.endpoint.starts_with("https://"),
+            field(path, "endpoint"),
+            "must be an http or https url",
+        );
+        issues.check(
+            (0.0..=1.0).contains(&self.sample_ratio),
+            field(path, "sample_ratio"),
+            "must be between 0 and 1",
+        );
+    }
+}
+
+/// 退出时导出尚未发送的 span
+pub struct TracingGuard {
+    provider: Option<TracerProvider>,
+}
+
+// 全局 subscriber 在进程退出前一直有效 此时仍可记录日志
+impl Drop for TracingGuard {
+    fn drop(&mut self) {
+        if let Some(provider) = self.provider.take() {
+            if let Err(e) = provider.shutdown() {
+                warn!("Failed to shutdown tracer provider: {}", e);
+            }
+        }
+    }
+}
+
+// 初始化日志 输出 INFO 及以上级别 配置了 OTLP 时同时导出 trace
+pub fn init_tracing(service: &'static str, config: Option<&TracingConfig>) -> Result<TracingGuard> {
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    let provider = config.map(|c| provider(service, c)).transpose()?;
+    let otel = provider.as_ref().map(|p| {
+        tracing_opentelemetry::layer()
+            .with_tracer(p.tracer(service))
+            .with_filter(LevelFilter::INFO)
+    });
+    let fmt = fmt::Layer::new().with_filter(LevelFilter::INFO);
+    tracing_subscriber::registry().with(fmt).with(otel).init();
+    Ok(TracingGuard { provider })
+}
+
+// 批量导出到 OTLP collector 的 TracerProvider
+pub fn provider(service: &'static str, config: &TracingConfig) -> Result<TracerProvider> {
+    let exporter = SpanExporter::builder()
+        .with_tonic()
+        .with_endpoint(&config.endpoint)
+        .build()?;
+    let sampler = Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(config.sample_ratio)));
+    Ok(TracerProvider::builder()
+        .with_batch_exporter(exporter, runtime::Tokio)
+        .with_sampler(sampler)
+        .with_resource(Resource::new([KeyValue::new("service.name", service)]))
+        .build())
+}
+
+// 将当前 span 的上下文以 traceparent 写入下游请求的 metadata
+pub fn inject(metadata: &mut MetadataMap) {
+    let cx = Span::current().context();
+    global::get_text_map_propagator(|p| p.inject_context(&cx, &mut MetadataInjector(metadata)));
+}
+
+struct MetadataInjector<'a>(&'a mut MetadataMap);
+
+impl Injector for MetadataInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(key), Ok(value)) = (MetadataKey::from_bytes(key.as_bytes()), value.parse()) {
+            self.0.insert(key, value);
+        }
+    }
+}
+
+struct HeaderExtractor<'a>(&'a http::HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|v| v.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|k| k.as_str()).collect()
+    }
+}
+
+/// 为每个 RPC 创建 span 上游通过 traceparent 传入的上下文作为父级
+#[derive(Debug, Clone, Default)]
+pub struct TraceLayer;
+
+#[derive(Debug, Clone)]
+pub struct TraceService<S> {
+    inner: S,
+}
+
+impl<S> Layer<S> for TraceLayer {
+    type Service = TraceService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TraceService { inner }
+    }
+}
+
+impl<S, ReqBody> Service<http::Request<ReqBody>> for TraceService<S>
+where
+    S: Service<http::Request<ReqBody>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Instrumented<S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<ReqBody>) -> Self::Future {
+        let path = req.uri().path();
+        let (service, method) = split_method(path).unwrap_or(("unknown", "unknown"));
+        let span = info_span!(
+            "grpc.server",
+            otel.name = path,
+            otel.kind = "server",
+            rpc.system = "grpc",
+            rpc.service = service,
+            rpc.method = method,
+        );
+        let parent =
+            global::get_text_map_propagator(|p| p.extract(&HeaderExtractor(req.headers())));
+        span.set_parent(parent);
+
+        let future = span.in_scope(|| self.inner.call(req));
+        future.instrument(span)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::trace::TraceContextExt;
+    use opentelemetry_proto::tonic::{
+        collector::trace::v1::{
+            trace_service_server::{TraceService, TraceServiceServer},
+            ExportTraceServiceRequest, ExportTraceServiceResponse,
+        },
+        trace::v1::Span as ExportedSpan,
+    };
+    use std::{
+        convert::Infallible,
+        sync::{Arc, Mutex},
+    };
+    use tonic::{
+        transport::{server::TcpIncoming, Server},
+        Request, Response, Status,
+    };
+    use tower::{service_fn, ServiceExt};
+
+    /// 代替 OTLP collector 收集导出的 span
+    #[derive(Clone, Default)]
+    struct Collector(Arc<Mutex<Vec<ExportedSpan>>>);
+
+    #[tonic::async_trait]
+    impl TraceService for Collector {
+        async fn export(
+            &self,
+            request: Request<ExportTraceServiceRequest>,
+        ) -> Result<Response<ExportTraceServiceResponse>, Status> {
+            let spans = request
+                .into_inner()
+                .resource_spans
+                .into_iter()
+                .flat_map(|r| r.scope_spans)
+                .flat_map(|s| s.spans);
+            self.0.lock().unwrap().extend(spans);
+            Ok(Response::new(ExportTraceServiceResponse::default()))
+        }
+    }
+
+    // 批量导出在后台任务中执行 force_flush 会阻塞当前线程
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn trace_context_should_propagate() -> Result<()> {
+        let addr = "[::1]:62200".parse()?;
+        let collector = Collector::default();
+        let incoming = TcpIncoming::new(addr, true, None).map_err(|e| anyhow::anyhow!(e))?;
+        let server = Server::builder()
+            .add_service(TraceServiceServer::new(collector.clone()))
+            .serve_with_incoming(incoming);
+        tokio::spawn(server);
+
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        let config = TracingConfig {
+            endpoint: format!("http://{}", addr),
+            sample_ratio: 1.0,
+        };
+        let provider = provider("test", &config)?;
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        // 调用方将当前 span 写入 metadata
+        let client = info_span!("client");
+        let mut metadata = MetadataMap::new();
+        client.in_scope(|| inject(&mut metadata));
+        let client_cx = client.context().span().span_context().clone();
+        assert!(metadata.get("traceparent").is_some());
+
+        // 服务端的 span 以调用方为父级
+        let svc = TraceLayer.layer(service_fn(|_req: http::Request<()>| async {
+            let cx = Span::current().context().span().span_context().clone();
+            Ok::<_, Infallible>(cx)
+        }));
+        let mut req = http::Request::builder().uri("/pkg.Svc/Call").body(())?;
+        *req.headers_mut() = metadata.into_headers();
+        let server_cx = svc.oneshot(req).await?;
+        assert_eq!(server_cx.trace_id(), client_cx.trace_id());
+        assert_ne!(server_cx.span_id(), client_cx.span_id());
+        drop(client);
+
+        for ret in provider.force_flush() {
+            ret?;
+        }
+        let spans = collector.0.lock().unwrap().clone();
+        let server = spans.iter().find(|s| s.name == "/pkg.Svc/Call").unwrap();
+        let client = spans.iter().find(|s| s.name == "client").unwrap();
+        assert_eq!(server.trace_id, client_cx.trace_id().to_bytes());
+        assert_eq!(server.parent_span_id, client.span_id);
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use chrono::{DateTime, TimeZone, Utc};
+use prost_types::Timestamp;
+use tonic::Status;
+
+/// 将 UTC 时间转换为 protobuf 时间戳
+pub trait ToTimestamp {
+    fn to_timestamp(&self) -> Timestamp;
+}
+
+/// 将 protobuf 时间戳转换为 UTC 时间 无效的时间戳返回 InvalidArgument
+pub trait ToDateTime {
+    fn to_datetime(&self) -> Result<DateTime<Utc>, Status>;
+}
+
+impl ToTimestamp for DateTime<Utc> {
+    fn to_timestamp(&self) -> Timestamp {
+        Timestamp {
+            seconds: self.timestamp(),
+            nanos: self.timestamp_subsec_nanos() as i32,
+        }
+    }
+}
+
+impl ToDateTime for Timestamp {
+    fn to_datetime(&self) -> Result<DateTime<Utc>, Status> {
+        u32::try_from(self.nanos)
+            .ok()
+            .and_then(|nanos| Utc.timestamp_opt(self.seconds, nanos).single())
+            .ok_or_else(|| Status::invalid_argument(format!("Invalid timestamp: {:?}", self)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::Code;
+
+    #[test]
+    fn timestamp_should_round_trip() {
+        let dt = Utc.with_ymd_and_hms(2024, 11, 1, 8, 30, 0).unwrap()
+            + chrono::Duration::nanoseconds(123);
+        let ts = dt.to_timestamp();
+        assert_eq!(ts.nanos, 123);
+        assert_eq!(ts.to_datetime().unwrap(), dt);
+    }
+
+    #[test]
+    fn invalid_timestamp_should_fail() {
+        for (seconds, nanos) in [(0, -1), (i64::MAX, 0)] {
+            let err = Timestamp { seconds, nanos }.to_datetime().unwrap_err();
+            assert_eq!(err.code(), Code::InvalidArgument);
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::config::{field, Issues, Validate};
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use tonic::transport::{
+    Certificate, Channel, ClientTlsConfig, Endpoint, Identity, Server, ServerTlsConfig,
+};
+
+/// 服务端 TLS 配置 设置 client_ca 时要求客户端出示证书 (mTLS)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerTls {
+    // 服务端证书 PEM
+    pub cert: PathBuf,
+    // 服务端私钥 PEM
+    pub key: PathBuf,
+    // 用于校验客户端证书的 CA
+    pub client_ca: Option<PathBuf>,
+}
+
+/// 客户端 TLS 配置 设置 cert 与 key 时向服务端出示客户端证书
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientTls {
+    // 用于校验服务端证书的 CA
+    pub ca: PathBuf,
+    // 客户端证书 PEM
+    pub cert: Option<PathBuf>,
+    // 客户端私钥 PEM
+    pub key: Option<PathBuf>,
+    // 校验的服务端域名 默认取自 url
+    pub domain: Option<String>,
+}
+
+impl ServerTls {
+    pub fn load(&self) -> Result<ServerTlsConfig> {
+        let identity = Identity::from_pem(read(&self.cert)?, read(&self.key)?);
+        let mut config = ServerTlsConfig::new().identity(identity);
+        if let Some(ca) = &self.client_ca {
+            config = config.client_ca_root(Certificate::from_pem(read(ca)?));
+        }
+        Ok(config)
+    }
+}
+
+impl ClientTls {
+    pub fn load(&self) -> Result<ClientTlsConfig> {
+        let mut config =
+            ClientTlsConfig::new().ca_certificate(Certificate::from_pem(read(&self.ca)?));
+        match (&self.cert, &self.key) {
+            (Some(cert), Some(key)) => {
+                config = config.identity(Identity::from_pem(read(cert)?, read(key)?));
+            }
+            (None, None) => {}
+            _ => bail!("Client cert and key must be set together"),
+        }
+        if let Some(domain) = &self.domain {
+            config = config.domain_name(domain);
+        }
+        Ok(config)
+    }
+}
+
+impl Validate for ServerTls {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        check_file(&self.cert, &field(path, "cert"), issues);
+        check_file(&self.key, &field(path, "key"), issues);
+        if let Some(ca) = &self.client_ca {
+            check_file(ca, &field(path, "client_ca"), issues);
+        }
+    }
+}
+
+impl Validate for ClientTls {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        check_file(&self.ca, &field(path, "ca"), issues);
+        match (&self.cert, &self.key) {
+            (Some(cert), Some(key)) => {
+                check_file(cert, &field(path, "cert"), issues);
+                check_file(key, &field(path, "key"), issues);
+            }
+            (None, None) => {}
+            _ => issues.push(path, "cert and key must be set together"),
+        }
+    }
+}
+
+fn check_file(file: &Path, path: &str, issues: &mut Issues) {
+    issues.check(
+        file.is_file(),
+        path,
+        format!("file {} does not exist", file.display()),
+    );
+}
+
+// 创建 Server builder 配置了 TLS 时使用 TLS
+pub fn server(tls: Option<&ServerTls>) -> Result<Server> {
+    let builder = Server::builder();
+    match tls {
+        Some(tls) => Ok(builder.tls_config(tls.load()?)?),
+        None => Ok(builder),
+    }
+}
+
+// 连接 url 配置了 TLS 时 url 需使用 https
+pub async fn connect(url: impl Into<String>, tls: Option<&ClientTls>) -> Result<Channel> {
+    let mut endpoint = Endpoint::from_shared(url.into())?;
+    if let Some(tls) = tls {
+        let mut config = tls.load()?;
+        // tonic 直接使用 url 中的 host 校验证书 IPv6 地址需要去掉方括号
+        if let (None, Some(host)) = (&tls.domain, endpoint.uri().host()) {
+            config = config.domain_name(host.trim_start_matches('[').trim_end_matches(']'));
+        }
+        endpoint = endpoint.tls_config(config)?;
+    }
+    Ok(endpoint.connect().await?)
+}
+
+fn read(path: &PathBuf) -> Result<Vec<u8>> {
+    match fs::read(path) {
+        Ok(data) => Ok(data),
+        Err(e) => bail!("Failed to read {}: {}", path.display(), e),
+    }
+}
+
+#[cfg(feature = "test_utils")]
+pub mod test_utils {
+    use super::{ClientTls, ServerTls};
+    use rcgen::{
+        BasicConstraints, Certificate, CertificateParams, DnType, ExtendedKeyUsagePurpose, IsCa,
+        KeyPair,
+    };
+    use std::{fs, path::PathBuf};
+    use tempfile::TempDir;
+
+    /// 测试使用的临时 CA 签发的证书写入临时目录
+    pub struct TestCa {
+        cert: Certificate,
+        key: KeyPair,
+        dir: TempDir,
+    }
+
+    impl TestCa {
+        pub fn new() -> Self {
+            let mut params = CertificateParams::new(vec![]).expect("ca params");
+            params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+            params
+                .distinguished_name
+                .push(DnType::CommonName, "crm test ca");
+            let key = KeyPair::generate().expect("generate ca key");
+            let cert = params.self_signed(&key).expect("sign ca");
+            let dir = TempDir::new().expect("create temp dir");
+            fs::write(dir.path().join("ca.pem"), cert.pem()).expect("write ca");
+            Self { cert, key, dir }
+        }
+
+        // 服务端证书 对 localhost 与 ::1 有效 mtls 为 true 时校验客户端证书
+        pub fn server_tls(&self, mtls: bool) -> ServerTls {
+            let (cert, key) = self.issue(
+                "server",
+                vec!["localhost".to_string(), "::1".to_string()],
+                ExtendedKeyUsagePurpose::ServerAuth,
+            );
+            ServerTls {
+                cert,
+                key,
+                client_ca: mtls.then(|| self.ca()),
+            }
+        }
+
+        // 信任此 CA 的客户端配置 identity 为 true 时带上客户端证书
+        pub fn client_tls(&self, identity: bool) -> ClientTls {
+            let (cert, key) = match identity {
+                true => {
+                    let (cert, key) =
+                        self.issue("client", vec![], ExtendedKeyUsagePurpose::ClientAuth);
+                    (Some(cert), Some(key))
+                }
+                false => (None, None),
+            };
+            ClientTls {
+                ca: self.ca(),
+                cert,
+                key,
+                domain: None,
+            }
+        }
+
+        pub fn ca(&self) -> PathBuf {
+            self.dir.path().join("ca.pem")
+        }
+
+        fn issue(
+            &self,
+            name: &str,
+            sans: Vec<String>,
+            usage: ExtendedKeyUsagePurpose,
+        ) -> (PathBuf, PathBuf) {
+            let mut params = CertificateParams::new(sans).expect("cert params");
+            params.distinguished_name.push(DnType::CommonName, name);
+            params.extended_key_usages = vec![usage];
+            let key = KeyPair::generate().expect("generate key");
+            let cert = params
+                .signed_by(&key, &self.cert, &self.key)
+                .expect("sign cert");
+
+            let cert_path = self.dir.path().join(format!("{}.pem", name));
+            let key_path = self.dir.path().join(format!("{}.key", name));
+            fs::write(&cert_path, cert.pem()).expect("write cert");
+            fs::write(&key_path, key.serialize_pem()).expect("write key");
+            (cert_path, key_path)
+        }
+    }
+
+    impl Default for TestCa {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use proto_builder_trait::tonic::BuilderAttributes;
+use std::{env, path::PathBuf};
 
 fn main() -> Result<()> {
     std::fs::create_dir_all("src/pb")?;
@@ -8,6 +9,8 @@ fn main() -> Result<()> {
 
     builder
         .out_dir("src/pb")
+        // reflection 使用的 FileDescriptorSet
+        .file_descriptor_set_path(PathBuf::from(env::var("OUT_DIR")?).join("metadata.bin"))
         .with_type_attributes(&["MaterializeRequest"], &[r#"#[derive(Eq,Hash)]"#])
         .compile_protos(
             &[
@@ -2,17 +2,20 @@ use crate::pb::metadata_server::Metadata;
 use crate::pb::{Content, MaterializeRequest, Publisher};
 use crate::{MetadataService, ResponseStream, ServiceResult};
 use chrono::{DateTime, Days, Utc};
+use crm_core::ToTimestamp;
 use fake::faker::chrono::zh_cn::DateTimeBetween;
 use fake::faker::lorem::en::Sentence;
 use fake::faker::name::zh_cn::Name;
 use fake::{Fake, Faker};
 use futures::{stream, Stream, StreamExt};
+use metrics::counter;
 use prost_types::Timestamp;
 use rand::Rng;
 use std::collections::HashSet;
 use tokio::sync::mpsc;
 use tokio_stream::wrappers::ReceiverStream;
 use tonic::{async_trait, Request, Response, Streaming};
+use tracing::{info_span, Instrument};
 const CHANNEL_SIZE: usize = 1024;
 impl MetadataService {
     pub async fn materialize(
@@ -23,15 +26,23 @@ impl MetadataService {
             + Unpin,
     ) -> ServiceResult<ResponseStream> {
         let (tx, rx) = mpsc::channel(CHANNEL_SIZE);
-        tokio::spawn(async move {
-            while let Some(Ok(req)) = stream.next().await {
-                let tx = tx.clone();
-                tokio::spawn(async move {
-                    let content = Content::materialize(req.id);
-                    tx.send(Ok(content)).await.unwrap();
-                });
+        tokio::spawn(
+            async move {
+                while let Some(Ok(req)) = stream.next().await {
+                    let tx = tx.clone();
+                    let span = info_span!("metadata.materialize", id = req.id);
+                    tokio::spawn(
+                        async move {
+                            let content = Content::materialize(req.id);
+                            counter!("metadata_contents_materialized_total").increment(1);
+                            tx.send(Ok(content)).await.unwrap();
+                        }
+                        .instrument(span),
+                    );
+                }
             }
-        });
+            .in_current_span(),
+        );
         let stream = ReceiverStream::new(rx);
         Ok(Response::new(Box::pin(stream)))
     }
@@ -109,10 +120,7 @@ fn before(days: u64) -> DateTime<Utc> {
 fn created_at() -> Option<Timestamp> {
     let date: DateTime<Utc> = DateTimeBetween(before(365), before(0)).fake();
 
-    Some(Timestamp {
-        seconds: date.timestamp(),
-        nanos: date.timestamp_subsec_nanos() as i32,
-    })
+    Some(date.to_timestamp())
 }
 
 #[cfg(test)]
@@ -120,6 +128,7 @@ mod tests {
     use super::*;
     use crate::AppConfig;
     use anyhow::Result;
+    use crm_core::ConfigLoader;
     use tonic::codegen::tokio_stream;
 
     #[tokio::test]
@@ -1,7 +1,9 @@
-use std::{env, fs::File};
-
-use anyhow::{bail, Result};
+use crm_core::{
+    config::{field, Issues, Validate},
+    AuthConfig, ConfigLoader, MetricsConfig, PolicyConfig, ServerTls, TracingConfig,
+};
 use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
 
 /// 服务配置
 #[derive(Debug, Serialize, Deserialize)]
@@ -10,36 +12,57 @@ pub struct AppConfig {
     pub server: ServerConfig,
     // 身份认证相关
     pub auth: AuthConfig,
-}
-
-/// 身份认证配置 暂未启用
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AuthConfig {
-    // 私钥
-    pub pk: String,
+    // 按方法授权相关
+    #[serde(default)]
+    pub policy: PolicyConfig,
+    // 配置后提供 /metrics
+    #[serde(default)]
+    pub metrics: Option<MetricsConfig>,
+    // 配置后通过 OTLP 导出 trace
+    #[serde(default)]
+    pub tracing: Option<TracingConfig>,
 }
 
 // 服务配置
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ServerConfig {
+    // 监听地址
+    #[serde(default = "crm_core::server::default_host")]
+    pub host: IpAddr,
     // 监听端口
     pub port: u16,
+    // 收到退出信号后 等待进行中请求完成的最长秒数
+    #[serde(default = "crm_core::server::default_drain_timeout")]
+    pub drain_timeout: u64,
+    // 未配置时使用明文
+    pub tls: Option<ServerTls>,
 }
 
-impl AppConfig {
-    // 加载配置
-    pub fn load() -> Result<Self> {
-        let ret = match (
-            File::open("metadata.yml"),
-            File::open("/etc/config/metadata.yml"),
-            env::var("METADATA_CONFIG"),
-        ) {
-            (Ok(reader), _, _) => serde_yaml::from_reader(reader),
-            (_, Ok(reader), _) => serde_yaml::from_reader(reader),
-            (_, _, Ok(path)) => serde_yaml::from_reader(File::open(path)?),
-            _ => bail!("Metadata Config file not found"),
-        };
+impl ConfigLoader for AppConfig {
+    const FILE: &'static str = "metadata.yml";
+    const ENV: &'static str = "METADATA_CONFIG";
+    const PREFIX: &'static str = "METADATA";
+}
+
+impl Validate for AppConfig {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        self.server.validate(&field(path, "server"), issues);
+        self.auth.validate(&field(path, "auth"), issues);
+        self.policy.validate(&field(path, "policy"), issues);
+        if let Some(metrics) = &self.metrics {
+            metrics.validate(&field(path, "metrics"), issues);
+        }
+        if let Some(tracing) = &self.tracing {
+            tracing.validate(&field(path, "tracing"), issues);
+        }
+    }
+}
 
-        Ok(ret?)
+impl Validate for ServerConfig {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        issues.check(self.port != 0, field(path, "port"), "must not be 0");
+        if let Some(tls) = &self.tls {
+            tls.validate(&field(path, "tls"), issues);
+        }
     }
 }
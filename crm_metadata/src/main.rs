@@ -1,23 +1,34 @@
 use anyhow::Result;
-use crm_metadata::{config::AppConfig, MetadataService};
-use tonic::transport::Server;
-use tracing::{info, level_filters::LevelFilter};
-use tracing_subscriber::{
-    fmt::Layer, layer::SubscriberExt as _, util::SubscriberInitExt as _, Layer as _,
+use crm_core::{init_tracing, metrics, Bootstrap, ConfigLoader};
+use crm_metadata::{
+    config::AppConfig,
+    pb::{metadata_server::MetadataServer, FILE_DESCRIPTOR_SET},
+    MetadataService,
 };
+use std::{env, net::SocketAddr, time::Duration};
+use tonic::{server::NamedService, service::Routes};
+use tracing::info;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let layer = Layer::new().with_filter(LevelFilter::INFO);
-    tracing_subscriber::registry().with(layer).init();
+    let config = AppConfig::load_with_args(env::args().skip(1))?;
+    let _guard = init_tracing("crm_metadata", config.tracing.as_ref())?;
+    if let Some(config) = &config.metrics {
+        metrics::install(config)?;
+    }
+    let addr = SocketAddr::new(config.server.host, config.server.port);
+    let server = Bootstrap::try_new(
+        addr,
+        config.server.tls.as_ref(),
+        &config.auth,
+        &config.policy,
+    )?
+    .drain_timeout(Duration::from_secs(config.server.drain_timeout))
+    // 没有外部依赖 启动后始终可用
+    .health(&[MetadataServer::<MetadataService>::NAME], ())
+    .reflection(FILE_DESCRIPTOR_SET);
 
-    let config = AppConfig::load()?;
-    let addr = config.server.port;
-    let addr = format!("[::1]:{}", addr).parse().expect("Invalid address");
-
-    info!("Starting metadata service on {}", addr);
+    info!("Starting metadata service on {}", server.addr());
     let svc = MetadataService::new(config).into_service();
-    Server::builder().add_service(svc).serve(addr).await?;
-
-    Ok(())
+    server.serve(Routes::new(svc)).await
 }
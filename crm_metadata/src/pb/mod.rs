@@ -1,3 +1,5 @@
 #[rustfmt::skip]
 mod metadata;
 pub use metadata::*;
+
+pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("metadata");
@@ -0,0 +1,114 @@
+use anyhow::{anyhow, Result};
+use crm_core::{auth::test_utils::TestKey, Bootstrap, ConfigLoader, PolicyConfig};
+use crm_metadata::{
+    config::AppConfig,
+    pb::{
+        metadata_client::MetadataClient, metadata_server::MetadataServer, MaterializeRequest,
+        FILE_DESCRIPTOR_SET,
+    },
+    MetadataService,
+};
+use futures::StreamExt;
+use std::time::Duration;
+use tokio::time::sleep;
+use tonic::{
+    server::NamedService,
+    service::Routes,
+    transport::{server::TcpIncoming, Channel},
+    Code,
+};
+use tonic_health::pb::{
+    health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest,
+};
+use tonic_reflection::pb::v1::{
+    server_reflection_client::ServerReflectionClient, server_reflection_request::MessageRequest,
+    server_reflection_response::MessageResponse, ServerReflectionRequest,
+};
+
+const PORT_BASE: u16 = 62100;
+const METADATA: &str = MetadataServer::<MetadataService>::NAME;
+
+#[tokio::test]
+async fn health_should_work_without_token() -> Result<()> {
+    let channel = start_server(PORT_BASE).await?;
+    let mut client = HealthClient::new(channel.clone());
+
+    // 首次检查完成后变为 SERVING
+    let mut status = ServingStatus::Unknown;
+    for _ in 0..10 {
+        status = check(&mut client, "").await?;
+        if status == ServingStatus::Serving {
+            break;
+        }
+        sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(status, ServingStatus::Serving);
+    assert_eq!(check(&mut client, METADATA).await?, ServingStatus::Serving);
+
+    let err = check(&mut client, "unknown.Service").await.unwrap_err();
+    assert_eq!(err.code(), Code::NotFound);
+
+    // 业务接口仍需要 token
+    let err = MetadataClient::new(channel)
+        .materialize(MaterializeRequest::new_with_ids(&[1]))
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::Unauthenticated);
+    Ok(())
+}
+
+#[tokio::test]
+async fn reflection_should_list_services() -> Result<()> {
+    let channel = start_server(PORT_BASE + 1).await?;
+    let req = ServerReflectionRequest {
+        host: String::new(),
+        message_request: Some(MessageRequest::ListServices(String::new())),
+    };
+    let mut stream = ServerReflectionClient::new(channel)
+        .server_reflection_info(tokio_stream::once(req))
+        .await?
+        .into_inner();
+    let res = stream
+        .next()
+        .await
+        .ok_or_else(|| anyhow!("No response"))??;
+    let Some(MessageResponse::ListServicesResponse(list)) = res.message_response else {
+        return Err(anyhow!("Unexpected response"));
+    };
+
+    let names: Vec<_> = list.service.into_iter().map(|s| s.name).collect();
+    assert!(names.iter().any(|n| n == METADATA));
+    assert!(names.iter().any(|n| n == "grpc.health.v1.Health"));
+    Ok(())
+}
+
+async fn check(
+    client: &mut HealthClient<Channel>,
+    service: &str,
+) -> Result<ServingStatus, tonic::Status> {
+    let req = HealthCheckRequest {
+        service: service.to_string(),
+    };
+    Ok(client.check(req).await?.into_inner().status())
+}
+
+async fn start_server(port: u16) -> Result<Channel> {
+    let addr = format!("[::1]:{}", port).parse()?;
+    let server = Bootstrap::try_new(
+        addr,
+        None,
+        &TestKey::new().config(),
+        &PolicyConfig::default(),
+    )?
+    .health(&[METADATA], ())
+    .reflection(FILE_DESCRIPTOR_SET);
+    let svc = MetadataService::new(AppConfig::load()?).into_service();
+    // 先绑定端口 再启动服务 避免客户端连接时服务尚未就绪
+    let incoming = TcpIncoming::new(addr, true, None).map_err(|e| anyhow!(e))?;
+    tokio::spawn(server.serve_with_incoming(Routes::new(svc), incoming));
+
+    let channel = Channel::from_shared(format!("http://{}", addr))?
+        .connect()
+        .await?;
+    Ok(channel)
+}
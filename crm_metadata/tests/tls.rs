@@ -0,0 +1,78 @@
+use anyhow::{anyhow, Result};
+use crm_core::{
+    tls::{self, connect, test_utils::TestCa},
+    ClientTls, ConfigLoader, ServerTls,
+};
+use crm_metadata::{
+    config::AppConfig,
+    pb::{metadata_client::MetadataClient, Content, MaterializeRequest},
+    MetadataService,
+};
+use futures::StreamExt;
+use tonic::transport::server::TcpIncoming;
+
+const PORT_BASE: u16 = 62000;
+
+#[tokio::test]
+async fn tls_should_work() -> Result<()> {
+    let ca = TestCa::new();
+    let url = start_server(&ca.server_tls(false), PORT_BASE)?;
+
+    let content = materialize(&url, Some(&ca.client_tls(false))).await?;
+    assert_eq!(content.id, 1);
+
+    // 明文客户端无法访问 TLS 服务
+    let plain = url.replace("https", "http");
+    assert!(materialize(&plain, None).await.is_err());
+    // 不信任服务端证书的客户端握手失败
+    let other = TestCa::new();
+    assert!(materialize(&url, Some(&other.client_tls(false)))
+        .await
+        .is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn mtls_should_work() -> Result<()> {
+    let ca = TestCa::new();
+    let url = start_server(&ca.server_tls(true), PORT_BASE + 1)?;
+
+    let content = materialize(&url, Some(&ca.client_tls(true))).await?;
+    assert_eq!(content.id, 1);
+
+    // 没有客户端证书时服务端拒绝握手
+    assert!(materialize(&url, Some(&ca.client_tls(false)))
+        .await
+        .is_err());
+    // 客户端证书由其他 CA 签发
+    let mut tls = TestCa::new().client_tls(true);
+    tls.ca = ca.ca();
+    assert!(materialize(&url, Some(&tls)).await.is_err());
+    Ok(())
+}
+
+async fn materialize(url: &str, tls: Option<&ClientTls>) -> Result<Content> {
+    let channel = connect(url, tls).await?;
+    let mut stream = MetadataClient::new(channel)
+        .materialize(MaterializeRequest::new_with_ids(&[1]))
+        .await?
+        .into_inner();
+    let content = stream.next().await.ok_or_else(|| anyhow!("No content"))??;
+    Ok(content)
+}
+
+fn start_server(tls: &ServerTls, port: u16) -> Result<String> {
+    let addr = format!("[::1]:{}", port).parse()?;
+    let svc = MetadataService::new(AppConfig::load()?).into_service();
+    let mut server = tls::server(Some(tls))?;
+    // 先绑定端口 再启动服务 避免客户端连接时服务尚未就绪
+    let incoming = TcpIncoming::new(addr, true, None).map_err(|e| anyhow!(e))?;
+    tokio::spawn(async move {
+        server
+            .add_service(svc)
+            .serve_with_incoming(incoming)
+            .await
+            .unwrap();
+    });
+    Ok(format!("https://{}", addr))
+}
@@ -1,18 +1,22 @@
 use anyhow::Result;
-use std::fs;
+use std::{env, fs, path::PathBuf};
 
 fn main() -> Result<()> {
     fs::create_dir_all("src/pb")?;
 
     let builder = tonic_build::configure();
 
-    builder.out_dir("src/pb").compile_protos(
-        &[
-            "../protos/notification/messages.proto",
-            "../protos/notification/rpc.proto",
-        ],
-        &["../protos"],
-    )?;
+    builder
+        .out_dir("src/pb")
+        // reflection 使用的 FileDescriptorSet
+        .file_descriptor_set_path(PathBuf::from(env::var("OUT_DIR")?).join("notification.bin"))
+        .compile_protos(
+            &[
+                "../protos/notification/messages.proto",
+                "../protos/notification/rpc.proto",
+            ],
+            &["../protos"],
+        )?;
 
     Ok(())
 }
@@ -1,25 +1,36 @@
+use lettre::Message;
 use tonic::Status;
 use tracing::warn;
 
 use crate::{
     pb::{send_request::Message as Msg, EmailMessage, SendRequest, SendResponse},
-    NotificationService,
+    NotificationService, Queued, Report,
 };
 
-use super::{to_ts, Sender};
+use super::Sender;
 
 impl Sender for EmailMessage {
-    async fn send(self, svc: NotificationService) -> Result<SendResponse, Status> {
+    async fn send(self, svc: NotificationService, report: Report) -> Result<SendResponse, Status> {
+        self.validate()?;
         let message_id = self.message_id.clone();
-        svc.sender.send(Msg::Email(self)).await.map_err(|e| {
-            warn!("Failed to send email message:{:?}", e);
-            Status::internal("Failed to send message")
-        })?;
-
-        Ok(SendResponse {
-            message_id,
-            timestamp: Some(to_ts()),
-        })
+        svc.sender
+            .send(Queued::new(Msg::Email(self), report))
+            .await
+            .map_err(|e| {
+                warn!("Failed to send email message:{:?}", e);
+                Status::internal("Failed to send message")
+            })?;
+
+        Ok(SendResponse::accepted(message_id))
+    }
+}
+
+impl EmailMessage {
+    // 解析发件人与收件人 不合法的地址在进入队列前就被拒绝
+    fn validate(&self) -> Result<(), Status> {
+        Message::try_from(self)
+            .map(|_| ())
+            .map_err(|e| Status::invalid_argument(format!("{:#}", e)))
     }
 }
 
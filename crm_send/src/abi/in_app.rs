@@ -1,26 +1,76 @@
-use tonic::Status;
-use tracing::warn;
+use crm_core::Claims;
+use futures::StreamExt;
+use tonic::{Response, Status};
+use tracing::{info, warn};
 // NotificationService
 use crate::{
-    pb::{send_request::Message as Msg, InAppMessage, SendRequest, SendResponse},
-    NotificationService,
+    pb::{
+        send_request::Message as Msg, AckRequest, AckResponse, InAppMessage, SendRequest,
+        SendResponse, SubscribeRequest,
+    },
+    InAppStream, NotificationService, Queued, Report, ServiceResult,
 };
 
-use super::{to_ts, Sender};
+use super::Sender;
+
+// 可以订阅与确认任意设备消息的权限 其他调用方只能访问 token 中 sub 对应的设备
+const ANY_DEVICE: &str = "notification.any_device";
 
 impl Sender for InAppMessage {
-    async fn send(self, svc: NotificationService) -> Result<SendResponse, Status> {
+    async fn send(self, svc: NotificationService, report: Report) -> Result<SendResponse, Status> {
+        if self.device_id.is_empty() {
+            return Err(Status::invalid_argument("Device id is required"));
+        }
         let message_id = self.message_id.clone();
 
-        svc.sender.send(Msg::InApp(self)).await.map_err(|e| {
-            warn!("Failed to send in-app message:{:?}", e);
-            Status::internal("Failed to send message")
-        })?;
+        svc.sender
+            .send(Queued::new(Msg::InApp(self), report))
+            .await
+            .map_err(|e| {
+                warn!("Failed to send in-app message:{:?}", e);
+                Status::internal("Failed to send message")
+            })?;
+
+        Ok(SendResponse::accepted(message_id))
+    }
+}
 
-        Ok(SendResponse {
-            message_id,
-            timestamp: Some(to_ts()),
-        })
+impl NotificationService {
+    pub fn subscribe(
+        &self,
+        req: SubscribeRequest,
+        claims: Option<&Claims>,
+    ) -> ServiceResult<InAppStream> {
+        self.authorize(&req.device_id, claims)?;
+        info!("Device {} subscribed", req.device_id);
+        let stream = self.inbox.subscribe(&req.device_id).map(Ok);
+        Ok(Response::new(Box::pin(stream)))
+    }
+
+    pub fn ack(&self, req: AckRequest, claims: Option<&Claims>) -> ServiceResult<AckResponse> {
+        self.authorize(&req.device_id, claims)?;
+        let acked = self.inbox.ack(&req.device_id, &req.message_ids);
+        Ok(Response::new(AckResponse {
+            acked: acked as u32,
+        }))
+    }
+
+    fn authorize(&self, device_id: &str, claims: Option<&Claims>) -> Result<(), Status> {
+        if device_id.is_empty() {
+            return Err(Status::invalid_argument("Device id is required"));
+        }
+        let claims = claims.ok_or_else(|| Status::unauthenticated("Missing claims"))?;
+        if claims.sub == device_id || self.config.policy.grants(claims, ANY_DEVICE) {
+            return Ok(());
+        }
+        warn!(
+            "{} is not allowed to access device {}",
+            claims.sub, device_id
+        );
+        Err(Status::permission_denied(format!(
+            "Not allowed to access device: {}",
+            device_id
+        )))
     }
 }
 
@@ -1,40 +1,65 @@
 mod email;
 mod in_app;
 mod sms;
-use std::{ops::Deref, sync::Arc, time::Duration};
+use std::{
+    ops::Deref,
+    sync::{Arc, Mutex},
+};
 
 use chrono::Utc;
+use crm_core::{HealthCheck, ToTimestamp};
 use crm_metadata::{pb::Content, Tpl};
 use futures::{Stream, StreamExt};
-use prost_types::Timestamp;
-use tokio::{sync::mpsc, time::sleep};
+use metrics::{counter, gauge};
+use tokio::sync::{
+    mpsc::{self, error::TrySendError},
+    oneshot,
+};
 use tokio_stream::wrappers::ReceiverStream;
-use tonic::{Response, Status};
-use tracing::info;
+use tonic::{Code, Response, Status};
+use tracing::{info, info_span, warn, Instrument};
 use uuid::Uuid;
 
 use crate::{
     config::AppConfig,
+    delivery::{Channels, Delivery, Inbox},
     pb::{
         notification_server::NotificationServer, send_request::Message as Msg, EmailMessage,
-        SendRequest, SendResponse,
+        SendRequest, SendResponse, SendStatus,
     },
-    NotificationService, NotificationServiceInner, ResponseStream, ServiceResult,
+    NotificationService, NotificationServiceInner, Outbox, Queued, Report, ResponseStream,
+    ServiceResult,
 };
 
 const CHANNEL_SIZE: usize = 1024;
 
 pub trait Sender {
-    async fn send(self, svc: NotificationService) -> Result<SendResponse, Status>;
+    async fn send(self, svc: NotificationService, report: Report) -> Result<SendResponse, Status>;
 }
 
 impl NotificationService {
-    pub fn new(config: AppConfig) -> Self {
+    pub fn try_new(config: AppConfig) -> anyhow::Result<Self> {
+        let inbox = Inbox::new(&config.in_app);
+        let channels = Channels::try_new(&config, inbox.clone())?;
+        let (sender, outbox) = start_outbox(channels);
         let inner = Arc::new(NotificationServiceInner {
             config,
-            sender: dummy_send(),
+            sender,
+            outbox: Mutex::new(Some(outbox)),
+            inbox,
         });
-        NotificationService { inner }
+        Ok(NotificationService { inner })
+    }
+
+    // 停止接收新消息 并等待队列中的消息投递完成
+    // 返回其中投递的消息数 应用内消息不再投递
+    pub async fn flush(&self) -> usize {
+        let outbox = self.outbox.lock().unwrap().take();
+        let Some(outbox) = outbox else {
+            return 0;
+        };
+        let _ = outbox.close.send(());
+        outbox.worker.await.unwrap_or_default()
     }
 
     pub fn into_server(self) -> NotificationServer<Self> {
@@ -48,23 +73,75 @@ impl NotificationService {
         let (tx, rx) = mpsc::channel(CHANNEL_SIZE);
         let notif = self.clone();
 
-        tokio::spawn(async move {
-            while let Some(Ok(req)) = stream.next().await {
-                let notif_clone = notif.clone();
-                let res = match req.message {
-                    Some(Msg::Sms(sms)) => sms.send(notif_clone).await,
-                    Some(Msg::Email(email)) => email.send(notif_clone).await,
-                    Some(Msg::InApp(in_app)) => in_app.send(notif_clone).await,
-                    None => Err(Status::invalid_argument("Invalid message type")),
-                };
-
-                tx.send(res).await.unwrap();
+        tokio::spawn(
+            async move {
+                // 每条入队的消息持有 tx 的副本 全部投递完成后响应流才结束
+                while let Some(Ok(req)) = stream.next().await {
+                    let res = notif.enqueue(req, &tx).await;
+                    // 客户端已断开 停止读取消息
+                    if tx.send(Ok(res)).await.is_err() {
+                        info!("Client disconnected, stop sending");
+                        break;
+                    }
+                }
             }
-        });
+            .in_current_span(),
+        );
 
         let stream = ReceiverStream::new(rx);
         Ok(Response::new(Box::pin(stream)))
     }
+
+    // 将单条消息放入队列 不合法的消息在其响应中报告 不影响流中的其他消息
+    async fn enqueue(&self, req: SendRequest, report: &Report) -> SendResponse {
+        let message_id = req.message_id().to_string();
+        let Some(msg) = req.message else {
+            let status = Status::invalid_argument("Invalid message type");
+            counter!("notifications_failed_total", "channel" => "unknown").increment(1);
+            return SendResponse::from_status(message_id, &status);
+        };
+
+        let channel = channel(&msg);
+        let span = info_span!(
+            "notification.enqueue",
+            channel,
+            message_id = message_id.as_str()
+        );
+        let notif = self.clone();
+        let res = async {
+            match msg {
+                Msg::Sms(sms) => sms.send(notif, report.clone()).await,
+                Msg::Email(email) => email.send(notif, report.clone()).await,
+                Msg::InApp(in_app) => in_app.send(notif, report.clone()).await,
+            }
+        }
+        .instrument(span)
+        .await;
+
+        match res {
+            Ok(res) => {
+                counter!("notifications_enqueued_total", "channel" => channel).increment(1);
+                gauge!("notification_queue_depth").increment(1);
+                res
+            }
+            Err(status) => {
+                counter!("notifications_failed_total", "channel" => channel).increment(1);
+                SendResponse::from_status(message_id, &status)
+            }
+        }
+    }
+}
+
+// outbox 仍在投递消息时服务可用
+impl HealthCheck for NotificationService {
+    async fn check(&self) -> anyhow::Result<()> {
+        let outbox = self.outbox.lock().unwrap();
+        let running = outbox.as_ref().is_some_and(|o| !o.worker.is_finished());
+        if !running {
+            anyhow::bail!("Outbox is closed");
+        }
+        Ok(())
+    }
 }
 
 impl Deref for NotificationService {
@@ -93,26 +170,133 @@ impl SendRequest {
 
         SendRequest { message: Some(msg) }
     }
+
+    // 被包装消息的ID 没有消息时为空
+    pub fn message_id(&self) -> &str {
+        self.message.as_ref().map_or("", Msg::message_id)
+    }
+}
+
+impl SendResponse {
+    pub fn accepted(message_id: String) -> Self {
+        SendResponse {
+            message_id,
+            timestamp: Some(Utc::now().to_timestamp()),
+            status: SendStatus::Accepted as i32,
+            reason: String::new(),
+        }
+    }
+
+    // 不合法的消息为拒绝 其他错误为失败 可以重试
+    pub fn from_status(message_id: String, status: &Status) -> Self {
+        let send_status = match status.code() {
+            Code::InvalidArgument => SendStatus::Rejected,
+            _ => SendStatus::Failed,
+        };
+        SendResponse {
+            message_id,
+            timestamp: Some(Utc::now().to_timestamp()),
+            status: send_status as i32,
+            reason: status.message().to_string(),
+        }
+    }
+}
+
+impl Msg {
+    pub fn message_id(&self) -> &str {
+        match self {
+            Msg::Email(email) => &email.message_id,
+            Msg::Sms(sms) => &sms.message_id,
+            Msg::InApp(in_app) => &in_app.message_id,
+        }
+    }
+}
+
+impl Queued {
+    fn new(msg: Msg, report: Report) -> Self {
+        Self { msg, report }
+    }
 }
 
-fn dummy_send() -> mpsc::Sender<Msg> {
+fn start_outbox(delivery: impl Delivery) -> (mpsc::Sender<Queued>, Outbox) {
     let (tx, mut rx) = mpsc::channel(CHANNEL_SIZE * 100);
+    let (close, mut closed) = oneshot::channel();
 
-    tokio::spawn(async move {
-        while let Some(msg) = rx.recv().await {
-            info!("Sending message:{:?}", msg);
-            sleep(Duration::from_millis(300)).await;
+    let worker = tokio::spawn(async move {
+        loop {
+            // 先检查关闭信号 发送后立即开始清空队列
+            tokio::select! {
+                biased;
+                _ = &mut closed => break,
+                queued = rx.recv() => match queued {
+                    Some(queued) => deliver(&delivery, queued).await,
+                    None => return 0,
+                },
+            }
+        }
+
+        // 此后拒绝新消息 投递队列中已有的消息
+        rx.close();
+        let mut flushed = 0;
+        let mut dropped = 0;
+        while let Some(queued) = rx.recv().await {
+            // 收件箱只在内存中 进程退出后其中的消息随之丢失
+            if let Msg::InApp(_) = queued.msg {
+                gauge!("notification_queue_depth").decrement(1);
+                let status = Status::unavailable("Server is shutting down");
+                undelivered(&queued.msg, &queued.report, &status);
+                dropped += 1;
+                continue;
+            }
+            deliver(&delivery, queued).await;
+            flushed += 1;
+        }
+        if dropped > 0 {
+            warn!("Dropped {} queued in-app messages", dropped);
         }
+        info!("Flushed {} queued messages", flushed);
+        flushed
     });
 
-    tx
+    (tx, Outbox { close, worker })
+}
+
+async fn deliver(delivery: &impl Delivery, queued: Queued) {
+    gauge!("notification_queue_depth").decrement(1);
+    let Queued { msg, report } = queued;
+    let channel = channel(&msg);
+    match delivery.deliver(&msg).await {
+        Ok(()) => counter!("notifications_sent_total", "channel" => channel).increment(1),
+        Err(e) => undelivered(&msg, &report, &e),
+    }
+}
+
+// 按错误状态补发 REJECTED 或 FAILED 响应 message_id 与入队时的响应相同
+fn undelivered(msg: &Msg, report: &Report, e: &Status) {
+    warn!(
+        "Failed to deliver message {}: {}",
+        msg.message_id(),
+        e.message()
+    );
+    let code = format!("{:?}", e.code());
+    counter!("notifications_undelivered_total", "channel" => channel(msg), "code" => code)
+        .increment(1);
+    // 不等待读取慢的客户端 以免阻塞其他消息的投递 客户端断开时无需报告
+    let res = SendResponse::from_status(msg.message_id().to_string(), e);
+    if let Err(TrySendError::Full(_)) = report.try_send(Ok(res)) {
+        warn!(
+            "Response stream is full, dropped delivery status of {}",
+            msg.message_id()
+        );
+    }
 }
 
-fn to_ts() -> Timestamp {
-    let now = Utc::now();
-    Timestamp {
-        seconds: now.timestamp(),
-        nanos: now.timestamp_subsec_nanos() as i32,
+// 指标中使用的渠道标签
+fn channel(msg: &Msg) -> &'static str {
+    match msg {
+        Msg::Email(_) => "email",
+        Msg::Sms(_) => "sms",
+        Msg::InApp(_) => "in_app",
     }
 }
 
@@ -122,12 +306,15 @@ mod tests {
 
     use crate::pb::{EmailMessage, InAppMessage, SmsMessage};
     use anyhow::Result;
+    use crm_core::ConfigLoader;
     use futures::StreamExt;
+    use std::time::Duration;
+    use tokio::time::sleep;
 
     #[tokio::test]
     async fn send_should_work() -> Result<()> {
         let config = AppConfig::load()?;
-        let service = NotificationService::new(config);
+        let service = NotificationService::try_new(config)?;
         let stream = tokio_stream::iter(vec![
             Ok(EmailMessage::fake().into()),
             Ok(SmsMessage::fake().into()),
@@ -140,4 +327,82 @@ mod tests {
         assert_eq!(ret.len(), 3);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn flush_should_deliver_queued_messages() -> Result<()> {
+        let service = NotificationService::try_new(AppConfig::load()?)?;
+        let stream = tokio_stream::iter(vec![
+            Ok(EmailMessage::fake().into()),
+            Ok(SmsMessage::fake().into()),
+            Ok(InAppMessage::fake().into()),
+        ]);
+        // 响应流要到消息投递完成后才结束 只读取入队时的响应
+        let ret = service.send(stream).await?.into_inner().take(3);
+        assert!(
+            ret.all(|r| async move { r.unwrap().status() == SendStatus::Accepted })
+                .await
+        );
+
+        // 让后台任务取走第一条消息 另外两条留在队列中 其中的应用内消息被丢弃
+        sleep(Duration::from_millis(100)).await;
+        assert_eq!(service.flush().await, 1);
+        assert_eq!(service.flush().await, 0);
+
+        // outbox 已关闭 新消息失败
+        let stream = tokio_stream::iter(vec![Ok(EmailMessage::fake().into())]);
+        let ret = service.send(stream).await?.into_inner();
+        let ret = ret.collect::<Vec<_>>().await;
+        assert_eq!(ret[0].as_ref().unwrap().status(), SendStatus::Failed);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn bad_message_should_not_end_stream() -> Result<()> {
+        let service = NotificationService::try_new(AppConfig::load()?)?;
+        let mut sms = SmsMessage::fake();
+        sms.recipients = vec!["not a number".to_string()];
+        let mut email = EmailMessage::fake();
+        email.recipients = vec!["not an email".to_string()];
+        let stream = tokio_stream::iter(vec![
+            Ok(EmailMessage::fake().into()),
+            Ok(SendRequest { message: None }),
+            Ok(sms.into()),
+            Ok(email.into()),
+            Ok(InAppMessage::fake().into()),
+        ]);
+
+        let ret = service.send(stream).await?.into_inner();
+        let ret: Vec<_> = ret.map(|r| r.unwrap()).collect().await;
+        let statuses: Vec<_> = ret.iter().map(|r| r.status()).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                SendStatus::Accepted,
+                SendStatus::Rejected,
+                SendStatus::Rejected,
+                SendStatus::Rejected,
+                SendStatus::Accepted
+            ]
+        );
+        assert!(ret[0].reason.is_empty());
+        assert_eq!(ret[1].reason, "Invalid message type");
+        assert!(ret[2].reason.starts_with("Invalid recipient"));
+        assert!(ret[3]
+            .reason
+            .starts_with("Invalid recipient \"not an email\""));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn client_disconnect_should_stop_sending() -> Result<()> {
+        let service = NotificationService::try_new(AppConfig::load()?)?;
+        let (tx, rx) = mpsc::channel(1);
+        let ret = service.send(ReceiverStream::new(rx)).await?;
+        drop(ret);
+
+        // 响应无法送达 请求流被丢弃
+        tx.send(Ok(EmailMessage::fake().into())).await?;
+        tokio::time::timeout(Duration::from_secs(1), tx.closed()).await?;
+        Ok(())
+    }
 }
@@ -2,25 +2,47 @@ use tonic::Status;
 use tracing::warn;
 
 use crate::{
+    delivery::sms::phone,
     pb::{send_request::Message as Msg, SendRequest, SendResponse, SmsMessage},
-    NotificationService,
+    NotificationService, Queued, Report,
 };
 
-use super::{to_ts, Sender};
+use super::Sender;
 
 impl Sender for SmsMessage {
-    async fn send(self, svc: NotificationService) -> Result<SendResponse, Status> {
+    async fn send(
+        mut self,
+        svc: NotificationService,
+        report: Report,
+    ) -> Result<SendResponse, Status> {
+        self.normalize()?;
         let message_id = self.message_id.clone();
 
-        svc.sender.send(Msg::Sms(self)).await.map_err(|e| {
-            warn!("Failed to send sms message:{:?}", e);
-            Status::internal("Failed to send message")
-        })?;
+        svc.sender
+            .send(Queued::new(Msg::Sms(self), report))
+            .await
+            .map_err(|e| {
+                warn!("Failed to send sms message:{:?}", e);
+                Status::internal("Failed to send message")
+            })?;
 
-        Ok(SendResponse {
-            message_id,
-            timestamp: Some(to_ts()),
-        })
+        Ok(SendResponse::accepted(message_id))
+    }
+}
+
+impl SmsMessage {
+    // 将号码转为 E.164 格式 不合法的号码在进入队列前就被拒绝
+    fn normalize(&mut self) -> Result<(), Status> {
+        if self.recipients.is_empty() {
+            return Err(Status::invalid_argument("Recipients are required"));
+        }
+        self.sender = phone::normalize(&self.sender)
+            .map_err(|e| Status::invalid_argument(format!("Invalid sender: {}", e)))?;
+        for recipient in &mut self.recipients {
+            *recipient = phone::normalize(recipient)
+                .map_err(|e| Status::invalid_argument(format!("Invalid recipient: {}", e)))?;
+        }
+        Ok(())
     }
 }
 
@@ -39,14 +61,15 @@ impl From<SmsMessage> for SendRequest {
 #[cfg(feature = "test_utils")]
 impl SmsMessage {
     pub fn fake() -> Self {
-        use fake::faker::phone_number::en::PhoneNumber;
         use fake::Fake;
         use uuid::Uuid;
 
+        // 虚构的 E.164 格式美国号码
+        let number = || format!("+1555{:07}", (0..10_000_000).fake::<u32>());
         SmsMessage {
             message_id: Uuid::new_v4().to_string(),
-            sender: PhoneNumber().fake(),
-            recipients: vec![PhoneNumber().fake()],
+            sender: number(),
+            recipients: vec![number()],
             body: "Hello World".to_string(),
         }
     }
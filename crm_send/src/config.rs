@@ -1,36 +1,219 @@
-use anyhow::{bail, Result};
+use crm_core::{
+    config::{field, Issues, Validate},
+    AuthConfig, ConfigLoader, MetricsConfig, PolicyConfig, ServerTls, TracingConfig,
+};
 use serde::{Deserialize, Serialize};
-use std::{env, fs::File};
+use std::net::IpAddr;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AppConfig {
     pub server: ServerConfig,
     pub auth: AuthConfig,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AuthConfig {
-    pub pk: String,
+    #[serde(default)]
+    pub policy: PolicyConfig,
+    // 配置后提供 /metrics
+    #[serde(default)]
+    pub metrics: Option<MetricsConfig>,
+    // 配置后通过 OTLP 导出 trace
+    #[serde(default)]
+    pub tracing: Option<TracingConfig>,
+    // 配置后通过 SMTP 发送邮件 否则只记录日志
+    #[serde(default)]
+    pub smtp: Option<SmtpConfig>,
+    // 配置后通过 HTTP 网关发送短信 否则只记录日志
+    #[serde(default)]
+    pub sms: Option<SmsConfig>,
+    // 应用内消息保留到设备确认
+    #[serde(default)]
+    pub in_app: InAppConfig,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ServerConfig {
+    #[serde(default = "crm_core::server::default_host")]
+    pub host: IpAddr,
     pub port: u16,
+    // 退出时等待进行中请求与队列中消息的秒数
+    #[serde(default = "crm_core::server::default_drain_timeout")]
+    pub drain_timeout: u64,
+    pub tls: Option<ServerTls>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmtpConfig {
+    pub host: String,
+    // 默认为 tls 模式对应的端口 25 587 或 465
+    pub port: Option<u16>,
+    #[serde(default)]
+    pub tls: SmtpTls,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    // 每条 SMTP 命令的超时秒数
+    #[serde(default = "default_smtp_timeout")]
+    pub timeout: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SmtpTls {
+    // 明文 仅用于本地中继
+    None,
+    // 通过 STARTTLS 升级连接 服务器不支持时失败
+    #[default]
+    Starttls,
+    // 建立连接时即使用 TLS
+    Tls,
+}
+
+fn default_smtp_timeout() -> u64 {
+    30
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SmsConfig {
+    // 每个分段和收件人发送一次 POST
+    pub url: String,
+    // http basic 认证
+    pub username: Option<String>,
+    pub password: Option<String>,
+    // bearer token 设置后不再使用 basic 认证
+    pub token: Option<String>,
+    // 每个请求的 JSON 请求体 字符串中的以下占位符会被替换
+    // {message_id} {sender} {recipient} {body} {segment} {segments} {encoding}
+    #[serde(default = "default_sms_template")]
+    pub template: serde_json::Value,
+    // 每个请求的超时秒数
+    #[serde(default = "default_sms_timeout")]
+    pub timeout: u64,
+}
+
+fn default_sms_template() -> serde_json::Value {
+    serde_json::json!({
+        "from": "{sender}",
+        "to": "{recipient}",
+        "text": "{body}",
+        "reference": "{message_id}",
+    })
+}
+
+fn default_sms_timeout() -> u64 {
+    10
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InAppConfig {
+    // 未确认消息的保留秒数
+    #[serde(default = "default_in_app_ttl")]
+    pub ttl: u64,
+    // 每个设备保留的消息数 超过时丢弃最早的消息
+    #[serde(default = "default_max_pending")]
+    pub max_pending: usize,
+}
+
+impl Default for InAppConfig {
+    fn default() -> Self {
+        Self {
+            ttl: default_in_app_ttl(),
+            max_pending: default_max_pending(),
+        }
+    }
+}
+
+fn default_in_app_ttl() -> u64 {
+    7 * 24 * 3600
+}
+
+fn default_max_pending() -> usize {
+    100
+}
+
+impl ConfigLoader for AppConfig {
+    const FILE: &'static str = "send.yml";
+    const ENV: &'static str = "SEND_CONFIG";
+    const PREFIX: &'static str = "SEND";
+}
+
+impl Validate for AppConfig {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        self.server.validate(&field(path, "server"), issues);
+        self.auth.validate(&field(path, "auth"), issues);
+        self.policy.validate(&field(path, "policy"), issues);
+        if let Some(metrics) = &self.metrics {
+            metrics.validate(&field(path, "metrics"), issues);
+        }
+        if let Some(tracing) = &self.tracing {
+            tracing.validate(&field(path, "tracing"), issues);
+        }
+        if let Some(smtp) = &self.smtp {
+            smtp.validate(&field(path, "smtp"), issues);
+        }
+        if let Some(sms) = &self.sms {
+            sms.validate(&field(path, "sms"), issues);
+        }
+        self.in_app.validate(&field(path, "in_app"), issues);
+    }
+}
+
+impl Validate for ServerConfig {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        issues.check(self.port != 0, field(path, "port"), "must not be 0");
+        if let Some(tls) = &self.tls {
+            tls.validate(&field(path, "tls"), issues);
+        }
+    }
+}
+
+impl Validate for SmtpConfig {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        issues.check(
+            !self.host.is_empty(),
+            field(path, "host"),
+            "must not be empty",
+        );
+        issues.check(self.port != Some(0), field(path, "port"), "must not be 0");
+        issues.check(self.timeout != 0, field(path, "timeout"), "must not be 0");
+        issues.check(
+            self.username.is_some() == self.password.is_some(),
+            field(path, "password"),
+            "username and password must be set together",
+        );
+    }
+}
+
+impl Validate for SmsConfig {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        issues.check(
+            self.url.starts_with("http://") || self.url.starts_with("https://"),
+            field(path, "url"),
+            "must be an http or https url",
+        );
+        issues.check(self.timeout != 0, field(path, "timeout"), "must not be 0");
+        issues.check(
+            self.username.is_some() == self.password.is_some(),
+            field(path, "password"),
+            "username and password must be set together",
+        );
+        issues.check(
+            self.token.is_none() || self.username.is_none(),
+            field(path, "token"),
+            "must not be set together with username",
+        );
+        let template = self.template.to_string();
+        issues.check(
+            template.contains("{recipient}") && template.contains("{body}"),
+            field(path, "template"),
+            "must contain {recipient} and {body}",
+        );
+    }
 }
 
-impl AppConfig {
-    pub fn load() -> Result<Self> {
-        // read from  ./app.yml, or /etc/config/app.yml, or from env CHAT_CONFIG
-        let ret = match (
-            File::open("send.yml"),
-            File::open("/etc/config/send.yml"),
-            env::var("SEND_CONFIG"),
-        ) {
-            (Ok(reader), _, _) => serde_yaml::from_reader(reader),
-            (_, Ok(reader), _) => serde_yaml::from_reader(reader),
-            (_, _, Ok(path)) => serde_yaml::from_reader(File::open(path)?),
-            _ => bail!("Send Config file not found"),
-        };
-        Ok(ret?)
+impl Validate for InAppConfig {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        issues.check(self.ttl != 0, field(path, "ttl"), "must not be 0");
+        issues.check(
+            self.max_pending != 0,
+            field(path, "max_pending"),
+            "must not be 0",
+        );
     }
 }
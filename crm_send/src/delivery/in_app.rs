@@ -0,0 +1,235 @@
+use futures::{stream, Stream, StreamExt};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, Weak},
+    time::Duration,
+};
+use tokio::{
+    sync::mpsc::{self, error::TrySendError},
+    time::{sleep, Instant},
+};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{async_trait, Status};
+use tracing::{info, warn};
+
+use super::Delivery;
+use crate::{
+    config::InAppConfig,
+    pb::{send_request::Message as Msg, InAppMessage},
+};
+
+// 在线设备缓冲的最大消息数 超过后视为设备消费太慢
+const SUBSCRIBER_BUFFER: usize = 64;
+// 清理过期消息的间隔
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// 等待设备接收的应用内消息 消息保留到设备确认或过期
+/// 设备重连后会再次收到未确认的消息
+#[derive(Clone)]
+pub struct Inbox {
+    mailboxes: Arc<Mutex<HashMap<String, Mailbox>>>,
+    ttl: Duration,
+    max_pending: usize,
+}
+
+#[derive(Default)]
+struct Mailbox {
+    pending: VecDeque<Pending>,
+    subscriber: Option<mpsc::Sender<InAppMessage>>,
+}
+
+struct Pending {
+    msg: InAppMessage,
+    expires_at: Instant,
+}
+
+impl Inbox {
+    pub fn new(config: &InAppConfig) -> Self {
+        Self::with_ttl(Duration::from_secs(config.ttl), config.max_pending)
+    }
+
+    pub fn with_ttl(ttl: Duration, max_pending: usize) -> Self {
+        let inbox = Self {
+            mailboxes: Default::default(),
+            ttl,
+            max_pending,
+        };
+        let mailboxes = Arc::downgrade(&inbox.mailboxes);
+        tokio::spawn(sweep(mailboxes, ttl.min(SWEEP_INTERVAL)));
+        inbox
+    }
+
+    // 先返回设备待接收的消息 再返回新到达的消息
+    // 同一设备之前的订阅会被关闭
+    pub fn subscribe(&self, device_id: &str) -> impl Stream<Item = InAppMessage> + Send {
+        let (tx, rx) = mpsc::channel(SUBSCRIBER_BUFFER);
+        let mut mailboxes = self.mailboxes.lock().unwrap();
+        let mailbox = mailboxes.entry(device_id.to_string()).or_default();
+        mailbox.expire(Instant::now());
+        if mailbox.subscriber.replace(tx).is_some() {
+            info!(
+                "Device {} subscribed again, closing the old stream",
+                device_id
+            );
+        }
+        let pending: Vec<_> = mailbox.pending.iter().map(|p| p.msg.clone()).collect();
+        stream::iter(pending).chain(ReceiverStream::new(rx))
+    }
+
+    // 删除已收到的消息 返回其中仍待确认的数量
+    pub fn ack(&self, device_id: &str, message_ids: &[String]) -> usize {
+        let mut mailboxes = self.mailboxes.lock().unwrap();
+        let Some(mailbox) = mailboxes.get_mut(device_id) else {
+            return 0;
+        };
+        let before = mailbox.pending.len();
+        mailbox
+            .pending
+            .retain(|p| !message_ids.contains(&p.msg.message_id));
+        before - mailbox.pending.len()
+    }
+
+    fn push(&self, msg: InAppMessage) {
+        let now = Instant::now();
+        let mut mailboxes = self.mailboxes.lock().unwrap();
+        let mailbox = mailboxes.entry(msg.device_id.clone()).or_default();
+        mailbox.expire(now);
+        if mailbox.pending.len() >= self.max_pending {
+            let dropped = mailbox.pending.pop_front();
+            if let Some(dropped) = dropped {
+                warn!(
+                    "Too many pending messages for device {}, dropping {}",
+                    msg.device_id, dropped.msg.message_id
+                );
+            }
+        }
+
+        if let Some(subscriber) = &mailbox.subscriber {
+            match subscriber.try_send(msg.clone()) {
+                Ok(()) => {}
+                // 设备重连后会再次收到该消息
+                Err(TrySendError::Full(_)) => {
+                    warn!("Device {} is too slow, closing its stream", msg.device_id);
+                    mailbox.subscriber = None;
+                }
+                Err(TrySendError::Closed(_)) => mailbox.subscriber = None,
+            }
+        }
+        mailbox.pending.push_back(Pending {
+            msg,
+            expires_at: now + self.ttl,
+        });
+    }
+}
+
+impl Mailbox {
+    fn expire(&mut self, now: Instant) {
+        self.pending.retain(|p| p.expires_at > now);
+        if self.subscriber.as_ref().is_some_and(|s| s.is_closed()) {
+            self.subscriber = None;
+        }
+    }
+}
+
+// 清理过期消息 并移除没有消息的设备 收件箱被释放后停止
+async fn sweep(mailboxes: Weak<Mutex<HashMap<String, Mailbox>>>, interval: Duration) {
+    loop {
+        sleep(interval).await;
+        let Some(mailboxes) = mailboxes.upgrade() else {
+            break;
+        };
+        let now = Instant::now();
+        mailboxes.lock().unwrap().retain(|_, mailbox| {
+            mailbox.expire(now);
+            !mailbox.pending.is_empty() || mailbox.subscriber.is_some()
+        });
+    }
+}
+
+#[async_trait]
+impl Delivery for Inbox {
+    async fn deliver(&self, msg: &Msg) -> Result<(), Status> {
+        let Msg::InApp(in_app) = msg else {
+            return Err(Status::invalid_argument("Not an in-app message"));
+        };
+        self.push(in_app.clone());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+
+    fn message(device_id: &str, message_id: &str) -> InAppMessage {
+        InAppMessage {
+            message_id: message_id.to_string(),
+            device_id: device_id.to_string(),
+            title: "Hello".to_string(),
+            body: "Hello World".to_string(),
+        }
+    }
+
+    // 流中已有的消息 不等待新消息
+    fn received(stream: &mut (impl Stream<Item = InAppMessage> + Unpin)) -> Vec<String> {
+        let mut ids = Vec::new();
+        while let Some(Some(msg)) = stream.next().now_or_never() {
+            ids.push(msg.message_id);
+        }
+        ids
+    }
+
+    #[tokio::test]
+    async fn pending_messages_should_be_delivered_until_acked() {
+        let inbox = Inbox::with_ttl(Duration::from_secs(60), 10);
+        inbox.push(message("d1", "m1"));
+        inbox.push(message("d2", "other"));
+
+        // 先收到离线消息 再收到实时消息
+        let mut stream = Box::pin(inbox.subscribe("d1"));
+        inbox.push(message("d1", "m2"));
+        assert_eq!(received(&mut stream), vec!["m1", "m2"]);
+
+        // 未确认的消息在重连后再次投递
+        assert_eq!(
+            inbox.ack("d1", &["m1".to_string(), "unknown".to_string()]),
+            1
+        );
+        let mut stream = Box::pin(inbox.subscribe("d1"));
+        assert_eq!(received(&mut stream), vec!["m2"]);
+
+        inbox.ack("d1", &["m2".to_string()]);
+        let mut stream = Box::pin(inbox.subscribe("d1"));
+        assert!(received(&mut stream).is_empty());
+    }
+
+    #[tokio::test]
+    async fn new_subscription_should_close_old_one() {
+        let inbox = Inbox::with_ttl(Duration::from_secs(60), 10);
+        let mut old = Box::pin(inbox.subscribe("d1"));
+        let mut new = Box::pin(inbox.subscribe("d1"));
+        inbox.push(message("d1", "m1"));
+        assert_eq!(old.next().await, None);
+        assert_eq!(received(&mut new), vec!["m1"]);
+    }
+
+    #[tokio::test]
+    async fn expired_and_excess_messages_should_be_dropped() {
+        let inbox = Inbox::with_ttl(Duration::from_millis(50), 2);
+        inbox.push(message("d1", "m1"));
+        inbox.push(message("d1", "m2"));
+        inbox.push(message("d1", "m3"));
+        let mut stream = Box::pin(inbox.subscribe("d1"));
+        assert_eq!(received(&mut stream), vec!["m2", "m3"]);
+
+        sleep(Duration::from_millis(100)).await;
+        let mut stream = Box::pin(inbox.subscribe("d1"));
+        assert!(received(&mut stream).is_empty());
+
+        // 没有待接收消息且没有订阅的设备会被清理
+        inbox.push(message("d2", "m1"));
+        sleep(Duration::from_millis(150)).await;
+        assert!(!inbox.mailboxes.lock().unwrap().contains_key("d2"));
+    }
+}
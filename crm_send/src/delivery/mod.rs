@@ -0,0 +1,71 @@
+mod in_app;
+pub mod sms;
+mod smtp;
+
+pub use in_app::Inbox;
+pub use sms::{HttpGateway, SmsDelivery, SmsPart, SmsProvider};
+pub use smtp::SmtpDelivery;
+
+use anyhow::Result;
+use std::time::Duration;
+use tokio::time::sleep;
+use tonic::{async_trait, Status};
+use tracing::info;
+
+use crate::{pb::send_request::Message as Msg, AppConfig};
+
+/// 将队列中的消息投递出去的后端 错误转换为最接近的 gRPC 状态
+#[async_trait]
+pub trait Delivery: Send + Sync + 'static {
+    async fn deliver(&self, msg: &Msg) -> Result<(), Status>;
+}
+
+/// 只记录日志 模拟投递
+pub struct DummyDelivery;
+
+#[async_trait]
+impl Delivery for DummyDelivery {
+    async fn deliver(&self, msg: &Msg) -> Result<(), Status> {
+        info!("Sending message:{:?}", msg);
+        sleep(Duration::from_millis(300)).await;
+        Ok(())
+    }
+}
+
+/// 按消息类型选择对应渠道的后端
+pub struct Channels {
+    email: Box<dyn Delivery>,
+    sms: Box<dyn Delivery>,
+    in_app: Inbox,
+}
+
+impl Channels {
+    // 未配置时 email 与 sms 使用模拟后端
+    // 应用内消息总是放入收件箱 等待设备接收
+    pub fn try_new(config: &AppConfig, inbox: Inbox) -> Result<Self> {
+        let email: Box<dyn Delivery> = match &config.smtp {
+            Some(smtp) => Box::new(SmtpDelivery::try_new(smtp)?),
+            None => Box::new(DummyDelivery),
+        };
+        let sms: Box<dyn Delivery> = match &config.sms {
+            Some(sms) => Box::new(SmsDelivery::new(HttpGateway::try_new(sms)?)),
+            None => Box::new(DummyDelivery),
+        };
+        Ok(Self {
+            email,
+            sms,
+            in_app: inbox,
+        })
+    }
+}
+
+#[async_trait]
+impl Delivery for Channels {
+    async fn deliver(&self, msg: &Msg) -> Result<(), Status> {
+        match msg {
+            Msg::Email(_) => self.email.deliver(msg).await,
+            Msg::Sms(_) => self.sms.deliver(msg).await,
+            Msg::InApp(_) => self.in_app.deliver(msg).await,
+        }
+    }
+}
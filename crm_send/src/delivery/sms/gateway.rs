@@ -0,0 +1,226 @@
+use anyhow::Result;
+use reqwest::{Client, StatusCode};
+use serde_json::Value;
+use std::time::Duration;
+use tonic::{async_trait, Status};
+
+use super::{SmsPart, SmsProvider};
+use crate::config::SmsConfig;
+
+// 状态中保留的服务商错误信息的最大长度
+const MAX_ERROR_LEN: usize = 200;
+
+/// 每个分段向 HTTP 网关发送一个 JSON 请求 请求体由配置的模板生成
+pub struct HttpGateway {
+    client: Client,
+    url: String,
+    auth: Option<Auth>,
+    template: Value,
+}
+
+enum Auth {
+    Basic(String, String),
+    Bearer(String),
+}
+
+impl HttpGateway {
+    pub fn try_new(config: &SmsConfig) -> Result<Self> {
+        let client = Client::builder()
+            .timeout(Duration::from_secs(config.timeout))
+            .build()?;
+        let auth = match (&config.token, &config.username, &config.password) {
+            (Some(token), _, _) => Some(Auth::Bearer(token.clone())),
+            (None, Some(username), Some(password)) => {
+                Some(Auth::Basic(username.clone(), password.clone()))
+            }
+            _ => None,
+        };
+        Ok(Self {
+            client,
+            url: config.url.clone(),
+            auth,
+            template: config.template.clone(),
+        })
+    }
+}
+
+#[async_trait]
+impl SmsProvider for HttpGateway {
+    async fn send(&self, part: &SmsPart<'_>) -> Result<(), Status> {
+        let mut req = self
+            .client
+            .post(&self.url)
+            .json(&render(&self.template, part));
+        req = match &self.auth {
+            Some(Auth::Basic(username, password)) => req.basic_auth(username, Some(password)),
+            Some(Auth::Bearer(token)) => req.bearer_auth(token),
+            None => req,
+        };
+
+        let res = req.send().await.map_err(|e| {
+            let message = format!("SMS gateway is unreachable: {}", e);
+            match e.is_timeout() {
+                true => Status::deadline_exceeded(message),
+                false => Status::unavailable(message),
+            }
+        })?;
+        let status = res.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let body = res.text().await.unwrap_or_default();
+        Err(provider_error(status, &body))
+    }
+}
+
+// 替换模板中所有字符串里的占位符 只包含 {segment} 或 {segments} 的字符串替换为数字
+fn render(template: &Value, part: &SmsPart) -> Value {
+    match template {
+        Value::String(s) => match s.as_str() {
+            "{segment}" => part.segment.into(),
+            "{segments}" => part.segments.into(),
+            _ => fill(s, part).into(),
+        },
+        Value::Array(values) => values.iter().map(|v| render(v, part)).collect(),
+        Value::Object(map) => map
+            .iter()
+            .map(|(k, v)| (k.clone(), render(v, part)))
+            .collect(),
+        v => v.clone(),
+    }
+}
+
+// 只扫描一遍 值中出现的占位符不会再被替换
+fn fill(s: &str, part: &SmsPart) -> String {
+    let mut filled = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find('{') {
+        filled.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        let var = tail
+            .find('}')
+            .and_then(|end| var(&tail[1..end], part).map(|v| (end, v)));
+        match var {
+            Some((end, value)) => {
+                filled.push_str(&value);
+                rest = &tail[end + 1..];
+            }
+            None => {
+                filled.push('{');
+                rest = &tail[1..];
+            }
+        }
+    }
+    filled.push_str(rest);
+    filled
+}
+
+fn var(name: &str, part: &SmsPart) -> Option<String> {
+    let value = match name {
+        "message_id" => part.message_id.to_string(),
+        "sender" => part.sender.to_string(),
+        "recipient" => part.recipient.to_string(),
+        "body" => part.body.to_string(),
+        "segment" => part.segment.to_string(),
+        "segments" => part.segments.to_string(),
+        "encoding" => part.encoding.as_str().to_string(),
+        _ => return None,
+    };
+    Some(value)
+}
+
+// 将网关的 HTTP 状态码转换为 gRPC 状态 响应体中有错误信息时一并保留
+fn provider_error(status: StatusCode, body: &str) -> Status {
+    let message = format!(
+        "SMS gateway returned {}: {}",
+        status.as_u16(),
+        error_message(body)
+    );
+    match status {
+        StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => {
+            Status::invalid_argument(message)
+        }
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => Status::permission_denied(message),
+        StatusCode::TOO_MANY_REQUESTS => Status::resource_exhausted(message),
+        StatusCode::REQUEST_TIMEOUT | StatusCode::GATEWAY_TIMEOUT => {
+            Status::deadline_exceeded(message)
+        }
+        s if s.is_server_error() => Status::unavailable(message),
+        _ => Status::internal(message),
+    }
+}
+
+// 依次取 {"message": ..} {"error": ..} 或 {"error": {"message": ..}} 都没有时使用原始响应体
+fn error_message(body: &str) -> String {
+    let json: Option<Value> = serde_json::from_str(body).ok();
+    let message = json.as_ref().and_then(|v| {
+        [&v["message"], &v["error"], &v["error"]["message"]]
+            .into_iter()
+            .find_map(|v| v.as_str())
+    });
+    let message = message.unwrap_or(body).trim();
+    match message.char_indices().nth(MAX_ERROR_LEN) {
+        Some((i, _)) => format!("{}...", &message[..i]),
+        None => message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delivery::sms::Encoding;
+    use serde_json::json;
+
+    fn part() -> SmsPart<'static> {
+        SmsPart {
+            message_id: "id",
+            sender: "+15550100000",
+            recipient: "+15550100001",
+            body: "Hi {sender} \"quoted\"",
+            segment: 2,
+            segments: 3,
+            encoding: Encoding::Gsm7,
+        }
+    }
+
+    #[test]
+    fn template_should_be_rendered() {
+        let template = json!({
+            "to": ["{recipient}"],
+            "text": "{body}",
+            "ref": "{message_id}-{segment}/{segments}",
+            "part": "{segment}",
+            "dcs": "{encoding}",
+            "unknown": "{nope}",
+            "flash": false,
+        });
+        let body = render(&template, &part());
+        assert_eq!(
+            body,
+            json!({
+                "to": ["+15550100001"],
+                "text": "Hi {sender} \"quoted\"",
+                "ref": "id-2/3",
+                "part": 2,
+                "dcs": "gsm7",
+                "unknown": "{nope}",
+                "flash": false,
+            })
+        );
+    }
+
+    #[test]
+    fn provider_error_should_map_to_status() {
+        let status = provider_error(StatusCode::BAD_REQUEST, r#"{"message":"bad number"}"#);
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        assert_eq!(status.message(), "SMS gateway returned 400: bad number");
+
+        let status = provider_error(StatusCode::TOO_MANY_REQUESTS, r#"{"error":"slow down"}"#);
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+        assert!(status.message().ends_with("slow down"));
+
+        let status = provider_error(StatusCode::BAD_GATEWAY, "upstream down");
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+        assert!(status.message().ends_with("upstream down"));
+    }
+}
@@ -0,0 +1,80 @@
+mod gateway;
+pub mod phone;
+pub mod segment;
+
+pub use gateway::HttpGateway;
+pub use segment::Encoding;
+
+use tonic::{async_trait, Status};
+use tracing::warn;
+
+use super::Delivery;
+use crate::pb::send_request::Message as Msg;
+
+/// 发给单个收件人的一段短信
+#[derive(Debug, Clone)]
+pub struct SmsPart<'a> {
+    pub message_id: &'a str,
+    pub sender: &'a str,
+    pub recipient: &'a str,
+    pub body: &'a str,
+    // 分段序号 从 1 开始
+    pub segment: usize,
+    pub segments: usize,
+    pub encoding: Encoding,
+}
+
+/// 短信服务商 错误转换为最接近的 gRPC 状态
+#[async_trait]
+pub trait SmsProvider: Send + Sync + 'static {
+    async fn send(&self, part: &SmsPart<'_>) -> Result<(), Status>;
+}
+
+/// 将短信拆分为多段 逐段交给服务商发送
+/// 号码在接收消息时已规范为 E.164
+pub struct SmsDelivery<P> {
+    provider: P,
+}
+
+impl<P: SmsProvider> SmsDelivery<P> {
+    pub fn new(provider: P) -> Self {
+        Self { provider }
+    }
+}
+
+#[async_trait]
+impl<P: SmsProvider> Delivery for SmsDelivery<P> {
+    async fn deliver(&self, msg: &Msg) -> Result<(), Status> {
+        let Msg::Sms(sms) = msg else {
+            return Err(Status::invalid_argument("Not an SMS message"));
+        };
+        let (encoding, segments) = segment::split(&sms.body);
+
+        // 一个收件人失败不影响其他收件人 返回第一个错误
+        let mut failed = None;
+        for recipient in &sms.recipients {
+            for (i, body) in segments.iter().enumerate() {
+                let part = SmsPart {
+                    message_id: &sms.message_id,
+                    sender: &sms.sender,
+                    recipient,
+                    body,
+                    segment: i + 1,
+                    segments: segments.len(),
+                    encoding,
+                };
+                if let Err(e) = self.provider.send(&part).await {
+                    warn!(
+                        "Failed to send SMS {} to {}: {}",
+                        sms.message_id,
+                        recipient,
+                        e.message()
+                    );
+                    failed.get_or_insert(e);
+                    break;
+                }
+            }
+        }
+        failed.map_or(Ok(()), Err)
+    }
+}
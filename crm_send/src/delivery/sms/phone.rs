@@ -0,0 +1,61 @@
+use anyhow::{bail, Result};
+
+// E.164 最多 15 位数字 实际使用中最短的号码为 7 位
+const MIN_DIGITS: usize = 7;
+const MAX_DIGITS: usize = 15;
+
+/// 将电话号码规范为 E.164 格式 如 "+1 (555) 010-0000" 转为 "+15550100000"
+/// 开头的 00 视为国际前缀
+pub fn normalize(number: &str) -> Result<String> {
+    let number = number.trim();
+    let digits = match (number.strip_prefix('+'), number.strip_prefix("00")) {
+        (Some(rest), _) | (None, Some(rest)) => rest,
+        (None, None) => bail!("{:?} must start with a country code", number),
+    };
+
+    let mut normalized = String::with_capacity(MAX_DIGITS + 1);
+    normalized.push('+');
+    for c in digits.chars() {
+        match c {
+            '0'..='9' => normalized.push(c),
+            ' ' | '-' | '.' | '(' | ')' => {}
+            _ => bail!("{:?} is not a phone number", number),
+        }
+    }
+
+    let len = normalized.len() - 1;
+    if !(MIN_DIGITS..=MAX_DIGITS).contains(&len) {
+        bail!(
+            "{:?} must have {} to {} digits",
+            number,
+            MIN_DIGITS,
+            MAX_DIGITS
+        );
+    }
+    if normalized.as_bytes()[1] == b'0' {
+        bail!("{:?} has an invalid country code", number);
+    }
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn number_should_be_normalized() {
+        assert_eq!(normalize("+1 (555) 010-0000").unwrap(), "+15550100000");
+        assert_eq!(normalize("0086 138.0013.8000").unwrap(), "+8613800138000");
+        assert_eq!(normalize(" +447700900123 ").unwrap(), "+447700900123");
+    }
+
+    #[test]
+    fn invalid_number_should_be_rejected() {
+        // 没有国家码
+        assert!(normalize("555-010-0000").is_err());
+        assert!(normalize("+0123456789").is_err());
+        assert!(normalize("+1 555 CALL NOW").is_err());
+        assert!(normalize("+12345").is_err());
+        assert!(normalize("+1234567890123456").is_err());
+    }
+}
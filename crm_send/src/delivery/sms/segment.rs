@@ -0,0 +1,131 @@
+// GSM 03.38 默认字母表中的字符 每个占一个 septet
+const GSM7_BASIC: &str = "@£$¥èéùìòÇ\nØø\rÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ !\"#¤%&'()*+,-./0123456789:;<=>?\
+    ¡ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§¿abcdefghijklmnopqrstuvwxyzäöñüà";
+// 扩展表中的字符 以转义符加字符发送 占两个 septet
+const GSM7_EXTENSION: &str = "\x0c^{}\\[~]|€";
+
+/// 短信正文的编码 决定每段能容纳多少字符
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Gsm7,
+    Ucs2,
+}
+
+impl Encoding {
+    // 所有字符都能用 GSM-7 表示时使用 GSM-7
+    pub fn detect(body: &str) -> Self {
+        match body.chars().all(|c| gsm7_septets(c).is_some()) {
+            true => Encoding::Gsm7,
+            false => Encoding::Ucs2,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Encoding::Gsm7 => "gsm7",
+            Encoding::Ucs2 => "ucs2",
+        }
+    }
+
+    // 单条短信与长短信每段可容纳的单位数 差值被拼接头占用
+    fn limits(&self) -> (usize, usize) {
+        match self {
+            Encoding::Gsm7 => (160, 153),
+            Encoding::Ucs2 => (70, 67),
+        }
+    }
+
+    // GSM-7 为 septet 数 UCS-2 为 UTF-16 编码单元数
+    fn units(&self, c: char) -> usize {
+        match self {
+            Encoding::Gsm7 => gsm7_septets(c).unwrap_or(1),
+            Encoding::Ucs2 => c.len_utf16(),
+        }
+    }
+}
+
+fn gsm7_septets(c: char) -> Option<usize> {
+    if GSM7_BASIC.contains(c) {
+        Some(1)
+    } else if GSM7_EXTENSION.contains(c) {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// 将正文拆分为实际发送的分段 转义序列与代理对不会被拆开
+pub fn split(body: &str) -> (Encoding, Vec<String>) {
+    let encoding = Encoding::detect(body);
+    let (single, multi) = encoding.limits();
+    let total: usize = body.chars().map(|c| encoding.units(c)).sum();
+    if total <= single {
+        return (encoding, vec![body.to_string()]);
+    }
+
+    let mut segments = Vec::with_capacity(total.div_ceil(multi));
+    let mut segment = String::new();
+    let mut units = 0;
+    for c in body.chars() {
+        let n = encoding.units(c);
+        if units + n > multi {
+            segments.push(std::mem::take(&mut segment));
+            units = 0;
+        }
+        segment.push(c);
+        units += n;
+    }
+    segments.push(segment);
+    (encoding, segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gsm7_body_should_fit_160_septets() {
+        let (encoding, segments) = split(&"a".repeat(160));
+        assert_eq!(encoding, Encoding::Gsm7);
+        assert_eq!(segments.len(), 1);
+
+        let (_, segments) = split(&"a".repeat(161));
+        let lens: Vec<_> = segments.iter().map(|s| s.len()).collect();
+        assert_eq!(lens, vec![153, 8]);
+    }
+
+    #[test]
+    fn gsm7_extension_should_take_two_septets() {
+        // 80 个欧元符号占 160 个 septet
+        let (encoding, segments) = split(&"€".repeat(80));
+        assert_eq!(encoding, Encoding::Gsm7);
+        assert_eq!(segments.len(), 1);
+
+        // 转义序列不会被拆到两段中
+        let body = format!("{}€{}", "a".repeat(152), "a".repeat(10));
+        let (_, segments) = split(&body);
+        assert_eq!(segments[0], "a".repeat(152));
+        assert!(segments[1].starts_with('€'));
+    }
+
+    #[test]
+    fn ucs2_body_should_fit_70_units() {
+        let (encoding, segments) = split(&"你".repeat(70));
+        assert_eq!(encoding, Encoding::Ucs2);
+        assert_eq!(segments.len(), 1);
+
+        let (_, segments) = split(&"你".repeat(71));
+        let lens: Vec<_> = segments.iter().map(|s| s.chars().count()).collect();
+        assert_eq!(lens, vec![67, 4]);
+    }
+
+    #[test]
+    fn surrogate_pair_should_not_be_split() {
+        // 只要有一个字符不在 GSM-7 中 整条短信都使用 UCS-2
+        let body = format!("{}😀{}", "a".repeat(66), "a".repeat(10));
+        let (encoding, segments) = split(&body);
+        assert_eq!(encoding, Encoding::Ucs2);
+        assert_eq!(segments[0], "a".repeat(66));
+        assert!(segments[1].starts_with('😀'));
+    }
+}
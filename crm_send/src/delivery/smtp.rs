@@ -0,0 +1,137 @@
+use anyhow::{Context, Result};
+use lettre::{
+    message::{header::ContentType, Mailbox},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+use std::time::Duration;
+use tonic::{async_trait, Status};
+
+use super::Delivery;
+use crate::{
+    config::{SmtpConfig, SmtpTls},
+    pb::{send_request::Message as Msg, EmailMessage},
+};
+
+type Transport = AsyncSmtpTransport<Tokio1Executor>;
+
+/// 通过 SMTP 中继投递邮件
+pub struct SmtpDelivery {
+    transport: Transport,
+}
+
+impl SmtpDelivery {
+    pub fn try_new(config: &SmtpConfig) -> Result<Self> {
+        let mut builder = match config.tls {
+            SmtpTls::None => Transport::builder_dangerous(&config.host),
+            SmtpTls::Starttls => Transport::starttls_relay(&config.host)?,
+            SmtpTls::Tls => Transport::relay(&config.host)?,
+        }
+        .timeout(Some(Duration::from_secs(config.timeout)));
+        if let Some(port) = config.port {
+            builder = builder.port(port);
+        }
+        if let (Some(username), Some(password)) = (&config.username, &config.password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+        Ok(Self {
+            transport: builder.build(),
+        })
+    }
+}
+
+#[async_trait]
+impl Delivery for SmtpDelivery {
+    async fn deliver(&self, msg: &Msg) -> Result<(), Status> {
+        let Msg::Email(email) = msg else {
+            return Err(Status::invalid_argument("Not an email message"));
+        };
+        let message =
+            Message::try_from(email).map_err(|e| Status::invalid_argument(format!("{:#}", e)))?;
+        self.transport.send(message).await.map_err(smtp_error)?;
+        Ok(())
+    }
+}
+
+// 5xx 永久错误表示服务器不会接受原样的邮件
+fn smtp_error(e: lettre::transport::smtp::Error) -> Status {
+    let message = format!("SMTP delivery failed: {}", e);
+    if e.is_permanent() {
+        Status::failed_precondition(message)
+    } else if e.is_timeout() {
+        Status::deadline_exceeded(message)
+    } else {
+        Status::unavailable(message)
+    }
+}
+
+impl TryFrom<&EmailMessage> for Message {
+    type Error = anyhow::Error;
+
+    fn try_from(email: &EmailMessage) -> Result<Self> {
+        let from: Mailbox = email
+            .sender
+            .parse()
+            .with_context(|| format!("Invalid sender {:?}", email.sender))?;
+        // 保留消息ID 以便退信能追溯到请求
+        let message_id = format!("<{}@{}>", email.message_id, from.email.domain());
+        let mut builder = Message::builder()
+            .message_id(Some(message_id))
+            .from(from)
+            .subject(&email.subject)
+            .header(ContentType::TEXT_PLAIN);
+        for recipient in &email.recipients {
+            let to: Mailbox = recipient
+                .parse()
+                .with_context(|| format!("Invalid recipient {:?}", recipient))?;
+            builder = builder.to(to);
+        }
+        Ok(builder.body(email.body.clone())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn email() -> EmailMessage {
+        EmailMessage {
+            message_id: "0f8a3c1e".to_string(),
+            subject: "Welcome".to_string(),
+            sender: "CRM <crm@example.com>".to_string(),
+            recipients: vec![
+                "alice@example.com".to_string(),
+                "bob@example.com".to_string(),
+            ],
+            body: "Hello World".to_string(),
+        }
+    }
+
+    #[test]
+    fn email_should_convert_to_mime_message() -> Result<()> {
+        let message = Message::try_from(&email())?;
+        let envelope = message.envelope();
+        assert_eq!(envelope.from().unwrap().to_string(), "crm@example.com");
+        assert_eq!(envelope.to().len(), 2);
+
+        let formatted = String::from_utf8(message.formatted())?;
+        assert!(formatted.contains("Message-ID: <0f8a3c1e@example.com>\r\n"));
+        assert!(formatted.contains("From: CRM <crm@example.com>\r\n"));
+        assert!(formatted.contains("To: alice@example.com, bob@example.com\r\n"));
+        assert!(formatted.contains("Subject: Welcome\r\n"));
+        assert!(formatted.contains("Content-Type: text/plain; charset=utf-8\r\n"));
+        assert!(formatted.ends_with("\r\n\r\nHello World"));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_address_should_be_rejected() {
+        let mut invalid = email();
+        invalid.sender = "not an address".to_string();
+        assert!(Message::try_from(&invalid).is_err());
+
+        let mut invalid = email();
+        invalid.recipients.clear();
+        assert!(Message::try_from(&invalid).is_err());
+    }
+}
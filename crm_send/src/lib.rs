@@ -1,17 +1,31 @@
+#![allow(clippy::result_large_err)]
+
 mod abi;
 mod config;
+pub mod delivery;
 pub mod pb;
-pub use config::AppConfig;
+pub use config::{AppConfig, InAppConfig, SmsConfig, SmtpConfig, SmtpTls};
+use crm_core::Claims;
+use delivery::Inbox;
 use futures::Stream;
 use pb::{
-    notification_server::Notification, send_request::Message as Msg, SendRequest, SendResponse,
+    notification_server::Notification, send_request::Message as Msg, AckRequest, AckResponse,
+    InAppMessage, SendRequest, SendResponse, SubscribeRequest,
+};
+use std::{
+    pin::Pin,
+    sync::{Arc, Mutex},
+};
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
 };
-use std::{pin::Pin, sync::Arc};
-use tokio::sync::mpsc;
 use tonic::{async_trait, Request, Response, Status, Streaming};
 
 type ServiceResult<T> = Result<Response<T>, Status>;
 type ResponseStream = Pin<Box<dyn Stream<Item = Result<SendResponse, Status>> + Send>>;
+type Report = mpsc::Sender<Result<SendResponse, Status>>;
+type InAppStream = Pin<Box<dyn Stream<Item = Result<InAppMessage, Status>> + Send>>;
 
 #[derive(Clone)]
 pub struct NotificationService {
@@ -21,12 +35,27 @@ pub struct NotificationService {
 #[allow(unused)]
 pub struct NotificationServiceInner {
     config: AppConfig,
-    sender: mpsc::Sender<Msg>,
+    sender: mpsc::Sender<Queued>,
+    outbox: Mutex<Option<Outbox>>,
+    inbox: Inbox,
+}
+
+/// 等待投递的消息 投递失败时通过 report 在原来的 Send 流中再发一条响应
+struct Queued {
+    msg: Msg,
+    report: Report,
+}
+
+/// 在后台投递队列中消息的任务
+struct Outbox {
+    close: oneshot::Sender<()>,
+    worker: JoinHandle<usize>,
 }
 
 #[async_trait]
 impl Notification for NotificationService {
     type SendStream = ResponseStream;
+    type SubscribeStream = InAppStream;
 
     async fn send(
         &self,
@@ -35,4 +64,17 @@ impl Notification for NotificationService {
         let stream = request.into_inner();
         self.send(stream).await
     }
+
+    async fn subscribe(
+        &self,
+        request: Request<SubscribeRequest>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        let claims = request.extensions().get::<Claims>().cloned();
+        self.subscribe(request.into_inner(), claims.as_ref())
+    }
+
+    async fn ack(&self, request: Request<AckRequest>) -> Result<Response<AckResponse>, Status> {
+        let claims = request.extensions().get::<Claims>().cloned();
+        self.ack(request.into_inner(), claims.as_ref())
+    }
 }
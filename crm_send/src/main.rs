@@ -1,26 +1,44 @@
 use anyhow::Result;
-use crm_send::{AppConfig, NotificationService};
-use tracing::{info, level_filters::LevelFilter};
-use tracing_subscriber::{
-    fmt::Layer, layer::SubscriberExt as _, util::SubscriberInitExt as _, Layer as _,
+use crm_core::{init_tracing, metrics, Bootstrap, ConfigLoader};
+use crm_send::{
+    pb::{notification_server::NotificationServer, FILE_DESCRIPTOR_SET},
+    AppConfig, NotificationService,
 };
+use std::{env, net::SocketAddr, time::Duration};
+use tokio::time::timeout;
+use tonic::{server::NamedService, service::Routes};
+use tracing::{info, warn};
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let layer = Layer::new().with_filter(LevelFilter::INFO);
-    tracing_subscriber::registry().with(layer).init();
+    let config = AppConfig::load_with_args(env::args().skip(1))?;
+    let _guard = init_tracing("crm_send", config.tracing.as_ref())?;
+    if let Some(config) = &config.metrics {
+        metrics::install(config)?;
+    }
+    let addr = SocketAddr::new(config.server.host, config.server.port);
+    let drain_timeout = Duration::from_secs(config.server.drain_timeout);
+    let server = Bootstrap::try_new(
+        addr,
+        config.server.tls.as_ref(),
+        &config.auth,
+        &config.policy,
+    )?
+    .drain_timeout(drain_timeout);
+    info!("Starting server at: {}", server.addr());
 
-    let config = AppConfig::load().expect("Failed to load config");
-    let addr = config.server.port;
-    let addr = format!("[::1]:{}", addr)
-        .parse()
-        .expect("Failed to parse address");
-    info!("Starting server at: {}", addr);
+    let svc = NotificationService::try_new(config)?;
+    let server = server
+        .health(
+            &[NotificationServer::<NotificationService>::NAME],
+            svc.clone(),
+        )
+        .reflection(FILE_DESCRIPTOR_SET);
+    server.serve(Routes::new(svc.clone().into_server())).await?;
 
-    let svc = NotificationService::new(config).into_server();
-    tonic::transport::Server::builder()
-        .add_service(svc)
-        .serve(addr)
-        .await?;
+    // 投递退出前已接收的消息 应用内消息无法保留 会被丢弃
+    if timeout(drain_timeout, svc.flush()).await.is_err() {
+        warn!("Timed out flushing queued messages");
+    }
     Ok(())
 }
@@ -2,3 +2,5 @@
 mod notification;
 
 pub use notification::*;
+
+pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("notification");
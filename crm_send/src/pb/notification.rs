@@ -79,6 +79,73 @@ pub struct SendResponse {
     /// timestamp of when the message was sent
     #[prost(message, optional, tag = "2")]
     pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    /// whether the message was queued, or why its delivery failed
+    #[prost(enumeration = "SendStatus", tag = "3")]
+    pub status: i32,
+    /// why the message was rejected or failed, empty when accepted
+    #[prost(string, tag = "4")]
+    pub reason: ::prost::alloc::string::String,
+}
+/// request to receive the in-app messages of a device
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubscribeRequest {
+    /// device to receive the in-app messages for
+    #[prost(string, tag = "1")]
+    pub device_id: ::prost::alloc::string::String,
+}
+/// acknowledge in-app messages received by a device
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AckRequest {
+    /// device that received the messages
+    #[prost(string, tag = "1")]
+    pub device_id: ::prost::alloc::string::String,
+    /// identifiers of the received messages
+    #[prost(string, repeated, tag = "2")]
+    pub message_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// response to an ack request
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct AckResponse {
+    /// number of messages removed from the pending ones
+    #[prost(uint32, tag = "1")]
+    pub acked: u32,
+}
+/// what happened to a message of the send stream
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SendStatus {
+    /// not set, treated as not accepted
+    Unspecified = 0,
+    /// queued for delivery
+    Accepted = 1,
+    /// invalid message or refused by the provider, sending it again will not help
+    Rejected = 2,
+    /// could not be queued or delivered, may be sent again later
+    Failed = 3,
+}
+impl SendStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "SEND_STATUS_UNSPECIFIED",
+            Self::Accepted => "SEND_STATUS_ACCEPTED",
+            Self::Rejected => "SEND_STATUS_REJECTED",
+            Self::Failed => "SEND_STATUS_FAILED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SEND_STATUS_UNSPECIFIED" => Some(Self::Unspecified),
+            "SEND_STATUS_ACCEPTED" => Some(Self::Accepted),
+            "SEND_STATUS_REJECTED" => Some(Self::Rejected),
+            "SEND_STATUS_FAILED" => Some(Self::Failed),
+            _ => None,
+        }
+    }
 }
 /// Generated client implementations.
 pub mod notification_client {
@@ -87,10 +154,10 @@ pub mod notification_client {
         dead_code,
         missing_docs,
         clippy::wildcard_imports,
-        clippy::let_unit_value
+        clippy::let_unit_value,
     )]
-    use tonic::codegen::http::Uri;
     use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
     /// The notification service provides a way to send notifications to users.
     #[derive(Debug, Clone)]
     pub struct NotificationClient<T> {
@@ -135,8 +202,9 @@ pub mod notification_client {
                     <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
                 >,
             >,
-            <T as tonic::codegen::Service<http::Request<tonic::body::BoxBody>>>::Error:
-                Into<StdError> + std::marker::Send + std::marker::Sync,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
         {
             NotificationClient::new(InterceptedService::new(inner, interceptor))
         }
@@ -171,7 +239,9 @@ pub mod notification_client {
             self.inner = self.inner.max_encoding_message_size(limit);
             self
         }
-        /// Send a notification to a user.
+        /// Send a notification to a user. Each message is answered once it is queued,
+        /// and again with the same message_id if its delivery fails. The stream ends
+        /// after all queued messages are delivered.
         pub async fn send(
             &mut self,
             request: impl tonic::IntoStreamingRequest<Message = super::SendRequest>,
@@ -179,16 +249,70 @@ pub mod notification_client {
             tonic::Response<tonic::codec::Streaming<super::SendResponse>>,
             tonic::Status,
         > {
-            self.inner.ready().await.map_err(|e| {
-                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
-            })?;
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/notification.Notification/Send");
+            let path = http::uri::PathAndQuery::from_static(
+                "/notification.Notification/Send",
+            );
             let mut req = request.into_streaming_request();
             req.extensions_mut()
                 .insert(GrpcMethod::new("notification.Notification", "Send"));
             self.inner.streaming(req, path, codec).await
         }
+        /// Receive the in-app messages of a device, pending ones are delivered first.
+        pub async fn subscribe(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::InAppMessage>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/notification.Notification/Subscribe",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("notification.Notification", "Subscribe"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Acknowledge received in-app messages so they are not delivered again.
+        pub async fn ack(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AckRequest>,
+        ) -> std::result::Result<tonic::Response<super::AckResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/notification.Notification/Ack",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("notification.Notification", "Ack"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -198,7 +322,7 @@ pub mod notification_server {
         dead_code,
         missing_docs,
         clippy::wildcard_imports,
-        clippy::let_unit_value
+        clippy::let_unit_value,
     )]
     use tonic::codegen::*;
     /// Generated trait containing gRPC methods that should be implemented for use with NotificationServer.
@@ -207,13 +331,32 @@ pub mod notification_server {
         /// Server streaming response type for the Send method.
         type SendStream: tonic::codegen::tokio_stream::Stream<
                 Item = std::result::Result<super::SendResponse, tonic::Status>,
-            > + std::marker::Send
+            >
+            + std::marker::Send
             + 'static;
-        /// Send a notification to a user.
+        /// Send a notification to a user. Each message is answered once it is queued,
+        /// and again with the same message_id if its delivery fails. The stream ends
+        /// after all queued messages are delivered.
         async fn send(
             &self,
             request: tonic::Request<tonic::Streaming<super::SendRequest>>,
         ) -> std::result::Result<tonic::Response<Self::SendStream>, tonic::Status>;
+        /// Server streaming response type for the Subscribe method.
+        type SubscribeStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::InAppMessage, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Receive the in-app messages of a device, pending ones are delivered first.
+        async fn subscribe(
+            &self,
+            request: tonic::Request<super::SubscribeRequest>,
+        ) -> std::result::Result<tonic::Response<Self::SubscribeStream>, tonic::Status>;
+        /// Acknowledge received in-app messages so they are not delivered again.
+        async fn ack(
+            &self,
+            request: tonic::Request<super::AckRequest>,
+        ) -> std::result::Result<tonic::Response<super::AckResponse>, tonic::Status>;
     }
     /// The notification service provides a way to send notifications to users.
     #[derive(Debug)]
@@ -237,7 +380,10 @@ pub mod notification_server {
                 max_encoding_message_size: None,
             }
         }
-        pub fn with_interceptor<F>(inner: T, interceptor: F) -> InterceptedService<Self, F>
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
         where
             F: tonic::service::Interceptor,
         {
@@ -292,18 +438,24 @@ pub mod notification_server {
                 "/notification.Notification/Send" => {
                     #[allow(non_camel_case_types)]
                     struct SendSvc<T: Notification>(pub Arc<T>);
-                    impl<T: Notification> tonic::server::StreamingService<super::SendRequest> for SendSvc<T> {
+                    impl<
+                        T: Notification,
+                    > tonic::server::StreamingService<super::SendRequest>
+                    for SendSvc<T> {
                         type Response = super::SendResponse;
                         type ResponseStream = T::SendStream;
-                        type Future =
-                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
                         fn call(
                             &mut self,
                             request: tonic::Request<tonic::Streaming<super::SendRequest>>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
-                            let fut =
-                                async move { <T as Notification>::send(&inner, request).await };
+                            let fut = async move {
+                                <T as Notification>::send(&inner, request).await
+                            };
                             Box::pin(fut)
                         }
                     }
@@ -329,19 +481,112 @@ pub mod notification_server {
                     };
                     Box::pin(fut)
                 }
-                _ => Box::pin(async move {
-                    let mut response = http::Response::new(empty_body());
-                    let headers = response.headers_mut();
-                    headers.insert(
-                        tonic::Status::GRPC_STATUS,
-                        (tonic::Code::Unimplemented as i32).into(),
-                    );
-                    headers.insert(
-                        http::header::CONTENT_TYPE,
-                        tonic::metadata::GRPC_CONTENT_TYPE,
-                    );
-                    Ok(response)
-                }),
+                "/notification.Notification/Subscribe" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeSvc<T: Notification>(pub Arc<T>);
+                    impl<
+                        T: Notification,
+                    > tonic::server::ServerStreamingService<super::SubscribeRequest>
+                    for SubscribeSvc<T> {
+                        type Response = super::InAppMessage;
+                        type ResponseStream = T::SubscribeStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubscribeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Notification>::subscribe(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubscribeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/notification.Notification/Ack" => {
+                    #[allow(non_camel_case_types)]
+                    struct AckSvc<T: Notification>(pub Arc<T>);
+                    impl<T: Notification> tonic::server::UnaryService<super::AckRequest>
+                    for AckSvc<T> {
+                        type Response = super::AckResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AckRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as Notification>::ack(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AckSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        let mut response = http::Response::new(empty_body());
+                        let headers = response.headers_mut();
+                        headers
+                            .insert(
+                                tonic::Status::GRPC_STATUS,
+                                (tonic::Code::Unimplemented as i32).into(),
+                            );
+                        headers
+                            .insert(
+                                http::header::CONTENT_TYPE,
+                                tonic::metadata::GRPC_CONTENT_TYPE,
+                            );
+                        Ok(response)
+                    })
+                }
             }
         }
     }
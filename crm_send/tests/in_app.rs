@@ -0,0 +1,143 @@
+use anyhow::Result;
+use crm_core::{
+    auth::{bearer, test_utils::TestKey, AUTHORIZATION},
+    Bootstrap, ConfigLoader,
+};
+use crm_send::{
+    pb::{
+        notification_client::NotificationClient, AckRequest, InAppMessage, SendRequest,
+        SubscribeRequest,
+    },
+    AppConfig, NotificationService,
+};
+use futures::StreamExt;
+use std::time::Duration;
+use tokio::time::timeout;
+use tonic::{
+    service::Routes,
+    transport::{server::TcpIncoming, Channel},
+    Code, Request, Streaming,
+};
+
+const PORT: u16 = 63100;
+
+#[tokio::test]
+async fn in_app_message_should_reach_device_until_acked() -> Result<()> {
+    let key = TestKey::new();
+    start_server(&key, PORT)?;
+    let marketer = key.token_with_roles("marketer", &["marketer"]);
+    let device = key.token_with_roles("d1", &["device"]);
+    let mut client = NotificationClient::connect(format!("http://[::1]:{}", PORT)).await?;
+
+    // 设备离线时发送
+    let m1 = send(&mut client, &marketer).await?;
+    let mut stream = subscribe(&mut client, &device).await?;
+    assert_eq!(next(&mut stream).await?.message_id, m1);
+
+    // 尚未确认 重连后再次收到
+    drop(stream);
+    let mut stream = subscribe(&mut client, &device).await?;
+    assert_eq!(next(&mut stream).await?.message_id, m1);
+
+    let ack = AckRequest {
+        device_id: "d1".to_string(),
+        message_ids: vec![m1],
+    };
+    let res = client.ack(request(ack, &device)?).await?.into_inner();
+    assert_eq!(res.acked, 1);
+
+    // 重连后只收到新消息
+    drop(stream);
+    let mut stream = subscribe(&mut client, &device).await?;
+    let m2 = send(&mut client, &marketer).await?;
+    assert_eq!(next(&mut stream).await?.message_id, m2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn device_should_not_send() -> Result<()> {
+    let key = TestKey::new();
+    start_server(&key, PORT + 1)?;
+    let device = key.token_with_roles("d1", &["device"]);
+    let mut client = NotificationClient::connect(format!("http://[::1]:{}", PORT + 1)).await?;
+
+    let msg = SendRequest::from(InAppMessage::fake());
+    let req = request(tokio_stream::iter(vec![msg]), &device)?;
+    let err = client.send(req).await.unwrap_err();
+    assert_eq!(err.code(), Code::PermissionDenied);
+    Ok(())
+}
+
+#[tokio::test]
+async fn device_should_only_access_itself() -> Result<()> {
+    let key = TestKey::new();
+    start_server(&key, PORT + 2)?;
+    let device = key.token_with_roles("d1", &["device"]);
+    let admin = key.token_with_roles("ops", &["admin"]);
+    let mut client = NotificationClient::connect(format!("http://[::1]:{}", PORT + 2)).await?;
+
+    let sub = SubscribeRequest {
+        device_id: "d2".to_string(),
+    };
+    let err = client
+        .subscribe(request(sub.clone(), &device)?)
+        .await
+        .unwrap_err();
+    assert_eq!(err.code(), Code::PermissionDenied);
+
+    let ack = AckRequest {
+        device_id: "d2".to_string(),
+        message_ids: vec!["m1".to_string()],
+    };
+    let err = client.ack(request(ack, &device)?).await.unwrap_err();
+    assert_eq!(err.code(), Code::PermissionDenied);
+
+    // admin 可以访问任意设备
+    client.subscribe(request(sub, &admin)?).await?;
+    Ok(())
+}
+
+fn start_server(key: &TestKey, port: u16) -> Result<()> {
+    let addr = format!("[::1]:{}", port).parse()?;
+    let config = AppConfig::load()?;
+    let server = Bootstrap::try_new(addr, None, &key.config(), &config.policy)?;
+    let svc = NotificationService::try_new(config)?;
+    let routes = Routes::new(svc.into_server());
+    // 先绑定端口 再启动服务 避免客户端连接时服务尚未就绪
+    let incoming = TcpIncoming::new(addr, true, None).map_err(|e| anyhow::anyhow!(e))?;
+    tokio::spawn(server.serve_with_incoming(routes, incoming));
+    Ok(())
+}
+
+// 向 d1 发送一条应用内消息 返回消息ID
+async fn send(client: &mut NotificationClient<Channel>, token: &str) -> Result<String> {
+    let mut msg = InAppMessage::fake();
+    msg.device_id = "d1".to_string();
+    let id = msg.message_id.clone();
+    let req = request(tokio_stream::iter(vec![SendRequest::from(msg)]), token)?;
+    let mut responses = client.send(req).await?.into_inner();
+    assert_eq!(responses.next().await.unwrap()?.message_id, id);
+    Ok(id)
+}
+
+async fn subscribe(
+    client: &mut NotificationClient<Channel>,
+    token: &str,
+) -> Result<Streaming<InAppMessage>> {
+    let req = SubscribeRequest {
+        device_id: "d1".to_string(),
+    };
+    // 收到响应头时订阅已注册
+    Ok(client.subscribe(request(req, token)?).await?.into_inner())
+}
+
+async fn next(stream: &mut Streaming<InAppMessage>) -> Result<InAppMessage> {
+    let msg = timeout(Duration::from_secs(2), stream.next()).await?;
+    Ok(msg.expect("stream closed")?)
+}
+
+fn request<T>(msg: T, token: &str) -> Result<Request<T>> {
+    let mut req = Request::new(msg);
+    req.metadata_mut().insert(AUTHORIZATION, bearer(token)?);
+    Ok(req)
+}
@@ -0,0 +1,94 @@
+use anyhow::Result;
+use crm_core::{
+    auth::{bearer, test_utils::TestKey, AUTHORIZATION},
+    Bootstrap, ConfigLoader, PolicyConfig,
+};
+use crm_send::{
+    pb::{notification_client::NotificationClient, EmailMessage, SendRequest, SendResponse},
+    AppConfig, NotificationService,
+};
+use futures::StreamExt;
+use std::time::Duration;
+use tokio::{
+    sync::{mpsc, oneshot},
+    task::JoinHandle,
+    time::timeout,
+};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{service::Routes, transport::server::TcpIncoming, Request, Streaming};
+
+const PORT_BASE: u16 = 63000;
+
+#[tokio::test]
+async fn in_flight_stream_should_drain() -> Result<()> {
+    let key = TestKey::new();
+    let (shutdown, server) = start_server(&key, PORT_BASE, Duration::from_secs(5))?;
+    let (tx, mut responses) = open_stream(&key, PORT_BASE).await?;
+    assert!(roundtrip(&tx, &mut responses).await?.timestamp.is_some());
+
+    // 已建立的 stream 在退出期间仍可继续发送
+    shutdown.send(()).unwrap();
+    assert!(roundtrip(&tx, &mut responses).await?.timestamp.is_some());
+
+    drop(tx);
+    assert!(responses.next().await.is_none());
+    timeout(Duration::from_secs(1), server).await???;
+    Ok(())
+}
+
+#[tokio::test]
+async fn drain_should_stop_at_deadline() -> Result<()> {
+    let key = TestKey::new();
+    let (shutdown, server) = start_server(&key, PORT_BASE + 1, Duration::from_millis(200))?;
+    let (tx, mut responses) = open_stream(&key, PORT_BASE + 1).await?;
+    roundtrip(&tx, &mut responses).await?;
+
+    // 客户端一直不关闭 stream 超过 drain_timeout 后服务退出
+    shutdown.send(()).unwrap();
+    timeout(Duration::from_secs(2), server).await???;
+    drop(tx);
+    Ok(())
+}
+
+fn start_server(
+    key: &TestKey,
+    port: u16,
+    drain_timeout: Duration,
+) -> Result<(oneshot::Sender<()>, JoinHandle<Result<()>>)> {
+    let addr = format!("[::1]:{}", port).parse()?;
+    let server = Bootstrap::try_new(addr, None, &key.config(), &PolicyConfig::default())?
+        .drain_timeout(drain_timeout);
+    let svc = NotificationService::try_new(AppConfig::load()?)?;
+    let routes = Routes::new(svc.into_server());
+    // 先绑定端口 再启动服务 避免客户端连接时服务尚未就绪
+    let incoming = TcpIncoming::new(addr, true, None).map_err(|e| anyhow::anyhow!(e))?;
+
+    let (tx, rx) = oneshot::channel();
+    let signal = async move {
+        let _ = rx.await;
+    };
+    let handle = tokio::spawn(server.serve_with_shutdown(routes, incoming, signal));
+    Ok((tx, handle))
+}
+
+async fn open_stream(
+    key: &TestKey,
+    port: u16,
+) -> Result<(mpsc::Sender<SendRequest>, Streaming<SendResponse>)> {
+    let mut client = NotificationClient::connect(format!("http://[::1]:{}", port)).await?;
+    let (tx, rx) = mpsc::channel(16);
+    let mut req = Request::new(ReceiverStream::new(rx));
+    req.metadata_mut()
+        .insert(AUTHORIZATION, bearer(&key.token("test"))?);
+    let responses = client.send(req).await;
+    Ok((tx, responses?.into_inner()))
+}
+
+async fn roundtrip(
+    tx: &mpsc::Sender<SendRequest>,
+    responses: &mut Streaming<SendResponse>,
+) -> Result<SendResponse> {
+    tx.send(EmailMessage::fake().into()).await?;
+    let res = timeout(Duration::from_secs(2), responses.next()).await?;
+    Ok(res.expect("stream closed")?)
+}
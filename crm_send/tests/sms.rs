@@ -0,0 +1,197 @@
+use anyhow::Result;
+use crm_core::ConfigLoader;
+use crm_send::{
+    delivery::{Delivery, HttpGateway, SmsDelivery},
+    pb::{send_request::Message as Msg, SendStatus, SmsMessage},
+    AppConfig, NotificationService, SmsConfig,
+};
+use futures::StreamExt;
+use serde_json::{json, Value};
+use tonic::Code;
+use wiremock::{
+    matchers::{body_partial_json, header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn sms_should_be_sent_through_gateway() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/sms"))
+        .and(header("authorization", "Bearer secret"))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(2)
+        .mount(&server)
+        .await;
+    let mut config = AppConfig::load()?;
+    config.sms = Some(sms_config(&server));
+    let svc = NotificationService::try_new(config)?;
+
+    // 200 个 GSM-7 字符分为两段
+    let mut sms = SmsMessage::fake();
+    sms.sender = "+1 (555) 010-0000".to_string();
+    sms.recipients = vec!["0044 7700 900123".to_string()];
+    sms.body = "a".repeat(200);
+    let stream = tokio_stream::iter(vec![Ok(sms.clone().into())]);
+    let ret = svc.send(stream).await?.into_inner();
+    assert!(
+        ret.all(|r| async move { r.unwrap().status() == SendStatus::Accepted })
+            .await
+    );
+    svc.flush().await;
+
+    let bodies = bodies(&server).await;
+    assert_eq!(
+        bodies,
+        vec![
+            json!({
+                "from": "+15550100000",
+                "to": "+447700900123",
+                "text": "a".repeat(153),
+                "reference": format!("{}-1", sms.message_id),
+                "part": 1,
+                "encoding": "gsm7",
+            }),
+            json!({
+                "from": "+15550100000",
+                "to": "+447700900123",
+                "text": "a".repeat(47),
+                "reference": format!("{}-2", sms.message_id),
+                "part": 2,
+                "encoding": "gsm7",
+            }),
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn invalid_number_should_be_rejected() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(0)
+        .mount(&server)
+        .await;
+    let mut config = AppConfig::load()?;
+    config.sms = Some(sms_config(&server));
+    let svc = NotificationService::try_new(config)?;
+
+    let mut sms = SmsMessage::fake();
+    sms.recipients = vec!["555-0100".to_string()];
+    let stream = tokio_stream::iter(vec![Ok(sms.into())]);
+    let ret = svc.send(stream).await?.into_inner();
+    let ret = ret.collect::<Vec<_>>().await;
+    let res = ret[0].as_ref().unwrap();
+    assert_eq!(res.status(), SendStatus::Rejected);
+    assert!(res.reason.starts_with("Invalid recipient"));
+    svc.flush().await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn provider_error_should_fail_delivery() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "to": "+15550100001" })))
+        .respond_with(
+            ResponseTemplate::new(400).set_body_json(json!({ "message": "Unknown destination" })),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(202))
+        .mount(&server)
+        .await;
+    let delivery = SmsDelivery::new(HttpGateway::try_new(&sms_config(&server))?);
+
+    let mut sms = SmsMessage::fake();
+    sms.recipients = vec!["+15550100001".to_string(), "+15550100002".to_string()];
+    let err = delivery.deliver(&Msg::Sms(sms)).await.unwrap_err();
+    assert_eq!(err.code(), Code::InvalidArgument);
+    assert_eq!(
+        err.message(),
+        "SMS gateway returned 400: Unknown destination"
+    );
+
+    // 另一个收件人仍能收到短信
+    let bodies = bodies(&server).await;
+    assert_eq!(bodies.len(), 2);
+    assert_eq!(bodies[1]["to"], "+15550100002");
+    Ok(())
+}
+
+#[tokio::test]
+async fn delivery_failure_should_be_reported_in_send_stream() -> Result<()> {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({ "to": "+15550100001" })))
+        .respond_with(
+            ResponseTemplate::new(400).set_body_json(json!({ "message": "Unknown destination" })),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(429).set_body_json(json!({ "error": "slow down" })))
+        .mount(&server)
+        .await;
+    let mut config = AppConfig::load()?;
+    config.sms = Some(sms_config(&server));
+    let svc = NotificationService::try_new(config)?;
+
+    let mut rejected = SmsMessage::fake();
+    rejected.recipients = vec!["+15550100001".to_string()];
+    let failed = SmsMessage::fake();
+    let stream = tokio_stream::iter(vec![Ok(rejected.clone().into()), Ok(failed.clone().into())]);
+    let ret = svc.send(stream).await?.into_inner();
+    let ret: Vec<_> = ret.map(|r| r.unwrap()).collect().await;
+
+    // 每条消息先收到入队时的 ACCEPTED 投递失败后再收到同一 message_id 的结果
+    let responses = |id: &str| -> Vec<_> {
+        ret.iter()
+            .filter(|r| r.message_id == id)
+            .map(|r| (r.status(), r.reason.as_str()))
+            .collect()
+    };
+    assert_eq!(
+        responses(&rejected.message_id),
+        vec![
+            (SendStatus::Accepted, ""),
+            (
+                SendStatus::Rejected,
+                "SMS gateway returned 400: Unknown destination"
+            ),
+        ]
+    );
+    assert_eq!(
+        responses(&failed.message_id),
+        vec![
+            (SendStatus::Accepted, ""),
+            (SendStatus::Failed, "SMS gateway returned 429: slow down"),
+        ]
+    );
+    Ok(())
+}
+
+fn sms_config(server: &MockServer) -> SmsConfig {
+    SmsConfig {
+        url: format!("{}/sms", server.uri()),
+        username: None,
+        password: None,
+        token: Some("secret".to_string()),
+        template: json!({
+            "from": "{sender}",
+            "to": "{recipient}",
+            "text": "{body}",
+            "reference": "{message_id}-{segment}",
+            "part": "{segment}",
+            "encoding": "{encoding}",
+        }),
+        timeout: 5,
+    }
+}
+
+async fn bodies(server: &MockServer) -> Vec<Value> {
+    let requests = server.received_requests().await.unwrap_or_default();
+    requests.iter().map(|r| r.body_json().unwrap()).collect()
+}
@@ -0,0 +1,180 @@
+use anyhow::Result;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use crm_core::ConfigLoader;
+use crm_send::{
+    delivery::{Delivery, SmtpDelivery},
+    pb::{send_request::Message as Msg, EmailMessage},
+    AppConfig, NotificationService, SmtpConfig, SmtpTls,
+};
+use futures::StreamExt;
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+};
+use tonic::Code;
+
+#[tokio::test]
+async fn email_should_be_delivered_over_smtp() -> Result<()> {
+    let server = SmtpServer::start(&[]).await?;
+    let mut config = AppConfig::load()?;
+    config.smtp = Some(server.config());
+    let svc = NotificationService::try_new(config)?;
+
+    let email = email("crm@example.com", "alice@example.com");
+    let stream = tokio_stream::iter(vec![Ok(email.clone().into())]);
+    let ret = svc.send(stream).await?.into_inner();
+    let ret = ret.collect::<Vec<_>>().await;
+    assert_eq!(ret[0].as_ref().unwrap().message_id, email.message_id);
+    svc.flush().await;
+
+    let mails = server.mails();
+    assert_eq!(mails.len(), 1);
+    let mail = &mails[0];
+    assert_eq!(mail.auth.as_deref(), Some("\0crm\0secret"));
+    assert_eq!(mail.from, "crm@example.com");
+    assert_eq!(mail.to, vec!["alice@example.com"]);
+    let message_id = format!("Message-ID: <{}@example.com>\r\n", email.message_id);
+    assert!(mail.data.contains(&message_id));
+    assert!(mail.data.contains("From: crm@example.com\r\n"));
+    assert!(mail.data.contains("To: alice@example.com\r\n"));
+    assert!(mail.data.contains("Subject: Hello\r\n"));
+    assert!(mail.data.ends_with("\r\n\r\nHello World\r\n"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn rejected_recipient_should_fail_delivery() -> Result<()> {
+    let server = SmtpServer::start(&["nobody@example.com"]).await?;
+    let delivery = SmtpDelivery::try_new(&server.config())?;
+
+    let rejected = Msg::Email(email("crm@example.com", "nobody@example.com"));
+    let err = delivery.deliver(&rejected).await.unwrap_err();
+    assert_eq!(err.code(), Code::FailedPrecondition);
+    assert!(err.message().contains("No such user"));
+
+    // 之后的邮件仍能投递
+    let accepted = Msg::Email(email("crm@example.com", "bob@example.com"));
+    delivery.deliver(&accepted).await?;
+    let mails = server.mails();
+    assert_eq!(mails.len(), 1);
+    assert_eq!(mails[0].to, vec!["bob@example.com"]);
+    Ok(())
+}
+
+fn email(sender: &str, recipient: &str) -> EmailMessage {
+    let mut email = EmailMessage::fake();
+    email.sender = sender.to_string();
+    email.recipients = vec![recipient.to_string()];
+    email
+}
+
+/// 测试 SMTP 服务器收到的邮件
+#[derive(Debug, Clone, Default)]
+struct Mail {
+    auth: Option<String>,
+    from: String,
+    to: Vec<String>,
+    data: String,
+}
+
+/// 最简单的明文 SMTP 服务器 除指定的收件人外全部接受
+struct SmtpServer {
+    addr: SocketAddr,
+    mails: Arc<Mutex<Vec<Mail>>>,
+}
+
+impl SmtpServer {
+    async fn start(rejected: &'static [&'static str]) -> Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let mails = Arc::new(Mutex::new(Vec::new()));
+        let accepted = mails.clone();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let mails = accepted.clone();
+                tokio::spawn(async move { session(stream, rejected, mails).await });
+            }
+        });
+        Ok(Self { addr, mails })
+    }
+
+    fn config(&self) -> SmtpConfig {
+        SmtpConfig {
+            host: self.addr.ip().to_string(),
+            port: Some(self.addr.port()),
+            tls: SmtpTls::None,
+            username: Some("crm".to_string()),
+            password: Some("secret".to_string()),
+            timeout: 5,
+        }
+    }
+
+    fn mails(&self) -> Vec<Mail> {
+        self.mails.lock().unwrap().clone()
+    }
+}
+
+async fn session(stream: TcpStream, rejected: &[&str], mails: Arc<Mutex<Vec<Mail>>>) -> Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    writer.write_all(b"220 localhost ESMTP\r\n").await?;
+
+    let mut auth = None;
+    let mut mail = Mail::default();
+    while let Some(line) = lines.next_line().await? {
+        let command = line.to_ascii_uppercase();
+        let reply: &[u8] = if command.starts_with("EHLO") {
+            b"250-localhost\r\n250 AUTH PLAIN\r\n"
+        } else if let Some(credentials) = line.strip_prefix("AUTH PLAIN ") {
+            auth = Some(String::from_utf8(STANDARD.decode(credentials)?)?);
+            b"235 2.7.0 Authentication successful\r\n"
+        } else if command.starts_with("MAIL FROM:") {
+            mail = Mail {
+                auth: auth.clone(),
+                from: address(&line),
+                ..Default::default()
+            };
+            b"250 OK\r\n"
+        } else if command.starts_with("RCPT TO:") {
+            let to = address(&line);
+            if rejected.contains(&to.as_str()) {
+                b"550 5.1.1 No such user\r\n"
+            } else {
+                mail.to.push(to);
+                b"250 OK\r\n"
+            }
+        } else if command == "DATA" {
+            writer
+                .write_all(b"354 End data with <CR><LF>.<CR><LF>\r\n")
+                .await?;
+            while let Some(line) = lines.next_line().await? {
+                if line == "." {
+                    break;
+                }
+                let line = line.strip_prefix('.').unwrap_or(&line);
+                mail.data.push_str(line);
+                mail.data.push_str("\r\n");
+            }
+            mails.lock().unwrap().push(std::mem::take(&mut mail));
+            b"250 OK\r\n"
+        } else if command == "QUIT" {
+            writer.write_all(b"221 Bye\r\n").await?;
+            break;
+        } else {
+            b"250 OK\r\n"
+        };
+        writer.write_all(reply).await?;
+    }
+    Ok(())
+}
+
+// MAIL FROM / RCPT TO 中尖括号内的地址
+fn address(line: &str) -> String {
+    let start = line.find('<').map_or(0, |i| i + 1);
+    let end = line.find('>').unwrap_or(line.len());
+    line[start..end].to_string()
+}
@@ -1,6 +1,6 @@
 use anyhow::Result;
 use proto_builder_trait::tonic::BuilderAttributes;
-use std::fs;
+use std::{env, fs, path::PathBuf};
 
 fn main() -> Result<()> {
     // 如果目录不存在则创建
@@ -10,6 +10,8 @@ fn main() -> Result<()> {
     // 设置输出目录
     builder
         .out_dir("src/pb")
+        // reflection 使用的 FileDescriptorSet
+        .file_descriptor_set_path(PathBuf::from(env::var("OUT_DIR")?).join("user_stats.bin"))
         // 生成时加入Serde的宏
         .with_serde(
             &["User"],
@@ -18,7 +20,33 @@ fn main() -> Result<()> {
             Some(&[r#"#[serde(rename_all = "camelCase")]"#]),
         )
         // 生成时加入 Sqxl 宏
-        .with_sqlx_from_row(&["User"], None)
+        .with_sqlx_from_row(&["User", "UserStat"], None)
+        // UserStat 只包含查询到的列 未查询的列使用默认值
+        .with_field_attributes(&["UserStat.name"], &[r#"#[sqlx(default)]"#])
+        .with_field_attributes(
+            &["UserStat.gender"],
+            &[r#"#[sqlx(default, try_from = "crate::abi::DbGender")]"#],
+        )
+        .with_field_attributes(
+            &[
+                "UserStat.created_at",
+                "UserStat.last_visited_at",
+                "UserStat.last_watched_at",
+                "UserStat.last_email_notification",
+                "UserStat.last_in_app_notification",
+                "UserStat.last_sms_notification",
+            ],
+            &[r#"#[sqlx(default, try_from = "crate::abi::DbTimestamp")]"#],
+        )
+        .with_field_attributes(
+            &[
+                "UserStat.recent_watched",
+                "UserStat.viewed_but_not_started",
+                "UserStat.started_but_not_finished",
+                "UserStat.finished",
+            ],
+            &[r#"#[sqlx(default, try_from = "crate::abi::DbIds")]"#],
+        )
         // 假如 derive_builder 宏可用，则生成 builder 配置
         .with_derive_builder(
             &[
@@ -41,7 +69,7 @@ fn main() -> Result<()> {
             &[r#"#[builder(setter(into,strip_option))]"#],
         )
         .with_field_attributes(
-            &["QueryRequest.timestamps"],
+            &["QueryRequest.timeStamps"],
             &[r#"#[builder(setter(each(name="timestamp",into)))]"#],
         )
         .with_field_attributes(
@@ -1,14 +1,24 @@
 use anyhow::Result;
-use chrono::{DateTime, Days, Utc};
+use chrono::{DateTime, Days, TimeZone, Utc};
 use fake::faker::chrono::en::DateTimeBetween;
 use fake::faker::internet::en::SafeEmail;
 use fake::faker::name::zh_cn::Name;
 use fake::{Dummy, Fake, Faker};
-use nanoid::nanoid;
+use rand::{rngs::StdRng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use sqlx::{Executor, PgPool};
 use std::collections::HashSet;
 use std::hash::{Hash, Hasher};
+use std::sync::OnceLock;
+use std::{env, fs};
+
+// 测试数据的随机种子、数量与生成时间 保证每次生成的 fixture 相同
+const FIXTURE_SEED: u64 = 42;
+const FIXTURE_SIZE: usize = 300;
+const FIXTURE_PATH: &str = "fixtures/data.sql";
+
+// 所有时间字段都以此为当前时间生成
+static NOW: OnceLock<DateTime<Utc>> = OnceLock::new();
 
 // generate 1000 fake users and run then in a tx,repeat 500 times
 /**
@@ -64,8 +74,13 @@ pub struct UserStat {
     last_sms_notification: DateTime<Utc>,
 }
 
+/// cargo run --example gen           向本地数据库写入 200000 条随机数据
+/// cargo run --example gen -- fixture 生成测试使用的 fixtures/data.sql
 #[tokio::main]
 async fn main() -> Result<()> {
+    if env::args().nth(1).as_deref() == Some("fixture") {
+        return gen_fixture();
+    }
     // let user: UserStat = Faker.fake();
     // println!("{:?}", user);
     let pool = PgPool::connect("postgres://:123456@localhost:5432/stats").await?;
@@ -79,12 +94,45 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+// 使用固定的种子和时间生成测试数据 email 重复的用户会被跳过
+fn gen_fixture() -> Result<()> {
+    NOW.set(Utc.with_ymd_and_hms(2024, 11, 1, 0, 0, 0).unwrap())
+        .expect("now already set");
+    let mut rng = StdRng::seed_from_u64(FIXTURE_SEED);
+    let mut emails = HashSet::new();
+    let mut users = Vec::with_capacity(FIXTURE_SIZE);
+    while users.len() < FIXTURE_SIZE {
+        let user: UserStat = Faker.fake_with_rng(&mut rng);
+        if emails.insert(user.email.clone()) {
+            users.push(user);
+        }
+    }
+
+    let sql = format!(
+        "-- generated by `cargo run --example gen -- fixture`, do not edit\n{};\n",
+        insert_sql(users)
+    );
+    fs::create_dir_all("fixtures")?;
+    fs::write(FIXTURE_PATH, sql)?;
+    println!("Fixture written to {}", FIXTURE_PATH);
+    Ok(())
+}
+
 async fn raw_insert(users: HashSet<UserStat>, pool: &PgPool) -> Result<()> {
+    let sql = insert_sql(users);
+    sqlx::query(&sql).execute(pool).await?;
+
+    Ok(())
+}
+
+// 生成批量插入的SQL
+fn insert_sql(users: impl IntoIterator<Item = UserStat>) -> String {
     let mut sql = String::with_capacity(10 * 1000 * 1000);
     sql.push_str("
     INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
     VALUES");
     for user in users {
+        sql.push('\n');
         let gender = match user.gender {
             Gender::Female => "female",
             Gender::Male => "male",
@@ -108,10 +156,8 @@ async fn raw_insert(users: HashSet<UserStat>, pool: &PgPool) -> Result<()> {
         ));
     }
 
-    let v = &sql[..sql.len() - 1];
-    sqlx::query(v).execute(pool).await?;
-
-    Ok(())
+    sql.pop();
+    sql
 }
 
 fn list_to_string(list: Vec<i32>) -> String {
@@ -154,11 +200,11 @@ impl Hash for UserStat {
 
 // 开始时间
 fn before(days: u64) -> DateTime<Utc> {
-    Utc::now().checked_sub_days(Days::new(days)).unwrap()
+    now().checked_sub_days(Days::new(days)).unwrap()
 }
 // 结束时间
 fn now() -> DateTime<Utc> {
-    Utc::now()
+    *NOW.get_or_init(Utc::now)
 }
 
 // 随机生成一个长度为size的整数列表，每个整数范围为[start, start+len)
@@ -184,8 +230,11 @@ const ALPHABET: [char; 36] = [
 impl Dummy<UniqueEmail> for String {
     fn dummy_with_rng<R: rand::Rng + ?Sized>(_: &UniqueEmail, rng: &mut R) -> String {
         let email: String = SafeEmail().fake_with_rng(rng);
-        let nanoid = nanoid!(8, &ALPHABET);
+        // 使用传入的 rng 生成后缀 保证固定种子时结果可复现
+        let suffix: String = (0..8)
+            .map(|_| ALPHABET[rng.gen_range(0..ALPHABET.len())])
+            .collect();
         let at = email.find('@').unwrap();
-        format!("{}.{}@{}", &email[..at], nanoid, &email[at + 1..])
+        format!("{}.{}@{}", &email[..at], suffix, &email[at + 1..])
     }
 }
@@ -0,0 +1,304 @@
+-- generated by `cargo run --example gen -- fixture`, do not edit
+
+    INSERT INTO user_stats(email, name, gender, created_at, last_visited_at, last_watched_at, recent_watched, viewed_but_not_started, started_but_not_finished, finished, last_email_notification, last_in_app_notification, last_sms_notification)
+    VALUES
+('bret.j1q0xie5@example.net', '梁念芹', 'female','2020-02-02 17:59:00 UTC', '2024-10-27 08:58:00 UTC', '2024-09-16 19:05:00 UTC', ARRAY[105849, 135101, 130197, 113988, 179438, 158258, 120353, 140171, 165460, 186539, 142398, 188848, 193125, 186930, 164560, 164889, 180872, 122077, 135417, 157703, 139010, 159953, 106673, 109697, 142826]::int[], ARRAY[279396, 260739, 245262, 224739, 202823, 287585, 215419, 226444, 238457, 200469, 249141, 281191, 249982, 285129, 294480, 254578, 295192, 276885, 278631, 299852, 284819, 298574, 238318, 298139, 288383, 222903, 295371, 224572, 288421, 230066, 247799, 292548, 296702, 295206, 274249, 246518, 253426, 262724, 211084, 232869, 276184, 224463, 229604, 256835, 285693, 228130, 205934, 271832, 247799]::int[], ARRAY[378190, 387014, 325654, 356784, 320447, 311263, 379920, 304067, 325766, 348755, 382937, 375671, 355719, 332018, 347832, 334254, 328591, 338297, 360607, 372260, 388583, 368456, 337974, 357621, 360931, 307952, 318488, 339181, 396150, 393293]::int[], ARRAY[414775, 484545, 422437, 466257]::int[], '2024-10-23 17:55:00 UTC', '2024-10-24 07:35:00 UTC', '2024-08-25 04:36:00 UTC'),
+('sofia.b5axu5el@example.org', '谢碧彤', 'female','2022-08-10 19:20:00 UTC', '2024-10-25 05:53:00 UTC', '2024-09-20 16:14:00 UTC', ARRAY[102040, 111709, 176151, 184116, 110466, 117284, 151716, 195948, 190060, 198921, 158840, 113198, 194331, 197327, 158594, 188925, 134397, 109199, 147927, 147226, 109346, 121817, 166450, 127339, 177478, 145489, 178526, 139385, 147186, 151552, 138387, 120113, 105898, 120352, 195433, 150854, 154976, 113434, 126534, 194276, 168931, 164675, 131520, 115475]::int[], ARRAY[211434, 272948, 269629, 246057, 231605]::int[], ARRAY[355291, 381780, 358196, 341199, 325322, 387984, 307085, 349127, 310242, 301767, 363964]::int[], ARRAY[400771, 414736, 451541, 451761]::int[], '2024-09-17 10:18:00 UTC', '2024-10-20 06:00:00 UTC', '2024-10-03 15:03:00 UTC'),
+('tamara.3oa2b08v@example.net', '毛若菲', 'male','2021-10-17 20:18:00 UTC', '2024-10-31 02:26:00 UTC', '2024-09-28 21:10:00 UTC', ARRAY[191570, 107625, 149493, 156315, 170441, 111264, 152838, 172627, 148561, 119756, 109401, 101524, 100196, 187908, 172464, 173440, 160617, 168408, 129584, 114113, 173658, 153790, 126824, 172410, 191391]::int[], ARRAY[233597, 277616, 211146, 295442, 264821, 268738, 272149, 264010, 205756, 244359, 269361, 287969, 225848, 220940, 202548, 233511, 254927, 247799, 268117, 229775, 276415, 258543, 226566, 245713, 207739, 281358, 221333, 276577, 276459, 265674, 269069, 227425, 239307, 288526, 277283, 278932, 230385, 210192, 236414, 213906, 276510, 209691, 269914, 248715, 271614, 217971, 282989, 253327, 287052]::int[], ARRAY[305755, 396521, 341444, 358489, 385125, 315436, 327299, 338094, 348985, 378246, 356384, 323440, 339366, 308506]::int[], ARRAY[444087]::int[], '2024-10-07 21:32:00 UTC', '2024-10-25 08:17:00 UTC', '2024-08-21 08:00:00 UTC'),
+('keenan.s1usvhjk@example.net', '唐德春', 'unknown','2022-05-25 22:45:00 UTC', '2024-10-04 22:54:00 UTC', '2024-08-08 01:46:00 UTC', ARRAY[182291, 132334, 195071, 170863, 115257, 111307, 191198, 170618, 161165, 146078, 157299, 104380, 185419, 125139, 194297, 176284, 150068, 199077, 164719, 123001, 105352, 171989, 187399, 177774, 104747, 126637, 195319, 151911, 196062, 100310, 106207, 192254, 153493]::int[], ARRAY[275905, 275175, 270440, 297913, 269736, 227901, 202933, 238370, 294007, 271157, 296980, 204431, 293894, 223159, 275119, 238314, 209857, 235009, 285671, 238935, 288847, 225067, 211531, 272022, 280885, 258763, 253168, 280842, 281000, 283703, 256421, 225509, 273869, 239129]::int[], ARRAY[330396, 346354, 318966, 372585, 373632, 350208, 368674, 366301, 394080, 326600, 363214, 353242, 372868, 348261, 308155, 373013, 385909, 388505, 365145, 371430, 305211, 331834, 356061, 323837, 319596, 335160, 339278, 391477, 324430, 377626]::int[], ARRAY[448955]::int[], '2024-09-22 00:54:00 UTC', '2024-10-26 04:42:00 UTC', '2024-09-27 06:40:00 UTC'),
+('nicklaus.ikpvyho0@example.net', '秦君翔', 'female','2024-10-21 05:25:00 UTC', '2024-10-15 16:32:00 UTC', '2024-08-22 04:43:00 UTC', ARRAY[124477, 156291, 108162, 147263, 195589, 169162, 100501, 164850, 116444, 169615, 186141, 142707, 138860, 140948, 195098, 115673, 118738, 127410, 171962, 103111, 108305, 147768, 196159, 125447, 124431, 118744, 149829, 136316, 120645, 166029, 178301, 152919, 103387, 197542, 130504, 128442, 173455, 198054, 121413, 186640, 102628, 146207, 115703]::int[], ARRAY[287127, 272353, 264925, 232366, 251101, 221607, 221043, 241578, 209803, 272493, 201849, 268332, 251867, 242890, 284168, 288666, 260019, 254284, 276978, 284868, 240241, 247566, 219170, 290252, 293972, 222682, 237417, 268505, 276462, 247178, 234274, 260827, 224873, 244740, 261223, 272942, 223130, 247778, 226697, 213092, 208945, 271803]::int[], ARRAY[361395, 374701, 398528, 310023, 365554, 367757, 316185, 355754, 378984, 358678, 355907, 336811, 339324, 317934, 309659, 344039, 351900, 370897, 312864, 373212, 338699, 383617, 388340, 383915, 375179, 389388, 367153]::int[], ARRAY[487291, 474031, 437907, 435505, 404714, 499049, 455224, 412949, 439423, 470122, 400777, 479093, 478995, 487337, 452844, 424069, 408939, 453596, 497828, 460449, 424409, 464170, 489152, 433621, 410067, 470134, 480326, 417335, 454904, 429428, 442711, 428001, 429273]::int[], '2024-09-25 06:26:00 UTC', '2024-10-23 16:24:00 UTC', '2024-08-22 01:51:00 UTC'),
+('douglas.kggvog31@example.net', '郑紫槐', 'male','2021-11-14 05:19:00 UTC', '2024-10-29 21:22:00 UTC', '2024-08-13 23:23:00 UTC', ARRAY[133894, 140508, 150645, 195198, 172682, 190354, 108864, 163559, 109961, 169023, 106501, 121656, 146637, 186307, 108366]::int[], ARRAY[291197, 267847, 268883, 286642, 299305, 278789, 296806, 279822, 252225, 270954, 259340, 203898, 280062]::int[], ARRAY[380449, 379172, 348670, 348554, 387484, 338847, 313943, 345510, 346054, 318435, 366481, 344970, 371596, 385001, 329891, 309105, 324825, 301327, 329526, 337892, 322374, 351116, 334346, 344163, 384791, 390797, 363547, 349613, 372612, 332943, 369480, 342281, 382763, 378580, 396439, 317205, 380550, 389026, 332008, 356626, 341847, 371463, 331889, 384016, 307751, 334031, 310742, 337685]::int[], ARRAY[475936, 475074, 443417, 495074, 475982, 488749, 404902, 437833, 493685, 460688, 457273, 481487, 402804, 467432, 493506, 433411, 445522, 493875, 426275, 444762, 463982, 498840, 494467, 430860, 424083, 462831, 403737, 469281, 478877, 466209, 405461, 406699, 419286, 429168, 482441, 436691, 477785, 451631, 434786]::int[], '2024-10-28 15:37:00 UTC', '2024-10-19 19:12:00 UTC', '2024-09-21 07:28:00 UTC'),
+('norval.ol6m4jjs@example.com', '卢梅雪', 'male','2021-10-07 18:03:00 UTC', '2024-10-23 11:27:00 UTC', '2024-10-29 14:19:00 UTC', ARRAY[141592, 152590, 143137, 190575, 158133, 153844, 193011, 192879, 199887, 112919, 107418, 139304, 124569, 191424, 112639, 103975, 127244, 161641, 107017, 124823, 184790, 188618, 150957, 150150]::int[], ARRAY[284508, 253168, 203445, 271778, 210760, 254403, 296877, 237370, 288206, 288676]::int[], ARRAY[390031, 343604, 323394, 338789, 361304, 395722, 372961, 334571, 366821, 312251, 360245, 380683, 399074, 325730, 384179, 394411]::int[], ARRAY[423969, 493746, 468149, 425849, 412416, 442237, 434835, 400540, 422763, 417992, 490533, 446235, 489846, 422944, 479904, 404902, 471015, 461430, 446226, 444245, 435413, 485597, 493074, 464518, 420176]::int[], '2024-09-20 09:14:00 UTC', '2024-10-26 18:07:00 UTC', '2024-10-07 10:03:00 UTC'),
+('sonia.4gi969ut@example.net', '崔棋博', 'unknown','2022-05-16 12:57:00 UTC', '2024-10-11 11:36:00 UTC', '2024-10-09 17:37:00 UTC', ARRAY[144006, 150162, 179135, 191915, 179614, 194166, 132496, 134520, 174325, 195298, 176787]::int[], ARRAY[275962, 267240, 258359, 225074, 252529, 268470, 219076, 257476, 229677, 214555, 229524, 231949, 299313]::int[], ARRAY[334949, 306884, 359469, 336831, 356550]::int[], ARRAY[444683, 494149, 415118, 483860, 499648, 406207, 412307, 440638, 449194, 490956, 490191, 433736, 451883, 466873, 498271, 481011, 455910, 432961, 402930, 496402, 467442, 430551, 431798, 437312, 447812, 442047, 414533, 425283, 422769, 400613, 419350, 416260, 422476, 488819, 493006, 427987, 492067, 455088, 458913, 468132]::int[], '2024-09-24 00:26:00 UTC', '2024-10-31 16:07:00 UTC', '2024-10-06 23:28:00 UTC'),
+('katharina.binu4tdo@example.net', '范任平', 'unknown','2024-03-06 14:46:00 UTC', '2024-10-21 17:51:00 UTC', '2024-08-09 16:53:00 UTC', ARRAY[116794, 136506, 132974, 148925, 156270, 128449, 165350, 196597, 125349, 197136, 184248, 115079, 187457, 190974, 132335, 173684, 198712, 157694, 198993, 180588, 151429, 173917]::int[], ARRAY[210827, 250807, 205028, 243906, 286550, 234790, 271166, 218964, 267247, 228365, 230682, 226351, 263280, 257055, 234371, 204039, 204603, 296423, 208159, 296013, 228628, 280916, 230277, 234224, 263701, 285304, 279921, 243726, 276162, 267868, 210057, 271938, 250998, 215785, 228980, 224625, 210273, 226317, 227442, 214651, 239323, 298652, 286100, 271769, 214719, 267716, 285006, 280858]::int[], ARRAY[366339, 309708, 329118]::int[], ARRAY[474635, 452276, 471799, 416888, 466183, 443703, 495648, 435816, 442131, 464301, 497989, 469883, 441406, 475742, 421290, 427506, 489753, 479082, 412288, 424300, 429312, 432233, 474570, 447719, 480692, 406041, 449606, 438927, 406916, 407070, 477049, 436216, 477311, 480582, 425178, 410485, 432444, 497762, 426712, 407648, 444823, 453505, 432921, 413258]::int[], '2024-09-29 20:48:00 UTC', '2024-10-28 10:11:00 UTC', '2024-10-06 18:06:00 UTC'),
+('crawford.jbmfs2xp@example.org', '萧晴柔', 'male','2020-02-29 19:44:00 UTC', '2024-10-23 16:57:00 UTC', '2024-09-06 03:36:00 UTC', ARRAY[145492, 109019, 100293]::int[], ARRAY[210075, 218517, 267064, 245900, 259232, 226633, 272914, 210073, 212951, 203763, 265234, 289434, 206928, 231448, 284905]::int[], ARRAY[348814, 396763, 329132, 386520, 303407, 354175, 303942, 375021, 313604, 355442, 372589, 385482, 321861, 322640, 348469, 357566, 375298, 333964, 316414, 326887, 384172, 371585, 359382, 356827, 359881, 329289, 370032, 363814, 326757]::int[], ARRAY[431144, 477536, 453822, 445696, 458751, 499962, 448731, 417104, 459724, 489939, 471740, 408038, 452632, 467865, 497051, 444380, 428851, 445916, 422009, 484707, 460110, 422619, 442931, 492960, 408878, 412550, 435152, 409227, 445337, 450847, 420755, 475564, 476496, 431913, 433197]::int[], '2024-10-22 06:06:00 UTC', '2024-10-17 00:09:00 UTC', '2024-09-17 07:18:00 UTC'),
+('oswaldo.xwiltb1f@example.net', '王杰书', 'female','2023-02-12 12:47:00 UTC', '2024-10-03 12:04:00 UTC', '2024-09-20 02:30:00 UTC', ARRAY[178817, 166515, 187983, 109740, 108344, 179722]::int[], ARRAY[211486, 296756, 261105, 293192, 210190, 281268, 276332, 203377, 239510, 229352, 289186, 233968, 245242, 223833, 278368, 209977, 222509, 259908, 271810, 281242, 228451, 247663, 278772, 205429, 248381, 297342, 273591, 251311, 227242, 247960, 233929, 259735, 258033, 220376, 230421, 202812, 203569, 231256, 218516, 216966, 252648]::int[], ARRAY[397969, 358638, 362209, 390606]::int[], ARRAY[415277, 459738, 437269, 461836, 480134, 434638, 412610, 461155, 443007, 481176, 468002, 405402, 437135]::int[], '2024-10-27 18:01:00 UTC', '2024-10-25 11:41:00 UTC', '2024-08-24 17:50:00 UTC'),
+('serenity.zqj67lbm@example.net', '顾博泰', 'female','2023-05-02 16:33:00 UTC', '2024-10-31 22:12:00 UTC', '2024-09-14 02:58:00 UTC', ARRAY[194409, 134712, 166783, 181002, 104960, 105138, 166529, 118627, 148664, 133054, 101348, 173158, 163488, 114837, 129552, 123700, 140396, 159789, 133818, 157291, 188300, 187359, 138188, 157222, 194785, 110600, 170159, 174404, 162303, 199366, 180951, 142116]::int[], ARRAY[280247, 252655, 236910, 233878, 299077, 231688, 207230, 237459, 217279]::int[], ARRAY[327222, 356474, 383555, 320095, 378314, 319272, 332242, 367641]::int[], ARRAY[455090]::int[], '2024-10-20 03:00:00 UTC', '2024-10-24 14:16:00 UTC', '2024-08-30 15:52:00 UTC'),
+('samantha.58ia0lk8@example.com', '卢俊伟', 'male','2024-03-17 14:18:00 UTC', '2024-10-29 22:31:00 UTC', '2024-09-06 19:00:00 UTC', ARRAY[189357, 194031, 114373, 121097, 163192, 164512, 126702, 174898, 166154, 122710, 148891, 114372, 110998, 146110, 182016, 159463, 194749, 161559, 194424]::int[], ARRAY[287060, 236250, 248219, 255384, 211104, 207774, 252191, 219716, 266121, 228711, 233112, 243882, 280349, 240145, 234029, 229889, 213544, 231295, 287057, 231491, 291008, 248186, 219415, 205245, 282530, 237197, 230673, 256990, 243310, 247578, 265915, 259007, 265009, 282126, 268527, 235957, 287229, 282643, 281759, 211442, 261758, 226797]::int[], ARRAY[355421, 362217, 312429, 372320, 375364, 309190, 305783, 351725, 375508, 358979, 301015, 358287, 333008, 337426, 361486, 314003, 388354, 344441, 350964, 313992, 363973, 301809, 340762, 321809, 384029, 348622, 365174, 348229, 348244, 345357]::int[], ARRAY[422578, 437134, 480158, 466673]::int[], '2024-09-25 15:49:00 UTC', '2024-10-24 08:58:00 UTC', '2024-10-19 09:00:00 UTC'),
+('dayton.xhxqg58z@example.net', '白彬城', 'female','2022-09-29 18:50:00 UTC', '2024-10-08 09:45:00 UTC', '2024-10-19 22:05:00 UTC', ARRAY[152269, 102406, 157918, 188248, 169754, 128670, 149298, 134583, 154243, 182045, 145600, 189273, 158286, 196560, 102078, 122427, 134716, 193875, 192629, 136685, 114192, 100498, 143331, 118118, 112034, 152632, 101668, 107386, 120468, 140052, 188739, 191341, 150396, 181793]::int[], ARRAY[212414, 287245, 280664, 224534, 285551, 282125, 242019, 279583, 274052, 240164, 269275, 250526, 237719, 263692, 267064, 279359, 258493, 230559, 219264, 297409, 287387, 213615, 259681, 213007, 231487]::int[], ARRAY[366686, 317561, 368061, 395161, 334824, 328152, 399067, 313323, 354105, 354485, 361244, 383087, 322160, 351399, 366905, 336617, 333211, 306700, 386802, 320483, 323088, 378730]::int[], ARRAY[410982, 402862, 458474, 412545, 427502, 477929, 411118, 498606, 485166, 406258, 448544, 435927, 479677, 414913, 458709, 428320, 472553]::int[], '2024-09-19 09:58:00 UTC', '2024-10-17 21:19:00 UTC', '2024-10-27 12:25:00 UTC'),
+('zackery.0uw3mmn6@example.org', '杨馨梨', 'female','2019-12-10 06:39:00 UTC', '2024-10-22 12:19:00 UTC', '2024-10-03 01:42:00 UTC', ARRAY[123181, 185913, 135005, 103066, 130283, 152239, 101707, 126099, 118170, 145935, 193933, 136242, 151778, 100502, 187236, 129953, 118632, 106322, 196185, 169298, 166879, 183602, 184175, 129093]::int[], ARRAY[259280, 231992, 249127, 227710, 297223, 256742, 293690, 250675, 229572, 214687, 293372, 299703, 253620, 224274, 273822, 257278, 227791, 227032, 261994, 245339, 271984, 281074, 251651, 257308, 256732, 269525, 220805]::int[], ARRAY[394146, 301677, 350620, 327970, 311070, 378687, 387071, 301031, 343157, 377640, 325993, 378550, 389947, 358276, 326804, 371122, 311776, 374124, 321319, 309102, 333728, 360296, 378184, 397017, 348443, 395503, 364710, 380189, 327777, 314988]::int[], ARRAY[417719, 400604, 482606, 488676, 417224, 479247, 461214, 410573, 431778, 462421, 447920, 456465, 421195, 430306, 425672, 488864, 418654, 480318, 408055, 407001, 453988, 444863, 495915, 430091, 481750, 476491]::int[], '2024-10-26 04:07:00 UTC', '2024-10-17 08:36:00 UTC', '2024-09-21 07:18:00 UTC'),
+('mireya.9r4djvki@example.org', '付鼎智', 'female','2022-03-04 02:10:00 UTC', '2024-10-05 07:10:00 UTC', '2024-09-23 08:26:00 UTC', ARRAY[106670, 165255, 113669, 174501, 150294, 110595, 140940, 166812, 149471, 198242, 195979, 191496, 170843, 117479, 142468, 172155, 194139, 152264, 100778, 198111, 168614, 135185, 101988, 149004, 159724, 126303, 165320, 106044, 152913, 111583, 177810, 160249, 170317, 169893, 195449, 160031, 185191, 175040, 156812, 139871, 104181, 198178, 184960, 179650, 139594, 181033, 156831, 185702, 170304]::int[], ARRAY[223606, 201989, 281459, 274151, 234339, 245004, 275562, 286227, 203332, 284336, 291043, 258227]::int[], ARRAY[376558, 318777, 317127, 384665, 343451, 306758, 312710, 307728, 392377, 341786, 333854, 347675]::int[], ARRAY[440928, 462833, 495633, 470511, 401287, 488609, 411293, 435240, 431205, 477710, 423520, 474112, 467122, 474730, 496904, 478520, 429582]::int[], '2024-10-30 19:34:00 UTC', '2024-10-23 12:43:00 UTC', '2024-08-30 23:30:00 UTC'),
+('esteban.ukvpygrp@example.com', '武泽君', 'male','2020-12-22 02:10:00 UTC', '2024-10-22 17:54:00 UTC', '2024-08-28 17:42:00 UTC', ARRAY[186804, 141682, 147619, 178558]::int[], ARRAY[299141, 203646, 246665, 245062, 231616, 227094, 290549, 283874, 295873, 277277, 253885, 273999, 268827, 249950, 213381, 266499, 242830, 254686, 213425, 215040, 236206, 202171, 202489, 274549, 298589, 237541, 236557, 244128, 272200, 297661, 208230, 278505, 211727, 255484, 286764, 261836, 206365, 205061, 239918, 274262, 245673, 227260, 208212, 289503, 245792, 269799, 237082, 268876, 266341]::int[], ARRAY[362111, 312697, 372375, 354906, 352628, 341662, 348568, 387343, 337477, 381639, 358960, 390697, 382765, 322805, 319738, 307905, 304110, 324542, 383718, 346691, 319116, 385633, 354940, 330226]::int[], ARRAY[445692, 496038, 413052, 431287, 412799, 448636, 434941, 463734, 448864, 414680, 400529, 453025, 446292, 445761, 433584, 452826, 469947, 495435, 452382, 496637, 411153, 447749, 454973, 451924, 417545, 417150, 438293, 484807, 462211, 483357, 451312, 419255, 495523, 476693, 446818, 421195, 464436, 436288, 426555, 403617, 473122, 424197, 493961, 497731, 458450, 484221]::int[], '2024-09-20 00:36:00 UTC', '2024-10-23 04:28:00 UTC', '2024-10-18 14:15:00 UTC'),
+('brian.jm14ihnn@example.com', '丁瑜柔', 'male','2024-05-27 18:42:00 UTC', '2024-10-07 04:18:00 UTC', '2024-09-08 05:19:00 UTC', ARRAY[167717, 132827, 143034, 136946, 165606, 166415, 114787, 177737, 185999, 104705, 175264, 100002, 144871, 182312, 170474, 176125, 171875, 195013, 107403, 154254, 190766, 141059, 103024, 146581, 183624, 181983, 149916, 132657]::int[], ARRAY[]::int[], ARRAY[333315, 389116]::int[], ARRAY[406639, 479373, 402019, 471201, 442576, 452739, 451809, 462434, 419537, 493961, 495349, 407161, 449054, 494549, 407702, 481165, 473313, 483309, 425532, 422773, 488019, 470660, 477945, 461860, 443659, 434296, 409208, 435695, 462368, 410518, 454194, 417882, 403845, 412423, 414314, 479703, 402464, 447513, 496279, 425999, 414944, 487617, 489927, 495320]::int[], '2024-09-21 21:57:00 UTC', '2024-10-24 06:21:00 UTC', '2024-10-09 06:03:00 UTC'),
+('shaun.ll46bgc6@example.net', '顾香怡', 'unknown','2023-10-02 12:58:00 UTC', '2024-10-23 15:53:00 UTC', '2024-08-07 21:48:00 UTC', ARRAY[198860, 104938, 137697, 156815, 151172, 172087, 158534, 131070, 162436, 194445, 173986, 141608, 173940, 114310, 181547, 135969, 130003, 125147, 101080, 162273, 121854, 165256, 139445, 191003, 144336, 145835, 118926, 161280, 162413, 176841, 114246, 122837, 172831, 111538, 160421, 162650, 111026, 127561, 169645, 135825]::int[], ARRAY[293676, 203222, 254751, 236192, 202528, 247274, 297769, 283793, 227821, 217003, 280606, 217460, 279648, 231782, 215764, 298670, 285773, 278377, 234675, 230579, 292421, 287340, 218786, 267535, 294302, 269984, 264647, 260076, 213163, 221514, 220435, 244042, 276276, 222100, 297190, 260337, 249887, 212406]::int[], ARRAY[311674, 379907, 328858, 390391, 376959, 375526, 391615, 363617, 342914, 380308, 333534, 371732, 341829, 333060, 362739, 302651, 374236, 331398, 332316, 304167, 375959, 383829, 316109, 346549, 309681, 307921, 342484, 396208, 369779, 308175, 371734, 337464, 317033, 319350, 355985, 368946, 363823, 312512, 313877, 328735, 331717, 359408, 386829, 374441, 380507]::int[], ARRAY[466306, 458209, 452166, 441057, 492869, 483556, 409369, 409143, 424532, 444569, 474810, 417888, 463063, 472633, 439949, 467300, 407129, 492373, 477067, 474177, 459989, 446099, 414195, 418708, 457564, 482610, 479687, 464266, 404305, 494097, 481730, 410096, 415953, 436890, 440764, 474753, 412023, 411315, 408454, 489349, 439452, 426003, 480321, 432755]::int[], '2024-10-31 07:30:00 UTC', '2024-10-30 22:55:00 UTC', '2024-08-22 00:51:00 UTC'),
+('keshawn.si205q6d@example.com', '雷志欧', 'unknown','2020-12-23 06:12:00 UTC', '2024-10-30 22:08:00 UTC', '2024-09-13 20:21:00 UTC', ARRAY[126603, 144692, 191322, 199749, 191863, 146189, 122588, 173453]::int[], ARRAY[216736, 245200, 202757, 220820, 233551, 245804, 291874, 285585, 233066, 279755, 291342, 276438, 244552, 267668, 233705, 256007, 258154, 254799, 221400, 229427, 249702, 276509, 252609, 240069, 275087, 248994, 212412, 283575, 297337, 247143, 290810, 285150, 203210, 201764, 209941, 275979, 264901, 260629, 229728, 257976]::int[], ARRAY[301580, 368102, 318124, 358320, 303341, 338723, 394686, 389209, 397393, 382995, 359316, 349372, 338267, 346585, 371456, 374217, 311524, 331995, 357672, 319487, 354814, 377735, 337925]::int[], ARRAY[427412, 432631, 418335, 456347, 469018, 450573, 415712, 464121, 464352, 476237, 403912, 409848, 412648, 456567, 452060, 442279, 472556, 490432, 458815, 440699, 424423, 446239, 401492, 490235, 444812, 463763, 475041, 410999, 409532, 494643, 413182, 482336, 400405, 466717, 418791, 476636, 460001, 443963]::int[], '2024-10-15 07:57:00 UTC', '2024-10-18 10:43:00 UTC', '2024-10-01 07:05:00 UTC'),
+('arvel.4qxug3rt@example.com', '于圣天', 'female','2021-04-10 12:59:00 UTC', '2024-10-09 23:52:00 UTC', '2024-09-22 17:14:00 UTC', ARRAY[112586, 165477, 178101, 111657, 187430, 140327, 185443, 134513, 180135, 118527, 124053, 113152, 149602, 135499, 104413, 103358, 107305, 129141, 152127, 177441, 155140, 198539, 110891, 130778, 169397, 115023, 103783, 114528, 108398, 171126, 163100, 139912, 168513, 121828, 199055, 139282]::int[], ARRAY[229984, 232297, 294463, 260821, 286822, 257603, 272562, 289119, 210032, 256119, 202644, 287566, 274123, 204391]::int[], ARRAY[349809, 316590, 367979, 331151, 381277, 334200, 333778, 383507, 311096, 381677, 321353, 333401, 396699, 377905, 380436]::int[], ARRAY[438817, 429672, 441903, 438959, 420047, 469312, 440728, 487474, 453005, 418545, 417648, 404873, 430387, 412032, 470978, 469270, 436518, 455668, 448834, 419632, 418619, 402120, 455253, 436451, 412596, 410585, 456238, 446306, 438187, 404433, 401585, 433366, 441088, 485206, 431675, 487805, 447383, 445613, 416456, 448593, 481748, 425313, 472663, 402869, 488968, 419890, 462213, 426795, 416431]::int[], '2024-09-19 11:36:00 UTC', '2024-10-21 01:16:00 UTC', '2024-10-23 14:06:00 UTC'),
+('jose.2yco9a7w@example.org', '蒋志邦', 'male','2022-06-01 18:13:00 UTC', '2024-10-29 19:33:00 UTC', '2024-09-06 08:38:00 UTC', ARRAY[108893, 132117, 178595, 192510, 115190, 198073, 124505, 169297, 123540, 123247, 186961, 138385, 155210, 138547, 161706, 112865, 123305, 149850, 159909, 179737, 145561, 101753, 179967, 199855, 180315, 173682]::int[], ARRAY[276958, 206839, 229235, 260568, 253961, 229237, 202600, 236949, 240787, 201184, 235747, 218771, 264155, 206130, 292867, 250870, 260773, 221467, 232442, 209705, 287574, 282192, 209578, 206743, 233237, 283751, 260575, 245995, 268301, 240934, 206143, 253032, 285546, 270697, 296231, 230919, 264872, 233387, 250822, 286592, 223363, 230843, 244237]::int[], ARRAY[371510, 339855, 335220, 349696, 395514, 305838, 357998, 301787, 317518, 342690, 384788, 345405, 335368, 394680, 371864, 397220, 350992, 350984, 360755, 357445, 375877, 355509, 380195, 327031, 349531, 399648, 394176]::int[], ARRAY[495716, 469569, 459716, 464190, 426280, 436681, 413671, 421899, 426420, 489637, 438556, 426640, 454696, 421075, 480279, 462454, 405256, 456462, 489279, 429829, 424465, 412823, 471314, 493506, 438259, 469328, 432439, 467400, 467171, 487496, 467787, 411164, 459854, 413409, 428498, 460387, 432641, 459379, 499622, 498622, 427676, 484573, 462963]::int[], '2024-10-08 00:27:00 UTC', '2024-10-23 14:51:00 UTC', '2024-10-07 00:05:00 UTC'),
+('spencer.wz5ox1a2@example.org', '欧阳水莹', 'male','2021-09-21 06:03:00 UTC', '2024-10-04 13:36:00 UTC', '2024-10-27 03:38:00 UTC', ARRAY[143659, 123538, 182609, 145862, 124206, 107688, 118191, 103533, 134370, 119067, 119566, 123663, 171851, 157012, 154509, 127817]::int[], ARRAY[267289, 249899, 222011, 225404, 245699, 289881, 219809, 260696, 207376, 217132, 237481, 242668, 292636, 247661, 276035, 273917, 210410, 214544, 214984, 231853, 288268, 254929, 236457, 223273, 233679, 239102, 204789, 291392, 207668]::int[], ARRAY[322576, 398424, 308055, 363378, 349672, 382349, 388171, 302277, 359461, 358671, 335978, 318253, 315053, 330582, 311693, 386032, 339544, 318428, 391815, 344333, 363826, 335794, 335556, 333591, 333287, 322584, 361925, 334155, 346238, 322591, 385405, 311932, 391026, 388853, 369679, 309517, 330532, 323333, 363509, 335513, 368263, 320844, 381453, 394542, 378441, 333714, 393178]::int[], ARRAY[451441, 415909]::int[], '2024-10-03 14:54:00 UTC', '2024-10-28 23:41:00 UTC', '2024-09-07 13:18:00 UTC'),
+('kylie.u9lt9tbc@example.net', '汤德宝', 'unknown','2024-09-14 14:08:00 UTC', '2024-10-16 22:11:00 UTC', '2024-10-02 00:34:00 UTC', ARRAY[162183, 165386, 133656, 141345, 140222, 168478, 128105, 139519, 153716, 104479, 179298, 161789, 128126, 196668, 106577, 117475, 107807, 157638, 173273, 115577, 192540]::int[], ARRAY[251168, 266074, 220330, 214501, 243395, 232508, 226687, 233254, 200315, 263880, 254121, 216433]::int[], ARRAY[353191, 398536, 312162, 330175, 354507, 387840, 349421, 324005, 368177, 324248, 319318, 373546, 331244, 364355, 351934, 375098]::int[], ARRAY[438863, 458951, 474418, 486182, 462850, 451995, 464928, 457103, 427415, 495976, 430699, 485667, 445207, 415373, 425040, 425463, 440685, 492839, 401785, 494911, 466437, 492422, 498328, 444909, 423109, 467332, 445764, 460223, 426582, 418310, 413187, 451245, 410363, 403602, 411687, 460177, 485577, 491578, 466733]::int[], '2024-10-22 17:14:00 UTC', '2024-10-26 22:56:00 UTC', '2024-08-07 19:45:00 UTC'),
+('garett.3jxcw9bj@example.net', '曾紫芳', 'unknown','2022-03-31 13:02:00 UTC', '2024-10-19 06:47:00 UTC', '2024-08-28 01:42:00 UTC', ARRAY[180022, 150388, 186440, 123571, 118817, 112105, 160363, 103386, 120931, 179094, 155694, 147434, 128272, 165396, 160998, 101440, 141139, 171861, 134099, 136880, 178544, 151200, 176657, 129897, 176957, 136914, 116987, 130587, 135806, 168139, 170023, 152430, 180898, 194134, 129248, 124002, 118504, 178266, 126319, 141788, 195287, 188255, 199714, 187723, 101387, 145480, 143153]::int[], ARRAY[222171, 242379, 258088, 278425, 204779, 278714, 245951, 238908]::int[], ARRAY[393631, 398163, 391431, 303014, 391737, 301511, 342930, 362943, 364832, 301737, 386031, 374786, 398930, 334921, 353690, 316174, 362425, 316168, 305648]::int[], ARRAY[489805, 488319, 489800, 422398, 436279, 406881, 459344, 407064, 480308, 480713, 442941, 481056, 409124, 493966, 421654, 469005, 454369, 460658, 430488, 459516, 436944, 411170, 449117, 497537, 457642, 470195, 408555, 496772, 439629, 477855, 437950, 438300, 463784, 457687, 463503, 455260, 480185, 489445, 424121, 449799]::int[], '2024-10-06 12:04:00 UTC', '2024-10-27 06:10:00 UTC', '2024-09-04 20:06:00 UTC'),
+('domenica.s3rf6gix@example.com', '王妍芳', 'unknown','2022-05-06 09:45:00 UTC', '2024-10-12 07:59:00 UTC', '2024-09-12 16:08:00 UTC', ARRAY[124362, 157554, 169049, 191624, 182883, 176311, 198196, 196667, 166281, 167167, 141652, 141279, 138026]::int[], ARRAY[229481, 232358]::int[], ARRAY[333384, 383650, 351559, 380647, 331633, 364250, 395438]::int[], ARRAY[473735, 477981, 424447, 406485, 497206, 466646, 494082, 433759, 425312, 477440]::int[], '2024-09-17 20:26:00 UTC', '2024-10-31 12:30:00 UTC', '2024-08-21 17:10:00 UTC'),
+('kaden.bmvv7h05@example.net', '任晓青', 'unknown','2021-07-03 13:51:00 UTC', '2024-10-30 15:29:00 UTC', '2024-08-17 01:47:00 UTC', ARRAY[131171, 194303, 100475, 130645, 130137, 150408, 141912]::int[], ARRAY[293601, 230522, 285521, 281335, 221470, 281778, 260609, 293353, 258368, 204665, 258426, 210563, 267647, 274376, 244691, 267348, 219121, 280646, 278636, 294658, 275636, 285116, 249684, 208060, 220829, 248919, 276801, 213324, 271250, 218059, 219752, 261385, 261744, 226826, 202578, 228970, 227380, 222885, 271233, 264730, 273828]::int[], ARRAY[374026, 308050, 376356, 357023, 364204, 377922, 365291, 350158, 302786, 388823, 397190, 399225, 377416, 390433, 316577, 326831, 376947, 328662, 378703, 355667, 358579, 306462, 363054, 307766, 309348, 357974, 380550, 377167, 320745, 357242, 346565, 377676]::int[], ARRAY[494772, 464578, 461334, 425763, 452218, 483736, 420076, 480434, 441576, 442262, 496049, 465125, 458136, 402996, 470004, 467115, 446158, 419481, 443551, 499140, 471778, 440152, 459445, 484587, 443336, 453289, 445761, 438845, 499056, 491119, 401035]::int[], '2024-10-06 21:20:00 UTC', '2024-10-20 13:54:00 UTC', '2024-10-26 13:09:00 UTC'),
+('bianka.86jh51iw@example.org', '叶文材', 'unknown','2021-07-06 10:24:00 UTC', '2024-10-23 12:09:00 UTC', '2024-10-12 14:49:00 UTC', ARRAY[132665, 162543, 127049, 197930, 148412, 143988, 186425, 152206, 184432]::int[], ARRAY[242047, 284649, 218538, 208112, 238289, 232285, 267882, 217506, 277722, 236868, 210697, 240167, 272221, 277397, 258901, 250276, 231393, 200154, 252677, 258822, 202442, 247130, 265704, 291593, 247231, 227035, 206148, 207181, 231239, 294113, 208697, 244660, 287074, 230095, 237029, 248847, 235372, 229541, 235720, 230482, 284133, 281665, 280979, 210055, 262789, 226241, 253096, 299744, 260814]::int[], ARRAY[359867, 337581, 347219, 373640, 398092, 365183, 302447, 349630, 318718, 397070, 328137, 331037, 395761, 376929, 312755, 375790, 397959, 337312, 336185, 334501, 344394, 363949, 353557, 368745, 354441, 322824, 324779, 393032, 333003, 365777, 387863, 300854, 368283, 397461, 349723, 329267, 377774, 300991, 346900, 368611, 356713, 356295, 337691, 358169, 389577, 314311]::int[], ARRAY[445277, 470729, 413888, 460571, 422259, 444649, 455242, 446304]::int[], '2024-10-22 13:04:00 UTC', '2024-10-29 02:22:00 UTC', '2024-08-27 20:37:00 UTC'),
+('tomasa.975dchnh@example.com', '雷平绿', 'unknown','2020-05-08 17:13:00 UTC', '2024-10-29 16:41:00 UTC', '2024-08-18 12:01:00 UTC', ARRAY[199113, 100792, 142341, 100050, 138569, 102495, 149566, 163319]::int[], ARRAY[280891, 200825, 271282, 236248, 205743, 271655, 224453, 261345, 236338, 290493, 245195, 253601, 231345, 206072, 219886, 235060, 236591, 219764, 249156, 261342, 221310, 233833, 228808, 247100, 227268, 226361, 201843, 279032, 282012, 275898, 266293, 235735, 242157, 239984, 243458, 211243, 275313, 244035, 224740, 258724, 237959, 279627, 276946, 256391, 286054, 275069, 232115, 243656, 269136]::int[], ARRAY[362960, 388284, 353802, 380777, 307596, 341492, 331452, 398719, 378605, 319447, 321535, 366178, 368638, 399660, 361547, 387667]::int[], ARRAY[454556, 430049, 401611]::int[], '2024-10-02 21:51:00 UTC', '2024-10-21 08:47:00 UTC', '2024-08-17 22:30:00 UTC'),
+('shayna.6gjp7223@example.com', '余文来', 'unknown','2020-09-16 18:44:00 UTC', '2024-10-22 04:42:00 UTC', '2024-10-29 19:52:00 UTC', ARRAY[118806, 166211, 154055, 117064, 118212, 173723, 179493, 130048, 141221, 116088, 101826, 192080, 135287, 134154, 184986, 144073, 161842, 180092, 137194, 157494]::int[], ARRAY[217705, 213410, 275537, 249078, 217315, 258095, 288739, 259545, 205407, 270852, 236842, 289363, 205964, 249020, 235562, 220141, 234712, 232143, 255935, 216924, 277186, 273520, 297192, 251909, 266038, 299933, 240806, 257630, 254721, 243338, 238703]::int[], ARRAY[390614, 353245, 336000, 377155, 387890, 319192, 311711]::int[], ARRAY[423539, 421736, 452660, 407871, 484126, 451423, 403644, 468701, 459616, 459951, 443904, 438833, 427390, 431917, 411792, 492603, 402631, 412095, 402379, 489380, 448050, 492719, 488013]::int[], '2024-10-31 13:55:00 UTC', '2024-10-21 00:32:00 UTC', '2024-09-28 10:41:00 UTC'),
+('porter.nwmw0g56@example.org', '董文材', 'male','2024-05-03 12:49:00 UTC', '2024-10-04 07:43:00 UTC', '2024-10-24 21:36:00 UTC', ARRAY[111287, 144736, 156190, 191641, 155146, 159517, 198869, 111304, 181802]::int[], ARRAY[252926, 251334, 281437, 230988, 222241, 261404, 235076, 291167, 250159, 207586, 268079, 272654, 277153, 247374, 246517, 221075, 262138, 227075, 225113, 270280, 266726, 223964, 273624, 219706, 220890, 288794, 296051, 215298, 212515, 246662]::int[], ARRAY[339242, 343785, 319741, 361912, 323595, 349165, 319687, 395948, 357957, 362751, 383261, 321707, 343003, 392154, 391313, 339461, 324361, 397398, 358650, 361733, 338612, 330393, 303150, 349757, 333088, 334072, 395101, 325734, 370636, 302495, 314887, 390298, 379992, 387270, 312089, 318437, 345713]::int[], ARRAY[439744, 437928, 428298, 466973, 423194, 420386, 410345, 417456, 418395, 495273, 417614, 442935, 489237, 429905, 416705, 489831, 455119, 451371, 481628, 422156, 419823, 486764, 492446, 486860, 493534, 443161]::int[], '2024-10-29 04:27:00 UTC', '2024-10-22 03:07:00 UTC', '2024-08-12 23:17:00 UTC'),
+('rachelle.jrn9ng3i@example.org', '秦智涛', 'female','2021-02-01 10:49:00 UTC', '2024-10-21 14:01:00 UTC', '2024-10-01 20:06:00 UTC', ARRAY[181550, 185344, 116030, 170817, 121086, 189113, 180646, 150603, 111996, 127130, 125766, 177673, 143564, 198381, 122775, 134046, 110605, 156175, 167644, 142363, 161452, 144758, 133114, 182561, 103671, 164277, 132843, 153809, 122611, 172457, 171664, 115471, 130882, 184518, 137276, 196364, 117930, 124842, 154141, 163573, 122038, 159660, 102186, 156037, 155243, 178835, 154851, 122021]::int[], ARRAY[239274, 205778, 240628, 288294, 233212, 293175, 207322, 262662, 296839, 217360, 204878, 237044, 228192, 208824, 252014, 279611, 269041, 296136, 214225, 242284]::int[], ARRAY[305187, 362341, 318779, 328644, 356168, 304461, 304505, 370727, 399566, 389584, 399770, 364841, 331069, 323262]::int[], ARRAY[404844, 430717, 452837, 455365, 497090, 452207, 419874, 461171, 417141, 433070, 487168, 481217, 441338, 491794, 448803, 478616, 408510, 482118, 473289]::int[], '2024-10-08 20:37:00 UTC', '2024-10-19 23:57:00 UTC', '2024-08-29 16:28:00 UTC'),
+('jensen.snc1avim@example.org', '姚瀚景', 'unknown','2024-07-16 22:12:00 UTC', '2024-10-11 03:57:00 UTC', '2024-08-19 09:30:00 UTC', ARRAY[154567, 128431, 162181, 107096, 195571, 146686, 140939, 109631, 157704, 185645, 156201, 195490, 154979, 116188, 108934, 106612, 144658, 121091, 136416, 195542, 191390, 151747, 181096, 185371, 127462, 109516, 105754, 126760, 120286, 154393]::int[], ARRAY[278028, 259466, 268778, 238111, 262851, 241359, 219088, 275493, 262238, 247183, 208110, 242784, 294470, 283853, 208986, 241911, 218244, 279956, 257883, 298468]::int[], ARRAY[351432, 358121, 350637, 307283, 382145, 327161, 311361, 329296, 333892, 338696, 326980, 345803, 336471, 392825, 315087, 350193, 384168, 381299, 316670, 354148, 335699, 339966, 314475]::int[], ARRAY[414210, 472070, 472643, 464618, 423628, 460636, 403254, 457889, 449875, 478669, 443247, 406990, 410468, 400781, 483816, 425812, 403749, 457676, 462738, 474167, 479931, 460757, 444099, 409558, 498979, 410162, 419585, 437649, 434460, 499565, 475910, 401564, 450475, 487909, 410116, 485817, 462917, 420573, 421461, 470095, 494352, 483027, 425132, 469786, 418876, 427246, 496665, 425201, 401432]::int[], '2024-10-09 12:49:00 UTC', '2024-10-23 06:10:00 UTC', '2024-09-02 15:49:00 UTC'),
+('watson.wtte8q0l@example.net', '潘若菲', 'female','2021-08-11 04:19:00 UTC', '2024-10-05 19:42:00 UTC', '2024-10-21 13:03:00 UTC', ARRAY[105074, 116326, 109607, 196926, 128632, 117580, 194905, 198891, 162827, 145569, 104200, 183747, 181254, 101517, 124836, 132064, 156589]::int[], ARRAY[259481, 202410, 271028, 251148, 253366, 283346, 230096, 242645]::int[], ARRAY[327452, 368574, 373058, 320653, 361071, 386128, 315438, 352526, 364859, 326130, 341913, 315505, 368214, 330129, 396916, 316758, 396484, 329083, 343069]::int[], ARRAY[478528, 432446, 416816, 409402, 473351, 429617, 428248, 404702, 424398, 447812, 426111, 451176, 461398, 452309, 437456, 485585, 484951, 445099, 430769, 477657, 466881, 400024, 457064, 491250, 429577, 402868, 480659, 496760, 446478, 450327, 439884, 459650, 421176, 458904, 456831, 414654, 438277, 490946, 475456, 454314, 408431, 471893, 494830, 401657, 466446]::int[], '2024-10-30 02:21:00 UTC', '2024-10-28 13:54:00 UTC', '2024-10-24 13:28:00 UTC'),
+('palma.itn71fli@example.org', '方泰玄', 'female','2020-09-11 11:56:00 UTC', '2024-10-14 11:02:00 UTC', '2024-10-08 10:48:00 UTC', ARRAY[169655, 170431, 127336, 154657, 167100, 173921, 167228, 145582, 148856, 105316, 119453, 174196, 174514, 195319, 140357, 120208, 153113, 191719, 103130, 187918, 167814, 111493, 139088, 185265]::int[], ARRAY[294106, 267612, 239159, 278086, 229312, 287602, 243942, 260072, 267725, 290576, 270348, 234859, 202365, 215466, 285374, 280363, 258289, 204229, 274085, 287630, 268110, 287242, 200941, 271525, 249618, 256638, 293432, 276602, 290444, 280281, 275583, 264366, 223834, 207536, 241918, 295224, 267646, 259009, 261867, 257204, 283567, 213808, 254162, 293886, 231997, 230529, 273608, 293024, 252942]::int[], ARRAY[383911, 350008, 311960, 345351, 309272, 350102, 355579, 346202, 365931, 354495, 399614, 306976, 395888, 315000, 374696, 331446, 380905, 388345, 361399, 300550, 373900, 303516, 362450, 355506, 318149, 392444, 371423, 337994, 321106, 335318, 306521, 329046, 344814, 313120]::int[], ARRAY[481059, 415830, 417804, 447599, 407635, 413923, 457951, 464578, 421681, 450336, 454240, 468209, 423715, 478004, 449067, 458459, 477986, 494521, 414070, 440742, 456416, 438232, 438692, 480032, 433674, 470011, 470414, 475226, 450724, 463936, 477307, 473583, 481638, 440543, 476844, 471187, 470005, 438977, 471916, 435224, 449624, 414234, 483877, 410773]::int[], '2024-10-01 11:00:00 UTC', '2024-10-23 00:13:00 UTC', '2024-09-05 07:29:00 UTC'),
+('soledad.mpyul58l@example.com', '刘德春', 'male','2020-01-29 15:55:00 UTC', '2024-10-07 03:45:00 UTC', '2024-09-17 10:42:00 UTC', ARRAY[128386, 178604, 124433, 183784, 111881, 136239, 102731, 147569, 100729, 144991, 143883, 184700, 188963, 176943, 128062, 103426, 155432, 181413, 126197, 114336, 139432, 136184, 116859, 168779, 131103, 157494, 150584, 143130, 153364, 125534, 100446]::int[], ARRAY[209778, 297536, 203903, 210098, 268157, 282238, 230328, 228377, 221802, 227033, 209439, 233714, 230645, 291901, 275581, 277728, 201062, 288673, 253594, 211112, 246931, 281092, 265952, 234368, 206229, 263108, 298970, 280446, 225345, 262609, 201473, 238344, 241810, 259133, 275684, 228307, 247242, 220995, 275097]::int[], ARRAY[368537, 354232, 331731, 308937, 358824, 368553, 369362, 364171, 387030]::int[], ARRAY[482180, 457738, 449503, 422795, 426735, 487939, 450784, 403208, 441753, 411461, 454843, 481804, 407381, 473549, 471744, 474970, 485802, 452095, 476589, 465726, 496483, 482389, 480548, 454784, 441121, 434274, 466201, 421078, 488379, 422913, 498432, 412186]::int[], '2024-10-06 01:49:00 UTC', '2024-10-27 06:46:00 UTC', '2024-08-30 14:03:00 UTC'),
+('frankie.ywk3l6hl@example.org', '尹鸿灿', 'female','2022-08-04 00:09:00 UTC', '2024-10-21 20:23:00 UTC', '2024-09-30 19:23:00 UTC', ARRAY[137674, 192213, 160778, 152165, 163080, 131495, 126162, 179576, 171448, 170278, 101628, 134806, 157608, 143222, 115377, 178942, 109928, 194173, 138227, 121149, 189114, 118003]::int[], ARRAY[279328, 235381, 213616, 251663, 248589, 244410, 285103, 214923, 274701, 293406, 272680, 210426, 264560, 240832, 244706, 248929, 227141, 258259, 284304, 266893, 265468, 297447, 219818, 293377, 242656, 250487, 220314, 254577, 218186, 214888, 247257, 212956]::int[], ARRAY[308356, 313693, 324681, 397091, 363410, 393065, 324207, 387601, 350953, 377949, 314646, 353806, 377760, 320763, 364417, 396974]::int[], ARRAY[425623, 497069, 469782, 454021, 420864, 441116, 448336, 441967, 482492, 487087, 404897, 471821, 434466, 424545]::int[], '2024-10-09 17:47:00 UTC', '2024-10-25 07:05:00 UTC', '2024-10-19 20:11:00 UTC'),
+('mellie.ioc74tn4@example.net', '曹甜英', 'female','2023-12-19 20:16:00 UTC', '2024-10-27 07:12:00 UTC', '2024-10-02 08:25:00 UTC', ARRAY[167655, 155226, 193499, 177137, 131650, 106380, 158935, 118091, 151814, 157488, 105540, 152235, 110680, 174009, 126162, 164748]::int[], ARRAY[236692, 253025, 258227, 240368, 239660, 259434, 232366, 286418, 213873, 237992, 280247, 288801, 213665, 257969, 291296, 242073, 294078, 274062, 226714, 226267, 262585, 230585, 208204, 286334, 278946, 293574, 202775, 278302, 208168, 282987, 276221, 222457, 227794, 262938, 216399, 296871]::int[], ARRAY[382388, 390654, 308899, 351699, 377536, 374808, 342441, 368816, 390301, 310326, 343817, 391994, 321471, 354744, 399989, 327148, 352196, 342651, 380053, 399385, 373591, 370955, 330373, 323814, 324618, 388951, 362632, 333058, 337419, 312296, 332174, 301818, 351266, 339208, 331013, 354485, 343634, 317309, 341012, 391676, 355541, 331660, 382728, 331865, 397921, 304700]::int[], ARRAY[457170, 421254, 431892, 441579, 409838, 405545, 448779, 481994, 493999, 457680, 448002, 437928, 431442, 450915, 444320, 488432, 430706, 447887, 463773, 491104, 415954, 485936, 430043, 408207, 420979, 410429, 468030, 468735, 452290, 473528, 404190, 453595, 482165, 448157, 400033, 450506, 424493, 427121, 415363, 409694, 430721]::int[], '2024-10-15 04:42:00 UTC', '2024-10-18 15:24:00 UTC', '2024-09-02 21:36:00 UTC'),
+('antonio.6orgo04i@example.net', '贺念薇', 'male','2023-06-09 21:49:00 UTC', '2024-10-11 04:35:00 UTC', '2024-10-24 10:06:00 UTC', ARRAY[182208, 123153, 107427, 106738, 119262, 183616, 109269, 165921, 182223, 118355, 162885, 178609, 102957, 168060, 158731, 174142, 150670, 124841, 185329, 169611, 168553, 125338, 165067, 157972, 159097, 134460]::int[], ARRAY[290504, 251137, 229398, 266175, 284690, 225772, 266763, 214728, 254962, 263716, 268227, 253522, 254077, 241653, 286545, 232431, 274897, 212289, 240264, 262905, 248694, 201501, 265638, 241239, 214540, 246340, 246044, 218700, 275812, 209052, 222422, 298212, 273764, 214738, 251011, 238054, 247648, 258818, 209438, 288390, 261862, 212873, 234270, 269219, 204500, 290901, 272010, 299592]::int[], ARRAY[322601, 335735, 385976, 364939, 311114, 374141, 302023, 344282, 327530, 331615, 303235, 332888, 314103, 375887, 329368, 324713, 346469, 374378, 341797, 386666, 335206, 315549, 392754, 331767, 323352, 380758, 311864, 330754, 328803, 398893, 380531, 393457, 374571, 323530, 306343, 325424, 369086, 377111, 360226, 312310, 305036, 356538, 355860, 356770, 370297, 328336, 392752, 359663]::int[], ARRAY[451121, 430369, 402175, 468989, 406991, 427946, 401548, 440930, 449405, 420046, 420336, 498752, 483297, 466756, 408962, 492644, 498636, 432544, 462626, 403969, 439004, 496729, 463652, 475636, 424747, 464854, 409423, 450523, 478541, 459910, 464316, 457214, 408697, 448370, 408580, 459069, 474248, 401715]::int[], '2024-09-29 06:17:00 UTC', '2024-10-21 12:45:00 UTC', '2024-10-15 19:21:00 UTC'),
+('dayne.yclg6tl8@example.org', '黎春竹', 'unknown','2020-04-30 00:51:00 UTC', '2024-10-30 15:45:00 UTC', '2024-09-17 00:36:00 UTC', ARRAY[115494, 164779, 184321, 108733, 179281, 130627, 113214, 124792, 155377, 133165, 196280, 133969, 144538, 150539, 106975, 155698, 160790, 120627, 113137, 175612, 105620, 109223, 121265, 174307, 124192, 122281, 160611, 181996, 152796, 119869, 160330, 177449, 125143, 109007]::int[], ARRAY[250419]::int[], ARRAY[329826, 347468, 381207, 349740, 359284, 380120, 308661, 310846, 359824, 301044, 352107, 382214, 376790, 394542, 392580, 341980, 303707, 345588, 377890, 376374, 348322, 379834, 368974, 311014, 368978, 316725, 366831, 385600, 339166, 392582, 354663, 382401, 316939, 375707, 301690, 349722, 395228]::int[], ARRAY[421237, 495335, 476103, 422247, 462685, 445586, 463089, 424162, 421068, 436936, 419631, 454456, 434995, 480759, 420409, 406406, 448137, 415518, 457914, 472650, 406985, 406325, 440556, 405217, 422488]::int[], '2024-10-11 09:15:00 UTC', '2024-10-17 00:02:00 UTC', '2024-10-16 07:27:00 UTC'),
+('morris.h1gczll0@example.com', '廖雅彤', 'unknown','2021-10-14 20:27:00 UTC', '2024-10-26 15:58:00 UTC', '2024-09-16 05:44:00 UTC', ARRAY[145581, 118324, 151151, 177880, 182636, 155934, 183552, 143212, 160263, 142372, 169100, 104203, 140995, 142469]::int[], ARRAY[236646, 209698, 212822, 205803, 228072, 217799, 240020, 296984, 242193, 284198, 259683, 234240, 287085, 262775, 204723, 232045, 218524, 253423, 236076]::int[], ARRAY[307397, 307939, 335726, 311584, 354545, 378561, 355424, 308853, 308432, 350346, 300130, 398269, 367323, 382406, 332696, 348978, 337677, 372742, 390285, 354689, 359788, 304873, 354083, 304590, 389765, 320143, 349769, 303699, 334447, 366011, 347310, 327307, 355666, 335960, 309235, 321462, 394605, 360186, 391143, 356979, 389213, 350248, 328875, 329435, 340211, 378090]::int[], ARRAY[440827, 403704, 425588, 472056, 462306, 490883, 496530, 417477, 489767, 463392, 444969, 443430, 482864, 438466, 440019, 488079, 451673, 473099, 455644]::int[], '2024-10-02 01:08:00 UTC', '2024-10-26 11:25:00 UTC', '2024-08-04 17:59:00 UTC'),
+('lenna.yjy2ohx1@example.com', '陶德君', 'unknown','2023-12-12 20:29:00 UTC', '2024-10-21 15:59:00 UTC', '2024-10-03 17:05:00 UTC', ARRAY[146973, 198308, 183444, 132141, 135004, 128513, 142714, 129583, 122345, 177429, 174557, 129973, 117746, 176365, 120308, 109246, 159495, 148727, 182342, 164328, 108864, 182054, 192599, 119701, 167841, 163472, 175110, 196960, 102277, 183186, 136913, 197628, 181806, 104308, 137166, 112476, 161082, 153872]::int[], ARRAY[271130, 272188, 282583, 245825, 213105, 275874, 281129, 231757, 247056, 220114, 248275, 299492, 248071, 295753, 233743, 234937, 230576, 287026, 293480, 284175, 279008, 293176, 225788, 273113, 280437, 247215, 211831, 230385, 282390, 222785, 277457, 282098, 264903, 285406, 244370, 291526]::int[], ARRAY[326696, 352253, 333582, 361115, 300896, 355579, 323812, 369663, 325011, 322850, 347394, 305460, 385331, 360233, 358712, 373865, 385315, 313723, 398200, 300455, 316838, 367685, 339559, 353441, 381729, 314108, 301937, 315909, 312635, 347737, 309012, 361811, 362121, 390913]::int[], ARRAY[408832, 457468, 419672, 453826, 441927, 429671, 433715, 498644, 419639, 461731, 441079, 407652, 450415, 427477, 423113, 444003, 410028, 447035, 450795, 424680, 439386]::int[], '2024-09-29 05:10:00 UTC', '2024-10-24 23:12:00 UTC', '2024-09-07 17:12:00 UTC'),
+('hailey.epau4eyk@example.net', '雷翰辰', 'unknown','2021-12-23 21:27:00 UTC', '2024-10-25 16:01:00 UTC', '2024-10-25 02:24:00 UTC', ARRAY[105641, 152968, 120720, 148907, 145070, 179072, 182899, 155240, 174221, 109937, 105824, 109685, 191528, 112909, 130005, 147048, 118432, 113579, 117374, 108307, 183043, 133813, 194069, 196044, 184531, 102971, 196703, 138757]::int[], ARRAY[220216, 288871, 271320, 207615, 231320, 240614, 212341, 239486, 259556, 219783, 212851, 265354, 211214, 245909, 277377, 293481, 275477, 226421]::int[], ARRAY[331309, 379001, 300282, 313176, 326322, 330419, 334888]::int[], ARRAY[418234, 463177, 433621, 479052, 415261, 455965, 437076]::int[], '2024-10-10 06:15:00 UTC', '2024-10-19 10:03:00 UTC', '2024-09-25 04:14:00 UTC'),
+('bennett.nqcq06wr@example.com', '韦昌云', 'female','2020-04-14 09:04:00 UTC', '2024-10-23 13:13:00 UTC', '2024-10-31 14:28:00 UTC', ARRAY[199119, 155332, 170167, 188832, 143434, 141607, 162381, 137801, 125603, 108411, 198493, 188012, 129247, 109497, 172306, 134863, 185088, 199864, 163258, 154893, 150833]::int[], ARRAY[214298, 200192, 235120, 224139, 247390, 286174, 229271, 223067, 289535, 213967, 222116, 215552, 243237, 290418, 298698, 204679, 242857, 245427, 251894, 272972, 223959, 259359, 222446, 268782, 229691, 268259, 244699, 268615, 253106, 200879, 297345, 299402, 281621, 261239, 297305, 259140]::int[], ARRAY[331710, 338982, 383518, 320491, 359646]::int[], ARRAY[486730, 478834, 407465, 493423, 406491, 450030, 477191, 445498, 483112, 447729, 497516, 469200, 448041, 461272, 410627, 483852, 477593, 456951, 467958, 497744, 468212, 477995, 411253, 432259, 456915, 497871, 469996]::int[], '2024-10-07 22:19:00 UTC', '2024-10-28 06:21:00 UTC', '2024-08-14 04:45:00 UTC'),
+('derick.9i82gr82@example.org', '韩君翔', 'male','2021-03-06 08:51:00 UTC', '2024-10-27 17:01:00 UTC', '2024-10-15 18:28:00 UTC', ARRAY[188473, 196810, 151783, 161514, 153966, 124297, 167129, 119370, 181171, 143509, 188753, 192848, 123860, 154585, 116171, 158514, 196950, 100033, 124137, 101425, 105900, 132118, 159327, 173130, 161993, 163635, 198048, 182815, 143928, 164188, 167169]::int[], ARRAY[253097, 295035, 255132, 221875, 204810, 280930, 287241, 205312, 211695, 249975, 223913, 234564, 227384, 202481, 274875, 235459, 284647, 247285, 271669, 209923, 280942, 251457, 248132, 263797]::int[], ARRAY[379384, 309544, 320162, 399758, 337820, 370197]::int[], ARRAY[439697, 419527, 443643, 479883, 432058, 442823, 413655, 484234, 447757, 461470, 443358, 435306, 439963, 425389, 493358, 470242, 488278, 486841, 468360, 490228, 487864, 475733, 461114, 414500, 479952, 439825, 454351, 472475, 444428, 407186, 419735, 407698, 411457, 486942, 417531, 498738, 442705]::int[], '2024-09-23 14:16:00 UTC', '2024-10-22 13:26:00 UTC', '2024-08-30 20:26:00 UTC'),
+('terence.9pu579oe@example.org', '顾晋刚', 'male','2022-01-13 04:54:00 UTC', '2024-10-27 12:48:00 UTC', '2024-08-04 21:14:00 UTC', ARRAY[193329, 123147, 100507, 140219, 190642, 120092, 109379, 189627, 148769]::int[], ARRAY[263802, 234727, 260254, 233471, 251232, 237685, 299155, 218304, 265279, 277498, 267593, 280423]::int[], ARRAY[310618, 361470, 393353, 391312, 368318, 334108, 355382]::int[], ARRAY[425205, 445080, 494082, 428130, 452020, 469183, 404559, 401094, 418341, 423300]::int[], '2024-10-28 08:50:00 UTC', '2024-10-23 03:37:00 UTC', '2024-09-28 13:35:00 UTC'),
+('terrence.dm1ksfgi@example.net', '彭彬逸', 'unknown','2020-06-25 22:53:00 UTC', '2024-10-31 09:37:00 UTC', '2024-10-26 13:38:00 UTC', ARRAY[169027, 128683, 127836, 105949, 117353, 126987, 134426, 155127, 182725, 119587, 193104, 143489, 144629, 132077, 110996, 183543, 197142, 144898, 187891, 114779, 185270, 176391, 135399]::int[], ARRAY[215238, 268939, 292396]::int[], ARRAY[364259, 384078, 319250, 368963, 350693, 372014, 334435, 331410, 330718, 342664, 378766, 395406, 369029, 306167, 367233, 392910, 382002, 360305, 354249, 321326, 365863, 340412, 358757, 398194]::int[], ARRAY[407256, 492403, 442897, 430629, 444690, 459994, 468196, 432246, 490229, 493053, 423404, 418441, 497139, 462109]::int[], '2024-10-08 19:51:00 UTC', '2024-10-17 05:36:00 UTC', '2024-08-07 13:06:00 UTC'),
+('norval.0ch8s5jl@example.net', '范文材', 'unknown','2024-10-18 23:57:00 UTC', '2024-10-03 01:58:00 UTC', '2024-08-13 05:23:00 UTC', ARRAY[188296, 195836, 166654, 147475, 156756, 179575, 122740, 166441, 108666, 161975, 148672, 180273, 183749, 105395, 156424, 116597, 191488, 118474, 143656, 123667, 198062, 158359, 129892, 140499, 125133, 151712, 192797, 172040, 165498, 149384, 152520, 122935, 180980, 140852, 158713, 186202, 104089]::int[], ARRAY[277747, 249829, 238208, 259327, 262272, 258974, 291852, 231770, 296667, 275393, 226823, 215652, 278257, 221309, 275756, 258360, 209535, 206353, 249811, 279416, 248121, 297790, 243916, 248275, 248628, 294923, 290981, 208537, 227296, 294659]::int[], ARRAY[387725, 343893, 314033, 372583, 390514, 365580, 366210, 310006, 367697, 369727, 394846, 386982, 304050, 385517, 338948, 377406, 354649, 379644, 389602, 378891, 368285, 300798, 372618, 306823, 308983, 394540, 371930, 303141, 375507, 377698, 355772, 322133]::int[], ARRAY[430740, 488961, 453343, 447500, 444461, 408633, 469194, 469988, 401273, 467037, 417781, 410912, 486008, 435403, 484169, 469365, 444311, 493835, 436746, 419741, 422431, 421876, 422644, 461018, 425848, 453612, 483653, 438692, 424503, 466935, 430092, 432317, 493944, 495265, 420383, 494883]::int[], '2024-10-03 04:10:00 UTC', '2024-10-23 15:59:00 UTC', '2024-08-29 15:39:00 UTC'),
+('kobe.h7ftqafe@example.net', '杨彬锐', 'female','2024-06-26 20:00:00 UTC', '2024-10-17 07:21:00 UTC', '2024-09-27 15:37:00 UTC', ARRAY[149434]::int[], ARRAY[228081, 214824, 297689, 242184, 216583, 296196, 226663, 280321, 253468, 280752, 246323, 294365, 235025, 238334, 262111, 230513, 256371, 252689, 204638, 223351, 244579, 270578, 217525, 214279, 276460, 204004, 204361, 225962, 257495, 237445]::int[], ARRAY[395624, 346337, 354126, 358412, 304507, 344482, 374641]::int[], ARRAY[400037, 474472, 446412, 419350, 405974, 459423, 482776, 456231, 487624, 440488, 433482, 415686, 410959, 462662, 462574, 402277, 491811, 472016, 417712, 489236, 484704, 465983, 479254, 411825, 438251, 494539, 470115, 406548, 457153]::int[], '2024-10-11 18:33:00 UTC', '2024-10-21 14:08:00 UTC', '2024-09-10 16:47:00 UTC'),
+('german.gx8o1jmt@example.org', '钱红芍', 'male','2023-07-24 01:52:00 UTC', '2024-10-09 23:24:00 UTC', '2024-08-26 17:38:00 UTC', ARRAY[110327, 171206, 166575, 173586, 188601, 164068, 120895, 138903, 115557, 164908, 143321, 140263, 177256, 120093, 151000, 155497, 122597, 150823, 175342, 115639, 149254, 141394, 144837, 159226, 111902]::int[], ARRAY[258293, 218365, 270035, 257467, 205567, 264570, 203419, 263664, 221005, 292011, 255814, 266104, 266866, 201240, 244403, 245993, 290346, 208570, 257001, 290510, 251909, 205023, 284374, 294418, 200110, 217727]::int[], ARRAY[353245, 321575, 357241, 344767, 362687, 398173, 396171, 341423, 362210, 354357, 364303, 381329, 390540, 384601]::int[], ARRAY[468636, 488886, 474789, 462149, 433491, 436447, 459657, 447187, 472680, 420859, 459292, 400358, 454730, 465327, 484752, 405206, 415578, 444889, 417665, 461916, 408082, 415054, 455758, 464758, 448221, 426873, 445487, 432810, 499186, 447358, 412356, 453920, 455902]::int[], '2024-10-17 10:29:00 UTC', '2024-10-17 09:03:00 UTC', '2024-09-05 14:17:00 UTC'),
+('janice.5wkg53sb@example.com', '黎雅儿', 'female','2023-11-14 00:01:00 UTC', '2024-10-23 21:03:00 UTC', '2024-09-20 10:11:00 UTC', ARRAY[150276]::int[], ARRAY[238285, 271513, 288455, 268441, 238397, 298630]::int[], ARRAY[341282, 396164, 313603, 399765, 310623, 306778, 378933, 362102, 353048, 372191, 365381, 397311, 392709, 319288, 346066, 300369, 390963, 350974, 367423, 301724, 315580, 321115, 384734, 311864, 313621, 342675, 380652, 338353]::int[], ARRAY[439953, 448302, 498453, 468923, 442909, 499473, 422786, 492500, 479743, 426246, 439225, 442743, 466792, 432981, 461242, 469741, 491958, 425884, 418788, 453236, 439725, 462924, 499500, 472704, 485095, 466929, 426248, 454819, 450052, 443265, 465044, 423380, 417673, 436783, 410742, 460907, 415998]::int[], '2024-10-10 00:58:00 UTC', '2024-10-23 02:42:00 UTC', '2024-09-19 19:27:00 UTC'),
+('myrtice.6nr3xmdw@example.com', '冯杰书', 'male','2023-03-24 03:47:00 UTC', '2024-10-05 02:07:00 UTC', '2024-10-09 04:15:00 UTC', ARRAY[162794, 143994, 145987, 181725, 114692, 134776, 135817, 185293, 176092, 128744, 166730, 112729, 132294, 179888, 182438, 148507]::int[], ARRAY[254318, 234278, 253088, 222307, 242279, 201457, 296805, 204695, 284881, 240413, 271137, 296809, 225386, 267740, 242116, 217929, 283214, 292637, 263372, 237138, 233012, 290278, 209917, 295787, 276393, 283464, 262863, 268223]::int[], ARRAY[386731, 362791, 303206, 312850, 329602, 316579, 313552, 344739, 335825, 300410, 393591, 387597, 301104, 333799, 382312, 395756, 363744, 314835, 399903, 319375, 395120, 338248, 368147, 395301, 375904, 311746, 375260, 302075, 376178, 335257, 331783, 376120, 337649, 316307, 396086, 379581, 322264, 342127, 386405, 397620, 326357, 368554]::int[], ARRAY[442222, 411792, 413928, 449441, 482955, 471826, 416112, 486152, 492367, 421933]::int[], '2024-10-17 12:33:00 UTC', '2024-10-18 17:13:00 UTC', '2024-10-07 09:12:00 UTC'),
+('jermaine.zqim4jb8@example.com', '莫德春', 'unknown','2023-12-29 13:29:00 UTC', '2024-10-30 06:29:00 UTC', '2024-10-19 20:29:00 UTC', ARRAY[164283, 186698, 140448, 111256, 130954, 133803, 122224, 143470, 137517, 176276, 117885, 162003, 114680, 180276, 143589, 198133, 115166, 133860, 161597, 110543, 135801, 145590, 139945, 143266, 110852, 117002, 187019, 157590, 102292, 149166, 124349, 109013, 168177, 161807, 102863, 148061, 178687, 105601, 195004, 117316, 111186, 177411, 137735, 129484, 194009, 173688]::int[], ARRAY[249374, 271165, 291744, 204354, 253688]::int[], ARRAY[328729, 349797, 374979, 396379, 333694, 391251, 349650, 337676, 362230, 305107, 374438, 359300, 390283, 340090, 308689, 365068, 367013, 350408, 381596, 342648, 346736, 391445, 358738, 301446, 359638, 374703, 368659, 374928, 360748, 340675, 362917, 344738, 392893, 349891, 301617, 357778, 332803, 376623, 308014, 370232, 300729, 329655]::int[], ARRAY[478195, 424230, 412631, 479995, 430909, 409790, 495135]::int[], '2024-10-02 14:39:00 UTC', '2024-10-23 16:22:00 UTC', '2024-10-14 05:55:00 UTC'),
+('jairo.85pjbwql@example.org', '向百川', 'unknown','2024-10-01 01:01:00 UTC', '2024-10-30 05:38:00 UTC', '2024-08-31 15:57:00 UTC', ARRAY[161339, 147798, 141932, 129307, 123179, 148101, 178456, 193203, 147612, 114796, 154432, 188492, 184081, 189884, 152997, 105995, 118680, 183683, 102147, 163099, 116712, 130245, 185209, 158973, 114001, 166866, 116882, 139122, 104504, 136119, 191804, 130153, 108041, 178416, 191856, 182211, 181194]::int[], ARRAY[256553, 244965, 255260, 298651, 297261, 278360, 213108, 225030, 245775, 298294, 211523, 287703, 286623, 224938]::int[], ARRAY[306687, 349795, 327653, 358178, 306659, 397286, 395389, 342089]::int[], ARRAY[471842]::int[], '2024-10-12 17:45:00 UTC', '2024-10-29 04:30:00 UTC', '2024-10-12 10:50:00 UTC'),
+('forest.y2nno2j1@example.net', '江醉蝶', 'female','2022-05-14 05:26:00 UTC', '2024-10-03 08:43:00 UTC', '2024-09-19 23:53:00 UTC', ARRAY[135656, 133740, 167471, 177964, 190615, 175097, 156903, 107827, 177779, 176597, 161606, 129528, 185538, 135525, 179588, 160562, 189633, 159287, 114052, 195161, 172352, 139742, 146643, 111677, 195074, 122745, 108538, 101464]::int[], ARRAY[203206, 222235, 284416, 263947, 291271, 240847, 215906, 248243, 284691, 217921, 249137]::int[], ARRAY[393095, 374025, 392323, 375261]::int[], ARRAY[436682, 436806, 479564, 473626, 466698]::int[], '2024-10-04 01:08:00 UTC', '2024-10-20 17:21:00 UTC', '2024-10-02 09:24:00 UTC'),
+('german.nmyv3td8@example.net', '胡寒云', 'female','2020-07-28 22:31:00 UTC', '2024-10-10 11:28:00 UTC', '2024-10-31 00:21:00 UTC', ARRAY[187800, 162637, 141549, 101879, 140614, 190717, 168998, 171584, 105021, 176587, 115295, 116320, 108172, 166135]::int[], ARRAY[216387, 210910, 216680, 206743, 283119, 205880, 277996, 241275, 288949, 220965, 265218, 273975, 256594, 262696, 230822, 291632, 216456, 253948, 248101, 213106, 246095, 268125, 294707, 224270, 242989, 258500, 286338, 213724, 277632, 236729]::int[], ARRAY[371571, 382615, 381483, 314693, 363320, 314423, 329438, 371880, 364681, 304802, 350457, 354726, 333511, 351565, 344509, 305158, 396481, 307736, 379154, 331747, 344321]::int[], ARRAY[415452, 449280, 450777, 495699, 405430, 466778, 427427, 487827, 430684, 461660, 492277, 460635, 430710, 404012, 473649, 415745, 446823, 421132, 457439, 473219, 475112, 497695, 423730, 452599, 452762, 424225, 411405, 493602, 479893, 413438, 433002, 425096, 421513, 476887, 427700, 447463, 408113, 471191, 477353, 474012, 483786, 485362, 444055, 401095, 493499, 447573, 429069, 443799]::int[], '2024-10-20 10:59:00 UTC', '2024-10-19 23:25:00 UTC', '2024-08-07 23:47:00 UTC'),
+('anibal.ma6torhp@example.org', '蒋月阳', 'male','2019-11-26 11:56:00 UTC', '2024-10-14 16:32:00 UTC', '2024-09-30 06:18:00 UTC', ARRAY[171825, 182765, 152010, 139646, 170174, 144229, 149281, 103833, 177006, 139379, 109991, 153939, 106180, 139674, 122994, 133430, 165376, 115775, 178735, 192767, 118428, 155206, 184039, 106595, 119951, 166193, 164602, 196651, 145661, 157768, 164156, 172934, 114468, 184400, 121900, 133065, 167538, 138876, 190975, 100663, 101698, 115635]::int[], ARRAY[205795, 244235, 240134, 252992, 249927, 238361, 203523, 237700, 238378, 237708, 235735, 296300, 216415, 212671, 212431, 243882, 232050, 221887, 235166, 249786, 245031, 223973, 297874, 268361, 234226]::int[], ARRAY[318233, 395492, 389246, 323452, 308324, 327709, 376107, 356534, 372788, 357560, 358625, 315492, 356332]::int[], ARRAY[459066, 415336, 455765, 454734, 451251, 461270, 491813, 429047, 433642, 403320]::int[], '2024-10-18 02:27:00 UTC', '2024-10-28 07:16:00 UTC', '2024-10-17 09:03:00 UTC'),
+('carter.vr57dpeu@example.org', '莫馨梨', 'unknown','2024-03-03 17:43:00 UTC', '2024-10-07 21:29:00 UTC', '2024-10-20 16:32:00 UTC', ARRAY[171677, 190086, 134090, 170622, 192598, 162427, 160521, 113248, 133284, 143572, 108609, 125161, 167354, 178609, 134795, 150856, 129539, 153811, 161311, 167574, 132011, 157898, 160093, 185721, 116629, 151448, 194566, 132545, 116517, 120263, 192749, 144718, 107154]::int[], ARRAY[]::int[], ARRAY[361940, 336589, 302100, 302957, 338105, 310828, 333963, 362584, 380373, 376940, 334615, 361698, 385304, 378103, 319770, 361452, 344566, 309942, 393202, 339164, 330958, 338808, 350333, 373926, 344377, 386507, 306950, 376607, 311505, 397522, 338070, 364958, 388678, 353869, 343313, 347811, 333849, 398849, 335118, 396279, 339741, 367443, 373812]::int[], ARRAY[439192, 409509, 495822, 415023, 422615, 431913, 462628, 445812, 418565, 437292, 480628, 499031, 452163, 450486, 482532, 474654, 427239, 452155, 403851, 460546, 477421, 486690, 489999, 434640, 461837, 439712, 476533, 424170, 410363, 482274, 452115, 448282, 489614, 477499, 483325, 439820, 477405, 496078]::int[], '2024-10-20 18:58:00 UTC', '2024-10-22 02:22:00 UTC', '2024-09-17 03:21:00 UTC'),
+('mallie.wpwqzqta@example.com', '彭冰兰', 'unknown','2022-02-23 16:27:00 UTC', '2024-10-05 07:36:00 UTC', '2024-08-27 03:02:00 UTC', ARRAY[185869, 170864, 131954, 131084, 148108, 120090, 144409, 184734, 185956]::int[], ARRAY[202839, 289705, 279409, 287605, 293034, 208632, 202762, 226734, 299109, 211063, 272317, 239962, 227216, 202951, 258499, 267532, 274025, 288381, 268553, 225069, 289837, 299555, 267413, 265215, 214948, 289681, 272791, 224881, 205721, 204070, 211229, 226674, 253209]::int[], ARRAY[374424, 330246, 372770, 382180, 332870, 300754, 314636, 308142, 390406, 342534, 327869, 399244, 340412, 328481, 342780, 382158, 375366, 352605, 388316, 314658, 317031, 352078, 310842, 319274, 357619, 370638, 379288, 388379, 344512, 326638, 384024, 346039, 388525, 389329, 364446, 357185, 383192, 312665, 366329, 352166]::int[], ARRAY[487362, 495315, 404265, 498758, 428214, 476257, 422843, 477746, 439994, 441027, 475486, 464985, 441949, 449353, 445836, 489719, 447196, 400749, 404619, 434040, 485488, 473454, 497716, 419623, 425265, 468406, 430434, 445580, 497730, 495168, 447733, 400375, 444144]::int[], '2024-10-17 10:31:00 UTC', '2024-10-26 05:55:00 UTC', '2024-10-01 07:29:00 UTC'),
+('alexie.demc5o0h@example.net', '石远乔', 'male','2021-08-31 16:29:00 UTC', '2024-10-15 19:14:00 UTC', '2024-08-15 17:31:00 UTC', ARRAY[193657, 169291, 161911, 168702, 190755, 154052, 121205, 134349, 128500, 190556, 178375, 107753, 136516, 146499, 154516, 148238, 126142, 196128, 147251, 103965, 125889, 168198, 191538, 108406, 134387, 139310]::int[], ARRAY[]::int[], ARRAY[334883, 336305, 353126, 368311, 342870, 364192, 339515, 366980, 322020, 363416]::int[], ARRAY[494524, 403103, 461687, 499162, 408432, 439755, 431187, 483780, 470552, 448070, 450805, 437215, 499903, 430158, 417354, 425079, 483014, 469444, 455346, 450327, 418547, 498752, 474067, 418141, 471151, 442075, 466237, 466154, 476064]::int[], '2024-10-25 20:41:00 UTC', '2024-10-29 07:04:00 UTC', '2024-08-10 21:34:00 UTC'),
+('carlo.2f8k8chl@example.net', '黄哲南', 'male','2023-11-17 22:01:00 UTC', '2024-10-05 20:18:00 UTC', '2024-10-16 05:22:00 UTC', ARRAY[178822, 113282, 149772, 172095, 146314, 157442, 102604, 162934, 183330, 170835, 185447, 153147, 117691, 198585, 106109, 189180, 109114, 170042, 114129, 182634, 104235, 112234, 195150, 148256, 182621, 105707, 103743, 142479, 116939, 133719, 132924, 114230, 158927, 164787]::int[], ARRAY[253716, 286840, 262304, 239361, 240487, 299603, 292924, 260614, 223765, 249002, 213953, 203229, 272781, 213238, 207505, 287164, 255784, 279286, 208319, 215577, 265391, 266015, 250836, 265863, 209033, 299634, 210456, 229219, 260087, 220559, 245953, 265382, 247475, 232376]::int[], ARRAY[384593, 364427, 329823, 377228, 383204, 321466, 395938]::int[], ARRAY[494585, 423851, 496762]::int[], '2024-10-25 12:52:00 UTC', '2024-10-31 18:29:00 UTC', '2024-09-06 15:57:00 UTC'),
+('kevon.u5p929en@example.com', '杜丽梅', 'unknown','2022-02-20 20:07:00 UTC', '2024-10-21 00:40:00 UTC', '2024-10-28 06:20:00 UTC', ARRAY[121226, 105573, 128155, 111040, 139257, 198087, 189610, 134905, 125469, 143160, 193484, 193155, 192028, 160412, 167651, 130349, 169594, 183505]::int[], ARRAY[208236, 279285, 248614, 213231, 268132, 232827, 240870, 292874, 273855, 298857, 279139, 250751, 226368, 283005, 271417, 266565]::int[], ARRAY[375601, 371742, 382267, 327981, 313119, 317330, 303943, 308388, 390172, 303410, 343439, 381710, 324123, 307430, 396239, 306570, 389673, 341700, 388021, 373570, 380924, 399927, 374870, 301077, 386157, 326711, 372468, 395605, 330447, 318164, 355504, 352921, 303129, 301483, 300816, 362528, 359496, 370527, 345419, 304187]::int[], ARRAY[477794, 429129, 407676, 487787, 426981, 406219, 490353, 472081, 417019, 468274, 495259, 487833, 403257, 430022, 460649, 427174, 499910, 403205, 456053, 494626, 445031, 442639, 403384, 497490, 413520, 459177, 404665, 426099]::int[], '2024-09-17 03:42:00 UTC', '2024-10-24 10:10:00 UTC', '2024-10-03 19:56:00 UTC'),
+('mckayla.6jccfc40@example.org', '冯娅楠', 'female','2023-10-15 21:27:00 UTC', '2024-10-09 08:26:00 UTC', '2024-09-15 06:24:00 UTC', ARRAY[163544, 103412, 112225, 154978, 132861, 104795, 154989, 175387, 169051, 182391, 186217, 188251, 175730, 149858, 144213, 163250, 168122, 183454, 174782, 152667, 153939, 136302, 153280, 117729, 142149, 134241, 112518, 121971, 116450, 143717, 198711, 127550, 121765, 145529, 127365, 183430, 135185, 105556, 162708, 145612, 165641]::int[], ARRAY[238014, 269687, 283119, 281630, 263603, 241242]::int[], ARRAY[324865, 348792, 367283, 310434, 311705, 321201, 383372, 388862, 363803, 341483, 344189, 306372, 302166, 339516, 366307, 355683, 354547, 306858, 372980, 352605, 315506, 378586, 326920, 316184, 389830, 304714, 319681]::int[], ARRAY[442137, 444974, 464447, 440020, 490413, 410628, 483026, 433716, 426098, 428357, 447305, 442234, 450483, 472606, 428121, 406131, 421776, 484207, 459708, 406127, 487739, 449695, 453260, 474087, 492234, 442522, 407183, 467605, 416226, 420094, 443311, 411707, 438912, 450643, 444944, 483002, 453908, 400862, 444108, 481874, 493115]::int[], '2024-09-22 10:06:00 UTC', '2024-10-19 10:52:00 UTC', '2024-10-02 10:36:00 UTC'),
+('norbert.b0ea81oa@example.com', '严荣银', 'female','2024-03-03 20:51:00 UTC', '2024-10-14 07:08:00 UTC', '2024-09-10 13:59:00 UTC', ARRAY[159758, 161375, 162028, 116675, 123684, 128523, 146149, 199514, 134481, 100450, 120554, 180545, 107496, 170832, 185256, 105410, 189585]::int[], ARRAY[276626, 208701, 233453, 228249, 276714, 216877, 211572, 237020, 266132, 264322, 271965, 267611, 237051, 286410, 248053, 269714, 216426, 210988, 226500, 295782, 218784, 249826, 224940, 238485, 226900, 298829, 291560, 270866, 212045]::int[], ARRAY[376265, 302129, 302754, 349297, 339531, 364903, 351433, 329083, 342623, 368895, 357501, 305134, 310318, 385439, 309623]::int[], ARRAY[491676, 445980, 446824, 456417, 480014, 415425, 401037, 481171, 420023, 417853, 485884, 477596, 413895, 455772, 479431, 489594, 435569]::int[], '2024-10-23 00:22:00 UTC', '2024-10-28 04:48:00 UTC', '2024-09-16 05:27:00 UTC'),
+('lacy.7beylypi@example.org', '朱俊新', 'male','2024-03-27 01:39:00 UTC', '2024-10-05 09:57:00 UTC', '2024-08-22 07:17:00 UTC', ARRAY[170388, 143505, 130095, 124165, 121414, 139257, 151804, 116993, 104147, 105815, 176760]::int[], ARRAY[241997, 296323, 295788, 251277, 288717, 285371, 246428, 241606, 202717, 227054, 299324, 286517, 256517, 289330, 249092, 298093, 221484, 228990, 248977, 289422, 220408, 254255, 209194, 204516, 210699, 292368, 250907, 243606, 226207, 227086, 233820, 277298]::int[], ARRAY[316657, 300718, 384094, 343102, 349342, 365980, 339873, 347821, 337725, 368649, 328491, 314860, 316956]::int[], ARRAY[473918, 453743, 463620, 450320, 467439, 477625, 412005, 433911, 464870, 489145, 472493, 481131, 496478, 466687, 406956, 446912, 482906, 408351, 418265, 487252, 433659, 439245, 428785, 468036, 453681, 416711, 479381, 458459, 497164, 414582, 485963, 471970, 404434, 437475, 469543, 491475, 497670, 489512, 492955, 414786, 402365, 445394, 439696, 466242, 476406]::int[], '2024-10-15 06:42:00 UTC', '2024-10-22 09:23:00 UTC', '2024-10-22 13:45:00 UTC'),
+('hailey.wpb9copt@example.org', '许海杉', 'male','2023-07-27 08:46:00 UTC', '2024-10-13 07:02:00 UTC', '2024-10-13 04:50:00 UTC', ARRAY[108008, 116929, 133828, 186948, 143734, 162023, 174501, 127321, 153132, 100813, 161718, 178339, 151276, 135291, 175913, 116609, 149243, 150325, 172382, 168848, 108472, 171080, 155658, 133597, 147633, 138385, 185278, 137066, 178037, 101543, 126085, 166280, 107429, 192374, 128015, 106079, 119577, 114245, 176160, 120218, 192899, 152565, 187836, 129633, 174420, 116195]::int[], ARRAY[272038, 214331, 214471, 276151, 291089, 270561, 294905, 272747, 267319, 298922, 249854, 266686, 275270, 293085, 253474, 230169, 245599, 245762, 268931, 270336, 274954, 244205, 264390]::int[], ARRAY[346896, 367779, 319293, 361111, 361102, 347742, 363386, 352892, 397257, 338551, 331288, 356911, 357115, 363212, 362659, 327468, 350250, 322293, 357422, 303207, 364536, 327170, 379827, 319826, 305392, 341722, 312413, 315610, 341610, 375832, 392868, 376600, 309504, 351837, 380494, 339819, 364052, 394988, 399454, 350121, 304249, 392811, 300231, 367190, 374229, 324989, 360686, 331761]::int[], ARRAY[449041, 411198, 423026, 459239, 454931, 470408, 494071, 416135, 416486, 422352, 445252, 470411, 474964, 422730, 484974, 405651, 499264, 463348, 448551, 430613, 485573, 490332, 451707, 419444, 478676, 426587, 412956, 469248, 440629, 450699, 492521, 429850, 482898, 443161, 460228, 441771, 469522, 476746, 492276, 411354, 471904, 422378, 422419, 444803, 481722, 482307]::int[], '2024-10-04 09:46:00 UTC', '2024-10-25 01:11:00 UTC', '2024-10-08 10:53:00 UTC'),
+('karolann.8g9ym3zq@example.com', '许清泉', 'female','2023-10-21 10:43:00 UTC', '2024-10-23 15:43:00 UTC', '2024-10-03 05:27:00 UTC', ARRAY[181955, 129552, 129717, 170208, 185336, 165746, 148056, 199415, 139900, 160169, 107956, 197939, 139794, 197702, 149410, 125867, 184126, 176882, 188978, 136385, 113381, 112933, 156767, 177611, 166749, 100030, 135817]::int[], ARRAY[225112, 264370, 217280, 256432, 242959, 282630, 229654, 260647, 249135, 259961, 255983, 295680, 232055, 275143, 278137, 262179, 246729, 205349, 264979, 235021, 220873, 235687, 244439, 214079]::int[], ARRAY[303435, 303240, 332470, 363979, 392343, 305577, 374252, 389970, 386420, 335695, 329437, 316498, 311543, 318865, 310719, 390555, 356855, 327218, 374172, 377054, 305798, 365289, 307204, 325519, 375336, 326835, 304514]::int[], ARRAY[489437, 467589, 455977, 407134, 406205, 439588, 428705, 404460, 454790, 490874, 443341, 425277, 411637, 452629, 468876, 431578, 417192, 488190, 438953, 414492, 490063, 495703, 418926, 492883, 493224, 407470, 435735, 482727, 494382, 401705, 491425, 471479, 487020, 427874, 440932, 429952, 401052, 406503, 480141, 452545, 456227, 437068, 418866, 489176, 455296, 435883]::int[], '2024-09-25 21:03:00 UTC', '2024-10-28 22:54:00 UTC', '2024-10-30 19:59:00 UTC'),
+('ludie.bhwlvil3@example.org', '黄娜盈', 'male','2019-12-17 02:00:00 UTC', '2024-10-09 08:51:00 UTC', '2024-08-30 13:08:00 UTC', ARRAY[158196, 165002]::int[], ARRAY[231261, 294893, 260110, 265197, 278303, 296145, 204780, 259893, 281881]::int[], ARRAY[366675, 355019, 393334, 347574, 395986, 384862, 391589, 380317, 301332, 378671, 385152, 399940, 336871]::int[], ARRAY[424698, 491932, 408043, 406809, 491333, 410501, 406382, 431855, 443272]::int[], '2024-10-24 02:18:00 UTC', '2024-10-18 09:34:00 UTC', '2024-09-25 14:39:00 UTC'),
+('lilliana.h5vb05cq@example.net', '闫志欧', 'male','2020-09-13 08:42:00 UTC', '2024-10-10 05:54:00 UTC', '2024-09-02 16:09:00 UTC', ARRAY[182987, 124737, 134265, 158900, 199304, 142701, 164869, 139039, 191998, 146619, 170717, 178185, 151122]::int[], ARRAY[209372, 278946, 249820, 259340, 205345, 235997, 221420, 245311, 259023, 206197, 229477, 292707, 250619, 222983, 248467, 222454, 256769, 262088, 241889, 234987, 271659, 275928, 297684, 253082, 261247, 296423, 261455, 200805, 206381, 280179, 253588, 218777, 272795, 214062, 210133, 205130, 204542, 297278, 244044, 257620, 260430, 294838, 291411, 202600]::int[], ARRAY[305519, 308677, 328638, 328964, 391918, 316744, 359838, 343843, 380685, 323181, 375918, 324877]::int[], ARRAY[425407, 487642, 412458, 424663, 499386, 472482, 480928, 463690, 489354, 481309, 450637, 479845, 418251, 465332, 474869, 488542, 467396, 469768, 489560]::int[], '2024-10-26 02:57:00 UTC', '2024-10-30 23:23:00 UTC', '2024-09-14 01:42:00 UTC'),
+('laverne.z6hkyamy@example.com', '薛畅瑞', 'female','2022-09-30 13:34:00 UTC', '2024-10-16 19:42:00 UTC', '2024-09-01 03:56:00 UTC', ARRAY[122299, 172010, 150554, 122491, 144390, 120333, 158522, 199512, 184804, 127444, 141901, 121838, 155602, 133774, 141864, 169316, 128305, 153797, 134866, 137099, 131354, 192363, 193009, 151649, 189339, 187923, 131874, 137070]::int[], ARRAY[208368, 203194, 249182, 270456, 225188, 215231, 231217, 260994, 271677, 211266, 217442, 290038, 266353, 259621]::int[], ARRAY[304363, 338321, 367780, 386053, 383074, 362809, 358711, 349359, 394169, 317000, 316717, 357959, 365411, 312701, 388337, 395384, 349045, 334494, 347415]::int[], ARRAY[472149, 412437, 458683, 432846, 455626, 442145, 473510, 472735, 420228, 473625, 452489, 456089, 450205, 416270, 455720, 493460, 445781, 457008, 497603, 474549, 426184, 456275, 483653, 437874, 415723, 484581, 494464, 425221, 483758, 481013, 495144, 418399, 474527, 470439, 416861, 490271, 441960, 466446]::int[], '2024-09-30 05:18:00 UTC', '2024-10-28 08:54:00 UTC', '2024-08-23 07:52:00 UTC'),
+('hershel.2j8nzq21@example.com', '叶畅瑞', 'male','2024-04-26 06:28:00 UTC', '2024-10-07 21:07:00 UTC', '2024-10-03 02:19:00 UTC', ARRAY[]::int[], ARRAY[290289, 268739, 282237, 288856]::int[], ARRAY[314247, 365957, 336271, 300650, 311937, 347422, 354605, 310085, 366377, 322172, 313872, 330920, 309922, 352556, 387389, 359431, 317581, 356761, 363008, 399954, 325348, 351114, 325118, 386821, 323362, 364868, 368371, 364407, 333366, 326379, 349726, 360863, 338910, 317533, 302056, 329090, 303071, 383543]::int[], ARRAY[495481, 475014, 429920, 452898, 428678, 486938, 456630, 484884, 499395, 438631, 406427]::int[], '2024-09-17 02:29:00 UTC', '2024-10-25 04:18:00 UTC', '2024-10-05 12:29:00 UTC'),
+('cecile.tff5d3ar@example.net', '郑德宝', 'female','2022-09-17 23:40:00 UTC', '2024-10-20 08:36:00 UTC', '2024-10-16 17:26:00 UTC', ARRAY[157379, 145714, 112504, 148581, 149915, 198390, 172184, 104467, 174645, 128694, 182087, 145561, 180405, 166513, 103188, 167110, 158949, 156646]::int[], ARRAY[249112, 243248, 210825, 297839, 218069, 282971, 296921, 238869, 254296, 241565, 227495, 243940, 262761]::int[], ARRAY[353280, 305333, 388607, 330205, 340455, 382541, 397739, 318123, 384150, 339557, 341672, 327806, 321329, 301031, 324081, 389394, 360391, 346651, 384984]::int[], ARRAY[424820, 489475, 466824, 483896, 452038, 403729, 419521, 495923, 474600, 402974, 488270, 408198, 437310]::int[], '2024-10-22 16:07:00 UTC', '2024-10-17 18:44:00 UTC', '2024-10-07 20:20:00 UTC'),
+('leanne.fdbpsaw2@example.net', '毛志邦', 'male','2022-06-30 19:19:00 UTC', '2024-10-22 18:31:00 UTC', '2024-08-28 05:30:00 UTC', ARRAY[190389, 139854, 105181, 141729, 155913, 128350, 196626, 196573, 101180, 137861, 159421, 197865, 146703, 193400, 152847, 175443, 111482, 172912]::int[], ARRAY[205573, 227567, 204882, 266773, 221986, 210826, 286688, 257577, 214072, 233170, 289408, 246624, 201858, 249438, 256774, 206323, 269378, 264779, 205580, 217811, 243033, 287071, 203492, 216288, 241058, 230935, 212021, 281161, 293309, 237705, 247867, 259328]::int[], ARRAY[349896, 389237, 398669, 330265, 389454, 318761, 331325, 318395]::int[], ARRAY[411247, 491793, 460867, 466798, 425332, 439934, 431351, 462623, 485996, 412534, 406530, 429547, 439855, 436092, 485456, 467444, 446176, 448101, 457454, 470088, 402437, 435568, 441712, 421810, 489469, 493646, 485417, 450777, 404792, 432355, 458368, 430275, 494861, 445045, 436197, 417904, 420896, 465605, 442992, 407587, 474029, 481172, 475578]::int[], '2024-10-17 11:29:00 UTC', '2024-10-27 19:42:00 UTC', '2024-08-05 14:35:00 UTC'),
+('danielle.ch6lqp0e@example.org', '刘彬城', 'male','2024-04-20 21:13:00 UTC', '2024-10-14 03:26:00 UTC', '2024-09-22 06:06:00 UTC', ARRAY[110875, 171148, 141275, 117978, 110903, 151994, 121990, 136855, 134483, 133365, 118721, 103491, 156641, 183035, 100508, 135292, 120683, 172133, 125993, 110742, 169548, 176303, 179145, 183991, 170876, 104827, 192043, 147006, 115742, 166179, 142737, 157090, 186980, 137538, 124338, 183343, 134719, 138963, 103919, 137373, 159904, 152926, 195316, 120032, 160831]::int[], ARRAY[208130, 234101, 277320, 227514, 293292, 269870, 205203, 269846, 296462, 201878, 244218, 286633, 220302, 235454, 210858, 232456, 295115, 253556, 203489, 250495, 270550, 276318, 217805, 277456, 216033, 287868, 240983, 249385, 213179, 254014, 231567, 204554, 241293, 202864, 274731]::int[], ARRAY[305048, 315645, 352571, 341572, 330779, 378060, 302280, 390007, 347452, 310910, 315641, 375457, 366124, 363599, 354675, 320669, 398568, 345098, 306719, 327523, 392669, 326242, 377261, 353001, 336446, 314314, 340687, 318664, 327672, 373462, 396306, 350240, 394980, 360052]::int[], ARRAY[457305, 493288, 420452, 408008, 452720, 473742, 462081, 436597, 426788, 472933, 489381, 407034, 424975, 480176, 434229, 404520, 471945, 466591, 477748, 480287, 454126, 438563, 493041, 448804, 441674, 470461, 480278, 414106, 413680, 424463, 487025, 499486, 437455, 468970, 467805, 414096, 483710, 408365, 427160, 432767]::int[], '2024-09-18 23:55:00 UTC', '2024-10-26 12:30:00 UTC', '2024-10-04 02:50:00 UTC'),
+('breanne.kbfbeui9@example.net', '曹幻紫', 'unknown','2021-01-06 04:53:00 UTC', '2024-10-09 17:44:00 UTC', '2024-09-13 13:25:00 UTC', ARRAY[121989, 136868, 196910, 187868, 150970, 111182, 164270, 162368, 149359, 114948, 127249, 106281]::int[], ARRAY[260556, 252108, 264342, 248690, 260639, 261304, 282487, 218430, 231305, 263614, 279971, 228560, 262280, 269729, 220336, 298150, 238722, 278301, 257397, 211805, 278984, 270214, 239058, 221884, 241815, 296057, 290509, 235033, 238988, 263955, 279724, 231308, 265418, 202783, 211846]::int[], ARRAY[367480, 346048, 390945, 377438, 358857, 382593, 387031, 325060, 357845, 395082, 306050, 343408, 330006, 318229, 372209, 336780, 355857, 323711, 384463, 343443, 359060, 355543, 303980, 321550, 370573, 328146, 356064, 333741, 314740, 394037, 341298, 309461, 300009, 366662, 365969, 315615, 367500, 338335, 339819, 343663, 339094, 391809, 379386]::int[], ARRAY[405073, 434701, 407939, 490417, 453859, 435413, 452976, 416159, 484125, 481943, 434331, 486972, 423271, 471756, 406974, 495025, 466113, 443689, 403382, 460436, 429799, 409353, 453079, 468859, 412642, 482551, 499868, 495971, 445557, 410525, 433632, 405552, 441629, 441998, 421393, 485999, 476646]::int[], '2024-10-06 06:25:00 UTC', '2024-10-27 18:23:00 UTC', '2024-08-20 05:05:00 UTC'),
+('devyn.azu70l5a@example.org', '程紫怡', 'unknown','2020-12-06 12:29:00 UTC', '2024-10-20 10:15:00 UTC', '2024-10-04 04:24:00 UTC', ARRAY[140803, 108846, 154833, 123473, 152686]::int[], ARRAY[268687, 276124, 265524, 247712, 231066, 274154, 231825, 287659, 227675, 255861, 220424, 294157, 230159, 270199]::int[], ARRAY[369095, 302458, 373535, 358807, 372120, 396449, 364617, 371820, 381035, 368514, 337283, 334424, 312606, 384581, 332982, 300097, 375724, 338508, 300165, 329413, 343919, 302082, 365775, 314908, 380113, 319021, 371571, 335729, 336984, 332393, 310100, 316226, 380044, 347180, 358816, 388830, 361322, 316655, 306303, 390708, 357884, 398946, 330420, 303553]::int[], ARRAY[468004, 497345, 440557, 474339, 493669, 490987, 488836, 456609, 453625, 453469, 496149, 489389, 417865, 430200, 411207, 403374, 408966, 451192, 470112, 485471, 401475, 426526, 416511, 473880, 401813, 438432, 453061, 435675, 463352, 437503, 474616, 439591, 448069, 471474, 490464, 445981, 471508, 427845, 417143, 443037, 424512, 409599, 431339, 404107, 494040]::int[], '2024-10-19 05:44:00 UTC', '2024-10-28 17:20:00 UTC', '2024-10-15 05:44:00 UTC'),
+('damaris.b1urmvh3@example.com', '徐秋风', 'unknown','2024-06-13 15:31:00 UTC', '2024-10-20 21:52:00 UTC', '2024-10-14 03:54:00 UTC', ARRAY[115095, 151917, 152283, 133190, 186837, 155869, 156196, 107960, 105830, 180226, 148515, 198718, 155286, 169470, 161629, 108136, 175065, 138469, 194592, 168325, 176396, 105347, 117610, 183891, 142417, 136027, 100290, 176557, 123462, 143767, 163804, 117724, 139089]::int[], ARRAY[209433, 244614, 267147, 289726, 218891, 243741, 200370, 217597, 204239, 258652, 225105, 288609, 255530, 238918, 276096, 274692, 217438, 252803, 219889, 204897, 264002, 222627, 221370, 284729, 276455, 295062, 279250, 275065, 252687, 257250, 263073, 203588, 235378, 233542, 222961, 290391, 275868, 266833, 232514, 273081, 244291, 236424, 278552, 207332]::int[], ARRAY[388254, 308034, 384349, 369554, 392494, 315816, 396530, 329326, 396803, 370411, 321339, 388046, 336677, 369093, 312954, 376834, 333898, 304858, 343788, 364464, 361718, 327238, 353546, 399131, 308066, 305290, 392220]::int[], ARRAY[477442, 481675, 462534, 461353, 464631, 471374, 490720, 442906, 402634, 414774, 423185, 493146, 481326, 489881, 464757, 453303, 414379, 467366, 476704, 441513, 490477, 484110, 400789, 457150, 452918]::int[], '2024-10-17 12:13:00 UTC', '2024-10-24 10:33:00 UTC', '2024-10-31 20:58:00 UTC'),
+('bennett.f52yfzw4@example.net', '宋浩新', 'female','2023-06-19 17:10:00 UTC', '2024-10-24 22:05:00 UTC', '2024-09-05 06:42:00 UTC', ARRAY[110718, 126901, 155180, 139816, 122919, 108410, 112997, 131926, 143197, 115966, 106044, 114494, 190091, 189861, 121686, 161980, 126837, 110187, 130951, 197151, 183638, 154900, 105788, 195257, 125244, 139359, 124791, 164368, 162892, 135688, 109046, 101006, 196644, 150521, 197859, 105289, 139540, 182113, 177792, 139108]::int[], ARRAY[235895, 284248, 244545, 201735, 233135, 246789, 250395, 296979, 291924, 240814, 212921, 287195, 234354, 288288, 226679, 264969, 241027, 297993, 297375, 229492, 271676, 275806, 227579, 293895, 240823, 240009, 265384, 292249, 295240, 203690, 233590, 204122]::int[], ARRAY[318552, 366072, 342746, 370449, 385234, 350518, 399171, 328701, 372670, 305203, 351796, 309221, 386928, 341589, 362285, 357801, 343933, 303411, 367908, 320943, 328791, 332199, 363685, 359258, 329613, 312477, 383084, 321164, 320495, 397209, 300869, 303177, 356188]::int[], ARRAY[489771, 467521, 487675, 480335, 400603, 425748, 444817, 471406, 425534, 487534, 437764, 477689, 429669, 409838, 454196, 490293, 429677, 475546, 476302, 443008, 427366, 415089, 448165, 439944, 401312, 426991, 483130, 410159, 442929, 479079, 446140, 466727, 421539, 418694, 492651, 465832, 488416, 491690, 431779, 435910, 463448, 445978, 473297, 402821]::int[], '2024-10-31 16:02:00 UTC', '2024-10-29 23:56:00 UTC', '2024-08-14 05:33:00 UTC'),
+('hallie.a4ykexsu@example.org', '朱志国', 'male','2020-05-01 16:39:00 UTC', '2024-10-18 18:52:00 UTC', '2024-10-05 07:51:00 UTC', ARRAY[109858, 186931, 177073, 115664, 187730, 119406, 167307, 138962, 107830, 199581, 192051, 170190, 184304, 112367, 167891, 178498, 120896, 185016, 190919, 100433, 131887, 133897, 106113, 110001, 150982, 180104, 188598, 197066, 148270, 194536, 178998, 117441, 152837, 188403, 146043, 196841, 125824, 163262, 121039, 151827, 105325, 113365, 194011]::int[], ARRAY[248904, 228477, 260960, 262489, 280006, 298381, 277820, 254122, 299349, 248721, 250775, 240645, 229520, 238401, 293715, 237171, 203076, 230920, 297237, 256052, 299836, 230794, 233615, 223176, 222019, 210920, 247794, 247847, 230095, 291554, 272312, 239764, 280096, 295109, 222346, 228170, 291376, 267568, 229076]::int[], ARRAY[]::int[], ARRAY[461881, 451943, 469308, 481840, 448782, 425145]::int[], '2024-10-26 20:51:00 UTC', '2024-10-21 22:31:00 UTC', '2024-08-28 17:52:00 UTC'),
+('fabiola.7zft8o4c@example.net', '罗任平', 'unknown','2021-07-18 13:17:00 UTC', '2024-10-19 04:10:00 UTC', '2024-10-17 22:07:00 UTC', ARRAY[102841, 189652, 132750, 181746, 103708, 158650, 188488, 154551, 131597, 142018, 117338, 179129, 145476, 113196]::int[], ARRAY[246695, 202505, 263506, 246422, 253452, 277812, 218200, 250386, 226585, 272584, 220359, 264585, 255728, 212324, 251424, 269712, 270588]::int[], ARRAY[311674, 301677, 314316, 384063, 354533, 379917, 306563, 398614, 386838, 325380, 320578, 348711, 368830, 337450, 351126, 321376, 303387, 341140, 382049, 369346, 383674, 302739, 360351, 350073, 367843, 343360, 388537, 331944, 310114, 319202, 368309, 306080, 396140, 306984, 308882, 350177]::int[], ARRAY[431403, 481199, 428812, 424124, 427800, 468968, 416547, 455513, 445992, 461084, 412888, 484677, 447829, 462652, 456854, 479895, 489052, 417800, 478696, 469346, 463581]::int[], '2024-10-16 03:20:00 UTC', '2024-10-29 23:46:00 UTC', '2024-08-10 06:56:00 UTC'),
+('amanda.ikybm4oi@example.net', '范艳姗', 'female','2019-12-05 17:11:00 UTC', '2024-10-07 22:05:00 UTC', '2024-08-26 11:44:00 UTC', ARRAY[120522, 115925, 190193, 104530, 102887, 111780, 112490, 134651, 115134, 111683, 166883, 153918, 114040, 199791, 170909, 158476, 117189, 127868, 183151, 156063, 144169, 124707, 108588, 180644, 148532, 113808, 173505, 155055, 195817, 177644, 116885, 151429, 133177]::int[], ARRAY[242523, 226788, 249385, 282639, 263170, 203943, 221253]::int[], ARRAY[342902, 379820, 354179, 347610, 320201, 316494, 311653, 340693, 356417, 333536, 332068, 397309, 344473, 330388, 398139, 349428, 345986, 351788, 396690, 305790, 331250, 341747, 376789, 340122, 377883, 378133, 317402, 387541, 320929, 351131, 372889, 396765, 356003]::int[], ARRAY[486013, 403565, 413874, 494371, 470436, 467449, 433992, 499426, 431000, 485536, 427933, 488798, 448141, 409499, 430339, 479609, 443580]::int[], '2024-09-21 20:11:00 UTC', '2024-10-30 03:56:00 UTC', '2024-09-05 05:48:00 UTC'),
+('jarret.mynlghxq@example.com', '曹晴柔', 'female','2020-11-15 20:06:00 UTC', '2024-10-15 22:52:00 UTC', '2024-09-16 19:39:00 UTC', ARRAY[106607]::int[], ARRAY[229133, 221059, 267765, 225233, 246848, 213877, 229055, 259017, 223658, 270181, 260140, 290390, 265735, 201244, 225630, 223381, 290825, 281752, 277253, 207051, 289433, 246139, 296996, 247518, 280783, 241176, 270153, 223871, 273065, 296308, 298648, 219453, 250201, 234509, 291503, 271482, 247421, 246176, 254936, 224660, 259285, 260141]::int[], ARRAY[307253, 381963, 357319, 332606, 333047, 316588, 378205, 347979, 389724, 389395, 350966, 314054, 362190, 305560, 395451, 347487, 329424, 323889, 311735, 364571, 384796, 370556, 346517, 331625, 370350, 347845, 360025, 307629, 378972]::int[], ARRAY[410360, 431714, 498707, 483127, 414577, 406588, 439752, 483112, 414812, 420312, 488732, 470277, 410709, 492751, 468768, 435408, 467267, 461107, 408090, 467378, 411983, 481387, 413979, 480355, 470272, 470091, 420487, 417165, 452059, 411034, 484956, 409847, 418565, 491381, 433531, 472335, 447924, 424314, 453153, 436915, 443124, 475768, 457294, 477704, 498271, 461085]::int[], '2024-10-01 11:07:00 UTC', '2024-10-21 04:30:00 UTC', '2024-08-09 06:25:00 UTC'),
+('theron.b2416ddr@example.net', '熊紫真', 'unknown','2021-02-07 07:10:00 UTC', '2024-10-16 08:24:00 UTC', '2024-08-04 08:22:00 UTC', ARRAY[173933, 197928]::int[], ARRAY[250821, 254808, 227647, 266789, 282795, 253723, 245746, 263040, 264737, 297735, 258596, 287870, 223382, 273315]::int[], ARRAY[340463, 370798, 351830, 303288, 395127, 300850, 376482, 343280, 317098, 386672, 301192, 394617, 380774, 360586, 374800, 312350, 338371, 304176, 346272, 365863, 393978, 312326, 374447, 399575, 372779, 302261, 320513, 314196, 308186, 380941, 344261, 321910, 363268]::int[], ARRAY[416166, 412057, 414333, 484283, 410123, 420815, 484283, 470526, 420757, 475865, 438115, 411644, 488313, 492709, 457623, 420271, 493155, 418944, 494858, 463081, 409568, 447288, 450210, 487081, 411464, 431257, 499521, 452214, 454477, 419018]::int[], '2024-09-30 04:11:00 UTC', '2024-10-19 10:17:00 UTC', '2024-10-31 06:49:00 UTC'),
+('lillian.ylsu6uxg@example.net', '史德宝', 'unknown','2024-01-10 08:28:00 UTC', '2024-10-18 14:45:00 UTC', '2024-09-09 18:41:00 UTC', ARRAY[116377, 148720, 157611, 101023, 147050, 196785, 158870, 125919, 102326, 139113, 187686, 123673, 154733, 142898, 104208, 180142, 182003, 182309, 146152, 164505, 184908, 129913, 130417, 153128, 117533, 153259, 114115, 137234, 192930, 190836, 107956, 150735, 187112, 109159, 103382, 198198, 170587, 135845, 153286]::int[], ARRAY[299881, 210585, 288297, 235795]::int[], ARRAY[346457, 304129, 306397, 381754, 390170, 336585, 385173, 307214, 396450, 301025, 377046, 396794, 371960, 351110, 302467, 355192, 353452, 307975, 305271, 396822, 322734, 391901, 357878, 324065, 372582, 315470, 358925, 315868, 312733, 328449, 376577, 380247, 369569, 302037, 370448, 379108]::int[], ARRAY[415444, 496682, 482578, 402255, 476116, 460850, 469342, 448812, 486118]::int[], '2024-10-12 02:51:00 UTC', '2024-10-26 21:52:00 UTC', '2024-10-19 13:11:00 UTC'),
+('devyn.xaq6b87v@example.net', '叶旭冠', 'female','2022-04-11 05:44:00 UTC', '2024-10-11 17:30:00 UTC', '2024-08-25 13:01:00 UTC', ARRAY[172440, 158364, 149678, 137204, 124313, 143501, 196013, 150293, 152478, 115339, 183296, 195741, 168229, 101058, 125236, 151941, 152650, 172899, 103092, 144589, 177262, 195848, 148395, 106064, 180343, 190986, 159152, 123799, 129847, 164262, 131361, 129371, 163025]::int[], ARRAY[221948, 232522, 227305, 238050, 266753, 256032, 298216, 291242, 271913]::int[], ARRAY[337309, 310559, 373736, 387671, 383427, 370835, 323280, 323035, 363258, 335558, 354847, 372022, 378973, 388669, 372638, 313671, 395429, 355948, 302902, 330714, 342245, 385008, 376330, 329885, 310011, 311518, 310009, 341344, 322009, 359351, 344232, 363249, 345787, 312969, 329705, 395535, 347164, 359269, 365521, 343340, 312474]::int[], ARRAY[481164, 401456, 454607, 436772, 407178, 478291, 473838, 493447, 452395, 481677, 434800, 493295, 496269, 482776, 467841, 447094, 411296, 414228, 473442, 434372, 426639]::int[], '2024-09-24 13:11:00 UTC', '2024-10-28 20:56:00 UTC', '2024-10-24 02:08:00 UTC'),
+('dell.qhzqoyel@example.org', '邓菲霞', 'female','2021-10-25 03:21:00 UTC', '2024-10-02 11:26:00 UTC', '2024-08-18 15:08:00 UTC', ARRAY[]::int[], ARRAY[231087, 270185, 260362, 231554, 209298, 204742, 279865, 261674, 257687, 244179, 200684, 202407, 237510, 200680, 205652, 268898, 201555, 248495, 248018, 279807]::int[], ARRAY[397659, 356415, 319942, 386948, 337697, 362622, 301727, 336359, 335987, 367855, 316613, 383154, 378525, 368297, 306125, 306429, 381476, 398224, 367485, 377306, 392646, 379350, 393065, 381011, 345049, 335633]::int[], ARRAY[498694, 489066, 492587, 473935, 441114, 451266, 482981, 475015, 424291, 419273, 491614, 437272, 446454, 486097, 481882, 408807, 454859, 473395, 422298]::int[], '2024-10-10 20:59:00 UTC', '2024-10-24 09:22:00 UTC', '2024-09-29 07:18:00 UTC'),
+('letitia.pm2xl8a3@example.net', '林醉珊', 'female','2023-05-12 01:13:00 UTC', '2024-10-28 06:34:00 UTC', '2024-09-29 16:52:00 UTC', ARRAY[136033, 138676, 186467, 116776, 124534, 104871, 128128, 174163, 173152, 145669, 174776, 164781, 130027, 168993, 147652, 149857, 157897, 188435, 128344, 138306, 136072, 124977, 127613, 113085, 182546, 166734, 156131, 192527, 142730, 198371, 182615, 192217, 195122, 113445, 191100, 178858, 112678, 138662, 115827, 115707, 106529, 184879, 125805, 187337, 186361, 176112, 140485, 138636]::int[], ARRAY[290069, 250187, 267894, 268210, 227759, 237787, 222791, 249191, 282879, 238684]::int[], ARRAY[368788, 378780, 330515, 314000, 326930, 375621, 315993, 372214, 324502, 325507, 354539, 370437, 359684, 339811, 398848, 386899, 363634, 384341, 338652, 394587, 316621, 393773, 351673, 397974, 392335, 316506, 385198, 355974, 332441, 338287, 370678, 378334, 339481, 383963, 361885, 353454, 338594]::int[], ARRAY[490306, 414660]::int[], '2024-10-22 11:00:00 UTC', '2024-10-27 17:01:00 UTC', '2024-10-04 23:44:00 UTC'),
+('santina.963oxguy@example.com', '顾沛菡', 'female','2020-03-25 13:50:00 UTC', '2024-10-24 05:08:00 UTC', '2024-10-14 08:41:00 UTC', ARRAY[127921, 190585, 135899, 125885, 110015, 154796, 188987, 168164, 107663, 141046, 120435, 185230, 188743, 101271, 171751, 122965, 141134, 171887, 158956, 193501, 191049, 191292, 118302, 127544, 118660, 174696, 132565, 116030, 146830, 123010, 101623, 159343, 167601, 186545, 183887, 181760, 104126, 177559, 145699, 179036, 107260, 152245]::int[], ARRAY[298014, 294454, 266408, 241033, 202386, 226723, 276719, 282659, 235402, 227524, 291489, 244458, 288772, 273672, 241747, 277349, 211544, 233495, 262609, 201491, 260602, 280957, 237904, 277678, 260803, 223533, 223788, 286924, 237700, 241769, 294828, 207178, 221186, 233802]::int[], ARRAY[323856, 394365, 337800, 342968, 399404, 352036, 344631, 375575, 377350, 352207, 311594, 303210]::int[], ARRAY[474283, 427073, 467564, 402813, 460238, 436942, 469746, 439137, 487901, 446642, 467629, 455510, 486290, 431877, 452769, 475335, 442581, 493948, 402171, 480492, 479056, 491162, 428183, 472169, 498687, 470532, 410377, 429164, 489303, 407828, 493839, 496050, 481026, 429772, 491708]::int[], '2024-09-29 04:53:00 UTC', '2024-10-25 19:01:00 UTC', '2024-09-01 09:56:00 UTC'),
+('nicola.6xlfptmc@example.org', '闫幻紫', 'male','2019-12-20 05:05:00 UTC', '2024-10-16 21:05:00 UTC', '2024-09-07 12:50:00 UTC', ARRAY[110046]::int[], ARRAY[244167, 253191, 279676, 203253, 215909, 241346, 259663, 226297, 231368, 273447, 254813, 252596, 218611, 229489, 230341, 266747, 273635, 291111, 216572, 299041, 250646, 244245, 222795, 212599, 217595, 268152, 240611, 210642]::int[], ARRAY[365186, 308544, 362941, 327058, 396920, 376792, 398571, 324242, 397847, 359353, 368827, 300454, 300631, 361794, 357318, 374000, 348942, 321606, 385692, 356517, 346473, 391317, 396340, 367926, 325316, 397162, 341403, 362936, 320910, 361720]::int[], ARRAY[477383, 426041, 438973, 481842, 440452, 474268, 495001, 446827, 471457, 442749, 446312]::int[], '2024-09-21 13:36:00 UTC', '2024-10-26 01:53:00 UTC', '2024-10-01 11:32:00 UTC'),
+('stuart.bybvv3ux@example.net', '姚杰雄', 'female','2020-12-03 10:01:00 UTC', '2024-10-16 00:50:00 UTC', '2024-09-08 14:39:00 UTC', ARRAY[192053, 106151, 161980, 139423, 148554, 170559, 183536, 106668, 106673, 119437, 161264, 131177, 115646, 135281, 152930, 184069, 106425, 101616, 156885, 169676, 182761, 170326, 146001, 153271, 151020, 159972, 117336, 133638, 143364, 162270, 184533, 171843, 132122, 193677, 176933, 139140, 189791, 147296, 190189, 143169, 100368, 156797, 158606, 188793, 166401, 153473, 198864]::int[], ARRAY[235218, 201373, 296230, 266363, 270524, 243067, 228103, 221003, 221219, 243957, 283321, 203322, 270408, 299961, 273275, 291865, 253321, 211836, 235238, 273479, 250100, 286848, 265209, 277015, 233541, 250204, 252008, 218869, 253877, 282935, 238984, 234433, 296342, 260209, 282247, 239124, 206440, 253186, 224052, 233011, 246309]::int[], ARRAY[320673, 361154, 343484, 325173, 300800, 364532, 329913, 390066, 340265, 335678, 304544, 355074, 396938, 394847, 381233, 342408, 309345, 380713, 352244, 318225, 391460, 361185, 396828, 329579, 340208, 312830, 326851, 383837, 389440, 353277, 312135, 396060, 309200, 331339, 366490, 390384, 385652, 371209, 327118, 302035, 336791, 330236, 325606, 341728, 330164, 318312, 353556, 301822]::int[], ARRAY[433458, 428453, 467855, 485669, 451555, 454285, 462291, 456658, 425506]::int[], '2024-10-01 04:07:00 UTC', '2024-10-28 20:41:00 UTC', '2024-10-29 13:43:00 UTC'),
+('marcelo.994zsads@example.org', '陈艳姗', 'male','2024-05-05 08:10:00 UTC', '2024-10-26 19:20:00 UTC', '2024-10-30 23:02:00 UTC', ARRAY[101526, 104149, 142244, 141036, 143852, 111346, 120762, 151400, 104711, 131878, 153849, 138467, 160190, 139135, 118910, 194238, 191222, 184623, 160422, 138962]::int[], ARRAY[272467, 294851, 229219, 280180, 258776, 278241, 272287, 272477, 253329, 200580, 236483, 203762, 274911]::int[], ARRAY[390313, 388525, 388804, 389429, 371972, 318556, 322409, 331875, 352994, 332489, 373674, 309571, 398591, 394374, 367163, 349904, 325713, 393696, 377849, 326345, 369141, 308205, 349857, 375897, 391295, 312630, 323542, 377027, 395928, 347764, 328546, 332516, 364328, 342936, 306742, 354192, 391314, 371486, 379022, 321023]::int[], ARRAY[479939, 460240, 429899, 478132, 424633, 486361, 427844, 445910, 489504, 461245, 455534, 472037, 435943, 412887]::int[], '2024-10-02 01:08:00 UTC', '2024-10-27 15:26:00 UTC', '2024-09-14 13:29:00 UTC'),
+('buster.t8g3lvs4@example.org', '马水莹', 'female','2020-02-17 19:49:00 UTC', '2024-10-04 21:24:00 UTC', '2024-09-03 05:13:00 UTC', ARRAY[172709, 128224, 183462, 112450, 115571]::int[], ARRAY[269620, 261488]::int[], ARRAY[316383, 363463, 335913, 337197, 338499, 366844, 340460, 373938, 300267, 398611, 305992, 306505, 365772, 321231, 344102, 364359, 349284, 327532, 387733, 318955, 313564, 361741, 304349, 381583, 381776, 379184, 327223, 301423, 332318, 370741, 376140, 397857, 374409, 328719, 311712, 314478]::int[], ARRAY[451797, 422341, 417245, 466728, 494231, 469807, 437588, 483900, 491166, 439874, 472358, 490554, 420904, 408069, 490805, 468287, 467289, 434265, 462945, 499566, 421080, 469841, 428860, 437361, 427818]::int[], '2024-10-04 19:51:00 UTC', '2024-10-30 02:29:00 UTC', '2024-09-11 17:43:00 UTC'),
+('isabelle.3lz26kt1@example.org', '萧德石', 'female','2020-01-30 17:18:00 UTC', '2024-10-29 15:12:00 UTC', '2024-10-08 23:41:00 UTC', ARRAY[101562, 193775, 160777, 164840, 109946, 167255, 187374, 199360, 177542]::int[], ARRAY[202635, 229279, 253616, 215183, 261581, 211919, 260138, 275537, 257098, 227700, 219425, 266846, 263029, 293670, 263574, 244348, 222578, 200269, 290165, 203308, 277793, 256671, 276550, 216917, 206833, 201874, 296061, 253531, 200856, 248831, 206630, 221469, 231308, 275891, 243105, 225101, 207101, 273294, 270115, 240056, 230079, 273212]::int[], ARRAY[383209, 389412, 351293, 375683, 319373, 397708, 386656, 394487, 367280, 393927, 300567, 362149, 333727, 303352, 378711, 307476, 340258, 376080, 320344, 300654, 376673, 362717, 344896, 314097, 347241, 311862, 377739, 309720, 367800, 336675, 333597, 338036, 305088, 314806, 361582, 343171, 355848, 332166, 364398, 333112, 308049, 301995]::int[], ARRAY[461787, 490492, 418496, 420504, 440121, 472749, 478031, 409053, 433140, 425618, 442119, 497848, 429422, 471624, 402207, 402723, 446898, 428018, 450082, 461063, 491693, 496501, 416732, 462397, 417163]::int[], '2024-10-15 23:47:00 UTC', '2024-10-30 12:41:00 UTC', '2024-10-29 13:08:00 UTC'),
+('malika.yzayefsc@example.com', '林冠宇', 'male','2021-02-16 05:03:00 UTC', '2024-10-16 17:35:00 UTC', '2024-09-10 02:06:00 UTC', ARRAY[119784, 188836, 138839, 123569, 160166, 193294, 122499, 144865, 112170, 168323, 157969, 161162, 150951, 138753, 176906]::int[], ARRAY[236330, 212730, 225764, 292844, 258314, 215437, 225824, 247949, 260866, 283191, 205621, 216601, 299826, 253911, 279756, 279093, 214923, 252272, 269469, 293569, 279561, 238527, 229081, 236249]::int[], ARRAY[384059, 357478, 384694, 313886, 325253, 366381, 391825, 336266, 303895, 321708, 306946, 337046, 398222, 390042, 337636, 392108, 377129, 321544, 377421, 383004, 398895, 353387, 351303]::int[], ARRAY[440958, 445531, 483220, 447198, 454516, 473342, 434420, 404233, 472515, 415761, 404630, 404644, 439458, 443437, 450673, 473450, 496968, 406142, 482640, 461990, 490681, 450793, 433000, 406517, 487386, 460825, 482805, 487462, 436642, 408958, 406490, 467170, 434114, 423806, 458641, 405167, 428364, 493271, 438969, 486924, 427817, 442817, 459590, 493754, 410777, 402084, 494276, 465205]::int[], '2024-09-22 13:28:00 UTC', '2024-10-18 23:15:00 UTC', '2024-09-17 10:32:00 UTC'),
+('stefan.n9fuukrt@example.net', '彭浩诚', 'male','2021-08-27 13:09:00 UTC', '2024-10-29 01:22:00 UTC', '2024-10-30 04:33:00 UTC', ARRAY[113955, 126703, 190962, 153088, 178392, 179121, 123110, 162475, 140689, 118611, 128644, 163336, 121776, 112557, 170221, 113803, 110916, 119247, 174444, 166247, 137812, 169601]::int[], ARRAY[254639, 287395, 207199, 224765, 219946, 271242, 257262, 228591, 294264, 221009, 221554, 204769, 200292, 260108, 248703, 268501, 269752, 259220, 209649, 258258, 201053, 266482, 261535, 242103, 268128, 259662, 280444, 283823, 227303, 260761, 210217, 218534, 220314, 246103, 212278, 214349, 216446, 258716, 292922]::int[], ARRAY[383418, 325001, 310265, 389193, 351387, 364520, 385552, 367608, 305556, 308511, 365215, 353983, 334059, 331076, 394224, 372088, 312887, 359979, 335490, 369345, 319729, 351093, 393508, 358577, 372793]::int[], ARRAY[439439, 498978, 471397, 442977, 433558, 485335, 498051, 450568, 417068, 481780, 419676, 492107, 411247, 430831, 433832, 435392, 478998, 423518, 438303, 470359, 434473, 407582, 482247, 401960, 481020, 408917, 443246, 461762, 482425, 483281, 490569, 485556, 428381, 452406, 475613, 497111, 422829, 432669, 467399, 458464, 431422]::int[], '2024-09-23 18:45:00 UTC', '2024-10-25 06:33:00 UTC', '2024-08-08 14:53:00 UTC'),
+('nikki.pm73exdz@example.net', '蒋翰颜', 'female','2020-07-31 03:39:00 UTC', '2024-10-12 18:18:00 UTC', '2024-09-04 08:07:00 UTC', ARRAY[133146, 147474, 173188, 108429, 160595, 128169, 170955, 110744, 182159, 105331, 117134, 197407, 188661, 174547, 125319, 144901, 124159, 130221, 160499, 158162, 159959, 110036, 115864, 117846, 128326, 182064, 163573, 177556, 170182, 182954, 196810, 180309, 140851, 170431, 149040, 162177, 179503, 184105, 155854]::int[], ARRAY[209698, 293772, 264229, 224018, 274350, 234455, 257624, 260207, 279714, 228294, 224111, 296046, 212044, 248118, 297850, 251785, 259584, 261307, 285575, 228298, 292135, 275009, 215488, 290013, 219383, 289590, 218760]::int[], ARRAY[340827, 377667, 302316, 384217, 394182, 360936, 399258, 353517, 378865, 397286, 371608, 339030, 396305, 396045, 366941, 315621, 308037, 314958, 333122, 382494, 320399, 325807, 395474, 346367, 349781, 399634, 350074, 309910, 301062, 350261]::int[], ARRAY[411201, 480228, 455094, 469380, 437493, 462092, 422644, 473506, 413621]::int[], '2024-10-22 16:18:00 UTC', '2024-10-24 18:41:00 UTC', '2024-09-07 12:11:00 UTC'),
+('josefa.342ibo3z@example.net', '夏德君', 'male','2022-11-09 10:41:00 UTC', '2024-10-30 02:51:00 UTC', '2024-08-10 12:13:00 UTC', ARRAY[108294, 123954, 139123, 144691, 114666, 139270, 198733, 138160, 116720, 159304, 162590, 192875, 130064, 163173]::int[], ARRAY[225671, 242021, 276943, 225437, 261419, 254621, 272507, 289591, 235278, 203179, 255962, 262669, 211084, 294076, 259644, 273889, 266343, 259475, 205494, 296798, 242501, 243780, 238032, 238442, 294379, 213025, 223583, 256385, 274327, 274767, 287180, 234528, 252847, 203389, 244255, 201857, 295448, 248651, 219239, 213113, 211799, 247221, 212165, 294136]::int[], ARRAY[343538, 353574, 343923, 317512, 323697, 334136, 394019, 367037, 324356, 331457, 308045, 311371, 300063, 363506, 305528, 360634, 356811, 311563, 319224, 312418, 378246, 305324, 374494, 356709, 318120, 383946, 318381, 302570, 366200, 353365, 321503, 342258]::int[], ARRAY[413176, 447618, 465084, 412978, 415886, 473204, 495731, 492354, 449537, 453481, 443103, 477118]::int[], '2024-09-22 21:23:00 UTC', '2024-10-28 10:29:00 UTC', '2024-10-20 07:57:00 UTC'),
+('jovany.o6oguhrk@example.net', '雷远鸿', 'female','2020-12-23 17:18:00 UTC', '2024-10-27 23:24:00 UTC', '2024-09-02 13:24:00 UTC', ARRAY[127422, 182283, 175737, 150734, 124055, 124224, 107682, 116641, 181586, 132486, 155367, 186636, 162253, 186854, 128779, 160645, 145389, 153205, 113808, 149087, 197365, 118672, 152349, 153603, 104718, 152796]::int[], ARRAY[237717, 275132, 297381, 204849, 209924, 213483, 257575, 235405, 283724, 236434, 209669, 287706, 202694, 226291, 249833, 281208, 218057, 287057, 285962, 237137, 263517, 246577, 202532, 283247, 273520, 248272]::int[], ARRAY[397972, 328843, 373089, 314464, 340547, 391549, 381325]::int[], ARRAY[468521, 402789, 411140, 410982, 462420, 431759, 430578, 435691, 427051, 441445, 482430, 443680, 437210, 443392, 407815, 497317, 433575, 450273, 449387, 440791, 436591, 405243, 441173, 425979, 486997, 401008, 473160, 485526, 415454, 443468, 493413, 401339, 463329, 432180, 443520, 417460, 448537, 480916]::int[], '2024-10-26 02:11:00 UTC', '2024-10-25 18:55:00 UTC', '2024-09-29 15:21:00 UTC'),
+('elise.szzd8xoh@example.org', '付德春', 'unknown','2020-06-02 18:12:00 UTC', '2024-10-28 09:52:00 UTC', '2024-10-18 03:08:00 UTC', ARRAY[198908, 152123, 151886, 191697, 130313, 110146, 134007, 177964, 179460, 163638, 122071, 132284, 187157, 194741, 165205, 140243, 179705, 190731, 178558, 148632, 170791, 174862, 178741, 113218, 146977, 140954]::int[], ARRAY[295849, 245155, 258130, 215150, 229332, 213579, 230357, 234210, 294320, 293078, 236106, 252684, 233143, 218040, 254465, 224064, 207221, 285772, 276408, 203460, 278634, 209647, 264158, 280056, 235244, 299568, 268173, 238661, 228233, 230549, 231805]::int[], ARRAY[349759]::int[], ARRAY[472064, 460471, 470041, 447258, 406467, 455120, 468935, 454229, 447801, 479579, 458916, 480406, 482006, 424355, 409164, 480448, 416403, 468663, 437639, 446446, 448032, 496002, 422318, 450013, 416392, 410973, 430266, 453320, 423267, 437155, 421708, 461001, 456526, 463671, 403891, 451335, 431732, 485634]::int[], '2024-09-24 17:47:00 UTC', '2024-10-24 18:54:00 UTC', '2024-10-06 18:30:00 UTC'),
+('joesph.1mxg3w0y@example.com', '冯蕾雅', 'male','2022-10-06 21:08:00 UTC', '2024-10-04 19:38:00 UTC', '2024-09-24 00:36:00 UTC', ARRAY[162573, 175510, 184103, 145554, 181403, 152914, 186063, 127960, 137800, 125982, 181069, 184219, 127766, 196339, 129507, 107492, 195576, 158361, 100828, 114255, 199451, 107555, 171244, 163172, 125196, 189980, 190256, 104430, 132964, 181916, 198156, 101559, 181245, 128543, 132263, 163356, 194245, 129591, 182613, 138800, 115030]::int[], ARRAY[269728, 285377, 286276, 226311, 284585, 216129, 282982, 276083, 288426, 230092, 295190, 234762, 220466, 286852, 292255, 217624, 234140, 219236, 225457, 298253, 259259, 286327, 219082, 218067, 292079, 234906, 205932, 208305]::int[], ARRAY[373725, 381028, 375474]::int[], ARRAY[414113, 481663, 458078, 479345]::int[], '2024-10-24 11:44:00 UTC', '2024-10-18 17:34:00 UTC', '2024-10-03 15:00:00 UTC'),
+('francesco.lhxnnh5l@example.com', '方杰奥', 'unknown','2024-10-14 03:23:00 UTC', '2024-10-13 17:24:00 UTC', '2024-09-27 14:43:00 UTC', ARRAY[121277, 159907, 195006, 103364, 192841, 196278, 130205, 106160, 142455, 194999, 157425, 141561, 162727, 137679, 172764, 188614, 114426, 117817, 154566, 130531, 132853, 142606, 161705, 191705, 131747, 153071, 117157, 159533, 154729, 125767, 199364, 101382, 171756]::int[], ARRAY[251479, 225563, 221411, 201767, 288254, 279657, 211037, 265607, 264442, 267709, 213601, 205017, 202925, 283628, 271224, 289171, 226389, 206792, 226601, 206922, 278175, 259638, 224396, 221865, 204564, 232670, 283134, 239321, 210817, 214934, 260506, 279500, 255306, 261645, 277468, 278542, 210117]::int[], ARRAY[373543, 336795, 324330, 359708, 352218, 363119, 362758, 338922, 341280, 379120, 309456, 333977, 360490, 353912, 345106, 398608, 326737, 391113, 330609]::int[], ARRAY[485629, 449711, 431249, 459790, 479994, 450751, 424364, 461432, 433208, 411086, 410220, 407391, 454315, 437897, 426181, 474518, 438137, 464733, 414322, 434407]::int[], '2024-10-27 12:17:00 UTC', '2024-10-26 08:28:00 UTC', '2024-08-16 06:49:00 UTC'),
+('ben.8s0b39ax@example.com', '尹珍美', 'female','2022-08-21 03:32:00 UTC', '2024-10-17 14:46:00 UTC', '2024-08-13 12:18:00 UTC', ARRAY[128821, 164598, 190981, 103514, 160010, 192437, 148300, 164294, 191823, 156862, 193417, 173574, 102023, 129293, 139281, 107862, 178724, 168584, 159239, 151881, 121465, 129237, 184871, 115949, 162850]::int[], ARRAY[213879, 263599, 279779, 202902, 299409, 285950, 293044, 266981, 217269, 233451, 235838, 233745, 208708, 233558, 207854, 235871, 293315, 248428, 235998, 226730, 227198, 296362, 232784, 243407, 267482, 242809]::int[], ARRAY[354327, 347226, 358209, 380071, 351463, 371798, 352961, 346907, 387279, 381553, 301195, 363657, 359776, 306294, 342099, 390372, 371087, 327046, 344419, 310611, 356415, 343833, 333146, 341172, 314998, 389193, 326853, 362371, 348668, 389718, 307900, 342985, 331868, 387587, 353609, 314846, 331530, 393399, 381031, 313721, 367931, 354877]::int[], ARRAY[427047, 405618]::int[], '2024-09-21 23:04:00 UTC', '2024-10-24 20:05:00 UTC', '2024-08-28 19:57:00 UTC'),
+('lila.31xyvb94@example.com', '孟鼎翎', 'unknown','2019-12-15 17:44:00 UTC', '2024-10-27 01:01:00 UTC', '2024-09-19 12:49:00 UTC', ARRAY[118591, 172807, 154152, 153855, 152069, 126355, 148714, 186797, 159052, 164132, 192812, 148860, 130468, 102396]::int[], ARRAY[284499, 260092, 203181, 203035, 233669, 220210, 279819, 256014, 279737, 249403, 243331]::int[], ARRAY[327741, 379802, 386435, 358538, 370654, 393363, 362080, 363620, 312769, 383570]::int[], ARRAY[470041, 416797, 479244, 417759, 410014, 412837, 476074, 428592, 456381, 419575, 490835, 423790, 433603, 410032, 476962, 446550, 429236, 455815, 423501, 492283, 457179, 455300, 472954, 466073, 484199, 425243, 468860, 454415, 429579, 476123, 483986, 453579, 468868, 426279, 484522, 482614, 494663, 415004, 402359, 476062, 420434, 474215, 486932, 497604, 473620, 422061]::int[], '2024-10-26 22:24:00 UTC', '2024-10-18 04:51:00 UTC', '2024-09-26 14:03:00 UTC'),
+('jeffrey.xhnsfz0j@example.org', '龚清泉', 'male','2024-06-14 06:06:00 UTC', '2024-10-19 22:13:00 UTC', '2024-08-12 18:10:00 UTC', ARRAY[179200, 164163, 108209, 137012, 158242, 161106, 112547, 104983, 150811, 134705, 156357, 195980, 124146, 133840, 188106, 159090, 154613, 163206, 103596, 148412, 133707, 195863]::int[], ARRAY[231043, 260872, 280150, 201791, 277858, 220680, 262688, 255175, 200992, 262628, 287005, 272183, 290674, 264984, 214520, 230487, 270013, 267743, 252200, 288837, 211362]::int[], ARRAY[323533, 351477, 326668]::int[], ARRAY[476059, 404624, 416495, 489647, 428989, 477426, 402513, 413502, 441326, 448894, 454300, 400861, 456996, 403901, 440519, 455628, 487348, 468440, 471243, 470605, 490441, 472407, 454058, 497781, 421223, 404318]::int[], '2024-10-14 20:25:00 UTC', '2024-10-19 03:17:00 UTC', '2024-08-12 14:12:00 UTC'),
+('kobe.0uhr7tkw@example.org', '钟蕾碧', 'male','2020-09-28 19:27:00 UTC', '2024-10-22 04:23:00 UTC', '2024-08-25 02:06:00 UTC', ARRAY[151580, 102950, 176153, 172635, 174449, 128975, 145202, 150370, 193216, 192091, 180778, 135411, 174194, 121024, 108407, 153251, 168908, 179782, 121871, 138843, 157678, 183675, 160640, 176825, 111763, 159811, 151324, 145857, 100260, 169126, 137759, 180654, 189618, 136654, 134473, 114820, 196933, 156314, 110574, 171270, 193643, 158823, 167635, 136849, 152752, 114254, 153137, 195733, 102414]::int[], ARRAY[219816, 225707, 270472, 251394, 219322, 220278, 206158, 262029, 286808, 248588, 264184, 235584, 250842, 229416, 280770, 209516, 218923, 214378, 260600, 270789, 247108, 250304, 225303, 229367, 288702, 201069, 274378, 236582, 200284, 290565, 249719, 207998, 298450, 271122, 208659, 205050]::int[], ARRAY[398078, 301013, 329459, 362820, 320093, 330966, 374159, 377525, 395195, 360609, 360200, 346337, 396683, 399507, 394473, 373682, 359934, 362104, 304117, 326839, 349573, 308291, 364092, 392937, 386311, 339213, 384842, 360726, 350254, 351986, 302546, 323499, 325165, 355803, 325229, 381784, 340108, 367671, 333780]::int[], ARRAY[407280, 489854, 405999, 405615, 481660, 478239, 455676, 474431, 413660, 420910, 472466, 490670, 405392, 475742, 482173, 479017, 492394, 448763, 496745, 459024, 412022, 486375, 490361, 472391, 456843, 445544, 448927, 486643, 467205, 427649, 483182, 490110, 476867, 476130, 408262, 474265, 453070, 488673, 475610, 455005]::int[], '2024-10-01 05:55:00 UTC', '2024-10-21 07:36:00 UTC', '2024-08-27 06:59:00 UTC'),
+('carol.pl6k8svu@example.org', '孔远乔', 'male','2020-02-10 18:52:00 UTC', '2024-10-03 05:40:00 UTC', '2024-10-30 12:36:00 UTC', ARRAY[171125, 135908, 105778, 171604, 164945, 156574, 151548, 175008, 185994, 110530, 150603, 179590, 105681, 168727, 140434, 149994, 148843, 192421, 174531, 146249, 152985, 180254, 114841, 192205, 196599, 163517, 129646, 140509, 140298, 159669, 194110, 145994, 185726]::int[], ARRAY[282961, 230799, 299316, 261073, 220849, 235237, 291220, 253109, 230266, 257912, 213161, 256793, 206700, 209576, 201544, 291638, 296328]::int[], ARRAY[306072, 393213, 393952, 375337, 384112, 359712, 324326, 326896, 305548, 329483, 318121, 338590]::int[], ARRAY[438579]::int[], '2024-09-20 13:24:00 UTC', '2024-10-25 09:41:00 UTC', '2024-10-19 08:49:00 UTC'),
+('kylee.anncbsph@example.net', '白棋博', 'female','2020-07-03 13:53:00 UTC', '2024-10-10 00:04:00 UTC', '2024-09-23 03:27:00 UTC', ARRAY[137193, 165685, 154213, 152060, 117496, 113732, 150764, 181122, 112826, 150391, 128912, 179088, 133108, 126945, 140896, 151104, 129036, 127664, 114077, 103323, 117296, 159026, 105888, 173544, 194390, 193563, 129590, 143429, 107491, 119837]::int[], ARRAY[219807, 262348, 230878, 276312, 229002, 221548, 228407, 298430, 264892, 268062, 281607, 249866, 262311, 282523, 286692, 259481, 201767, 297576, 208556, 240588, 250550, 205167, 247780, 288598, 261903, 255789, 254225, 230636, 203233, 274216, 228506, 256635, 216682, 200191, 224818, 225240, 228362, 283974, 271738, 250164, 234606, 258375, 283835, 256086, 273910, 280727]::int[], ARRAY[359437, 377994, 378244, 367406, 373792, 308149, 307750, 353151, 379482, 345254, 367967, 359331, 304116, 391290, 302855, 349382, 397552, 300960, 390761, 317178]::int[], ARRAY[487917, 499332, 488257, 422914, 453100, 489975]::int[], '2024-09-30 22:58:00 UTC', '2024-10-28 11:48:00 UTC', '2024-08-27 02:51:00 UTC'),
+('merritt.vwifdpeb@example.org', '张昌翔', 'female','2021-10-10 15:57:00 UTC', '2024-10-05 12:43:00 UTC', '2024-09-12 20:58:00 UTC', ARRAY[118153, 127950, 110004, 112467, 134198, 184405, 191774, 146504, 145540, 111584, 111645, 197504, 172347, 117819, 181907, 166106, 104395, 195591, 190082]::int[], ARRAY[226609, 283107, 284687, 252783, 259474, 246568]::int[], ARRAY[310163, 390579, 336181, 393644, 387702, 392895, 363510, 399195, 369609, 386043, 384941, 315068, 390734, 351258, 311275, 362144, 359505, 375498, 383659, 357686]::int[], ARRAY[481880, 444726, 416890, 441687, 408679, 411168, 455617, 449283, 404678, 482145, 481429]::int[], '2024-09-17 01:34:00 UTC', '2024-10-27 02:19:00 UTC', '2024-08-13 14:34:00 UTC'),
+('kieran.5avueg6j@example.com', '张鼎风', 'female','2021-06-05 19:48:00 UTC', '2024-10-04 19:51:00 UTC', '2024-08-14 09:42:00 UTC', ARRAY[168273, 110362, 168856, 193538, 171681, 108846, 124988, 114285, 163980, 194544, 129745, 129169, 105930, 137601, 131377]::int[], ARRAY[207371, 253644, 206968, 255670, 213672, 248586, 254481, 242727, 289410, 237717, 296735, 206505, 209235, 299436, 260670, 207907, 217260, 242616, 222524, 252152, 273205, 224035, 242194, 202833, 280728, 208105, 273807, 218243, 278295, 214912, 255301, 287512, 239831, 282458, 201130, 252284, 209640, 289715]::int[], ARRAY[376086, 350311, 303525, 309866, 382118]::int[], ARRAY[439700, 422345, 457851, 430857, 417610]::int[], '2024-10-14 05:02:00 UTC', '2024-10-22 10:16:00 UTC', '2024-08-04 05:30:00 UTC'),
+('adrien.u0ay9hbq@example.org', '程馨梨', 'unknown','2023-01-18 05:11:00 UTC', '2024-10-04 20:08:00 UTC', '2024-09-04 11:03:00 UTC', ARRAY[105999, 132941, 119159, 138912, 141692, 190549, 123656, 112298, 180461, 125863, 121386, 187180, 132878, 139815, 100078, 182060, 187856, 144248, 108018, 131789, 115210, 162477]::int[], ARRAY[297199, 235332, 227818, 275392, 285476, 270692, 299669, 215423, 295451, 297961, 298446, 282893, 204217, 280072, 213148, 233033, 223768, 263993, 223316, 254434, 238997]::int[], ARRAY[383037, 314873, 309376, 339337, 391033, 385593, 385322, 364341, 315093, 313133, 392017, 350636, 373315, 385480, 366715, 320566, 309892, 395493, 339070, 352772]::int[], ARRAY[415140, 411121, 410597, 420407, 488342, 443570, 425127, 447434, 491209, 423561, 488435, 409337, 419866, 428986, 410154, 423676, 475606, 411114, 458644, 444456, 450911, 440237, 454941, 469940, 456712, 493580, 412070, 428072, 419514]::int[], '2024-10-02 08:44:00 UTC', '2024-10-23 15:43:00 UTC', '2024-08-29 16:43:00 UTC'),
+('trinity.xw10qu2p@example.com', '郑雨嘉', 'female','2024-07-25 23:27:00 UTC', '2024-10-09 00:19:00 UTC', '2024-10-16 23:27:00 UTC', ARRAY[188836, 146766, 167598, 102270, 193463, 108885, 100445, 159214, 137873, 195109, 102331, 153946, 132021, 122850, 191109, 104171, 180496, 134893, 156371, 193657]::int[], ARRAY[207213, 285267]::int[], ARRAY[304735, 318348, 354247, 376686, 325393, 309646, 314028, 378053, 379690, 349264, 316250, 373115, 301637, 336846, 364283, 303011, 329955, 375033, 349562, 315959, 315012, 392412, 335412, 381750, 356713, 319739, 384321, 334182, 323092, 387607, 330277]::int[], ARRAY[489000, 482246, 442606, 448325, 461300, 454110, 457371, 441341, 456134, 461037, 451151, 485551, 429179, 408692, 459938, 493417, 402617, 406713, 479173, 459597, 456093, 448226, 443518, 421225, 436164, 406726, 401522, 481118, 415999, 431138, 406512, 422678, 430800, 464867, 499139, 483676]::int[], '2024-09-27 23:31:00 UTC', '2024-10-23 21:25:00 UTC', '2024-09-10 21:22:00 UTC'),
+('therese.3f5djbjb@example.org', '熊秀永', 'male','2024-07-27 18:43:00 UTC', '2024-10-26 04:05:00 UTC', '2024-10-14 07:19:00 UTC', ARRAY[158983, 191956, 101960, 115084, 189730, 141996, 174374, 188131, 177671, 167056, 134046, 187177, 190159, 138299, 112503, 127042, 141170, 122600, 148478, 159941, 166115, 160091, 190166, 103969, 113050, 122490, 130101]::int[], ARRAY[222417, 289855, 282490, 292677, 248557, 298667, 219293, 291208, 291943, 286875, 258944, 273340, 201347, 243529, 289456, 292990, 235321, 273354, 249525, 227527, 200994, 236016, 209389, 203747, 205253, 211127, 279448, 200420, 204548, 251810, 240262, 225699, 250380, 284423, 296075, 227921, 256972, 274009, 210306, 203066, 243670, 243877, 254733, 225894]::int[], ARRAY[336755, 352467, 300381, 348830, 361200, 319915, 397495, 307321, 325006, 338687, 332736, 367231, 378478, 343086, 352569, 357239, 304098, 350031, 308162, 385739, 316798, 372414, 350753, 347902]::int[], ARRAY[446698, 413691, 458345, 492323, 418398, 420322, 405902, 400234, 490314, 442286, 413483, 480176, 449919, 482434, 424981, 427733, 479870, 466969, 446224, 412473, 487919, 475685, 459702, 423967, 495434, 437658, 463354, 435705, 491837, 418474, 491860, 444205, 416570, 479738, 413993, 418857, 421896, 456345, 497094, 461940, 475110, 476631, 421391, 458391, 464604, 498452, 418916, 451790, 436798]::int[], '2024-10-08 13:43:00 UTC', '2024-10-21 19:14:00 UTC', '2024-08-12 10:38:00 UTC'),
+('tobin.xg7ffvcf@example.com', '郝雁卉', 'male','2021-08-22 00:16:00 UTC', '2024-10-13 07:55:00 UTC', '2024-10-25 12:42:00 UTC', ARRAY[119465, 180765, 120040, 131946, 110691, 142350, 191321, 168472, 164505, 131705, 185976, 191848, 107433, 110643, 153688, 136405, 100278]::int[], ARRAY[215969, 216445, 284162, 220077, 268816, 201608, 236097, 250329, 264921, 294833, 278895, 278608, 233749, 203119, 214731, 206238, 241924, 207421, 233710, 218088, 217223, 271189, 252597, 239870, 221261]::int[], ARRAY[379842, 336629, 350518, 381986, 357592, 354661, 366951, 347489, 314888, 342583, 389656, 301151, 364800, 377279, 309392, 328194, 324577, 326755, 358965, 324213, 333740, 398519, 328265, 341515, 346255, 309708, 336829, 393618, 353597, 336870, 310448, 303557, 356357, 373133, 300771, 381519]::int[], ARRAY[438292, 424401, 454087, 475234, 493883, 470128, 474203, 469024, 414228]::int[], '2024-09-26 13:25:00 UTC', '2024-10-19 13:05:00 UTC', '2024-08-19 17:36:00 UTC'),
+('lia.x5qg7620@example.org', '武曲漾', 'unknown','2024-07-29 16:49:00 UTC', '2024-10-10 22:05:00 UTC', '2024-10-18 11:35:00 UTC', ARRAY[102378, 122214, 159538, 191493, 169973, 161058, 190282, 173827, 174112, 162910, 138900, 137586, 198488, 182330, 115147, 157321, 134162, 114358, 172183, 140248, 175451, 122604]::int[], ARRAY[254747, 290866, 265380, 290907, 229409, 279493, 281369, 272711, 288126, 239526, 285730, 218027]::int[], ARRAY[352850, 304648, 316079, 329016, 354193, 358472, 317856, 324517, 342050, 341269, 359844, 313469, 394958, 359813, 354936, 377710, 338588, 316630, 375932, 335676, 303344, 383069, 375162, 329224, 395694, 386930, 397848, 356482]::int[], ARRAY[494476, 469643, 487602, 429706, 452435, 466385, 471129, 433138, 428450, 434516, 476037, 463461, 473179, 449704, 429109, 438858, 415237, 468437, 478458, 469953, 439303, 470969, 469255, 453047, 407951, 456003, 426464]::int[], '2024-10-13 14:51:00 UTC', '2024-10-31 22:53:00 UTC', '2024-10-08 18:17:00 UTC'),
+('dorris.b7jy3hdt@example.org', '武泽君', 'female','2022-09-17 10:57:00 UTC', '2024-10-18 01:31:00 UTC', '2024-09-28 09:23:00 UTC', ARRAY[140684, 144706, 143103, 170545, 125397, 180274, 149735, 191158, 112109, 141173, 107741]::int[], ARRAY[246981, 249521, 261300, 298665, 270225, 291339, 251735, 290664]::int[], ARRAY[325005, 326020, 325508, 399391, 350431, 365935, 301251, 353706, 315078, 365178, 331539, 317427, 379526, 359233]::int[], ARRAY[453459, 452290, 414366, 430136, 456739, 417000, 456606, 432457, 430295, 475874, 421269, 479700, 462782, 458107, 484904, 435764, 441422, 431440, 423121, 488403, 456883, 478049, 456011, 411292, 495314, 435484, 426689, 479245, 466022, 414923, 459933]::int[], '2024-10-12 02:54:00 UTC', '2024-10-25 01:00:00 UTC', '2024-09-06 11:25:00 UTC'),
+('berniece.46wczenv@example.com', '刘向卉', 'male','2020-04-27 01:00:00 UTC', '2024-10-09 15:55:00 UTC', '2024-09-02 04:18:00 UTC', ARRAY[112350, 151665, 154469, 167178, 187378, 194257, 167769, 113349, 118269, 186471, 193294, 159866, 135720, 105191, 156036, 174507, 154095, 121476, 144180, 179668, 122134, 109982, 125062, 148388, 126623, 103809, 150655, 175485, 165258, 199678, 199412, 124312, 125682, 149886, 120123, 146245, 122860, 146374, 132838, 137270, 129017, 123905]::int[], ARRAY[286964, 275258, 221629, 253518, 254018, 292823, 243341, 225638, 264011, 246956, 290772, 212974, 257777, 226819, 237452, 266681]::int[], ARRAY[379582, 346671, 303764, 352164, 332147, 362650, 362750]::int[], ARRAY[484526, 435619, 473270, 448868, 410363, 468886, 401422, 435306, 434678, 429950, 417106, 483509, 466502]::int[], '2024-09-23 23:45:00 UTC', '2024-10-23 06:57:00 UTC', '2024-08-13 21:36:00 UTC'),
+('tremayne.fm2epsyb@example.net', '袁智涛', 'male','2021-05-06 20:07:00 UTC', '2024-10-27 20:30:00 UTC', '2024-10-09 04:28:00 UTC', ARRAY[163243, 172288, 130868, 197599, 140368, 132914, 199145, 178317, 107576, 134261, 133037, 154037, 198644, 199811, 160281, 190184, 169436, 167120, 119310, 198745, 197723, 175455, 119631, 197644, 157053, 141371, 178784, 186754, 186980, 148514, 147181, 183627, 140432, 194497, 118976, 164587, 186575, 167524, 179683, 128983, 137206, 149314]::int[], ARRAY[210915, 296656, 228343, 228258, 242053, 287623, 250276, 273321, 212862, 260487, 280673, 275142, 234846, 274276, 283521, 295654, 219851, 291063, 247982]::int[], ARRAY[380870, 375319, 365296, 348207, 350566, 314971, 396524, 368707, 356620, 320112, 386939]::int[], ARRAY[468909, 420824, 465004, 493505, 495169, 449553, 472808, 424421, 451377, 430724, 405165, 420412, 432917, 402735, 410996, 468642, 478288, 411512, 409473, 408562, 482110, 459085, 445612, 458675, 418325, 437672, 451068, 463122, 479631, 457780, 475418, 488936, 487757, 411676, 425399, 486410, 415779, 460025, 468470, 465945, 446034, 430897, 453876, 486365, 417725]::int[], '2024-10-23 08:39:00 UTC', '2024-10-24 16:02:00 UTC', '2024-08-25 22:59:00 UTC'),
+('darby.qiuimmtj@example.net', '梁黛亦', 'female','2023-06-17 21:05:00 UTC', '2024-10-10 08:04:00 UTC', '2024-10-25 17:25:00 UTC', ARRAY[189475, 188059, 167361, 138090, 110958, 156680, 135104, 158382, 186838, 105993, 115388, 151495]::int[], ARRAY[226466, 212624, 266190, 275495, 206346, 228816, 290772, 299156, 235895, 218878, 286079, 270170, 237696, 209846, 231172, 247525, 211061, 261626, 236022, 294891, 255678, 267367, 262122, 283927, 291456, 212214, 270573, 293694, 254692, 268929, 244841]::int[], ARRAY[399108, 364677, 300003, 308563, 367074, 312391, 378168, 341273, 386597, 376450, 304022, 320605, 343338, 339675, 319416, 302468, 302865, 344572, 328953]::int[], ARRAY[428339, 474409, 418053, 447653, 483496, 450736, 412308, 434730, 454417, 462503, 406400, 467073, 403350, 472500, 487594, 405023, 431605, 402398, 484291, 418988, 421261, 448315, 411070, 400332, 422847, 490637, 417943, 435661, 430096, 408180, 408980, 442767, 435337, 483485, 453807, 402857, 428232, 482651, 494676, 425176, 453817, 432695, 456764, 477390, 449730, 411755, 432957]::int[], '2024-10-02 21:48:00 UTC', '2024-10-18 14:58:00 UTC', '2024-09-15 16:03:00 UTC'),
+('alayna.8a9mqemo@example.org', '董紫芳', 'unknown','2023-05-12 16:46:00 UTC', '2024-10-23 12:31:00 UTC', '2024-08-14 17:35:00 UTC', ARRAY[193890, 119490, 137577, 126919, 185210, 107685, 181857, 178114, 130204, 128101, 119086, 195527, 167559, 195843, 114203]::int[], ARRAY[250756, 230973, 235136, 265958, 237805, 260392, 270316, 235115, 207980, 255018, 217184, 262872, 244491, 296179, 212262, 257866, 217837, 263901, 222902, 227132, 289652, 246403, 283954, 250412, 217795, 231176, 276529, 277036, 294005, 214445, 228755, 281369, 227981, 273346, 293300, 296304, 206572, 239027, 297210, 214846, 214802]::int[], ARRAY[315409, 383010, 315711, 331488, 368668, 317935, 302286, 368641, 369190, 330787, 321448, 366616, 321309, 389413, 308610, 371243, 363149, 332273, 301591]::int[], ARRAY[461110, 441350, 436300, 463655, 487488, 430811, 495823, 488020, 423024, 445691, 431571, 466769, 458098, 462070, 483541, 463968, 477649, 445175, 414212, 446449, 436917, 435602, 450648, 447729, 404681, 405092, 434627, 427482, 409783, 413960, 464503, 426046, 465415, 490523, 495014]::int[], '2024-10-06 16:58:00 UTC', '2024-10-21 15:48:00 UTC', '2024-10-19 09:26:00 UTC'),
+('antoinette.79iantzi@example.org', '段清云', 'unknown','2022-02-26 11:12:00 UTC', '2024-10-16 19:19:00 UTC', '2024-09-05 05:12:00 UTC', ARRAY[193680, 199263, 142253, 102962, 163284, 110317, 124586, 109998, 123735, 120446, 175597, 106410, 152572, 198274, 116541, 163894, 102363, 184254, 168919, 167822, 164197, 112098, 199678, 158716, 100684, 196639]::int[], ARRAY[227135, 299454, 266767, 217851, 264829, 217428, 220523, 213748, 217915, 299876, 280644, 242840, 268733, 247632, 225559, 213959, 292946, 273522, 208455, 264686, 223370, 260297, 293768, 227759, 204266, 274456, 226841, 293291, 295004, 217043, 220631, 287199, 252158, 237956, 252043, 286608]::int[], ARRAY[333218, 371077]::int[], ARRAY[415564, 498884, 484613, 494084, 478843, 403370, 459421, 416740, 477050, 472857, 403771, 446163, 450944, 457785, 461377, 401556, 401417, 419421, 449497, 482739, 423627, 413463, 416209, 428581, 456371, 403491, 482012, 447333]::int[], '2024-10-26 12:54:00 UTC', '2024-10-20 12:43:00 UTC', '2024-09-07 06:15:00 UTC'),
+('rosalind.d0rj2cwd@example.net', '田雨雪', 'unknown','2022-05-05 12:39:00 UTC', '2024-10-11 01:17:00 UTC', '2024-08-13 15:33:00 UTC', ARRAY[142133, 152760, 170345, 136090, 172522, 155612, 111123, 119778, 187755, 125187, 100384, 103523, 109450, 148246, 162853, 152828, 100020, 157565, 137486, 166845, 152696, 122288, 100629, 159558, 104525, 180680, 110713, 125644, 176661, 148763, 182697, 132842, 189443, 188618, 179118, 103431]::int[], ARRAY[283215, 222686, 276470, 245558, 270052]::int[], ARRAY[370059, 323914, 303960, 353933, 302458, 355974, 301749, 337514, 335016, 305434, 361950, 334325, 370517, 369914, 371681, 353920, 386572, 394689, 359233, 392229, 300614, 322207, 378287, 315701, 371770, 343039]::int[], ARRAY[438426, 466505, 423104, 446722, 457352, 452857, 489440, 493739, 416000, 477590, 466988, 448909, 473910]::int[], '2024-09-21 04:16:00 UTC', '2024-10-31 12:18:00 UTC', '2024-10-04 04:03:00 UTC'),
+('blair.vc200zkq@example.org', '任百川', 'male','2023-08-26 15:28:00 UTC', '2024-10-24 17:20:00 UTC', '2024-09-05 09:41:00 UTC', ARRAY[100855, 184935, 109400, 152630, 171641, 175402, 112353, 162273, 102710, 121708, 119743, 192459, 139860, 182926, 165279, 128980, 102416, 143033, 195915, 174782, 140926, 153922, 100569, 130181, 110778, 129213, 177024, 167404, 182752]::int[], ARRAY[263993, 295873, 215514, 247179]::int[], ARRAY[389176, 344574, 335737, 375594, 363204, 317929, 370000, 388435, 388478, 312038, 337249, 333229, 321540, 313223, 359806, 321524, 390136, 319897, 338135, 370517, 390748, 394067, 386408, 308056, 374617, 391633, 342525, 378826, 362427, 324298, 304268, 340868, 302759, 393278, 363475, 369410, 382178, 313670]::int[], ARRAY[421875, 481911, 497302, 447245, 462003, 496109, 491687, 406549, 425694, 441977, 475430, 410062, 475701, 482488, 400293, 440925, 446521, 445767, 433614, 453634, 428138, 488546, 455027, 475604, 487163, 468369, 418414, 405507, 494204, 447416, 435287, 463023, 457933, 489897, 453190, 486560, 416475, 447646, 485581, 413774, 472875]::int[], '2024-10-02 23:31:00 UTC', '2024-10-24 21:08:00 UTC', '2024-08-12 20:17:00 UTC'),
+('bret.52yzbf5v@example.org', '余瀚舟', 'unknown','2020-03-16 02:02:00 UTC', '2024-10-09 03:33:00 UTC', '2024-09-14 22:33:00 UTC', ARRAY[172652, 109171, 121642, 139398, 166691, 169673, 115267, 126631, 167150, 164035, 100874, 125574, 121320, 115827, 170006, 170348, 118950, 194877]::int[], ARRAY[281355, 241317, 275231, 232216, 260757, 207104, 233429, 231268, 296102, 201730, 212450, 290995, 271629, 258012, 206854, 275013, 289919, 227407, 294024, 273983, 259795, 207958, 243659, 203592, 280061, 233255, 297087, 200188, 212742, 206165, 219515, 283154, 295609, 273623, 245181, 268018, 207569, 238322, 252301, 218644, 291399, 264439, 250338, 267783, 254841, 285892]::int[], ARRAY[344625, 342615, 365699, 394124, 374987, 337593, 339349, 310903, 351592, 396489, 334339, 375282, 365280, 396232, 388103, 374525]::int[], ARRAY[494749, 483666, 403617, 467773, 478900, 447604, 472394, 427713, 476030, 484778, 483786, 416366, 485862, 489520, 491828, 462321]::int[], '2024-10-11 14:37:00 UTC', '2024-10-25 06:38:00 UTC', '2024-10-29 16:40:00 UTC'),
+('hosea.np04fg00@example.com', '汤鼎遥', 'male','2020-03-05 04:13:00 UTC', '2024-10-23 07:20:00 UTC', '2024-08-20 15:12:00 UTC', ARRAY[139830, 157152, 108308, 196195, 169552, 146311, 115909, 175876, 116162, 154219, 130913, 131860, 195485, 130804, 193694, 157297, 132342, 146063, 198079, 159124, 136714, 131541, 148726, 109552, 111982, 156945, 100892, 121856, 145967, 140832, 173564, 187178, 126411, 101196, 172548]::int[], ARRAY[259105, 211833, 294278, 233760, 248533, 231072, 276683]::int[], ARRAY[346635, 301008, 390298, 357206, 352356]::int[], ARRAY[467688, 431655, 442575, 459189, 410601, 419292, 427371, 488423, 490130, 416525, 492083, 456564, 407821, 403694, 426228, 400345, 438552, 432843, 490514, 472802, 432105, 423274, 425538, 429634, 418137, 470205, 482058, 403532, 403051, 426131, 490270, 459447, 408671, 445160, 459174, 452566, 406673, 423557]::int[], '2024-09-21 23:06:00 UTC', '2024-10-29 02:51:00 UTC', '2024-08-31 17:15:00 UTC'),
+('tyshawn.8pz0enl8@example.net', '孔昌毅', 'unknown','2024-06-09 00:38:00 UTC', '2024-10-03 22:13:00 UTC', '2024-08-12 14:38:00 UTC', ARRAY[157127, 112808, 178619, 162905, 186510, 150185, 156515, 114103, 120619, 154163, 123937, 161566, 133228, 145829, 144412, 139920, 127285, 176959, 174010, 117526, 179001, 105515, 185192, 194108, 107324, 173602, 190483, 188953, 196923, 135751, 111834, 142723, 166409]::int[], ARRAY[286272, 272925, 258891, 240916, 250791, 231824, 206923, 277141, 257709, 293037, 269254]::int[], ARRAY[395867, 356862, 381471, 361278, 399973, 373191, 367673, 356373, 328603, 317259, 349882, 390464, 365400, 350355, 312588, 338464, 381598, 364125, 302146, 348270]::int[], ARRAY[489787, 476411, 423740, 424455, 497929, 471081, 498752, 437522, 470146, 461973, 413374, 406768, 479352, 402528, 462622, 463704, 471282, 417382, 479004, 413143, 483313, 456746, 423706, 400611, 468653, 487732, 438256, 473091, 488414, 465044, 450147, 476659, 488630, 481310]::int[], '2024-10-04 20:23:00 UTC', '2024-10-30 17:16:00 UTC', '2024-10-26 11:08:00 UTC'),
+('lela.nclf8u26@example.com', '唐芳虹', 'unknown','2021-09-08 16:30:00 UTC', '2024-10-11 09:30:00 UTC', '2024-09-17 01:05:00 UTC', ARRAY[103192, 166688, 157371, 140539, 117174, 191236, 119764, 179274, 192284, 171336, 112526, 114178, 110541, 184146, 177526, 165554, 191124, 182990, 132742, 126594, 125896, 170504, 194911, 108287, 194818, 185825, 136159, 146134, 148331, 118186, 132877, 148920, 126132, 163360, 159013, 111357, 157180, 156593, 174131]::int[], ARRAY[225142, 212089, 228235, 282250, 259114, 243731, 229143, 236517, 281815, 254875, 289213, 212498, 201181, 269034, 202864, 286534, 247392, 238962, 226367, 291469, 297588, 246348, 247905, 247300, 285274, 251939, 247000, 253632, 206384, 268534, 230660, 253841, 224777, 274575, 291272, 241215, 208926, 239199, 254070, 241020, 275942]::int[], ARRAY[343690, 322433, 306185, 386348, 355031, 381099, 339273, 392728, 338410, 348762, 384856, 385960, 326792, 346892, 359771, 317456, 329112, 358534, 345094, 345576, 362282, 340297, 318024, 328024, 376952, 324684, 305578, 309682, 335909, 334959, 340077, 357270, 381423, 343844, 358246, 333503, 355347, 370869, 380945]::int[], ARRAY[436573, 481938, 474603, 483170, 422072, 411784, 424178, 434989, 409579, 496289, 484169, 401034, 498905, 409868, 488915, 409227, 425990, 474717, 471791, 442179, 489895, 477280, 448953, 466018, 407643, 487043, 402429, 478184, 436667, 442905, 415787, 471313, 449943, 480207, 434957, 495824, 492646, 490461, 435380, 400866, 452636]::int[], '2024-09-17 21:30:00 UTC', '2024-10-17 20:28:00 UTC', '2024-10-20 07:11:00 UTC'),
+('lorenzo.98tljyla@example.com', '史晓玫', 'female','2023-11-30 23:31:00 UTC', '2024-10-07 00:05:00 UTC', '2024-08-25 21:36:00 UTC', ARRAY[130812, 101878, 105228, 197143, 176740, 195551, 193802, 100830, 171198, 199872, 123551, 118636, 128387, 123286, 166495, 110742, 147254, 166291, 181656, 168903, 179155, 130216]::int[], ARRAY[209353, 289149, 265903, 248312, 293395, 237600, 233948, 284422, 291081, 255448]::int[], ARRAY[332230, 381337, 325899, 307905, 338134, 357891, 359485, 323294, 357511, 318100, 369138, 344318, 348228, 316151, 395797, 310254, 317155, 307955, 332314, 398200, 303011, 303544, 359725, 380850, 374884, 343886, 329584, 386116, 373132, 389311, 378308, 355010, 358092, 399669, 322598, 377609, 316527, 384963, 334194, 395541, 353381]::int[], ARRAY[411375, 456380, 413150, 478272, 477051, 454399, 443387, 493256, 414921, 486746, 416911, 475482, 417085, 495446, 498828, 433478, 471330, 466833, 497370, 438343, 430414, 488414, 442505, 423038, 405453, 402659, 460187, 445758, 439447, 448953, 483734, 467271]::int[], '2024-10-14 19:30:00 UTC', '2024-10-26 16:24:00 UTC', '2024-08-22 15:47:00 UTC'),
+('erica.8wsqb07o@example.net', '陆晋岳', 'unknown','2022-12-14 18:36:00 UTC', '2024-10-02 02:52:00 UTC', '2024-08-29 04:21:00 UTC', ARRAY[106430, 192431, 138715, 123666, 134978, 144323, 172669, 193392, 189553, 112431, 165005, 107595, 163434, 151174, 125353, 117505, 189170]::int[], ARRAY[277731, 234933, 266848, 238324, 235711, 234798, 224733, 291484, 211828, 265412, 242692, 246042, 243938, 297804, 235369, 268792, 290712, 208601]::int[], ARRAY[320313, 325299, 342756, 347911, 333449, 321715, 395692, 359090, 329144, 302870, 334876, 310200, 346585, 334469, 373228, 316141, 354127, 341963, 329054, 365108, 336343, 399613, 361966, 370705, 325056, 325453, 390539, 394170, 318460, 372801, 370196, 398254, 390229, 335740, 369279, 385367]::int[], ARRAY[461894, 417726, 431330, 409915, 456287, 429560, 405132, 470393, 425868, 445155, 473595, 420267, 436015, 424579, 476659, 415051, 497961, 424573, 455274, 485481, 401475, 445040, 422429, 465475, 454064, 402858, 423716, 410197, 457021, 446827, 486330, 420285, 497893, 498089, 441096, 476055, 496283, 445607, 484423, 484381, 491020, 444246, 448131]::int[], '2024-10-01 00:20:00 UTC', '2024-10-18 00:20:00 UTC', '2024-09-09 17:59:00 UTC'),
+('heath.7x7kujcy@example.com', '江明健', 'unknown','2023-09-24 01:57:00 UTC', '2024-10-21 07:40:00 UTC', '2024-09-23 11:53:00 UTC', ARRAY[126933, 186376, 105998, 115055, 190143, 123308, 173164, 164141, 152255, 169353, 128100, 127626, 120824, 192850, 194544, 143682, 148509, 150684, 167014, 156266, 169376, 151086, 175637, 162717, 157113, 192704, 127550, 112048, 144149, 112871, 118674, 170398, 130110, 150889, 127961, 140492, 170672, 115343, 130123, 126939, 136468, 180523, 152598, 133414, 191975]::int[], ARRAY[202332, 245694, 298503, 207555, 269173, 236309, 228618, 255912, 219223, 223614, 291999, 277397, 288545, 277675, 261389, 255027, 263442, 213977, 237211, 268654, 289953, 208890, 268652, 281525, 245036, 249017, 283881, 260855, 232581, 242710, 209349, 202498, 220805, 223208, 240550, 227614]::int[], ARRAY[377479, 310179, 368904, 365736, 397995, 354053, 319789, 371202, 369047, 371948, 365169, 339857, 307284, 365646, 358197, 364741, 355442, 314386, 389462, 346889, 335077, 397077, 318195, 349901, 385404]::int[], ARRAY[435790, 419383, 476935, 412028, 451132, 430754, 464996, 463069, 485058, 481023, 446404, 437565, 454265, 463814, 484253, 468303, 469616, 495471, 469381]::int[], '2024-10-21 18:19:00 UTC', '2024-10-27 19:33:00 UTC', '2024-10-08 17:56:00 UTC'),
+('jimmie.4hy989zq@example.net', '吴宛凝', 'male','2020-12-17 21:30:00 UTC', '2024-10-12 11:58:00 UTC', '2024-10-11 06:49:00 UTC', ARRAY[168137, 154713, 135354, 141061, 177583, 171238, 169781, 159982, 136945, 135372, 102404, 177432, 178226, 156494, 199233, 118472, 173047, 154247, 189380, 133824, 101097, 117170, 170904, 152007, 115473, 197632, 102340, 189221, 159965, 161660, 113993, 173893, 188902, 105145, 180537, 140819, 161758, 123607, 162857, 192205]::int[], ARRAY[294317, 253719, 250183, 274177, 271854, 201043, 243581, 239865, 279347, 237404, 284169, 203672, 221997, 209044, 221842, 298751]::int[], ARRAY[338321, 321404, 335122, 331576, 379344, 381582, 350238, 349372, 323213, 363252, 360009, 313494, 398961, 387783, 313095, 358287, 307255, 314379, 379892, 398212, 384214, 332836, 364712, 339929, 337750, 356819, 389020]::int[], ARRAY[400369, 407371, 434759, 494919, 471551, 419208, 446991, 403500, 480899, 404780, 498668, 491578, 481189, 497847, 458446, 453331, 493903, 454964, 466607, 498234, 400109, 430671, 457835, 418592, 457242, 478907, 463648, 466918, 445881, 421549, 439118, 437863, 426277, 491584, 417475, 431196, 484776, 497720, 433774, 431651, 452555, 463257, 406061, 434172]::int[], '2024-10-06 00:25:00 UTC', '2024-10-26 22:38:00 UTC', '2024-08-06 23:24:00 UTC'),
+('arlene.sfxulntu@example.com', '刘紫超', 'male','2022-05-06 12:54:00 UTC', '2024-10-18 16:01:00 UTC', '2024-10-02 18:58:00 UTC', ARRAY[180585, 137653, 185600, 144765, 152475, 111173, 137280, 164644, 124264, 160211, 134717, 135037, 173418, 119561, 128491, 115577, 173655, 114680, 118063, 162300, 138474, 135052]::int[], ARRAY[268161, 270498, 225735, 264171, 232228, 281207, 249817, 201194, 289203, 218423, 235908, 275881, 288374, 274284, 244539, 210746, 221877, 264689, 268175, 234348, 272328, 286983, 265231, 237932, 254138, 298895, 236204, 228670, 263707, 241409, 272876, 288711, 208840, 274404, 277452, 223314, 214114, 206157, 209903, 201060, 249184]::int[], ARRAY[392925, 394679, 338637, 307615, 323309, 398769, 337811, 379648, 347743, 326262, 310042, 326659, 307473, 387940, 306350, 391393, 378224, 350772, 358776, 319176, 320942, 327562, 325253, 386304, 334751, 351472, 397485, 332114, 357955, 330538, 392940]::int[], ARRAY[483640, 412147, 483313, 463133, 477886, 465885, 458816, 405586, 441606, 453825, 436376, 428943, 402231, 472991, 486101, 490849, 400463, 431756, 439918, 483005, 483959, 432330]::int[], '2024-10-11 14:16:00 UTC', '2024-10-30 10:30:00 UTC', '2024-10-20 03:05:00 UTC'),
+('heaven.e7pod3qq@example.org', '陶玮畅', 'unknown','2024-01-20 16:24:00 UTC', '2024-10-08 10:11:00 UTC', '2024-09-04 16:42:00 UTC', ARRAY[165630, 191699, 145988, 154274]::int[], ARRAY[233964, 268074, 245461, 222988, 266763, 237591, 238416]::int[], ARRAY[394075, 338107, 325692, 309426, 369727, 318909, 312104, 358659]::int[], ARRAY[464942, 468254, 499142, 479697, 440451, 465151, 490532, 452394, 439108, 406594, 474980, 493284, 432991, 423730, 437629, 479910, 456096, 480386, 465883, 494196, 425516, 485694, 499620, 413765, 420187, 416361, 479012, 456197, 436952, 469302, 419855, 409958, 423754]::int[], '2024-10-28 09:52:00 UTC', '2024-10-23 07:15:00 UTC', '2024-09-14 04:20:00 UTC'),
+('orrin.6dsw2vgx@example.org', '沈静璇', 'female','2020-11-11 13:25:00 UTC', '2024-10-23 07:19:00 UTC', '2024-10-17 18:08:00 UTC', ARRAY[158956, 190829, 192585, 189561, 191736, 118371, 110678, 102807, 179200, 115536]::int[], ARRAY[275930, 226406, 241866, 278103]::int[], ARRAY[353861, 333391, 351359, 366539, 378221, 362781, 332663, 393077, 381930, 340276, 334092, 364509, 358486, 342492, 357387, 380987, 365513, 325233, 300316, 350466, 326525, 364559, 318322, 314003, 310541, 364129, 396005, 327236, 383063, 386202, 393139, 332346, 381350, 345791, 307775, 364709, 322695, 365536, 355057, 370318, 358391, 336663, 339911, 325736, 382752, 381457, 312265]::int[], ARRAY[453328, 467241, 414433, 486036, 411382, 401957, 410221, 458655, 497098, 493987, 439084, 438221, 461055, 423100, 449967, 490956, 470377, 447552, 422286, 448893, 401573, 425704, 481826, 447951, 419397, 425516, 454429]::int[], '2024-09-18 08:57:00 UTC', '2024-10-26 19:48:00 UTC', '2024-08-28 10:03:00 UTC'),
+('kelsi.4lcgrkg8@example.net', '毛蕾雅', 'female','2024-05-06 01:25:00 UTC', '2024-10-15 16:06:00 UTC', '2024-10-01 20:08:00 UTC', ARRAY[105505, 134401, 148806, 174610, 155965, 137792, 100823, 143114, 147780, 197910, 161805, 140679, 188040, 122006, 121600, 133077, 130210, 137300, 111104, 182588]::int[], ARRAY[224863, 239703, 268350, 247834, 262571, 215266, 252966, 252473, 253798, 292513, 292803, 227871, 237439, 261777, 299508, 244044, 264027, 222642, 229782, 252965, 216940, 224661, 233238, 228695, 289646, 227102, 200965, 292194, 225945, 233162, 203171, 295305, 293300, 288817, 257885, 226401, 287186, 280635, 214250, 237511, 227069, 292436, 261590, 263241, 202426]::int[], ARRAY[309731, 358084, 309767, 344606, 353045]::int[], ARRAY[416972, 453531, 402336, 421806, 430853, 445137, 433595, 404096, 458152, 458291, 477414, 444894, 426437, 446479, 455024, 450656, 433449, 449846, 442205, 492035, 414654, 422479, 496460, 491907, 405099, 486933, 448652, 428113, 424753, 402521, 438866, 463163, 494558, 414146]::int[], '2024-09-22 23:34:00 UTC', '2024-10-24 08:48:00 UTC', '2024-10-15 11:27:00 UTC'),
+('thea.jnymvr3z@example.com', '丁蝶葵', 'male','2021-11-16 14:41:00 UTC', '2024-10-17 04:23:00 UTC', '2024-10-08 18:36:00 UTC', ARRAY[194144, 105390, 152101, 150782, 147724, 145205, 114499, 198109, 196820, 163847, 155173, 175762, 188289, 109499, 128056, 131117, 137494, 132480, 137243, 157130, 123114, 136793, 108415, 129852, 195056, 195107, 130044, 159746, 133922, 152995, 177196, 129449, 194612, 197673, 112901, 199091, 196314, 136125, 194755]::int[], ARRAY[]::int[], ARRAY[325562, 310157, 364263]::int[], ARRAY[457282, 421219, 456416, 420334, 473040, 436207, 492105, 467270, 464129, 420522, 454816, 476019, 435442, 426081, 491270, 489225, 407277, 446249, 482136, 470068, 487296, 457674, 420568, 452143, 433640, 487128, 434765, 477492, 477198, 409471, 447411, 461864, 436056, 411246, 484035, 409611, 482544, 468985]::int[], '2024-10-14 16:27:00 UTC', '2024-10-20 08:04:00 UTC', '2024-10-26 16:40:00 UTC'),
+('lilian.kktjqha0@example.org', '贾淑颖', 'female','2023-12-08 08:28:00 UTC', '2024-10-27 05:39:00 UTC', '2024-10-01 11:13:00 UTC', ARRAY[141764, 156612]::int[], ARRAY[]::int[], ARRAY[333653, 320907, 399737, 384165, 301873, 377725, 373788, 378044, 313158, 322832, 338249, 300592, 308350, 300024, 369194, 354815, 324457, 326118, 396162, 317425, 309629, 349781, 311409, 366687, 363846, 336060, 395159, 328092, 397521, 342051, 317550, 359370, 340643, 349327, 372796, 361347, 362932, 341090, 375560, 314389, 395003]::int[], ARRAY[411093, 403748, 429133, 437627, 449326]::int[], '2024-10-19 17:29:00 UTC', '2024-10-17 14:36:00 UTC', '2024-08-26 16:10:00 UTC'),
+('maximillian.73t05awr@example.org', '潘水瑶', 'male','2022-03-28 16:19:00 UTC', '2024-10-20 21:26:00 UTC', '2024-10-04 14:33:00 UTC', ARRAY[152235, 199441]::int[], ARRAY[206852, 285816, 215037, 276095, 276072, 262191, 232703]::int[], ARRAY[336612, 382121, 316214, 326035, 317919, 316702, 378500, 361997, 337577, 374025, 309007, 332332, 355488, 306284, 358343, 353222, 301424]::int[], ARRAY[478980, 409827, 499488]::int[], '2024-10-17 08:39:00 UTC', '2024-10-27 03:55:00 UTC', '2024-08-30 19:49:00 UTC'),
+('jaycee.ha315b95@example.net', '廖巧春', 'male','2024-05-11 15:53:00 UTC', '2024-10-19 20:30:00 UTC', '2024-08-28 17:23:00 UTC', ARRAY[194593, 167208, 112482, 117883, 156819, 103259, 115356, 117144, 131556, 125365, 141283, 136262, 178248, 189120, 197822, 123066, 197283, 156930, 134931, 152178, 190534, 144330, 125577, 103537, 173388, 115869, 154056]::int[], ARRAY[297367, 221468, 232974, 274576, 237056, 222163, 260221, 244882, 299648, 218475, 289492, 246834, 225257, 209915, 288462, 206700, 227737, 237778, 282206, 217067, 203581, 239265, 207455, 299123, 255764, 226072, 276446, 236782, 208390, 214532, 294639, 225579, 274351, 291745, 229937, 230316, 243833, 244523, 268327]::int[], ARRAY[307635, 379749, 324006, 307097, 325242, 388998, 399765, 374831, 301095, 311029, 374643, 355492, 390643, 360769, 381925, 391965, 368518, 318376, 380988, 328053, 305150, 385059, 389864, 370729, 336577, 322633]::int[], ARRAY[429211, 452866, 431837, 413748, 473476, 466570, 427121, 474709, 461144, 452408, 413278, 463093, 429402, 435917, 464199, 428836, 465822, 417017, 457298, 424874, 419853, 463529]::int[], '2024-10-14 07:47:00 UTC', '2024-10-27 09:24:00 UTC', '2024-09-15 09:47:00 UTC'),
+('maxwell.8g8dfdon@example.com', '郝韵雪', 'unknown','2023-11-01 14:18:00 UTC', '2024-10-17 21:33:00 UTC', '2024-09-07 10:03:00 UTC', ARRAY[156815]::int[], ARRAY[201946, 247816, 225139, 230514, 274113, 220010, 249177, 282475, 284348, 200534, 246649, 293161]::int[], ARRAY[330112, 308544, 394630, 383618, 328762, 394706, 315250, 347910, 351474, 325178, 305396, 314676, 330584, 380549, 387851, 345923, 327933, 395941, 366520, 336715, 326375, 342487, 365938, 336161, 349065, 399113, 349745, 316895, 383190, 342755]::int[], ARRAY[450451, 447735, 428074, 421531, 406838, 479727, 401360, 440499, 424564, 427589, 444264, 421964, 405735, 402941, 483209, 426108, 422901, 411853, 490265, 475618, 405553, 472014, 460872, 446038, 442061, 445482, 449433, 400908, 442779, 488267, 467175, 484879, 478177, 492188, 467557, 446720, 456473, 454418, 403977, 484877, 413070, 442030, 426906, 459450, 426444, 422322]::int[], '2024-10-24 13:21:00 UTC', '2024-10-22 04:59:00 UTC', '2024-08-12 13:40:00 UTC'),
+('raegan.33zgd753@example.net', '梁文春', 'female','2022-01-02 07:07:00 UTC', '2024-10-07 17:50:00 UTC', '2024-08-16 16:34:00 UTC', ARRAY[130308, 109727, 154169, 105773, 115158, 155602, 180365, 169968, 115035, 122176, 145464, 190961, 143678, 129906, 163891, 123514, 168012, 178725, 149933, 188092, 149718, 116590]::int[], ARRAY[242337, 299794, 262519, 219432, 250420, 219693, 279514, 297665, 292254, 243345, 221357, 227269, 299247, 236429, 270531, 255972, 279018, 292417, 232059, 258228, 244814, 202618, 270351, 292078, 250963, 291891, 240978, 211290, 206693, 254832, 206409, 239291, 247462, 296046, 205498, 232473, 235003, 219343, 238528, 215547, 271783]::int[], ARRAY[357937, 321951, 346303, 374721, 382775, 329997, 363944, 377958, 354902, 338629, 321249, 326153, 347864, 328041, 336459, 389921, 384561, 363104, 363759, 383543, 325007, 383374, 343362, 334905, 345020, 374917, 306406, 325883, 351953, 305630, 346740, 316846, 320730, 381721, 332211]::int[], ARRAY[418416, 465637, 448278, 438237, 482314, 422820, 407398, 452392, 411725, 465376, 479727, 425209, 466444, 442146, 467134, 417637, 407540, 456432, 433167, 424708, 445437, 438976, 403747, 472486, 463769]::int[], '2024-10-15 01:26:00 UTC', '2024-10-29 19:07:00 UTC', '2024-08-23 00:51:00 UTC'),
+('cheyanne.dwclluih@example.com', '孙黛亦', 'female','2019-12-21 10:37:00 UTC', '2024-10-04 16:05:00 UTC', '2024-08-06 14:13:00 UTC', ARRAY[]::int[], ARRAY[259803, 204417, 271954, 258796, 281107, 222807, 296835, 293661, 267143, 274944, 239206, 264528, 278058, 283451, 236353, 269163, 277659, 230493, 244393, 201276, 272010, 272511, 262434, 293261, 283086]::int[], ARRAY[313792, 348074, 372300, 357238, 335451, 398058, 308937, 322793, 379890, 342359, 384333, 331574, 338419]::int[], ARRAY[439150, 462459, 489178, 445571, 453947, 411432, 436127, 401970, 494726, 447551, 486568, 424956, 452380, 437663, 435636, 497079, 449122, 442774, 458500, 484770, 467848, 437113, 456520, 424186]::int[], '2024-10-16 18:48:00 UTC', '2024-10-22 12:56:00 UTC', '2024-09-18 13:47:00 UTC'),
+('dulce.4rw2yjcp@example.com', '黎紫怡', 'female','2023-06-04 12:50:00 UTC', '2024-10-15 20:10:00 UTC', '2024-09-15 09:26:00 UTC', ARRAY[124314, 108456, 184548, 175781, 102364, 114391, 141008, 145849, 130291, 132011, 154166, 132389, 158711, 184099, 162419, 108819, 102029, 110069, 129603, 185603, 100577, 104089, 141884, 115924, 192969, 112038, 123607, 195750, 159928, 101727, 171474, 100006, 186798, 135128, 153515, 189879, 114008, 183264, 167232, 144734, 125765]::int[], ARRAY[299178, 235574, 206079, 237906, 286378, 210982, 266629, 238479, 244434, 285511, 293982, 292884, 269351, 269251, 261358, 262391, 297001, 215990, 202352, 229799, 274343, 283237, 258424, 208450, 205342, 237678, 213578, 212700, 207714, 272862, 282525, 287362]::int[], ARRAY[398253, 351850]::int[], ARRAY[494866, 457389, 419486, 417239, 414399, 400711, 493448, 468995, 426547, 440650, 456439, 484869, 406243, 455921, 462349, 443612, 421488, 404281, 426082, 449380, 466368, 453228, 400677, 439488, 458446]::int[], '2024-10-03 16:07:00 UTC', '2024-10-29 09:00:00 UTC', '2024-09-23 11:08:00 UTC'),
+('gia.7pzhim42@example.net', '崔念芹', 'male','2024-01-30 17:18:00 UTC', '2024-10-18 22:32:00 UTC', '2024-10-06 23:51:00 UTC', ARRAY[180430, 153161, 171804, 135435, 197106, 119078, 137734, 164619, 115561]::int[], ARRAY[267283, 270214, 201242, 262755, 284028, 256171, 240789, 213293, 213589, 267105, 278994, 277209, 206968, 295296, 245449, 229127, 279744, 252215, 242261, 221593, 240586, 236621, 235597, 235274, 297936]::int[], ARRAY[399810, 305634, 372295, 317474]::int[], ARRAY[425479, 418621, 444313, 494125, 457144, 445115, 470921, 400539, 416156, 467017, 402704, 436986, 404829, 463366, 401211, 450480, 475037, 445958, 461581, 478193, 458429, 481299, 484484, 424658, 414060, 403416, 419809, 402836]::int[], '2024-10-01 14:43:00 UTC', '2024-10-30 16:49:00 UTC', '2024-09-05 22:34:00 UTC'),
+('lonie.hea0tsn6@example.net', '万瀚诚', 'male','2023-11-30 19:04:00 UTC', '2024-10-22 12:59:00 UTC', '2024-10-04 18:42:00 UTC', ARRAY[117066, 131737, 151568, 195802, 163878, 165330, 151726, 102435, 100664, 128282, 182993, 131916, 188995, 129471, 174363, 184694, 168703, 163465, 197435, 140613, 113572, 181965, 173628, 146904, 129783, 181556, 145434, 164281, 114503, 132052, 106626, 154768, 197120, 148701, 180462, 166557, 123739]::int[], ARRAY[219371, 246795, 228814, 206200, 229129, 276193, 254781, 209744]::int[], ARRAY[370047, 380303, 372910, 328531, 314977, 355297, 326768, 303715, 347746, 358932, 334352, 369531, 365248, 309106, 387565, 345335, 399547, 331391, 377828, 340548, 364786, 351501, 396116, 355953, 335098]::int[], ARRAY[466890, 456043, 475772, 408169, 423946, 428515, 436177, 459122, 472241, 461792, 457105, 465002, 438750, 437551, 400014, 453220, 481891, 471403]::int[], '2024-09-18 11:50:00 UTC', '2024-10-18 00:14:00 UTC', '2024-10-11 21:57:00 UTC'),
+('bradley.mqt3bo69@example.com', '罗涛羽', 'unknown','2023-02-01 17:31:00 UTC', '2024-10-14 13:35:00 UTC', '2024-09-15 13:42:00 UTC', ARRAY[130642, 140159, 166647, 145475]::int[], ARRAY[214169, 258567, 285275, 242407, 269052, 265717, 215384, 289758, 244456, 241174, 223926, 208393, 260632, 233036, 268444, 216657, 258292, 273438, 237748, 238609, 255531, 287291, 269717, 298329, 282938, 290972, 219785, 237428, 249243, 229881, 273862, 221125, 233683, 272544, 266927, 275817, 221936, 288244, 228816, 205525, 242390, 244024, 289956, 244338, 230114, 228081, 221484]::int[], ARRAY[397130, 313083, 354513, 355316, 301690, 306973, 392382, 364141, 379627, 363112, 390026, 378366, 387125, 347368, 324113, 375182, 349088, 303998, 372621, 364381, 389890, 380726, 321277, 395669, 383327, 382386, 399335, 323125, 345937, 376916, 316641, 326821, 340119]::int[], ARRAY[416397, 422178, 484968, 417340, 479092, 469460, 480294, 450448, 475812, 494624, 455841, 416926, 450704, 411018, 428835, 418658, 468503, 441102, 409355, 432871, 417702, 439167, 434674, 451228, 479252, 451951, 407189, 427104, 474985, 422976, 427574, 450884, 403778, 413076, 430548, 408971, 488432, 434156, 461665, 417382, 447694]::int[], '2024-09-29 04:31:00 UTC', '2024-10-23 17:57:00 UTC', '2024-08-17 03:27:00 UTC'),
+('renee.k4kw03hr@example.net', '胡金福', 'unknown','2022-12-04 21:18:00 UTC', '2024-10-05 07:24:00 UTC', '2024-10-29 02:52:00 UTC', ARRAY[173146, 136114, 168286, 158094, 108064, 115674, 138685, 138788, 113037, 120300, 112855, 167296, 147882, 101112, 166402, 176897, 140065, 197914, 197465, 164096, 103460, 190944, 135943, 146229, 168634]::int[], ARRAY[247613, 238928]::int[], ARRAY[373065, 336893, 319036, 395863, 313425, 323201, 384573, 368228, 349180, 348308, 395514, 309336, 331285, 352088, 337907, 362956, 386522, 312892, 362587, 375435, 317387, 337023, 349230, 382406, 377006, 371732, 399949, 323151, 317770, 324199, 393871]::int[], ARRAY[492040, 412656, 479260, 461583, 450493, 449214, 488685, 475532, 440402, 440557, 412337, 432018, 434404, 429601, 416508, 494928, 479259, 419132, 436110, 451230, 481643, 469832, 449584, 416169, 463407, 467868, 481352, 428728, 413304, 450831, 452659, 468382, 443887, 418118, 403717, 432109, 483562, 450358, 470424, 444771, 436333, 432628]::int[], '2024-10-13 01:21:00 UTC', '2024-10-23 22:00:00 UTC', '2024-08-10 14:10:00 UTC'),
+('darrion.1tl3o0hd@example.com', '罗娜漫', 'unknown','2024-03-08 06:56:00 UTC', '2024-10-11 08:13:00 UTC', '2024-10-14 01:10:00 UTC', ARRAY[190376, 132881, 104932, 136471, 112455, 169222, 198322, 101111, 172441, 176820, 164937, 151251, 111157, 181257, 143712, 184223, 191435, 168588, 128572, 115328, 198758, 162340, 134332, 181298, 142058, 196634, 134019, 167094, 184934, 195888, 170650]::int[], ARRAY[271316, 206408, 218067, 295559, 293587, 222222, 240294, 250566, 269820, 246953, 280880, 234231, 298238, 214343, 274005, 283206, 274717, 293769, 289899, 206378, 271571, 219864, 293035, 239740, 275293, 290041, 285664, 222742, 215426, 246999, 278016, 284053, 265250, 233158, 281672, 273415, 281831, 244051, 278425]::int[], ARRAY[390860, 321783]::int[], ARRAY[471208, 407433, 409795, 429432, 429233, 434041, 410977, 470916, 468431, 460429, 416777, 428740, 443593, 491513]::int[], '2024-10-22 19:33:00 UTC', '2024-10-26 04:08:00 UTC', '2024-08-22 10:10:00 UTC'),
+('adolphus.ehbjruby@example.net', '严凝雁', 'unknown','2024-03-18 11:47:00 UTC', '2024-10-23 10:18:00 UTC', '2024-09-07 05:37:00 UTC', ARRAY[186149, 158160, 106295, 126600, 153495, 140478, 175021, 165359, 185059, 162848, 156123, 148945, 176808, 163232, 191943, 174414, 144323, 121674, 112910, 182863, 129851, 177392, 151546]::int[], ARRAY[]::int[], ARRAY[324289, 390321, 323852, 345739, 325769, 300143, 349211, 324776, 389345, 385041, 336848, 366769, 363704, 318274, 305696, 313609, 342490, 379599, 312744, 371924, 389478, 332129, 328015, 320370, 310044, 359181, 315071, 397175, 351123, 385365]::int[], ARRAY[464928, 467315, 440464, 462450, 450181, 408324, 458482, 447615, 437241, 486243, 429485, 434054, 458463, 405101, 488201, 422774, 495403, 425137, 491422, 464650, 487269, 417583, 420026, 482914, 476344, 468361, 421635, 495249, 454632, 428685, 431135, 450461, 451616, 434590, 408864]::int[], '2024-10-06 09:10:00 UTC', '2024-10-31 14:49:00 UTC', '2024-09-17 03:11:00 UTC'),
+('mason.823jfl4m@example.com', '覃芳虹', 'female','2022-02-28 20:40:00 UTC', '2024-10-23 03:24:00 UTC', '2024-10-08 04:29:00 UTC', ARRAY[109351, 159371, 149386, 153762, 118305, 100455, 114395, 105461]::int[], ARRAY[255489, 294141, 258500]::int[], ARRAY[313426, 315614, 307917, 322607, 392304, 357283, 367788, 364669, 350097, 326910, 350703, 310546, 333648, 335803, 377672, 361840, 352821, 380907, 378327, 303541, 382242, 328154]::int[], ARRAY[436924, 446681, 491639, 424442, 487718, 423026, 462794, 442487, 470964, 474934, 400250, 450088, 447493, 423409, 495967, 416817, 419943, 477003, 454752]::int[], '2024-09-25 07:16:00 UTC', '2024-10-21 19:06:00 UTC', '2024-10-03 08:13:00 UTC'),
+('sarah.5s3igzvw@example.org', '郝玮畅', 'male','2022-11-09 22:58:00 UTC', '2024-10-31 13:07:00 UTC', '2024-09-23 13:53:00 UTC', ARRAY[105812, 146769, 114929, 163394, 126620, 129282, 101355, 107860, 140776, 189711, 153315, 186242, 172245, 177105, 162012, 182000]::int[], ARRAY[220171, 277647, 251236, 275658, 201913, 204911, 246789, 296956, 283728, 259780, 287412, 262716]::int[], ARRAY[386728]::int[], ARRAY[489923, 446233, 409866, 456609, 421161, 403361, 443892, 474326, 450243, 493950, 450804, 475175, 441530, 420845, 480588, 402576, 406496, 427430, 439024, 492502, 404328, 468940, 458028, 468974, 453153, 464914, 486183, 403913, 412691, 418197, 497223, 457362, 417016, 478277, 483161, 434746]::int[], '2024-10-11 20:10:00 UTC', '2024-10-27 07:50:00 UTC', '2024-10-18 08:59:00 UTC'),
+('matteo.vxzqnrvc@example.com', '欧阳瀚福', 'female','2022-07-06 08:44:00 UTC', '2024-10-07 21:22:00 UTC', '2024-09-22 05:31:00 UTC', ARRAY[110278, 180190, 179493, 140328, 166961, 122590, 160123, 163533, 147015, 127482, 129020, 132248, 139154, 184680, 106929, 166952, 138043, 111802, 180494, 195892, 133525, 192032, 189088, 191291, 178533, 154816, 130815, 180988, 157781, 172245, 123211, 100183, 178700, 115399, 175835, 179742, 138859, 103552, 112163, 194396, 197895, 190110, 149570, 195237, 129860, 160397, 150680, 186410, 133931]::int[], ARRAY[250183, 297982]::int[], ARRAY[368666, 321277, 366693, 390202, 392448, 333924, 332698, 339961, 324602, 302780, 362681, 388832, 358066, 366728, 389469, 369580, 301450, 371607, 313415, 304911, 398710, 348707, 356002, 396774, 349686, 347688, 362745, 366201, 364720, 376669, 372165, 398133, 324864, 394260, 310486]::int[], ARRAY[425375, 428541]::int[], '2024-10-27 09:26:00 UTC', '2024-10-23 11:16:00 UTC', '2024-10-26 22:25:00 UTC'),
+('adelbert.5gxm6m0w@example.com', '宋璇盈', 'male','2020-08-31 13:51:00 UTC', '2024-10-11 10:23:00 UTC', '2024-08-17 15:47:00 UTC', ARRAY[196648, 113103, 153978, 192127, 130215, 124940, 146324, 195829, 120118, 125040, 106023, 102307, 161366, 159808, 171343, 100424, 142761, 147195, 152997, 136535, 129164, 138973, 131100, 136183, 160487, 147858, 174464, 166458, 182728, 144479, 188489, 147952, 144079, 199915, 134512, 145674, 105988, 139918, 194393, 118661, 155004, 153101, 116006, 199882, 126883, 165551]::int[], ARRAY[283304, 296677, 218367, 210854, 220444, 215468, 204869, 243108, 224053, 285228, 225826, 203944, 282047, 247239, 226448, 298547, 276803, 235839, 253685, 231207, 284811, 265007, 235518, 291872, 257759, 205019, 224356, 289842, 214445, 203413, 233112, 203810, 279025, 272649, 233643, 296076, 214049, 201288]::int[], ARRAY[377512, 353839, 399589, 326306, 307823, 320203, 345645, 390797, 332033, 315097, 370559, 307528, 398919, 317260, 349335, 366000, 389235, 342079, 395476, 392410, 341650, 398339, 395643, 326592, 373995, 319517, 320260, 360694, 345755, 348465, 342473]::int[], ARRAY[463071, 482654, 483808, 483341, 472347, 471708, 471620, 460451, 486073, 461453, 435621]::int[], '2024-09-20 17:36:00 UTC', '2024-10-21 20:38:00 UTC', '2024-08-26 09:00:00 UTC'),
+('bennie.u93mcym2@example.net', '蒋翰逸', 'unknown','2020-10-05 03:58:00 UTC', '2024-10-31 20:35:00 UTC', '2024-08-09 22:41:00 UTC', ARRAY[188849, 173710, 123669, 178733, 133782, 135806, 157682, 124923, 166620, 177222, 136360, 145662, 180171, 148169, 144061, 195208, 192997, 109487, 166479, 127451, 112136, 185147, 190972, 113870, 168279, 121209, 105428, 112158, 127054, 159010, 124231, 120829, 174033, 157571, 111236, 179316]::int[], ARRAY[219870, 248291, 250262, 269506, 246723, 262768, 267150, 206036, 289172]::int[], ARRAY[384240, 352155, 364226, 384176, 360243, 388808, 323449]::int[], ARRAY[461300, 483205, 420022, 436639, 400415, 410263, 443211, 431935, 445155, 417820, 442053, 461930, 401433, 452551, 494895, 443327, 409421, 428346, 419980]::int[], '2024-10-18 02:02:00 UTC', '2024-10-21 08:50:00 UTC', '2024-10-10 19:48:00 UTC'),
+('margarette.dgphtucs@example.net', '董乐丹', 'female','2021-10-30 15:21:00 UTC', '2024-10-09 07:19:00 UTC', '2024-08-25 06:13:00 UTC', ARRAY[139598, 132380, 153706, 148385, 123362, 178533, 182944, 173434, 176677, 173279, 129534, 180138, 106505, 184310, 176849, 152702, 182794, 145784, 153287, 131961, 154540, 142158, 178396, 194969, 126132, 133540, 179065, 158491, 186133, 153394, 145467, 188102, 189111, 123927]::int[], ARRAY[220759, 281532, 246059, 241674, 239826, 299386, 292764, 249837, 267007, 207473, 207101, 223344, 264081, 248410, 234153, 208269, 215536, 246071, 260658, 223901, 224852, 217482, 259930, 295276, 298336, 239117, 246529, 270353, 290165, 293400, 276548, 232190, 261877, 284467, 246694, 294437, 297683, 260795, 203471, 258319, 297816, 216042, 270560, 231319, 240287, 208137, 241702]::int[], ARRAY[391009, 370181, 362409, 366106, 342151, 316012, 341081, 320501, 368605, 306678, 308557, 343589, 342414, 335492, 365210, 334798, 314084]::int[], ARRAY[484916, 456441, 431114, 495902, 474505, 485816, 422061, 432579, 498581, 468151, 439784, 444259, 498980, 421460, 432414, 400604, 421936, 406349, 490931, 426978, 433250, 485008, 422193, 414617, 429381, 494203, 428031]::int[], '2024-09-28 16:07:00 UTC', '2024-10-30 20:41:00 UTC', '2024-08-23 14:16:00 UTC'),
+('orland.rkh9ausr@example.org', '王紫芳', 'unknown','2022-04-01 14:27:00 UTC', '2024-10-07 15:15:00 UTC', '2024-09-14 09:08:00 UTC', ARRAY[146632, 155464, 147220]::int[], ARRAY[234070, 216148, 232821, 201892, 236579, 222381, 214687, 212944, 219756, 286361, 213561, 273258, 240943, 298822, 252072, 261277, 288681, 217710, 297243, 218703, 225414, 289518, 271521, 220422, 207040, 240396, 274266, 269820]::int[], ARRAY[325038, 343309, 309297, 347604, 380075, 384395, 392398, 308710, 324602, 328795, 312532, 386713, 330880, 330152, 349007, 313583, 389281, 315410, 319989, 323414, 392855, 391532, 354042, 391799, 337402, 341647, 396077, 340349, 331373, 306572, 389632]::int[], ARRAY[495727, 441613, 420883, 478018, 477528, 497415, 437080, 406825, 469598, 490234, 450102, 461756, 457984, 415602, 476574, 478921, 437261, 475669, 474070, 440256]::int[], '2024-10-26 01:03:00 UTC', '2024-10-29 21:35:00 UTC', '2024-09-02 22:35:00 UTC'),
+('elisha.nr57d9vx@example.org', '林圣天', 'female','2023-10-22 00:41:00 UTC', '2024-10-25 21:41:00 UTC', '2024-09-01 03:17:00 UTC', ARRAY[131379, 156919, 124824, 133330, 140224, 173380, 149646, 129998, 193661, 190674, 132924, 118483, 110542, 160243, 124332, 188478, 164703, 144975, 147568, 177219, 133620, 151008, 171063, 181696, 107418, 114857, 162208, 187942, 143490]::int[], ARRAY[201731, 259208, 228058, 242638]::int[], ARRAY[369528, 397312, 357238, 392293, 384865, 337380, 349396, 330403, 371861, 384995, 389590, 321076, 335252, 320296, 323223, 344121, 385114, 343489, 394925, 357184, 314631, 306191, 314860, 382156, 395319, 318422, 394457, 330485, 380302, 346569]::int[], ARRAY[455449, 466243, 409877, 425683, 414710, 456812, 489774, 408465, 470538, 402986, 401423, 476625, 499304, 419652, 476721, 446046, 439907, 438138, 479658, 463934, 437637, 414102, 461478, 402292, 468129, 449558, 494405, 409532, 435505, 410977, 421685, 405582, 418457, 497098, 486546, 483946, 476464, 478016, 485302, 419945, 423822, 478774, 431617, 464325, 430984, 436777]::int[], '2024-10-05 06:22:00 UTC', '2024-10-17 08:48:00 UTC', '2024-09-07 19:20:00 UTC'),
+('maida.rdov0ehj@example.org', '余瀚君', 'unknown','2022-09-26 13:03:00 UTC', '2024-10-05 16:05:00 UTC', '2024-09-11 00:15:00 UTC', ARRAY[161667, 107216, 109316]::int[], ARRAY[208232, 219161]::int[], ARRAY[367081, 368827, 343453, 324293, 371717, 335580, 345498, 327303, 337331, 314137, 383581, 312015, 358496, 374171, 383651, 318531]::int[], ARRAY[473297, 469523, 425353, 495168, 462913, 468807, 474141, 469456, 411559, 482444, 409823, 495361, 455511, 452980, 425850, 404878, 471282, 468743, 457034, 420226, 471240, 490580, 434692, 472193, 436261, 416912, 401487, 408225, 478940, 475213, 435039, 420350, 421851, 475018, 481787, 413750, 480119, 481725, 416791, 498458, 443601, 459962, 450120, 492005]::int[], '2024-09-23 00:34:00 UTC', '2024-10-27 16:45:00 UTC', '2024-08-20 00:48:00 UTC'),
+('ramona.lomlvqk7@example.org', '孟佳红', 'female','2023-02-09 11:15:00 UTC', '2024-10-22 21:34:00 UTC', '2024-10-05 08:17:00 UTC', ARRAY[109085, 150582, 190054, 159834, 127501, 119995, 126973, 187088, 101130, 144388, 118780, 189502, 184050, 193666, 105960, 186662, 132652, 136416, 199710, 155142, 169776, 149605, 122735, 198643, 115286, 156224, 149402, 123478, 104260, 173490, 122796, 179769, 145015, 140842, 185109, 182373, 146995, 180835]::int[], ARRAY[224098, 231092, 271232, 271762, 286904, 292233, 215383, 250572, 250611, 209301, 293784, 246849, 298250, 247650, 255427, 241787, 233207, 203561, 279430, 281402, 237661, 289236, 212700, 263080, 269472, 262400, 200657, 257158, 252437, 201245, 252139, 217337, 267678, 261042, 219914, 237944, 290583, 271822, 275426, 249753, 272379, 253154, 230283, 207767]::int[], ARRAY[317454, 347026, 308451, 365665, 328827, 375525, 385210, 329935, 385861, 391191, 344312, 357608, 399827, 397714, 321887, 398010, 374908, 338539, 303536, 313991, 380220, 385985, 333774, 378170, 339425, 388188, 335772, 305741, 322344, 341756, 332610, 363805, 318084, 377625, 343335, 392093, 373992, 376781, 357065, 394973, 337003, 393043, 371415, 314848, 305932, 301848, 330108]::int[], ARRAY[469850, 403005, 469459, 457686, 480962, 450270, 444249, 423677, 466243, 483807, 458973, 496308, 401272, 483241, 475337, 490489, 456504, 429421, 404572, 439425, 468651, 439180, 498321, 471183, 446861, 484580, 466285, 485329, 472347, 498231, 480800, 441934]::int[], '2024-10-29 06:55:00 UTC', '2024-10-22 11:53:00 UTC', '2024-08-05 19:34:00 UTC'),
+('laverne.s1a66ykp@example.net', '向棋翰', 'female','2020-12-02 03:23:00 UTC', '2024-10-26 06:16:00 UTC', '2024-08-22 06:06:00 UTC', ARRAY[154288, 183682, 189318, 183102, 113675, 161646, 171080, 112194, 112758, 126426, 167950, 141599, 162780, 176829, 125009, 124824, 180727, 105971, 187695, 117436, 150610, 101985, 174886, 138045, 127336, 175759, 162136, 126385]::int[], ARRAY[207089, 224354, 251422, 286252, 285584]::int[], ARRAY[362899, 363582, 305427, 385539, 390473, 331992, 300497, 326283, 354025, 346668, 384121, 348371, 309243, 382059, 365745, 385082, 364323, 371459, 350531, 371141, 382892, 330109, 335297, 385364, 349220, 352019, 350692, 395264, 384818, 375380, 311695, 385689, 372590, 304554, 380287, 359984, 370582, 323922, 375199, 318044, 362981, 316392, 354299]::int[], ARRAY[470250, 471988, 401845, 494158, 474667, 432937, 496692, 474273, 477374, 403818, 448663, 418650, 411360, 472551, 438930, 415363, 464675, 416964, 430919, 432004, 427220, 434448, 439101, 477673, 450190, 464151, 407553, 453176, 460119, 419485, 484333, 440270, 444470, 450630, 421301, 447947, 481845, 494985, 416482, 430850]::int[], '2024-09-22 10:05:00 UTC', '2024-10-24 15:34:00 UTC', '2024-08-16 13:56:00 UTC'),
+('janessa.90dz2tbm@example.org', '冯沛菡', 'unknown','2020-09-18 13:07:00 UTC', '2024-10-28 18:53:00 UTC', '2024-10-22 07:38:00 UTC', ARRAY[143977, 116406, 101011, 190549, 140462, 132523, 129666, 154713, 193125, 177662, 199004, 139636, 164024, 179187, 150169, 193229, 107055, 157272, 125451, 177923, 191472, 183819, 166752, 108235, 178698, 112243, 133826, 144675, 192911, 163208, 138079, 130399]::int[], ARRAY[200785, 291288, 295221, 242486, 254168, 280316, 224851, 288776, 288117, 269743, 203135, 275658]::int[], ARRAY[311702, 395155, 317696, 363610, 385573, 343646, 371685, 381162, 360883, 391603, 304168, 368602, 371505, 371336, 350899, 310888, 330903]::int[], ARRAY[442551, 427995, 457921, 437219, 491702, 443214, 494105, 464118, 497400, 418602, 482241, 410728, 468715, 457296, 448609, 426696, 409831, 481250, 435646, 468187, 454355, 450241, 454693, 405381, 451461]::int[], '2024-09-26 09:15:00 UTC', '2024-10-19 22:51:00 UTC', '2024-09-16 02:32:00 UTC'),
+('rubie.ksytuk2e@example.com', '江君裕', 'unknown','2020-08-07 16:42:00 UTC', '2024-10-07 15:54:00 UTC', '2024-08-17 21:56:00 UTC', ARRAY[191486, 125319]::int[], ARRAY[251897, 285755, 211053, 251414, 243163, 203383, 299794, 239439, 286381, 217420, 267365, 254326, 287258, 202233, 241635, 240424, 200026, 298750, 252793, 255529, 271581]::int[], ARRAY[340606, 332509, 307090, 370297, 356889, 395609, 353092, 373763, 305128, 378557, 307629, 365298, 327681, 303176, 346583, 321687, 309550, 388684, 349503, 370757, 352935, 356413, 320847, 319282, 386720, 338461, 332307, 365446, 368315, 358066, 391812, 306420, 315104, 350144, 357571, 358500, 382052, 365752, 307877, 334117, 309525, 332437]::int[], ARRAY[489530, 475780, 439997, 471314, 432568, 434490, 454545, 419617, 494066, 419720, 440349, 440515, 440716, 410087, 421134, 465495, 443945, 470800, 410346, 443956, 471790, 476457, 494877, 481747, 465977, 450650, 421739, 487492, 489383, 498932, 444333, 458345, 419366, 458532, 486197, 467958, 411386, 488642, 473153, 492187]::int[], '2024-09-28 05:03:00 UTC', '2024-10-28 04:13:00 UTC', '2024-08-12 04:21:00 UTC'),
+('jayde.7vq738zo@example.net', '唐明轩', 'female','2021-08-14 06:16:00 UTC', '2024-10-30 02:16:00 UTC', '2024-10-27 06:14:00 UTC', ARRAY[166663, 180210, 187051, 119036, 127446, 170295, 176118, 100906, 148921, 178559, 138638, 166905, 141940, 122044, 180786, 156306, 194447, 156043, 186707, 168572, 162629, 142408, 182452, 174009, 125542, 128009, 118204, 138273, 149715, 147785, 173928, 122031, 104720, 136962, 181796, 120471, 164241, 177490, 170984, 184226]::int[], ARRAY[228989, 212706, 295133, 261963, 260219, 270913, 251022, 241444, 293205, 246973, 238854, 233161, 251011, 231522, 282886, 293836, 225135, 272467, 293004, 227949, 238399, 289644, 295229, 287676, 210440, 287541, 286271, 234484, 259430, 227797, 204683, 222415, 215833, 202241, 275092, 276010, 258166, 269821, 291106]::int[], ARRAY[314812, 345798, 339623, 397784, 346823, 300808, 374827, 301626, 321989, 353533, 314988, 348281, 304283, 333456, 353531, 355684, 360496, 370095, 323351]::int[], ARRAY[491292, 441097, 412364, 456950, 445615, 417311, 448705, 475308, 465368, 412495, 459013, 467828, 476421, 465843, 489837, 404177]::int[], '2024-10-10 14:15:00 UTC', '2024-10-24 20:00:00 UTC', '2024-08-14 23:05:00 UTC'),
+('maegan.yocnxrng@example.net', '史静影', 'male','2019-11-25 17:07:00 UTC', '2024-10-16 20:31:00 UTC', '2024-10-28 18:31:00 UTC', ARRAY[194464, 147032, 114334, 124466, 132374, 115460, 194924, 192562, 181465, 134252, 153704, 161001, 151664, 179453, 121684, 194422, 118725, 104227, 144910]::int[], ARRAY[210080, 265061, 219954, 236615, 275599, 254084, 255332, 227858, 215715, 287379, 263712, 202866, 263372, 265023, 285924, 264158, 257832, 225253, 219793, 283899, 272261, 241363, 288705, 252821, 273798, 282797, 217760, 273867, 253855, 213464, 294978]::int[], ARRAY[328133, 354069, 321901, 371096, 317491]::int[], ARRAY[435998, 455364, 463169, 432541, 486955, 443734, 460651, 415180, 403711, 495168, 476492, 464079, 403023, 468977, 419354, 415173, 468338, 418020, 499073, 494030, 405154, 406921]::int[], '2024-09-17 00:51:00 UTC', '2024-10-25 18:03:00 UTC', '2024-09-13 07:52:00 UTC'),
+('cristal.ck1z3679@example.net', '雷娜漫', 'male','2024-04-25 00:44:00 UTC', '2024-10-09 14:30:00 UTC', '2024-09-25 20:41:00 UTC', ARRAY[158573, 154928, 128188, 137907, 155838, 147483, 155538, 146979, 114916, 149094, 135282, 118766, 152984, 179269, 190801, 167712, 183493, 119961, 169821, 162341, 167248]::int[], ARRAY[252202, 285640, 218844, 267119, 227072, 245719, 281528, 274205, 235124, 225628, 209632, 288816, 204481, 248358, 205848, 255078, 287282, 270312, 287767, 202407, 290827, 295661, 273620, 246818, 276099, 285612, 299579, 283069, 265550, 243884, 282049, 256700, 220821, 240606, 286417, 232293, 207048, 276102, 275160]::int[], ARRAY[341689, 391734, 392966, 300498, 317217, 386413, 343807, 301182, 351733, 360932, 340620, 350581, 300275, 361098, 303229, 315879, 325494, 393282, 374856, 377162, 338984, 354381, 329678, 313110, 386035, 316388, 365821, 331268, 388835, 361850, 354412, 345198, 375635, 306951, 385243, 346915, 399206, 358731, 330294, 326747, 311853, 334414, 340382, 398528, 379792, 325271]::int[], ARRAY[401221, 406714, 423768, 425574, 490746, 439619, 479426, 454213, 447495, 436381, 479439, 469490, 440819, 430351, 421602, 438406, 428848, 463219, 479176, 452612, 436195, 497473, 489474, 411027, 413833, 428529, 485972, 469490, 462815, 411450, 471765, 447651, 455241, 423155, 496211, 465974, 440184, 446404, 468780, 411537, 486732, 438566, 490146, 419270, 482057, 499606, 425862, 424135]::int[], '2024-10-12 20:49:00 UTC', '2024-10-30 13:43:00 UTC', '2024-09-26 06:32:00 UTC'),
+('clyde.o8ev4h4p@example.com', '谭菡颖', 'male','2023-03-22 17:25:00 UTC', '2024-10-23 00:45:00 UTC', '2024-09-02 01:43:00 UTC', ARRAY[124028]::int[], ARRAY[261560, 267143, 259602, 273721, 274300, 212534, 237657, 292461, 288640, 297721, 234666, 299931, 295256, 219013, 265268, 238105, 241520, 290285, 215248, 274167, 278675, 256506, 208601, 265062, 289026, 276037, 239647, 211392, 229346, 248754, 232352, 276203, 205984, 263521, 276510, 279602, 297626, 203967, 240637, 230498, 256979, 279511, 202422, 239395, 289924, 225253, 299866]::int[], ARRAY[325847, 396257, 345741, 312163, 379046, 384677, 376547, 353249, 378053, 321474, 364396, 382044, 395885, 309849, 348267, 306279, 377879, 306525, 372114, 346514, 386945, 318457, 317432, 376669, 365389, 346005, 323226, 371603, 309254, 329662, 371018, 360957, 388067]::int[], ARRAY[493224, 414723, 475606, 434153, 470238, 430221, 483897, 440883, 459859, 439958, 429915, 498316, 417441, 423688, 497265, 449994, 476641, 426448, 480367, 415787, 441043, 428945, 466806, 465650, 475985, 434437, 405573, 489466, 481166, 417400, 447042]::int[], '2024-10-28 04:39:00 UTC', '2024-10-29 11:27:00 UTC', '2024-08-05 17:13:00 UTC'),
+('elbert.b7qv9cdp@example.com', '吴彬琪', 'unknown','2023-05-17 23:10:00 UTC', '2024-10-06 02:50:00 UTC', '2024-08-22 12:23:00 UTC', ARRAY[121068, 159654, 131859, 163247, 162392, 137404, 166801, 120513, 111275, 115930, 101844, 121197, 141286, 180258, 177741, 137932, 124456, 167035, 100123, 153980, 128552, 112260, 166548, 117488, 131335, 148325, 134857, 111864, 147587, 167010, 179168, 184878, 186157]::int[], ARRAY[295399, 289475, 288397, 296983, 230908, 221201, 210450, 224257, 260032, 298940, 280793, 289597, 266806]::int[], ARRAY[325199, 373361, 320673, 315934, 383935, 332787, 333969, 303566, 328901, 338868, 393236, 377325, 350950, 371814, 344274, 390965, 308021, 341240, 307490, 363492, 342294, 377153, 379684, 339908, 307572, 328160, 348269, 327354, 380841, 375113, 364960, 376573, 322876, 339081, 364280, 353772, 316887, 361298, 371994, 363787, 379186, 360000, 384618, 351852, 355595, 317275, 313031]::int[], ARRAY[491616, 495885, 456040, 430210, 417034, 442790, 494120, 406320, 406253, 454963, 421363, 400745, 449664, 412359, 431517, 496662]::int[], '2024-10-17 03:52:00 UTC', '2024-10-30 14:34:00 UTC', '2024-08-14 03:27:00 UTC'),
+('hillard.apfmy0y1@example.net', '郭柯勤', 'unknown','2023-08-19 04:47:00 UTC', '2024-10-13 04:33:00 UTC', '2024-08-17 12:55:00 UTC', ARRAY[106717, 144563, 123897, 179263, 116953, 136911, 160500, 124580, 159667, 116684, 160379, 157457, 166813, 110153, 138492, 171959, 175248, 165353, 196565, 119789, 192677, 135213, 171782, 191543, 182469, 171976, 138185, 127725, 176402, 168984, 150216, 145655, 113421, 104723, 148712, 166700, 140455, 134001, 138492, 127100, 181934, 165999, 148172, 129985, 189565, 102083]::int[], ARRAY[207202, 200507, 289277, 205638, 281992, 282827, 212945, 262123, 254709, 270768, 271937, 244602, 291655, 285853, 209414, 291188, 296402, 250495, 275423, 204487, 226394, 266009, 254131, 296219, 224465, 291560, 267247, 277674, 221583, 200074, 274164, 256372, 241976, 294018, 211667]::int[], ARRAY[302071, 361861, 301560, 375296, 395794, 374389, 305825, 318474, 337574, 366403, 378743, 300352, 344657, 332504, 364774, 307827, 317526, 326924, 329256, 372986, 323189, 396678, 365019]::int[], ARRAY[458084, 495896, 412629, 499808, 435617, 482289, 489981, 474626, 472757, 470891, 468203, 494500, 437070, 461929, 463573, 427913, 403703, 412320, 431465, 430634, 475031, 433565, 492151, 496871, 461291, 446533, 476238, 495544, 461312, 409967, 451667, 434344, 411640, 480919, 491648, 408662, 413281, 438858, 488575, 476928, 403125, 414591]::int[], '2024-09-26 10:50:00 UTC', '2024-10-22 22:20:00 UTC', '2024-10-07 15:14:00 UTC'),
+('cassidy.omusz7d4@example.com', '金远乔', 'unknown','2021-06-05 00:01:00 UTC', '2024-10-05 18:20:00 UTC', '2024-09-18 04:54:00 UTC', ARRAY[164019, 102415, 130130, 131636, 106115, 147421, 113532, 194654, 142130, 103526, 123566, 103975, 134173, 125809, 158397, 179771, 161273, 119848, 127716]::int[], ARRAY[290844, 283640, 242878, 251022, 278485, 245508, 238242, 290906, 249152, 232055, 240711, 203072, 270730, 232062, 279880, 259712, 277610, 297430, 225125, 214725, 266232, 248412, 238619, 218448, 209231, 247639, 239715, 281800, 210682, 228268, 244266, 283056, 237207, 262641, 245873, 211763, 238079, 267589, 252161, 232643]::int[], ARRAY[342329, 370677, 396175, 341206, 349777, 343941, 339313, 397069, 382623, 343543, 329621, 334633, 381225, 351514, 341756, 326837, 357278, 327636, 382699, 385739, 356001, 301625, 347425, 338321, 358302, 333506, 315671, 334061, 325171, 320978, 309461, 302898, 364929, 302116, 341846, 301613, 357561, 372230, 367524, 334164, 304255]::int[], ARRAY[427908, 464708, 441728, 405878, 449680, 449211, 491895, 488408, 410108, 473376, 428172, 448004, 477638, 459650, 409665, 446427, 485145, 424717, 413204, 490719, 403113, 483333, 442342, 412418, 431393, 422043, 484724, 439187, 437283, 476002, 415521, 427879, 404973, 411528, 469609]::int[], '2024-10-22 19:57:00 UTC', '2024-10-18 04:00:00 UTC', '2024-08-30 09:04:00 UTC'),
+('jovani.5k0q2bbb@example.org', '程鸿灿', 'male','2024-08-15 21:30:00 UTC', '2024-10-26 01:14:00 UTC', '2024-10-12 13:28:00 UTC', ARRAY[107753, 148150, 198720, 136467, 191748, 171603, 150219, 111948, 170570, 195390, 169829, 186838, 160894, 143306, 185956, 127052, 148994, 186889, 170383, 151047, 160484, 187368, 136421, 177142, 192251, 130554, 143257, 159062, 105981, 119574, 197456, 104973, 167726, 146635, 179511, 153144, 126344, 195969, 182801, 177278, 159162, 175751, 182095]::int[], ARRAY[255052, 213959, 220683, 239232, 253032, 231065, 218650, 272054, 272423, 248333, 226367, 234755, 268220, 251204, 288386, 209312, 281904, 278882, 243491, 223414, 209282, 297318, 299225, 231678, 259714, 243372, 290148, 204629, 240276, 289510, 223737, 252797, 233324, 252782, 275053, 224584, 262476, 288655, 284818, 268327, 245029, 286344]::int[], ARRAY[336792, 362704, 363454, 387489, 301127, 337457, 364638, 377596, 337079, 383546, 360325, 359145, 389652, 345505, 371029, 316028, 329661, 371529, 397098, 313505, 366420, 360667, 349892, 361747, 381204, 310986, 396002, 387088, 380343, 341057, 308778, 397642, 340099, 331147, 350136, 392610, 310592]::int[], ARRAY[448962, 468555, 461910, 443166, 415081, 421438, 451960, 462357, 409308, 411183, 450337, 456928, 414224, 474353, 426824, 468896, 428589, 413204, 408025, 400422, 430494, 475717, 437452, 446208, 444612, 470137, 403659, 434813, 403540, 404777, 419285, 459513, 468630, 403901, 402021, 467082, 422012, 489897, 432606]::int[], '2024-10-31 23:27:00 UTC', '2024-10-29 02:59:00 UTC', '2024-10-04 02:34:00 UTC'),
+('rafael.8utiox2o@example.net', '谭志辉', 'unknown','2024-09-16 10:33:00 UTC', '2024-10-18 01:32:00 UTC', '2024-10-27 15:47:00 UTC', ARRAY[185295, 124245, 180949, 116026, 148631, 176041, 141417, 157289, 127017, 104534, 161046, 186408, 109147]::int[], ARRAY[254172, 210257]::int[], ARRAY[376576, 325090, 340173, 353362, 353341, 332661, 355777, 385892, 388428, 342313, 376972, 384469, 346285, 333280, 399221, 305555, 338070, 368634, 316142, 351400, 372410, 378944, 345833, 346321, 330891, 367079, 313852, 343050, 386000, 341989, 376542, 376138, 372796, 380006, 331590, 345250, 390168, 351918, 335266]::int[], ARRAY[466173, 456043, 413957, 413637, 463204, 469002, 415920, 472666, 463600, 451901, 408375, 477062, 417800, 469041, 421772, 487263, 434315, 428102, 493673, 418597, 493574, 415358, 494878, 494400, 421970, 402511, 462272, 411988]::int[], '2024-10-25 03:38:00 UTC', '2024-10-18 16:41:00 UTC', '2024-10-14 13:43:00 UTC'),
+('hollie.wuxyqfy4@example.org', '谭睿博', 'female','2023-07-23 08:21:00 UTC', '2024-10-24 10:25:00 UTC', '2024-10-16 14:36:00 UTC', ARRAY[129960, 158379, 130733, 197783, 191098, 191515, 152250, 119406, 185908, 141088, 194625, 176750, 155301, 168169, 175216, 191268, 168981, 111426, 171819, 136016, 123390, 108894, 186153, 159638, 107913, 163695, 111583, 126549, 103212, 196538, 106534, 185494, 194736, 114821, 155315, 142183]::int[], ARRAY[261737, 260065, 211754, 275531, 218049, 226046, 258936, 221338, 238934, 280118]::int[], ARRAY[329149, 341624, 348322, 378073, 321471, 300682, 395438, 317453, 332084, 344671, 395891, 312171, 352140, 385614, 305763, 302254, 386276, 314552, 377779, 383557, 303228, 372819, 395297, 359594, 301165, 304653, 394221, 349755, 359940, 309281, 338150, 365509, 398038, 379612, 312815, 314093, 370449, 378015, 379093, 346146, 301555, 393092, 334830, 375418, 354746]::int[], ARRAY[490050, 482477, 466792, 415528, 464891, 490588, 469344, 465137, 482513, 415873, 461567, 454198, 412666, 457406]::int[], '2024-09-24 21:53:00 UTC', '2024-10-31 05:47:00 UTC', '2024-10-01 07:06:00 UTC'),
+('edwin.1fn0w56u@example.org', '冯安荷', 'male','2020-03-05 13:15:00 UTC', '2024-10-25 18:21:00 UTC', '2024-09-25 14:46:00 UTC', ARRAY[164196, 154712, 165310, 177475, 180831, 148534, 166292, 113346, 185147, 120902, 112915, 104050, 188228, 184137, 161499, 113245, 118310, 184963, 147522, 121302, 130299, 103271, 161781, 188843, 114810, 116425, 128597, 113323, 136841, 160270, 167493, 113506]::int[], ARRAY[257793, 204367, 288187, 229933, 262783, 263589, 274874, 229439, 227797, 241801, 298831, 200670, 269504, 272257, 223933, 216761, 252663, 263868, 292783, 224000, 283969, 252025, 287780, 270637, 212471, 247479, 294465, 266775, 210701, 296379, 200395, 268307, 252727, 268344, 249444, 295685]::int[], ARRAY[396753, 340085, 367503, 399192, 360321, 396671, 383539, 340142, 354430, 361276, 370386, 385622, 382617, 343505, 338543, 326889, 324696, 338080, 307784, 337847, 302289, 381655]::int[], ARRAY[476756, 476034, 470591, 470626, 495010, 426357, 412624, 421908, 443180, 435846, 492215, 462493, 410531, 447908, 402139, 493745, 459254, 484442, 494919, 424610, 421801, 433645, 408876, 434284, 476501, 495241, 491855, 417205, 457080, 416038, 401676, 455098, 494174, 450221, 492498, 491212, 463188, 404124, 410458, 481663, 462621, 483547, 407146, 433219, 483268]::int[], '2024-10-23 06:26:00 UTC', '2024-10-28 17:23:00 UTC', '2024-08-29 01:17:00 UTC'),
+('brandyn.mbkio9f5@example.net', '夏昌贤', 'male','2023-02-27 07:28:00 UTC', '2024-10-08 07:01:00 UTC', '2024-08-10 12:37:00 UTC', ARRAY[108916, 188295, 137296, 168903, 188064, 119363, 132539, 149955, 198765, 179587, 195497, 148679, 165559, 132403, 184919, 161666, 188691, 199057, 112315, 160391, 121936, 158330, 177370, 110538, 144607, 161812, 107782, 150108, 129829, 136582, 186534, 181313, 141263, 138638, 197160, 146677, 180386, 118912, 177636, 155930]::int[], ARRAY[285177, 264891, 241115, 225333, 271714, 298944, 201518, 214426, 200074, 282503, 268390]::int[], ARRAY[358857, 317661, 320418, 347509, 318866, 330976, 387371, 301806, 322533, 364251, 354796, 329987, 331589, 328158, 373818, 361784, 380659, 391475, 379163, 313618, 394640, 317437, 382566, 377602, 315113, 397788, 367913, 362919, 357599, 319004, 348442, 386845, 340707, 338776, 391506, 368561, 305239, 316681, 364069, 310916]::int[], ARRAY[490095, 405606, 480407, 449758, 450278, 426824, 442957, 467221, 427628, 465082, 493401, 490335, 467364, 403339, 486379, 484732, 417560, 431610, 464435, 466514, 467098, 480679, 476386, 402947, 488344, 426293, 461365, 422455, 416807, 416059, 466900, 443719, 478237, 479789, 405927, 416387, 493784, 422458, 490276, 445363, 412726, 491850, 466506, 409277, 444525, 420946, 486608, 489170]::int[], '2024-09-18 06:56:00 UTC', '2024-10-30 21:36:00 UTC', '2024-09-27 20:02:00 UTC'),
+('ernestine.u1wvzkji@example.net', '夏俊帆', 'female','2022-11-04 15:29:00 UTC', '2024-10-30 20:00:00 UTC', '2024-09-05 17:38:00 UTC', ARRAY[136788, 107982, 117243, 120735, 113910, 105945, 134584, 134558, 185173, 192265, 118278, 187543, 147646, 168753, 156300, 191360, 195792, 169516, 145459, 106707, 182593, 179391, 115365, 182273, 174443, 187365, 121983, 154215, 160029, 128980, 125159, 148292, 151223, 110768, 131873, 157470, 152879, 154259, 111964, 137677, 136247, 170514]::int[], ARRAY[255953, 288973, 268690, 274407, 249538, 296229, 256062, 299371, 264176, 260007, 237102, 277133, 284165, 200795, 290615, 242802, 245691]::int[], ARRAY[377244, 372669, 368116, 361011, 330978, 345425, 394733, 337206, 378557, 327929]::int[], ARRAY[404194, 435785, 480669, 473614, 415168, 415719, 465738, 486764, 424102, 408282, 436442, 464551, 496240, 417238, 472115, 474219, 438550]::int[], '2024-10-19 07:25:00 UTC', '2024-10-29 06:02:00 UTC', '2024-10-02 21:56:00 UTC'),
+('chesley.wr1ip1kb@example.net', '方哲瀚', 'unknown','2020-07-20 16:59:00 UTC', '2024-10-12 10:29:00 UTC', '2024-10-25 14:56:00 UTC', ARRAY[178021, 104811, 167573, 176921, 124306, 111514, 110559, 134265, 186200, 138994, 122047, 163827, 191038, 157337]::int[], ARRAY[284587, 218639, 242506, 238084, 299857, 280124, 207954, 249067, 282678, 294258, 241007, 203640, 261594, 226763, 245324, 222034, 252201, 259023, 244158, 225310, 209847, 291687, 256349, 225968, 209255, 292711, 279918, 298031, 268554, 249099, 279503, 233608, 230806, 268835, 254956, 243781, 270385, 226145, 299800, 227864]::int[], ARRAY[335508, 391553, 382224, 336203, 334194, 333772, 383555, 365057, 351546, 324265, 328975, 382360, 335074, 346490, 331007, 329135, 353013, 323078, 384238, 380538, 377863, 397965, 389713, 350767, 387977, 360547, 383657, 301601, 382074, 389814, 300555, 352373, 369362, 324660, 339202, 329782, 315680, 368728, 335936, 373155, 336812, 388422, 344210, 371943, 385892]::int[], ARRAY[492242, 473981, 429734]::int[], '2024-10-17 04:08:00 UTC', '2024-10-28 21:01:00 UTC', '2024-09-18 00:34:00 UTC'),
+('kiarra.bogddjre@example.com', '钟瀚君', 'unknown','2024-08-31 00:25:00 UTC', '2024-10-20 18:00:00 UTC', '2024-09-18 03:13:00 UTC', ARRAY[129653, 120863, 121473, 117129, 122098, 127481, 147183, 131464, 160843, 157225, 119710, 132422, 129666, 111548, 131719, 188171, 124367, 132217, 134306, 194375, 152413, 197228, 104608, 122671]::int[], ARRAY[293139, 253371, 241131, 203102, 204296, 206029, 205622, 218379, 262441, 255763, 259939, 215122, 212932, 288188, 277754, 200472, 267459, 236117, 231485, 208990, 245080, 272857, 294484, 248104, 250800, 230525, 299654, 248037, 200980, 284650, 234921, 209227, 245825, 224876, 252498]::int[], ARRAY[367140, 381281, 306566, 375577, 361837, 318635, 366698, 371745, 393344, 361468, 368175, 322285, 358791, 360537, 325949, 345783, 382709, 325129, 395254, 345751, 334948, 397459, 341875, 353111, 315468, 347585, 336200, 378215, 357944, 312257, 306861, 343119, 359383, 393680, 314180, 346152, 356882, 361626, 352981, 342791, 365762, 330565, 302835, 317871, 380471, 343312, 336595, 343565]::int[], ARRAY[440935, 430002, 422146, 415198, 462334, 410368, 423998, 428739, 433148, 425632, 402875, 491579, 412332, 418999, 444904, 459774, 450569, 452719, 464518, 424991, 484586, 469867, 427950, 401126, 460150, 464570, 481695, 432611, 445334, 406355, 463136, 415654, 427282, 447093, 436119, 427122, 411948, 422552, 454073, 499726, 417163, 498816, 484171, 491151, 436519, 457729, 456219, 451131, 488559]::int[], '2024-09-23 22:26:00 UTC', '2024-10-30 22:37:00 UTC', '2024-09-16 06:06:00 UTC'),
+('craig.jwzvl3jv@example.org', '冯博尘', 'male','2021-07-14 06:41:00 UTC', '2024-10-05 06:53:00 UTC', '2024-09-09 00:27:00 UTC', ARRAY[144934, 131722, 105875, 178360, 112156, 105991, 156126, 131860, 184354, 195348, 108693, 167959, 112772, 161973, 193134, 117437, 141501, 129151, 139902, 102166, 106274, 126260, 164308, 186216, 122751, 111300, 182245, 139013, 172657, 190456, 105565, 163087, 102693, 141413, 104895, 179103, 180971, 137613, 199848, 156202, 168750, 165049, 119250, 168304, 145908, 157069, 136279]::int[], ARRAY[255635, 264560, 240289, 264880, 287291, 289464, 247859, 216249, 284607, 220947, 269860, 207230, 245897]::int[], ARRAY[314549, 357464, 300055, 367342, 362332, 375941, 389954, 356433, 340507, 314454, 344066, 341182, 371903, 375394, 386747, 337819, 336777, 372996, 320840, 379155, 373134, 300742, 349714, 337578, 373041, 392272, 317959, 393326, 349265, 369470, 366673, 363342, 333983, 300084, 367544, 344219, 364389, 318574]::int[], ARRAY[487286, 446408, 466383, 406716, 454885, 442045, 409153, 455997, 456679, 403603, 412756, 420020, 479425, 482409, 452696, 492347, 465921, 413153, 487719, 433952, 429295, 433021]::int[], '2024-09-22 05:38:00 UTC', '2024-10-21 21:19:00 UTC', '2024-09-30 23:31:00 UTC'),
+('rozella.5d7f7amp@example.org', '萧芳虹', 'female','2021-08-02 12:18:00 UTC', '2024-10-31 13:37:00 UTC', '2024-10-06 01:28:00 UTC', ARRAY[192313, 149999, 136718, 154206, 112939, 198324, 134406, 192345, 177308, 136238, 163463, 196571, 121130, 105413, 127205, 149924, 196386, 187636, 172723, 186299, 188241, 129807, 146039, 152415, 189314, 113843, 183743, 180925, 184034, 190794, 136689, 161031]::int[], ARRAY[216358, 225658, 273927, 267398, 275003, 284790, 287811, 223969, 295869, 282611, 269648, 229777, 227877, 296972, 258480, 219848, 257916, 232173, 286722, 241740, 205186, 207982, 261491, 298528, 208137, 254681, 247900, 206088, 207428, 274545, 224374, 264853, 237452]::int[], ARRAY[319292, 308680, 302255, 312761, 360354, 338832, 333802, 320645, 347212, 345930, 339452, 331296, 372211, 348773, 369152, 313966, 308125, 332012, 353408, 349088, 325248, 392917, 383579, 356969, 365032, 355647, 351237, 373532, 358325, 302489, 361909, 316941, 353055, 365925, 316096, 377913, 344203, 372754, 353596, 317110, 389846, 317487, 314439, 367853, 307298, 323599, 318983, 343054]::int[], ARRAY[455176, 445710, 417347, 434054, 459508, 442431, 401880, 428491, 439884, 466461, 424446, 421285, 476155, 489719, 496603]::int[], '2024-10-10 17:21:00 UTC', '2024-10-22 08:51:00 UTC', '2024-08-03 01:34:00 UTC'),
+('marilie.5brfvgne@example.com', '苏辰逸', 'unknown','2020-08-08 18:18:00 UTC', '2024-10-13 14:46:00 UTC', '2024-10-05 02:34:00 UTC', ARRAY[172439, 120948, 115368, 151022, 114903, 129426, 110456, 132596, 197994, 189296, 120382, 176286, 128387, 170419, 136471, 188905, 148568, 170062, 122943, 109751, 172287, 199607, 109792, 127468, 137902, 180058, 146526, 186399, 181107, 112596, 133262, 180730, 114592, 162860, 161941, 196631, 117730, 169800, 117835, 125839, 149760, 167023, 139278, 136883, 152631, 155960, 135749, 194550]::int[], ARRAY[299079, 217996, 220755, 291659, 272869, 234669, 210895, 223961, 217475, 247432, 250302, 290357, 250018, 235668, 200296, 222539, 240260, 216188, 216149, 265064, 270789, 241208, 215725, 268107, 296097, 212941, 294000]::int[], ARRAY[344834, 300475, 336846, 335574, 338663, 333478, 389771, 367852, 328000, 352025, 332808, 338097, 371001, 345554, 342560]::int[], ARRAY[470382, 444175, 462005, 468385, 416861, 475244, 498151, 446038, 457716, 409158, 497906, 415791, 400291, 436718, 462518, 470752, 416889, 484660, 487492, 404759, 494745, 499356, 407085, 464002, 428435, 492221, 434481, 404285]::int[], '2024-10-03 14:31:00 UTC', '2024-10-20 16:50:00 UTC', '2024-09-04 09:55:00 UTC'),
+('anahi.6eht4xc7@example.com', '段文兴', 'male','2023-04-23 02:43:00 UTC', '2024-10-20 19:28:00 UTC', '2024-10-14 02:02:00 UTC', ARRAY[127293, 173541, 137504]::int[], ARRAY[238901, 253293, 211266, 255124, 247770, 207181, 259454, 225440, 291569, 292452, 237675, 228246, 238082, 256561, 251898, 296496, 211540, 242482, 297658, 237837, 207332]::int[], ARRAY[311714, 320013, 392231, 352845, 374346, 351152, 390228, 339957, 345967, 358099, 339420, 393266, 375468, 382150, 312719, 384026, 305810, 393297, 352322, 313273, 324436, 399148, 374772, 349518, 347789, 394530, 308028, 308146, 370693, 397293, 307423, 372007, 391251, 378056, 387797, 377985]::int[], ARRAY[499988, 472186, 435362, 467201, 474097, 470460, 448384, 413128, 434120, 414510, 465925, 405639, 480778, 427093, 443761, 472889, 473633, 469290, 452165, 463961, 475730, 466491, 407714, 439848, 411503, 471672, 456148, 490943]::int[], '2024-09-24 07:20:00 UTC', '2024-10-28 21:41:00 UTC', '2024-10-18 17:13:00 UTC'),
+('polly.lki9r819@example.net', '唐瀚福', 'female','2023-09-26 00:02:00 UTC', '2024-10-31 04:28:00 UTC', '2024-08-13 05:55:00 UTC', ARRAY[130008, 192630, 105670, 113667, 149362, 184759, 126066, 172244, 160702, 110931, 118605, 108506, 135154, 161853, 188984, 150014, 170908, 154950, 139772, 184767, 171623, 178719, 192362, 197002, 112863, 160120, 181429, 112658, 143788, 159126, 173882, 101073, 164164, 135467, 104180, 107098, 146192, 116174, 124176, 142920, 175349, 187438, 127005, 168832, 171273, 118044, 121654]::int[], ARRAY[295044, 272158, 202844, 299182, 267703, 232219, 231005, 207368, 279938, 205047, 212710, 251607, 219378, 246690, 243615, 203157, 204541, 271778, 220864, 209093, 232636, 204269, 222908, 254518, 222341, 290006, 214365, 260347, 233732, 294880, 212747, 292491, 263371, 280501, 298711, 279543, 246266, 229426, 249186, 279666, 261397, 209743]::int[], ARRAY[346376, 338122, 364180, 364965, 344502, 396445, 305887, 318365, 332746, 376055, 352912, 300490, 311707, 337833, 383471, 305193, 327413, 363100, 331845, 344468, 319396]::int[], ARRAY[447783, 411262, 453557, 452627, 428372, 468304, 454875, 459386, 496998]::int[], '2024-10-24 05:39:00 UTC', '2024-10-29 04:02:00 UTC', '2024-08-08 10:45:00 UTC'),
+('noe.0iweimxz@example.com', '董秋风', 'male','2022-03-03 07:37:00 UTC', '2024-10-18 16:17:00 UTC', '2024-08-25 05:19:00 UTC', ARRAY[158528, 195311, 126036, 119613, 160095, 133787, 174591, 154748, 122344, 115906, 178615, 176947, 153803, 131783, 169982, 172620, 177849, 161260]::int[], ARRAY[201185, 294849, 299891, 293839, 248674, 286559, 253026, 299595, 276010, 236524, 238855, 211951, 238199]::int[], ARRAY[320624, 398921, 331570, 322027, 310938, 343719, 301951, 397852, 346438, 374461, 350633, 331386, 326688, 386525, 342554, 379204, 392336, 349685, 371988, 361399, 304221, 392798, 362980, 399869, 381447, 356987, 343946]::int[], ARRAY[411534, 432264, 439947, 451332, 401533, 453734, 417480, 467692, 465000, 452903, 429201, 438126]::int[], '2024-10-11 21:12:00 UTC', '2024-10-22 23:25:00 UTC', '2024-08-08 04:57:00 UTC'),
+('deshawn.1j653nah@example.org', '任杰宏', 'male','2020-05-08 22:47:00 UTC', '2024-10-14 15:30:00 UTC', '2024-10-18 00:15:00 UTC', ARRAY[133218, 117823, 167360, 120887, 190687, 112915, 183647, 130929, 145254, 114800, 133767, 102576, 154487, 189210, 133031, 132970, 119057, 101588, 125703, 123399, 168751, 104874, 182162, 169727, 183649, 135536, 162915, 178698, 174301, 158374, 165056, 181196, 194370, 172563, 101842, 136742]::int[], ARRAY[263918, 206881, 226978, 234557, 218162, 253821, 236291, 203863, 205980, 297687, 258988, 296366, 297492, 257588, 277720, 229877, 215478, 236392, 261330, 206483, 270546, 209150, 270653, 264005, 234985, 270421, 280794, 273904, 234410, 281020, 207313, 222255, 253416, 203905, 255686, 274363, 203301, 244990, 251982]::int[], ARRAY[]::int[], ARRAY[423039, 446679, 402495, 431030, 437316, 472068, 488781, 480568, 491955, 485556, 469791, 498829, 432002, 498988, 434715, 419687, 461956]::int[], '2024-10-27 20:37:00 UTC', '2024-10-31 04:19:00 UTC', '2024-10-30 00:09:00 UTC'),
+('anastasia.cwog84tg@example.com', '金鼎鸿', 'male','2020-12-08 17:10:00 UTC', '2024-10-13 15:14:00 UTC', '2024-10-31 18:04:00 UTC', ARRAY[137414, 101083, 137818, 158196, 126863, 112007, 171711, 191422, 101590, 133967, 109002, 177492, 107666, 119873, 128072, 148808, 192456, 132270, 104038, 151721, 102077, 161143, 110112, 193192, 107682, 165435, 163803, 173637, 133788, 190007, 178242, 137633, 184617, 120946]::int[], ARRAY[293506, 206646, 222760, 215380, 259057, 241696, 290790, 288574, 234423, 210876, 246675, 225804, 224913, 205052]::int[], ARRAY[320286, 396460, 305649, 395918, 329789, 322808, 380424, 386219, 316710, 392209, 354583, 331514, 350942, 349752, 362299, 345144, 301340, 369447, 349111, 368546, 311739, 316077, 330032]::int[], ARRAY[441415, 479860, 479416, 419757, 486769, 473866, 489978, 422592, 444982, 480015, 454387, 444599, 473326, 496604, 484176, 409253, 492645, 410984, 424257, 439808, 431089, 437441, 460385, 404274, 458620, 491655, 490509]::int[], '2024-10-06 18:13:00 UTC', '2024-10-26 17:51:00 UTC', '2024-08-17 12:32:00 UTC'),
+('quinten.hnh92rjy@example.net', '沈幻丝', 'unknown','2023-03-18 14:37:00 UTC', '2024-10-07 07:55:00 UTC', '2024-10-01 04:14:00 UTC', ARRAY[188312, 193160, 117135, 127589, 160568, 179696, 167578, 110967, 199701, 108765, 157972, 123696, 174576, 183444, 150367, 118238, 187569, 122377, 178410, 106879, 117332, 119682, 173510, 107949, 198706, 155806, 170295, 146802, 113808, 127276, 148954, 118639, 164370, 106520, 195505, 181078, 101329]::int[], ARRAY[275900, 240886, 263758, 295037, 216307, 207499, 213602, 210554, 232913, 285602, 243577, 234805, 213836, 255093, 258316, 286752, 296941, 298593, 251256, 246305, 245625, 224407, 223858, 242187, 241399, 221968, 200662, 287339, 252246, 230264, 244080, 259485, 228642, 241740, 250851, 289000, 252526, 269205, 233501, 269238, 224871, 258943, 249640, 298038, 227453]::int[], ARRAY[305314, 351413, 371872, 340292, 387852, 357854, 329856, 384018, 313619, 323510, 369095, 393856, 336994, 356419, 398589, 309923, 379287, 365691, 365930, 373588, 380381, 319650, 361860, 392096, 304987, 320513, 326714, 340059, 379799, 329237, 308020, 300415, 359805, 328846, 302240, 385539]::int[], ARRAY[]::int[], '2024-09-25 22:57:00 UTC', '2024-10-24 14:04:00 UTC', '2024-08-27 08:27:00 UTC'),
+('alvera.bniv2u4k@example.com', '赵婷莉', 'male','2024-03-09 18:45:00 UTC', '2024-10-19 11:39:00 UTC', '2024-10-08 14:41:00 UTC', ARRAY[177750, 159253, 180420, 196339, 135113, 129793, 167449, 157517, 142695, 135035, 170525, 188859, 137546, 163287, 157466, 131513, 113698, 131348, 189497, 108742, 101790, 127541, 186039, 113393, 178958, 189041, 143214, 149754, 113221, 102690, 121692]::int[], ARRAY[233460, 299787, 231036, 217569, 246038, 239806, 256044, 212062, 210245, 206548, 252664, 281651, 283460, 222706, 259087, 247945, 271944, 223239]::int[], ARRAY[305775, 315672, 394444, 353127, 355207, 333126, 391466, 376400, 372666, 386048, 388756]::int[], ARRAY[480410, 418631, 448143, 401917, 430658, 499906, 400290, 418888, 467887, 417778, 464409, 469640, 422323, 434113, 418268, 459179, 490042, 429457, 457496, 403306, 401421, 434313, 482599, 407986, 475458, 453117, 491761, 445067, 457712, 445071, 478173, 444121, 479811, 424760, 400787, 494805, 421800, 445126, 417181, 455703, 404969, 444772]::int[], '2024-10-12 10:36:00 UTC', '2024-10-22 22:02:00 UTC', '2024-09-05 18:40:00 UTC'),
+('mary.npoumaov@example.com', '潘晋刚', 'female','2021-05-20 20:29:00 UTC', '2024-10-02 10:14:00 UTC', '2024-08-04 12:05:00 UTC', ARRAY[161931, 148997, 102125, 119937, 188799, 197967]::int[], ARRAY[256777, 239112, 265582, 258367, 227188, 287674, 223647, 235809, 279339, 220402, 262917, 259996, 262104, 261482, 231030, 211865, 267441, 223266, 293150]::int[], ARRAY[378798, 315403, 302011, 320533, 315470, 330230, 391481, 390414, 332620, 399748, 302320, 344441, 349939, 350323, 311523, 396217, 302740, 399510]::int[], ARRAY[498532, 403868, 455223, 405300, 423025, 445435, 493329, 451253, 459657, 451582, 470231, 408779]::int[], '2024-10-27 06:07:00 UTC', '2024-10-31 00:27:00 UTC', '2024-08-22 15:06:00 UTC'),
+('gerardo.1sak590y@example.net', '邵璇盈', 'unknown','2020-10-04 05:33:00 UTC', '2024-10-03 01:03:00 UTC', '2024-08-29 14:29:00 UTC', ARRAY[103997, 118179, 137924, 111598, 148667, 122347, 167314, 193163, 146697, 103612, 168041, 164614, 123367, 139741, 142489, 110037, 151866, 108505, 124877, 184177, 170902, 179607, 160454, 174778, 116290, 171791, 128465, 134411, 129574, 197492, 196687, 149699, 146625, 149965, 105856, 167125, 112696, 187645, 113235, 168962, 189528, 104473, 104269]::int[], ARRAY[256672, 238169, 219177, 201511, 246444, 277824, 209777, 241258, 253233, 273025, 263205, 216334, 289719, 275675, 270130, 260229]::int[], ARRAY[385375, 344906, 349166, 331763]::int[], ARRAY[442822, 488859, 474132, 424265, 497996, 480650, 422214, 459485, 497948, 465529, 404559, 495162, 456414, 428543, 411195, 449532, 418018, 471535, 408223, 418080, 483204, 414488, 419406, 456972, 481663, 491078, 491329, 464325, 470033, 486176, 480615, 499937, 461923, 448588, 478126, 495543, 493048, 420541]::int[], '2024-10-24 10:32:00 UTC', '2024-10-20 23:39:00 UTC', '2024-10-11 16:39:00 UTC'),
+('virginie.gxifaffg@example.net', '朱展瀚', 'female','2024-03-14 01:07:00 UTC', '2024-10-12 23:10:00 UTC', '2024-08-27 18:10:00 UTC', ARRAY[190475, 177506, 175532, 149903, 176305, 119012, 178196, 126851, 143656, 127415, 159816, 173859, 162221, 124996, 122835, 101994, 125872, 180781, 152533, 182170, 183903, 160371, 161098, 139933, 141715, 189097, 187853, 193776, 103755, 160604, 129247, 137410, 197019, 181148, 116870, 158674, 134174, 175823, 150241, 110543, 174271, 181510, 187747, 135393, 177591, 141003, 117347]::int[], ARRAY[253040, 224564, 229154, 225070, 297206, 294384, 248035, 268004, 207712, 288517, 251249, 286457, 246484, 235852, 255078, 249923, 205640, 268876, 297743, 239644, 235740, 285599, 212090, 220361, 247329, 222203, 216891, 219456, 211027, 282061, 209880, 276496, 288076]::int[], ARRAY[365835, 314883, 303781, 346755, 360327, 309872, 329754, 388171, 329228, 318358, 313385, 376102, 309605, 362139, 367321, 324672, 354632, 375884, 310800, 303531, 346375, 382971, 306562, 321842, 352166, 366015, 365723, 315727, 383450, 337309, 378103, 337963, 341201, 310528, 370579, 314792, 363286, 335071, 316631, 363726]::int[], ARRAY[400550, 439530, 441790, 489774, 402241, 465702, 451236]::int[], '2024-10-19 14:18:00 UTC', '2024-10-28 09:40:00 UTC', '2024-10-05 09:00:00 UTC'),
+('chadd.l54sxnlv@example.net', '顾鼎佑', 'unknown','2024-04-06 18:08:00 UTC', '2024-10-10 23:19:00 UTC', '2024-10-16 13:52:00 UTC', ARRAY[144297, 179347, 163536, 100298, 187591, 168719, 160129, 199627, 167253, 119006, 122060, 106919, 174328, 122324, 177412, 169052, 144022, 175431, 122446, 112257, 104463, 124762, 137720, 180199, 171644, 189662, 153202, 141711, 175315, 111697, 164581, 106978, 154815, 146529, 106998, 185931, 174801]::int[], ARRAY[206601, 217747]::int[], ARRAY[311022, 348767, 306066, 328058, 300185, 385904, 367558, 371460, 357383, 303684, 300208, 353296, 384935, 330198, 330587, 342210, 377968, 359555, 351648, 314907, 382218, 341559, 324335, 356900, 333733, 349683, 377677, 317682, 301672, 311712, 334435, 324093, 387829, 307605, 386893, 338920, 336486, 341283]::int[], ARRAY[439913, 464930, 461662, 420158, 423752, 472883, 411385, 451974, 491520, 492556, 433497, 441495]::int[], '2024-10-17 01:03:00 UTC', '2024-10-24 12:47:00 UTC', '2024-08-06 08:56:00 UTC'),
+('lexie.uc0x966f@example.net', '田琪涵', 'male','2022-03-26 18:14:00 UTC', '2024-10-03 19:02:00 UTC', '2024-08-10 12:28:00 UTC', ARRAY[107102, 194574, 100563, 183052, 110214, 132736, 149996, 154341, 159949, 144201, 100152, 180948, 143367, 166856, 128250, 159386, 183444, 186362, 109409, 110755, 147391, 170089, 170132, 169826, 183651, 166601, 174373, 183383, 185939, 167910, 113569, 129807, 147865, 117642, 171650, 171970]::int[], ARRAY[222090, 240695, 243306, 286791, 218751, 272249, 288986, 297451, 246424, 261621, 261495, 218760, 209504, 289240, 234634, 203398, 256812, 200421, 269594, 211784, 292975, 261444, 263509, 292407, 245799, 247932, 239200, 239561, 226372, 225168, 244855, 296833, 262100, 273216, 265230, 208564, 243252, 291758, 244507, 227531, 256957, 290697, 243279]::int[], ARRAY[300726, 373475, 301536, 330666, 395859, 382744, 307705, 398919, 376179, 329117, 331356, 341504, 305930, 345968, 316801, 324060, 313814, 379514, 304385, 356468, 387676, 356913, 330214, 324408, 381711, 308805, 396155, 374717, 350274, 371126, 352690, 353411, 347268, 374863, 303447, 318453, 330778]::int[], ARRAY[459761, 462378, 424065, 484369, 425908, 407233, 423793, 471129, 407078, 474980, 412590, 491576, 453461, 479253, 417254, 493289, 450417, 443853, 489343, 429234, 497738, 455036, 417503]::int[], '2024-10-21 15:47:00 UTC', '2024-10-17 07:35:00 UTC', '2024-08-15 01:06:00 UTC'),
+('doug.iyvc015r@example.org', '宋鼎风', 'male','2024-02-11 05:09:00 UTC', '2024-10-17 07:06:00 UTC', '2024-09-08 01:27:00 UTC', ARRAY[117937, 110249, 125592, 181704, 108844, 194383, 152625, 194448, 114943, 142525, 125850, 190132, 153000, 113710, 192941, 174440, 143994, 131067, 104503, 186917, 127824, 118229, 163568, 132676, 174246, 174442, 116811, 175304, 102312, 105237, 122162, 131822, 127236, 131299, 153464]::int[], ARRAY[238117, 286491, 295527, 254632, 230570]::int[], ARRAY[366403, 368359, 345727, 378182, 363620, 381252, 384699, 328828, 398833, 361332, 358846, 328648, 388639, 378617, 325721, 368806, 321995, 351274, 389906, 347380, 378174, 348038, 371374, 398384, 378926, 359796, 308213, 390490, 378602, 388626, 357044, 382106]::int[], ARRAY[492861, 423177, 446897, 479499, 411432, 424398, 404408, 476891, 487874, 466122, 445031, 430294, 425008, 495149]::int[], '2024-10-17 14:20:00 UTC', '2024-10-22 06:24:00 UTC', '2024-08-10 18:27:00 UTC'),
+('keagan.b51zg6c7@example.org', '袁娅楠', 'female','2022-06-06 13:40:00 UTC', '2024-10-31 16:16:00 UTC', '2024-08-25 05:39:00 UTC', ARRAY[144302, 125620, 158419, 115560, 194214, 144330, 106434, 155460, 187228, 176102, 130706, 150313, 182318, 155589, 193305, 172630, 184955, 128831, 189046, 169188, 134288, 174035, 158496, 195755, 172741, 189783, 136584, 121968]::int[], ARRAY[256280, 254281]::int[], ARRAY[325384, 327606, 376014, 304910, 385854, 342422, 301594, 399832, 341970, 354851, 391190, 347320, 355343, 326285, 378836, 350186, 351920, 393827, 345510, 350239, 392838, 302660, 332995, 398670, 350365, 398924, 397791, 337138, 341543, 342888, 381326, 321270, 352276, 301980, 317150, 348094, 359968, 376428, 313145, 360560, 377217, 351583, 303060, 344557]::int[], ARRAY[464702, 451822, 480880, 417560, 437321, 499155, 427867, 494346, 415004, 495458, 451320, 429977, 407057, 405044, 461499, 480553, 400220, 451659, 466120]::int[], '2024-09-21 05:15:00 UTC', '2024-10-29 22:23:00 UTC', '2024-10-02 02:55:00 UTC'),
+('kyra.9fm6in4f@example.org', '袁静璇', 'female','2023-05-30 09:55:00 UTC', '2024-10-27 13:47:00 UTC', '2024-10-25 23:28:00 UTC', ARRAY[101309, 176142, 192161, 100495, 199313, 115103, 118413, 162455, 125127, 100083, 129555, 105572, 153192, 173364, 148908, 186007, 189484, 110992, 183604, 146460, 148271, 196838, 174043, 122513, 115288, 143987, 186744, 181225, 163387, 148884, 185750, 158653, 180048, 188669, 111855, 147565, 127349, 105730, 174131, 161334, 159725, 144895, 109421]::int[], ARRAY[207734, 268629, 215143, 293365, 251118, 252526, 286913, 276119, 273546, 244246, 293622, 285082, 221340, 264506, 225470, 269808, 267799, 297531, 264048, 263679, 292721, 293465, 214000, 293930, 228237, 215641, 263036, 243776, 243354, 213115, 220828, 239044, 206654, 246939, 212089]::int[], ARRAY[327874, 380423, 373107]::int[], ARRAY[426493, 417700, 461219, 443129, 412797, 477323, 422456, 466319, 463830, 448088]::int[], '2024-09-19 07:59:00 UTC', '2024-10-18 06:28:00 UTC', '2024-08-20 09:44:00 UTC'),
+('lelah.0177ijpj@example.net', '史晋运', 'unknown','2020-05-31 07:17:00 UTC', '2024-10-20 08:57:00 UTC', '2024-08-27 22:44:00 UTC', ARRAY[145202, 119724, 155052, 195471, 113354, 122497, 116888, 119191, 144360, 178158, 146057, 196993, 116822, 182081, 127397, 175203, 189031, 118670, 169873, 117189, 161812, 199339, 111130, 140003, 110325, 144651, 178290, 108311, 168645, 168894, 123151, 109238, 119085, 124062, 162620, 128129, 196398, 133196, 172073, 173705, 185121, 151748]::int[], ARRAY[289663, 277620, 289745, 251234, 233347, 296746, 247359, 261104, 297690, 214437, 275957, 225324, 216630, 243298, 258354, 279715]::int[], ARRAY[367421, 357008, 352789, 353687, 391939, 303522, 378763, 318602, 360316, 303689, 372177, 394574, 317790, 330706, 307068, 303243, 363860, 330485, 308276, 320597, 328911, 310884, 341513, 313017, 325714, 311251, 305458, 320246, 339073, 396603, 341151, 394729, 311108, 370144, 372812, 317577, 311108, 307587, 332041, 386709, 314351, 384843, 378425, 304533, 395899]::int[], ARRAY[420737, 490882, 446364, 478775, 488124, 482279, 454163, 401570, 444389, 450255, 467752, 463032, 489092, 452993, 400044, 417859, 413364, 423115, 415042, 445595, 468512, 496999, 488599, 440831, 468261]::int[], '2024-09-22 22:08:00 UTC', '2024-10-26 03:10:00 UTC', '2024-10-10 12:21:00 UTC'),
+('armand.vst1kv0n@example.org', '吴哲瀚', 'unknown','2021-02-22 01:50:00 UTC', '2024-10-07 05:07:00 UTC', '2024-10-26 20:47:00 UTC', ARRAY[166157, 156285, 176939, 194500, 105198, 161559, 162272, 120556, 162047, 196299, 120337, 158695, 138858, 138366, 130355, 184694, 103288, 158691, 198820, 151844, 162430, 130983, 121455, 138722, 130851, 150089, 150230, 122204, 169241, 191791, 172768, 178520, 167978, 118675, 139527, 126125, 189107, 153295, 157095, 175260, 165503, 158017, 155271, 157182, 138217, 139776, 121191, 186060]::int[], ARRAY[285843]::int[], ARRAY[340091, 365584, 363997, 388046, 330405, 383886, 391363, 302184, 352154, 302020, 349743, 312302, 367303, 348949, 327892, 345790, 371424, 394796, 336828]::int[], ARRAY[450288, 470039, 496578, 410814, 482504, 482730, 461163, 406995, 434414, 408753, 490375, 486091, 486341, 461349, 479880, 405383, 431687, 449778, 416185, 474366, 485050, 432232, 449732, 447451, 444322, 435458, 482077, 486039, 418440, 452821, 439144, 493007, 447428, 476436, 429332, 471845, 405395, 488464, 452636, 436648, 401497, 412886, 406463, 427728]::int[], '2024-10-16 06:28:00 UTC', '2024-10-17 14:01:00 UTC', '2024-08-17 18:19:00 UTC'),
+('marcia.ox3empa1@example.net', '莫菲霞', 'unknown','2019-11-12 16:26:00 UTC', '2024-10-31 01:55:00 UTC', '2024-10-29 10:09:00 UTC', ARRAY[189380, 164115, 115980, 128204, 146078, 164316, 178489, 124892, 160504, 140536, 104521, 176751, 196489, 120700, 154024, 141109, 135073]::int[], ARRAY[270943, 282598, 239087, 249063, 260031, 204402, 233645, 247100, 209611, 201382, 218513, 229217, 216703, 241441, 261806, 204328, 265377, 218131, 233504, 290125, 293070, 232851, 205269, 258436, 243463, 207743, 204464, 238523, 235278, 248250, 288581, 283327, 224031, 206385, 223989, 210887, 236820, 284146, 240872, 261495]::int[], ARRAY[344178]::int[], ARRAY[484715, 485955, 442243, 487159, 428604, 421574, 448033, 490028, 461418, 410046, 430995, 498810, 499273, 403447, 435553, 415556, 462741, 407836, 493787, 462082, 485317, 437567, 445888, 498426, 480844, 461089, 411618, 410408, 451991, 411944, 466749]::int[], '2024-10-08 18:04:00 UTC', '2024-10-19 15:04:00 UTC', '2024-10-12 06:42:00 UTC'),
+('eleanore.jd5bsyql@example.net', '叶荣银', 'unknown','2022-11-12 05:00:00 UTC', '2024-10-18 01:24:00 UTC', '2024-09-14 08:02:00 UTC', ARRAY[114251, 186306, 146195, 169634, 142342, 168905, 179451, 115935, 127015, 155853, 171892, 180756, 140110, 188301]::int[], ARRAY[231638, 207075, 201695, 231196, 202124, 229389, 270137, 268747, 234494, 236043, 240406, 249336, 235607, 235194, 271704, 229411, 270360, 276968, 269308, 215366, 286969, 283632, 223347, 239968, 203702, 219454, 229560, 201118, 203937, 231280, 219574]::int[], ARRAY[329578, 320760, 351940, 305725, 378115, 351721, 329377, 385330, 340025, 302280, 365668, 329698, 339375, 347669, 317707, 393777, 343222, 360701, 371868, 316823, 323686, 394447, 374340, 374275, 367231, 323953, 390831, 303047, 370813, 387314, 315315, 371099, 390107, 340186, 313667, 355755, 312907, 368270, 327421, 323731, 380290, 331394, 320172, 352690, 383128, 336131, 300735, 308651]::int[], ARRAY[428793, 474184, 474517, 472907, 468061, 450612, 439350, 487731, 441154, 400559, 499000, 438402, 413129, 492237, 490293, 465380, 458321, 479759, 407509, 424679, 460408, 462427, 460974, 448826, 499515]::int[], '2024-09-25 01:13:00 UTC', '2024-10-27 22:02:00 UTC', '2024-10-29 08:36:00 UTC'),
+('elton.qi5sgba6@example.org', '杜蕾雅', 'female','2022-03-05 21:43:00 UTC', '2024-10-05 00:22:00 UTC', '2024-08-15 15:26:00 UTC', ARRAY[171763, 196249, 138516, 153318, 123005, 183023, 187899, 144871, 155865, 133424, 189279, 137177, 102116, 118476, 127966, 127576, 142498, 125244, 101919, 128646, 130528, 184889, 144185, 126551, 172703, 132335, 184969, 140887, 194217, 142234, 180362, 119704, 193122, 145946]::int[], ARRAY[281494, 278781, 244286, 218247, 286348, 276381, 278153, 210558, 227396, 296366]::int[], ARRAY[374712, 329353, 385409, 320456, 360091, 300568, 391045, 370770, 347398, 318515, 325027, 325028, 358157, 343321, 351259, 300459, 316547, 305124, 355437, 308514, 374820, 308784, 371378, 396449, 306283, 381515, 332530, 397138, 305779, 328787, 371189, 311743, 334312, 302719, 343154]::int[], ARRAY[464983, 456016, 434594, 425993, 441544, 453656, 431860, 438842, 470324, 454828, 428612, 483847]::int[], '2024-10-18 07:53:00 UTC', '2024-10-22 14:02:00 UTC', '2024-10-11 05:06:00 UTC'),
+('cheyenne.e9n5xqrk@example.com', '覃玫雅', 'unknown','2020-07-05 19:39:00 UTC', '2024-10-06 20:55:00 UTC', '2024-09-04 10:57:00 UTC', ARRAY[129746, 180599, 113596, 193412, 112908, 139284, 139729, 173535, 159672, 187528, 174909, 154767, 183464, 156470, 196052, 168953, 194796, 162451, 148657, 157909, 148938, 176766, 155641, 132254, 195867, 118205, 167924, 188072, 114939, 189585, 160298, 198522, 103120, 118430, 125689, 174806, 101711, 138304, 154025, 120960, 117926]::int[], ARRAY[277908, 250650, 284343, 254085, 225696, 291714, 248673, 221069, 298257, 227728, 250809, 219169, 205635, 254069, 285169, 242199, 287194, 214227, 293065, 282030, 204476, 254935, 292928, 216714, 295376, 203611]::int[], ARRAY[356807, 390014, 306930, 310841, 375070, 357149, 384620, 320973, 380837, 340962, 346028, 361639, 335613, 319333, 377573, 377084, 353952, 377769, 393413, 383958, 396523, 358671, 362497, 335679, 387540, 346398, 369464, 322568, 382924, 315597, 369744, 374578, 332565, 305736, 306614, 343572, 385361, 392903, 391147, 391192, 353259, 390242, 369486, 336469, 394905, 335449, 327654]::int[], ARRAY[411959, 488212, 428029, 437838, 419340, 475136, 412831, 488512, 458343, 414002, 420154, 492176, 447483, 457930, 464509, 410345, 422019, 429594, 444372, 497170, 411206, 405715, 499794, 417891, 430221, 400241, 422356, 458506, 423654, 429239, 403966, 496285, 486101, 460572, 457396, 469736, 426325, 461371, 435722]::int[], '2024-10-09 10:22:00 UTC', '2024-10-25 13:14:00 UTC', '2024-10-20 10:19:00 UTC'),
+('franz.vxwx7k52@example.net', '杨菲霞', 'female','2020-12-08 11:11:00 UTC', '2024-10-06 18:02:00 UTC', '2024-10-12 03:31:00 UTC', ARRAY[130050, 132181, 146423, 168026, 160177, 178644, 134474, 104624, 163495, 113729, 115910, 178317, 193569, 150465, 163606, 158244, 175334, 114377, 139234, 174162, 195160, 131970, 149519, 142307, 191151]::int[], ARRAY[255785, 206416, 250659, 211784, 286369, 286889, 263711, 259999, 268801, 240810, 252236, 229902, 273314, 253861, 261659, 261555, 237716, 292833, 278176, 287857]::int[], ARRAY[303133, 341054, 308741, 314697, 318327, 325481, 397347, 300047, 357980, 320950, 338404, 316033, 343346, 351444, 306614, 356797, 303383, 306111, 357770, 371398, 317028, 397109, 312959, 345971, 336497, 340542, 355800, 313918, 343526, 372595, 362654, 317938, 325603, 383892, 379265, 327918, 388986, 389331, 347564, 337107, 335135, 359965, 374119]::int[], ARRAY[431720, 497598, 491233, 495351, 463101, 481483, 405074, 490332, 493488, 444796, 408965, 402822, 419082, 431778, 471216, 424415, 409183, 491234, 417552, 435831, 410345, 440045]::int[], '2024-10-08 07:28:00 UTC', '2024-10-26 08:25:00 UTC', '2024-10-15 07:48:00 UTC'),
+('birdie.452pc816@example.org', '袁梅雪', 'male','2024-08-08 03:35:00 UTC', '2024-10-02 21:12:00 UTC', '2024-08-20 05:15:00 UTC', ARRAY[186226, 190982, 122079, 169403, 183175, 180719, 130231, 169273, 129780, 162680, 181157, 176124, 179073, 177008, 181506, 178031, 148014, 195692, 128882, 129702, 134946, 165802, 178990, 111990, 157950, 140150, 150531, 103056]::int[], ARRAY[214155, 209597, 246612, 226547]::int[], ARRAY[388848, 359957, 394460, 372852, 318217, 366066, 329191, 398230, 332433, 326421, 312078, 395975, 376336, 323872, 375107, 378085, 360202, 334524, 322010, 336246, 353920, 345514, 316935, 371216, 370945, 371566, 326366, 344714, 362024, 398280, 380005, 377610, 333559, 364965, 379212, 363632, 393055, 380497]::int[], ARRAY[466814, 458168, 402768, 405403, 414134, 400754, 485032, 430214, 474725, 440904, 422133, 447558, 413460, 470790]::int[], '2024-10-16 10:34:00 UTC', '2024-10-24 04:30:00 UTC', '2024-10-15 16:06:00 UTC'),
+('deshawn.5a2qkhin@example.org', '邓俊颜', 'male','2023-05-02 17:36:00 UTC', '2024-10-31 14:48:00 UTC', '2024-09-14 16:03:00 UTC', ARRAY[136934, 151619, 184324, 127012, 155931, 138642, 122736, 128557, 127278, 145943, 186857, 186697, 157665, 190976, 179269, 161782, 187994, 143071, 179857, 160842, 105909, 148415]::int[], ARRAY[271646, 262002, 217038, 235595]::int[], ARRAY[374374, 364749, 362537, 388219, 378066, 380651, 358488, 323535, 378056, 317481, 393919, 393069, 377765, 375815, 309032, 339824, 312716, 351056, 366668, 380145, 393851, 390851, 393491, 307253, 323243, 317099, 359451, 345010]::int[], ARRAY[453642, 464983]::int[], '2024-10-29 11:28:00 UTC', '2024-10-22 14:41:00 UTC', '2024-09-09 23:22:00 UTC'),
+('genesis.np26gv6b@example.org', '姚韵雪', 'female','2023-10-14 23:03:00 UTC', '2024-10-10 14:46:00 UTC', '2024-10-01 20:20:00 UTC', ARRAY[174738, 170790, 160908, 131060, 116192, 132109, 166140, 130382, 190272, 120632]::int[], ARRAY[275659, 258551]::int[], ARRAY[378367, 394801, 399339, 336224, 389508, 378007, 344404, 323023, 327837, 388194, 336197, 363263, 361083, 324670, 317937, 367923, 339109, 383774, 324060, 332770, 350566, 390694, 336855, 360190, 372166, 302386, 390294, 343951, 337809, 325445, 353606, 301293, 333967, 321150, 361792, 392655, 334337, 343034, 377369, 386056, 393050, 382727, 315490, 313962, 326051]::int[], ARRAY[473463, 404192, 451427, 430858, 430310, 487375, 497619, 430033, 425647, 430959, 475852, 428367, 496634, 474513, 498714, 487443, 460796, 444313, 442101, 487816, 495156, 457735, 416344, 403775, 405069, 481144, 466583, 499993, 493635, 463521, 435694, 459183, 490053, 437377, 479249, 422185, 411675, 430723, 466077, 410046, 449971, 466222, 430493, 426888, 492827, 465983, 418935, 491766]::int[], '2024-09-17 09:27:00 UTC', '2024-10-21 23:50:00 UTC', '2024-09-09 11:48:00 UTC'),
+('sophia.8ibtcm6l@example.com', '李金福', 'male','2022-09-29 20:56:00 UTC', '2024-10-05 01:04:00 UTC', '2024-08-17 23:22:00 UTC', ARRAY[105194, 127141, 112229, 147458, 181544, 142706, 121289, 111941, 138251, 139724, 139633]::int[], ARRAY[219336, 283311, 230964, 223267, 268642, 280545, 273241, 283015, 207238, 211462, 216702, 283448, 271519, 236640, 201237, 235544, 247630, 203265, 246138, 269807, 264697, 206600]::int[], ARRAY[341929, 349064, 358521, 367133, 304625, 378661, 397083, 331376, 380147, 392990, 352036, 334084, 396333, 367020, 399236, 361954, 379658, 367681, 318622, 360792, 302350, 339837, 331356, 357911, 372629, 322545, 338791, 394971, 358362, 336727, 300401, 391175, 304727, 390126, 315229, 393073, 327947, 314262, 380577, 310658, 346771, 312912, 344798, 325778, 375335, 310538, 324058]::int[], ARRAY[416562, 482468, 437573, 487844, 403921, 442561, 423803, 438141, 428713, 431539, 407767, 403959, 440391, 463288, 412930, 416783, 400573, 471287, 454788, 442798, 482474, 457117, 461444, 474018, 429522, 492365, 435155, 496349, 427035, 420867, 427617, 439346, 484187, 488833, 423583, 402078, 465529, 468862, 416540, 443269]::int[], '2024-10-02 08:58:00 UTC', '2024-10-31 17:40:00 UTC', '2024-08-23 06:18:00 UTC'),
+('lillian.ab0n5efo@example.org', '熊杰依', 'female','2023-09-07 11:46:00 UTC', '2024-10-16 20:20:00 UTC', '2024-09-19 13:43:00 UTC', ARRAY[188855, 125525, 192237, 122078, 168983, 153159, 164129, 151886, 162609, 162634, 124141, 197363, 104297, 186653, 188573, 109871, 164887, 139059, 185733, 133575, 146857, 148730, 122674, 186613, 112822]::int[], ARRAY[277263, 262358, 276561, 271970, 207007, 265532, 259765, 268380, 297851, 233396, 282042, 298769, 257410, 261693, 206355, 287085, 242595, 299154, 214201, 259100, 218327, 263937, 211741, 202100, 209059, 298689, 206060, 255016, 214257, 213779, 233351, 235787, 278007, 287185, 294652, 255004, 243340, 288245, 241212, 281669, 244264, 240580, 270099, 265755]::int[], ARRAY[326218, 335989, 384375, 383123, 396170, 390581, 322140, 398953, 327205, 374702, 306880]::int[], ARRAY[485188, 439276, 418605, 409867, 421127, 497735, 421936, 451489, 476622, 468683, 466661, 458125, 403019, 451190, 422185]::int[], '2024-10-04 03:38:00 UTC', '2024-10-17 06:41:00 UTC', '2024-08-06 08:17:00 UTC'),
+('domenick.vxghhx6h@example.com', '何志国', 'unknown','2022-05-04 04:36:00 UTC', '2024-10-13 21:53:00 UTC', '2024-08-08 12:18:00 UTC', ARRAY[105716, 117546, 168036, 106399, 111828, 192259, 152791, 185601, 190191, 105758, 178923, 117435, 163368, 150364, 186839, 135386, 183406, 187582, 138684, 174137, 168295, 104288, 141497, 110870, 161474, 144216, 164399, 183627, 108730, 177087, 187143, 120315, 151690, 159030, 179401, 142240, 186463, 141290, 158686, 156324, 102293]::int[], ARRAY[295552, 209013, 235584, 245369, 276735, 254255, 227316, 200736, 272979, 279546, 271070, 257522, 253282, 279290, 233970, 274473, 272436, 286314, 296802, 281202, 229966, 282641, 215806, 283746, 234862, 200211, 239749, 272898, 270147, 281333, 221127, 275942, 264160, 285999, 232451, 229929, 276401, 260156]::int[], ARRAY[379227, 341178, 311372, 374412, 340156, 346437, 382105]::int[], ARRAY[489614, 401837, 460864, 468504, 466724, 403932, 428475, 451275, 480362, 471879, 473082, 458609, 430400, 449689, 490658, 411394, 417439, 479438, 407693, 437901, 493151, 431171, 407649, 458375, 467342, 452980, 493422, 413138, 488780, 488194, 426322, 499708, 462458, 408953, 499920, 431090, 489215, 447746, 489989, 416298, 450918, 439383, 478435, 404857, 406056]::int[], '2024-10-04 22:57:00 UTC', '2024-10-26 20:50:00 UTC', '2024-10-22 01:11:00 UTC'),
+('katelin.vsh6z4xy@example.com', '叶紫芳', 'male','2023-07-18 10:28:00 UTC', '2024-10-24 16:43:00 UTC', '2024-09-03 02:19:00 UTC', ARRAY[186340, 111164, 154351, 184597, 103118, 103882, 191419, 129482, 130839, 181713]::int[], ARRAY[268321, 216947, 202973, 224511, 257615, 210512, 213934]::int[], ARRAY[336501, 315069, 327580, 363824, 354432, 330633, 338533, 310821, 386017, 311278, 310161, 329598, 343760, 359812, 304851, 397584, 361504, 311311, 342299, 361544, 365557, 316638, 388259, 391178, 343612, 347936, 380274, 300148, 331108, 301383, 348261, 350997, 321395, 332292, 353260, 379346, 329266, 342298, 323630, 331834]::int[], ARRAY[420789, 435073, 481252, 443687, 491194, 401661, 471288, 432616, 473878, 465373, 408027, 446088, 494314, 421523, 491079, 407291, 496964, 493311, 495752, 417587, 422621, 460867, 438777, 457946, 477940, 461511, 425681, 440042, 482363, 498103, 429625, 483284, 411357, 474436]::int[], '2024-10-24 05:22:00 UTC', '2024-10-30 09:35:00 UTC', '2024-08-05 09:55:00 UTC'),
+('trey.ta1tc8x1@example.org', '黎雅彤', 'unknown','2020-12-15 16:57:00 UTC', '2024-10-18 19:14:00 UTC', '2024-08-13 03:01:00 UTC', ARRAY[]::int[], ARRAY[289039, 209190, 279323, 221990, 213541, 239009, 299314, 243230]::int[], ARRAY[334461, 350671, 364026, 303118, 393653, 311500, 321210, 398523, 325400, 351896, 333059, 352624, 327151, 366497, 396436, 301894, 374326, 358431, 327221, 382088, 319076, 350089, 393826, 352260, 349869, 395663, 336041, 351104, 332047, 391251, 313863, 355606, 367189, 354259, 328324, 380904, 396603, 377229, 307159, 380962, 311288, 331685]::int[], ARRAY[446471, 471531, 427083, 406708, 447523, 428898, 406320, 488506, 487023, 446733]::int[], '2024-09-27 13:53:00 UTC', '2024-10-30 10:49:00 UTC', '2024-09-26 11:38:00 UTC'),
+('elvis.7efaaqu1@example.org', '吴柔雅', 'male','2021-10-23 00:38:00 UTC', '2024-10-18 19:01:00 UTC', '2024-10-19 10:07:00 UTC', ARRAY[152422, 180527, 108732, 148662, 166390, 137210, 100071, 163197, 153105, 149182, 111988, 108683, 179770, 150694]::int[], ARRAY[217992, 239958, 234174, 229570, 249796, 221936]::int[], ARRAY[300240, 354303, 305322, 319691, 377669, 363586, 362232, 341991, 346765, 358668, 368501, 345323, 321411, 336702, 303292]::int[], ARRAY[480512, 409343, 487913, 418334, 433698, 484001, 489079, 461364, 499198, 405644, 477992, 473406, 489770, 495939, 498742, 479732, 405756, 498436, 410803, 454004, 417740, 433077, 468655, 438608, 412780, 414866]::int[], '2024-10-20 13:38:00 UTC', '2024-10-17 01:48:00 UTC', '2024-08-03 21:07:00 UTC'),
+('aryanna.295vivsf@example.com', '向怡钰', 'male','2023-01-06 06:00:00 UTC', '2024-10-15 10:20:00 UTC', '2024-08-04 05:44:00 UTC', ARRAY[110523, 126950, 133357, 141336, 119373, 105281, 194186, 155018, 182974, 103500, 168909, 111934, 136028, 138442, 105446, 172759, 148731]::int[], ARRAY[268635, 249046, 236794, 257021, 267712, 213992, 263783, 210718, 202834, 252288, 288575, 206143, 298355, 216541, 281915, 270234, 280829]::int[], ARRAY[]::int[], ARRAY[489089, 499575, 459217, 495198, 481304, 424006, 427662, 444291, 442749, 423629, 433296, 466835, 481081, 413589, 437743, 493393, 435068, 458586, 460342]::int[], '2024-10-11 23:24:00 UTC', '2024-10-30 03:57:00 UTC', '2024-10-20 02:14:00 UTC'),
+('una.tuz7phn1@example.org', '潘文皓', 'male','2021-05-17 06:54:00 UTC', '2024-10-08 16:40:00 UTC', '2024-08-05 04:53:00 UTC', ARRAY[130599, 138584, 184835, 140151, 164243, 147361]::int[], ARRAY[206858, 233548, 262370, 286977, 226963, 205587, 266219, 291153, 277128, 281825]::int[], ARRAY[370776, 300229, 377956, 368520, 309578, 321360, 349906, 396465, 386132, 332257, 374300, 323050, 336047, 334626, 339102, 313482, 394805, 388795, 325967, 302079, 333824, 370937, 389612, 368902, 390654, 319195]::int[], ARRAY[464457, 487803, 413027, 427498, 466600, 460828, 442073, 447471, 487590, 467994, 484355, 493075, 421829, 480658, 472731, 493222, 428025, 488349, 468034, 452362, 490710]::int[], '2024-10-14 02:01:00 UTC', '2024-10-28 06:46:00 UTC', '2024-09-19 07:21:00 UTC'),
+('jacquelyn.77tkw2zl@example.com', '曾寒烟', 'unknown','2020-09-28 06:47:00 UTC', '2024-10-02 18:08:00 UTC', '2024-08-19 19:05:00 UTC', ARRAY[144157, 170616, 189453, 130815, 181906, 189946, 158892, 182700, 159597, 150327, 128984, 185929, 124556, 190007, 113793, 169069, 155565, 147896, 188120, 143295, 183588, 174358, 110512, 118992, 163253, 174744, 153108, 155397, 194201, 125121, 192857, 123765, 158723, 126319, 162481, 128309, 195002, 158143]::int[], ARRAY[277920, 260118, 212645, 235941, 210403, 286350, 210578, 273391, 264757, 262360, 246862, 240983, 274085, 202561, 287252]::int[], ARRAY[372920, 341876, 392520, 396386, 316261, 357284, 380065, 375096, 345818, 341105, 327597, 305894, 324679, 364163, 367514, 388066, 382608, 360769, 317718, 310643]::int[], ARRAY[440210, 497983, 426300, 414527, 449115, 488576, 403889, 415799, 471061, 410488, 476370, 417106, 469941, 486883, 441640, 430856, 496143]::int[], '2024-10-08 14:08:00 UTC', '2024-10-22 14:29:00 UTC', '2024-09-23 00:01:00 UTC'),
+('herminio.g7tbetm8@example.net', '周静影', 'unknown','2021-10-16 00:12:00 UTC', '2024-10-18 22:38:00 UTC', '2024-08-21 17:23:00 UTC', ARRAY[185338, 105903, 119808, 120037, 133357, 133170, 133861, 118861, 125834, 155271, 173515, 138440, 144692, 140066, 186250, 155255, 168596, 144468, 166674, 191744, 114396, 160430, 139343, 172158, 101754, 131194, 169921, 135011, 103174, 146207, 163547, 141430]::int[], ARRAY[219608, 207702, 246604, 241531, 299165, 248005, 272361]::int[], ARRAY[375723, 351314, 369624, 382415, 342982, 313639, 307430, 379060, 382754, 309206, 342090, 370018, 382943, 394159, 310029, 306995, 387233, 376856, 352966, 383014, 379854, 378238, 360273, 390095, 363456, 356109, 386444, 347200, 375545, 323966, 330743, 316917, 335129, 331659, 303731, 319203, 305963, 387973, 303930, 386327, 358925]::int[], ARRAY[470254, 467188, 429663, 431289, 483699, 440522, 405429, 485150, 483851, 415110, 470965, 434955, 476816, 420103, 453136, 466503, 431695]::int[], '2024-10-06 03:00:00 UTC', '2024-10-22 15:40:00 UTC', '2024-08-18 01:50:00 UTC'),
+('easton.c4q2cps4@example.com', '侯弘靖', 'female','2022-06-22 09:57:00 UTC', '2024-10-06 09:20:00 UTC', '2024-09-21 13:16:00 UTC', ARRAY[179360, 106701, 127907, 186281, 183233, 157880, 175391, 167260, 122603, 191458, 162088, 102576, 148643, 159198, 178884, 100819, 179027, 112149, 102259, 175489, 172512, 105742, 112074, 152508, 113441, 101534, 125394, 149478, 124932, 169381, 147566, 185915, 168461]::int[], ARRAY[276337, 231689, 258089, 289416, 232019, 249141, 292267, 291078, 258395, 246692, 278738, 292764, 263756, 219398, 283055, 255751, 240035, 238007, 204211, 237787, 280688, 277680, 243583, 287936, 206591, 264890, 229553, 280497, 280706, 241943, 276350, 232031, 205129, 235301, 273096, 234672, 253188, 212157, 289417, 248542, 264576, 243460, 260452, 204414, 253725, 277216, 216077, 262050, 211857]::int[], ARRAY[319261, 388604, 310571, 363630, 354438, 310490, 331737, 319233, 374394, 308911, 327415, 306013, 309231, 352899, 357413, 316573, 394770, 368155, 304046, 386037, 332635, 323097, 316223, 334575, 301260]::int[], ARRAY[478883, 420303, 460113, 455475, 413749, 487942, 466836, 499512, 469067, 432513, 437850, 432338, 447859, 448087, 417067]::int[], '2024-10-09 06:57:00 UTC', '2024-10-17 16:12:00 UTC', '2024-08-15 13:41:00 UTC'),
+('emma.61ns7icl@example.net', '莫月虹', 'female','2023-01-25 05:37:00 UTC', '2024-10-16 02:36:00 UTC', '2024-10-04 17:50:00 UTC', ARRAY[132345, 156559, 172342, 138259]::int[], ARRAY[233957, 218219, 214398, 231568, 235059, 271118, 298123, 274128, 221413, 242197, 252313, 229475, 286866, 219873, 228529, 249260, 299135, 262323, 268266, 243676, 212247, 299132, 286494, 200086, 247636, 292227, 278424, 221683, 231602, 233709, 269879, 268859, 231391, 216265, 281054, 222045, 209812, 223566, 278242, 218984]::int[], ARRAY[301220, 373357, 331962, 384377, 323104, 305873, 328716, 314398, 399644, 335619, 377264, 327374, 383351, 303088, 303142, 313121, 333437, 357305, 351028, 367524, 358907, 356172, 359627, 388797, 370303, 358969, 323723]::int[], ARRAY[458811, 491351, 436870, 487475, 417027, 432759, 487491, 473254, 498514, 400752, 422655, 407935, 487225, 419812, 409345, 418705, 465327, 415372, 454990, 447973, 490844]::int[], '2024-10-23 22:39:00 UTC', '2024-10-27 23:00:00 UTC', '2024-10-26 17:02:00 UTC'),
+('aliyah.d90qgmvj@example.org', '谢宇驰', 'male','2022-06-02 21:58:00 UTC', '2024-10-08 09:30:00 UTC', '2024-10-25 09:20:00 UTC', ARRAY[180814, 105764, 106055, 125475, 111342, 110406, 198381, 155994, 196272, 188894, 133436, 151583, 175350, 127736, 146917, 162239, 114385, 195261, 196542, 191150, 192386, 154879, 194868, 174216, 118690, 167652, 101414, 154492, 149763, 157187]::int[], ARRAY[222912, 266138, 201682, 283145, 205164, 209224, 206325, 206571, 250755, 288402, 273228, 213085, 260757, 250748, 299202, 263411, 293894, 287344, 224292, 227917, 251207, 208243]::int[], ARRAY[391592, 348411, 326098, 334524, 393143, 302848, 326147, 332695, 369647, 331402, 308230, 341737, 370969, 326973, 308548, 326085, 346014, 360958, 347859, 306921, 357609, 335183, 343381]::int[], ARRAY[415610, 499243, 449282, 442882, 484007, 451362, 419275, 440949, 428566, 417258, 485805, 423260, 493379, 495110, 498325, 411731, 496690, 475052, 423105, 430298, 454856, 413152, 495971, 496543, 447051, 422318, 456610, 448045, 428130, 425808, 447718]::int[], '2024-09-28 04:44:00 UTC', '2024-10-22 10:29:00 UTC', '2024-09-15 01:33:00 UTC'),
+('alessandro.m3ilrlzw@example.com', '周曦奇', 'male','2024-05-05 03:16:00 UTC', '2024-10-02 09:49:00 UTC', '2024-08-22 22:04:00 UTC', ARRAY[164563, 190437, 101281, 188466, 113986, 123086, 123350, 173662, 125748, 163598, 151569, 197849, 193874, 190408, 107871, 160422, 196244, 102786, 149036, 131883, 158547, 143885, 108752, 197853, 104527, 107559, 169607, 119818, 167259, 151688, 133519, 134873, 163874, 141655, 173699, 178398, 118493]::int[], ARRAY[247977, 299592, 225702, 284644, 221111, 251376, 270043, 265677, 243593, 236630, 280039, 207291, 270095, 241755, 268636, 247907, 290894, 208785, 247276, 264330, 205285, 207311, 257691, 236023, 299687, 291928, 257972]::int[], ARRAY[365220, 379719, 338921, 354734, 339607, 328406, 382689, 302696, 326985, 394338, 367939, 383121, 311427, 305358, 364473, 302965, 311295, 314285, 348399, 312356, 339073, 381564, 390929, 376151, 393436, 379265, 355604, 394358, 341226, 341248, 347767]::int[], ARRAY[454545, 457039, 410256, 428973, 442865, 458229]::int[], '2024-10-22 21:18:00 UTC', '2024-10-20 05:02:00 UTC', '2024-08-04 06:38:00 UTC'),
+('chauncey.4wqmtef3@example.com', '孟彬逸', 'female','2023-02-18 23:19:00 UTC', '2024-10-22 01:17:00 UTC', '2024-10-28 17:48:00 UTC', ARRAY[130157, 108687, 137532, 152742, 143642, 182868, 160808, 121305, 187611, 189737, 133901, 153861, 142112, 145423, 124631, 176437, 180097, 188740, 169630, 101382, 150495, 167317, 186526, 183864, 136160, 122756, 163417, 182392, 146842, 165723, 198099, 152789, 130813, 167229, 162344, 187727, 169476, 114933, 175247, 138146, 136726, 114388]::int[], ARRAY[239259, 256290, 206696, 233595, 243254, 228632, 214843, 215190, 203991, 208399, 251951, 248483, 213549, 291838, 297693, 222135, 232894, 215497, 241868, 229324]::int[], ARRAY[395726, 333935, 391084, 380021, 317881, 328559, 352707, 308564, 373999, 334709, 388484, 389941, 312336, 362619, 388074, 361429, 350532, 324691, 311651, 390040, 371409, 387428, 399520, 376676, 357201, 304323, 303619, 360356, 317529, 308899, 366053, 370175, 383565, 307160, 325885, 395647, 320449, 325133, 334785, 330916, 368254, 331778]::int[], ARRAY[456438, 438554, 482032, 426726, 444995, 460073, 479209, 486533, 484801, 469475, 411683, 428010, 496406, 476900, 457346, 432238, 450358, 403694, 442647, 458104, 420639, 440565, 432920, 418059, 460777, 447702, 488505, 486302]::int[], '2024-10-19 23:32:00 UTC', '2024-10-30 04:28:00 UTC', '2024-10-11 03:43:00 UTC'),
+('miller.8cfnmon0@example.net', '邓鸿承', 'female','2019-11-09 06:19:00 UTC', '2024-10-07 11:12:00 UTC', '2024-08-23 17:06:00 UTC', ARRAY[170323, 176094, 163426, 180964, 109389, 136083, 162942, 181271, 103260, 125343, 192962, 183578, 178096, 101140, 143343, 135892, 143790, 180128, 158577, 113308, 138038, 115471, 122191, 126837, 185791, 108560, 111989, 184719, 109691, 166684, 193698, 100852, 185180, 106717, 140849, 121110]::int[], ARRAY[258642, 229034, 210514, 289218, 262653, 276570, 276336, 277884, 252724, 278590, 276742, 220086, 214266, 262252, 238005, 247254, 267321, 233037, 241284, 240875, 203417, 208325, 233212, 261499, 216130, 238659, 285072, 263531, 240332, 253781, 265780, 235144, 298265, 267537, 268652, 264249, 279107, 252289, 252572, 222945, 251374, 249144, 296447, 249187, 288944, 222655, 200069, 207393, 227722]::int[], ARRAY[357613, 326708, 307337, 381826, 349052, 330225, 329835, 346070]::int[], ARRAY[439243, 415932, 493946, 475191, 485340, 452250, 473494, 417003, 423773, 490972, 469209, 409790, 436836, 473240, 423516, 437737, 430293, 444754, 456101, 462825, 458495, 446852, 441308, 463828, 449226, 482835, 451945, 447244, 459151, 493443, 465859, 478051, 459404, 488229, 425723, 423293, 487384, 462073, 416809]::int[], '2024-10-04 17:27:00 UTC', '2024-10-25 05:05:00 UTC', '2024-09-29 20:38:00 UTC'),
+('wilbert.bdb6d5vf@example.com', '周瑜柔', 'female','2023-09-02 01:11:00 UTC', '2024-10-14 06:40:00 UTC', '2024-10-17 02:32:00 UTC', ARRAY[105730, 197255, 135896, 183615, 109955, 151986, 148539, 118906, 116573, 120311, 136310, 102049, 146356, 177240, 188792, 199766, 131316, 188481, 183972, 134522, 104582, 193863, 116204, 106527, 189404, 154222, 163083, 133814, 145067, 104296, 163754, 107037, 130843]::int[], ARRAY[283019, 275295, 267434, 271327, 280153, 200179, 274258, 299201, 286260, 205630]::int[], ARRAY[378099, 342814, 387137, 300348, 304537, 330030, 357317, 338674, 307519, 312172, 363279, 311205, 368719, 338893, 346530, 305855, 373932, 391860, 376579, 300016, 319632, 332616, 343397, 317247, 315638, 396052, 376346, 350846]::int[], ARRAY[403775, 496447, 447392, 411706, 422187, 414543, 425257, 484107, 475232, 495739]::int[], '2024-10-25 04:56:00 UTC', '2024-10-25 01:00:00 UTC', '2024-09-01 16:16:00 UTC'),
+('art.woohc0v0@example.org', '曾水瑶', 'male','2021-08-05 09:56:00 UTC', '2024-10-12 19:37:00 UTC', '2024-10-17 00:48:00 UTC', ARRAY[194307, 115889, 122410, 169196, 149661, 172416, 101880, 164498, 156806, 193900, 132356, 177620, 159246, 148280, 174160, 189897, 176671, 107255, 184498, 109637, 183117, 159682, 103692, 118439, 133410, 180119, 152075, 149991, 186807, 120484, 181481, 174756, 141741, 164527, 138485, 127872, 186675, 138055, 105815, 160078, 148300, 118636, 186491, 127960, 104384, 199023, 140375, 104010, 188627]::int[], ARRAY[295810, 200145, 225023, 273655, 258104, 265052, 215876, 232118, 251823, 218825, 201878, 219984, 257951, 210475, 204113, 282472, 297201, 233995, 283441, 264525, 217211, 281332, 294183, 221045, 285522, 250173, 214696, 220038]::int[], ARRAY[338445, 386992, 368581, 342533, 387918, 334545, 391240, 323658, 347075, 327435, 346047, 390349, 365030, 310275, 377267, 327721, 390416, 346308, 371570, 390064, 336054, 393977, 326302, 340750, 359636, 325879, 325934, 341458, 382134]::int[], ARRAY[430442, 478928, 448262, 421665, 422406, 491120, 456734, 492020, 424137, 442295, 469105, 451205, 438543, 415084, 416411, 402296, 442158, 441174, 478205, 449888, 485044, 467687, 401466, 473050, 448525, 419728, 437489, 449233]::int[], '2024-10-04 07:06:00 UTC', '2024-10-27 05:32:00 UTC', '2024-08-10 22:00:00 UTC'),
+('manuela.betluk16@example.com', '白俊颜', 'female','2021-10-10 18:18:00 UTC', '2024-10-21 02:53:00 UTC', '2024-09-01 09:32:00 UTC', ARRAY[175388, 157701, 101924, 186227, 134402, 105453, 149370, 151119, 186318, 172027, 171481, 160857, 104243, 146147, 142831, 138776, 153945, 199921, 163324, 144030, 130451, 163189, 106549, 119806, 191935, 170349, 108050, 115548, 168365, 160345, 154472]::int[], ARRAY[236795, 245209, 232367, 267862, 259724, 212258, 251044, 274532, 240067, 208597, 292036, 235917, 221857, 245479, 284469, 280609, 280165]::int[], ARRAY[337779, 302360, 382481, 350247, 343854, 344416, 394414, 354721, 313171, 324191, 301086, 333788, 375573, 358127]::int[], ARRAY[421264, 465822, 492292, 495250, 487745, 418867, 472100, 476783, 486739, 415716, 489203, 497739, 404505, 467138, 475564, 491722, 473473, 435032, 446755, 464343, 474712, 438105, 434634, 406622, 488692, 431293, 435201, 403031, 472597, 455590, 481869, 484455, 413963, 423414, 483615, 491734]::int[], '2024-10-31 13:07:00 UTC', '2024-10-19 23:42:00 UTC', '2024-10-27 05:52:00 UTC'),
+('jefferey.b125essd@example.net', '赵语雅', 'female','2020-06-06 16:21:00 UTC', '2024-10-08 17:20:00 UTC', '2024-08-29 23:14:00 UTC', ARRAY[138952, 126685, 139082, 145258, 127381, 190671, 177979, 153634, 154306, 134785, 117487, 110525, 126466, 142479, 149165, 180807, 101680, 188772, 178499, 153999, 126888, 115262, 122321, 147665, 129596, 112975, 149213, 155252, 103726, 144244, 130641]::int[], ARRAY[237838, 213374, 276607, 200214, 232124, 266939, 246522, 281435, 295750, 265318, 252368, 243792, 220842, 260275, 206040, 232368, 228003, 252664, 219661, 212267, 287937, 255126, 258844, 293305, 267741, 231399, 238716, 240069, 281411, 250390, 236627, 200968]::int[], ARRAY[318824, 301403, 326235, 332532, 397024, 303553, 337197, 379808, 302724, 398703, 387161, 394047, 377295, 315536, 307502, 348868, 324295, 312010, 325244, 345134, 313536, 323025, 302242, 383999, 359648, 340356, 399611, 376108]::int[], ARRAY[475088, 415086, 418170, 412459, 455697, 409528, 412224, 427085, 443669, 463433, 415914, 475614, 481981, 436474, 400181, 495992, 403419, 449201, 437318, 466983, 401038, 480333, 450941, 495223, 458123, 447372, 468620, 418826, 467546, 486731, 414093, 447065, 480840, 419604, 451504, 403815, 469377]::int[], '2024-09-21 00:13:00 UTC', '2024-10-25 08:57:00 UTC', '2024-09-30 16:06:00 UTC'),
+('sandra.mxtyh50t@example.com', '邵沛菡', 'female','2020-11-28 06:33:00 UTC', '2024-10-29 16:05:00 UTC', '2024-09-01 08:04:00 UTC', ARRAY[103041, 166650, 102376, 168874, 119920, 155562, 145701, 143618, 199806, 128154, 171028, 171069, 145166, 183946, 168006, 132196, 149415, 160279, 144800, 171369, 137622, 163512]::int[], ARRAY[240370, 211661, 216172, 207585, 208992, 266447, 294473, 250382, 243574, 296421, 202727]::int[], ARRAY[380997, 383085, 382167, 383505, 334159, 330662, 344290, 364694, 351925, 365879, 309110]::int[], ARRAY[491986, 462455, 493921, 468164, 481478, 456572, 498544, 470538, 429930, 432483, 414996, 468707, 473660, 428536, 461353, 493745, 478475, 441535, 431067, 463466, 498031, 417748, 491049, 496007, 483977]::int[], '2024-10-31 00:06:00 UTC', '2024-10-30 14:03:00 UTC', '2024-08-11 02:38:00 UTC'),
+('dayne.2cnqxb6d@example.org', '范若菲', 'male','2020-11-11 03:35:00 UTC', '2024-10-23 14:53:00 UTC', '2024-10-30 17:35:00 UTC', ARRAY[117277, 163693, 133556, 191140, 188771, 124168, 153228, 159031, 135094, 171576, 172001, 118227, 114748, 184239, 177817, 180558, 174534, 189170, 109044, 110520, 108570, 164982, 171890, 100152, 196589, 128451, 113269, 196513, 168593, 195142, 157222, 106983, 144859, 173885, 162736, 126410, 185135, 137825, 150332, 138772, 185305, 172056]::int[], ARRAY[228572, 257765, 272831, 227327, 238230, 274021, 201974, 274229, 206844, 254525, 287265, 222609, 268362]::int[], ARRAY[357262, 337964, 386824, 376285, 391713, 344474, 356484, 391869, 396057, 389541, 331646, 300886, 397304, 322672, 384368, 308306, 365026, 339010, 362335, 316497, 355947, 371301, 397007, 304657, 357979, 336393, 374037, 396351, 335336, 356290, 383374, 319766, 344072, 357398, 382463, 347559, 346651, 352687, 356249, 335801, 388472, 391536, 305679, 384252, 350786, 328237]::int[], ARRAY[495507, 443230, 487412, 426826, 452883, 450620, 441126, 491639, 454308, 478708, 450761, 498853, 481846, 484319, 481671, 448413, 413470, 439455, 443805, 474496, 466415, 428508, 475708, 415952, 492324]::int[], '2024-10-20 14:48:00 UTC', '2024-10-28 14:04:00 UTC', '2024-09-30 16:34:00 UTC'),
+('heather.wc31mw4j@example.org', '黄芙香', 'male','2024-05-13 15:19:00 UTC', '2024-10-16 07:40:00 UTC', '2024-08-09 22:01:00 UTC', ARRAY[170843, 173404, 197097, 106297, 137603, 111659, 182855, 129842, 179339, 104746, 173270, 148789, 135693, 173234, 101877, 113501, 160498, 152818, 175494, 132383, 148813, 121523, 177059, 151781, 195737, 104663, 116057]::int[], ARRAY[293368, 253410, 254302, 257553, 254843, 202429, 227097, 287218, 237629, 225605, 282842, 297852, 284263, 240071, 276700, 262814, 242828, 213040, 214869]::int[], ARRAY[328298, 335647, 370085, 360509, 356718, 338771, 377914, 346976, 397682, 372570, 319757, 338230, 323153, 316208, 373534, 356568, 361097, 348850, 335747, 384544, 318515]::int[], ARRAY[434255, 451604, 496619, 455286, 421126, 414947, 470055, 419113, 409601, 468091]::int[], '2024-10-04 23:41:00 UTC', '2024-10-18 01:30:00 UTC', '2024-10-27 19:17:00 UTC'),
+('beau.neq57z50@example.com', '段紫芳', 'female','2024-06-20 19:33:00 UTC', '2024-10-24 07:49:00 UTC', '2024-08-17 02:46:00 UTC', ARRAY[133801, 180709, 103471, 166621, 108662, 146995, 192006, 184586, 178349, 105894, 195395, 153633, 174274, 105344, 119977, 144693, 173500, 193287, 134378, 185885, 152329, 187648, 149548, 125578, 172179, 168594, 143665]::int[], ARRAY[204297, 293031, 224402, 221662, 206737, 205500, 236139, 280028, 246756, 291791, 242741, 213434, 240720, 239991, 208782]::int[], ARRAY[394437, 390532, 395554, 395646, 388172, 311419, 394083, 381355, 366870, 335476, 309803, 328391, 399580, 394004, 332276, 356350, 376014, 378462, 342602, 360421, 355615, 335210, 335993, 330048, 394597, 375813, 357729, 334547, 356092, 355321, 337106, 343565, 359308, 332854, 346924, 350895, 345442, 344385, 327150, 380840, 386211, 358290, 376065, 342439, 373718, 392411]::int[], ARRAY[493812, 426904, 448852, 426730, 440152, 449256, 495194, 429069, 485234, 405460, 485790, 429096, 407598, 403539, 454286, 448084, 414191, 405666, 406800, 439154, 432492, 479695]::int[], '2024-10-22 14:13:00 UTC', '2024-10-30 09:26:00 UTC', '2024-10-23 17:16:00 UTC'),
+('evan.zs4hgqmo@example.net', '欧阳晋永', 'male','2022-02-17 11:46:00 UTC', '2024-10-06 10:31:00 UTC', '2024-10-24 05:50:00 UTC', ARRAY[120611, 136695, 183748, 148326, 144006, 129282, 174484, 111037, 147187, 120220, 133373, 133208, 180629, 110569, 174047, 142498, 179669, 154689, 103354, 186452, 199399, 148833, 122547, 171359, 192238, 195930, 194390, 110851, 132305, 194311, 174836, 130370, 114132, 155510, 195304, 157112, 190352]::int[], ARRAY[262295, 211048, 292060]::int[], ARRAY[399997, 336407, 323365, 327195, 399887, 371732, 366301, 356919, 305243, 343410, 352425, 340934, 385487, 306188, 355099, 381133]::int[], ARRAY[436312, 412464, 449667, 458740, 412104, 411731, 498914, 488282, 442459, 442187, 452929, 422894, 451110, 415072, 410050, 479727, 461899, 403821, 429318, 467349, 414291, 409926, 485562, 429530, 422127, 434407]::int[], '2024-10-25 20:04:00 UTC', '2024-10-20 13:36:00 UTC', '2024-08-05 21:24:00 UTC'),
+('myron.6lvtgm4k@example.net', '卢柯勤', 'female','2021-10-05 20:41:00 UTC', '2024-10-10 19:20:00 UTC', '2024-10-03 07:07:00 UTC', ARRAY[121990]::int[], ARRAY[220243, 212209, 245406, 212710, 209605, 238769, 277901, 240058, 288883, 238265, 243031, 217138, 224815, 287137, 291699, 250904, 290272, 270038, 223769, 258551, 232341, 258179, 208136, 254444, 273599, 212767, 213155, 209242, 246436, 212049, 217829, 252742, 280025]::int[], ARRAY[313704, 386162, 314299, 367322, 391300, 399475, 399385, 399852, 336582, 307978, 346130, 352166, 375223, 396314, 340115, 326510, 373802, 345263, 303738, 312304, 317364, 381898, 345374, 395358, 351558, 322536, 303704, 387124, 307922, 312719, 320300, 355513, 371285, 365865, 318687]::int[], ARRAY[426513, 431432, 499533, 431599, 427985, 439133, 489728, 489114, 456121, 478460, 493452, 459674, 411900]::int[], '2024-10-29 20:39:00 UTC', '2024-10-18 17:35:00 UTC', '2024-09-11 11:49:00 UTC'),
+('kurt.1597s77s@example.net', '秦艳蓉', 'female','2020-03-31 14:17:00 UTC', '2024-10-08 12:33:00 UTC', '2024-10-15 03:46:00 UTC', ARRAY[153447, 150139, 195718, 113357, 192962, 187859, 138635, 185337, 126415, 198476, 124986, 186710, 105376, 188030, 109585, 160107, 151114, 109741, 199735, 104384, 104312, 162242, 148703, 100853, 138974, 184991, 152851, 144284, 144185, 104935, 118225, 106020, 165915, 179162, 106873, 155700, 141825]::int[], ARRAY[223024, 260390, 239807, 230272, 248668, 220072, 230827, 231733, 295806, 238389, 234062]::int[], ARRAY[367999, 332725, 315856, 306252, 353739]::int[], ARRAY[498764, 402638, 490403, 427262, 476191, 491629, 409120, 441445, 496220, 429248, 440188, 424986, 499223, 485608, 417999, 472454, 488516, 464973, 483843, 498669, 434354, 439996]::int[], '2024-10-29 19:34:00 UTC', '2024-10-23 11:23:00 UTC', '2024-10-14 22:02:00 UTC'),
+('curtis.k1p6y7p0@example.net', '高雅瑾', 'male','2024-04-28 09:16:00 UTC', '2024-10-17 23:56:00 UTC', '2024-08-07 15:37:00 UTC', ARRAY[169080, 150923, 180685, 190333, 171234, 150367, 175491, 193748, 194908, 169297, 129962, 146376, 142186, 104952, 114635, 118244, 180435, 101405, 191393, 105505]::int[], ARRAY[234078, 245137, 275211, 211215, 273084, 297056, 297130, 227888, 240794, 252931, 226656, 282541, 256313, 231802, 225491, 221241, 275586, 273716, 259788, 241694, 249055, 297440, 204116, 234488, 293636, 273796, 269139, 250960, 216713, 269793]::int[], ARRAY[319473, 330772, 356040, 366624, 304759, 383247, 394988, 355242, 348729, 301524, 367315, 316742, 378022, 349118, 308833, 317091, 331195, 391304, 359032, 300859, 344955, 395549, 357923, 379554, 302715, 319489, 358662, 350957, 378075, 302946, 373303, 363529, 375269, 346935, 384039, 373234, 347420, 374197, 356793, 328346, 369602, 311347, 395543, 386194, 388934, 308218, 344610, 308520]::int[], ARRAY[431767, 453508, 435801, 498551, 435198, 499383, 480415, 471665, 445247, 463704, 451104, 419389, 469402, 421130, 452753, 416512, 461561, 417398, 430548, 482198, 404895, 421758, 466944, 485071, 458137, 425705, 474513, 409829, 488497, 469150, 452976, 419902, 423408]::int[], '2024-10-24 00:39:00 UTC', '2024-10-24 03:36:00 UTC', '2024-09-07 23:26:00 UTC'),
+('jordi.5b9ol91w@example.net', '刘文兴', 'unknown','2022-05-28 06:16:00 UTC', '2024-10-25 23:06:00 UTC', '2024-08-21 03:33:00 UTC', ARRAY[152791, 127758, 180335, 101353, 163550, 147435, 116933, 172844, 139375, 158860, 116874, 182770, 162973, 106728, 135142, 167346, 167979, 173797, 106701, 178518, 143018, 153238, 172232, 147504, 121510, 124701, 129033, 154991, 167750, 181470, 156226, 129737, 129647, 161409, 160530, 161527]::int[], ARRAY[252021, 297853, 213677, 278598, 233653, 289061, 243373, 225442, 272733, 254672, 286827, 260287, 284757, 225682, 209240, 228796, 200374, 234729, 253443, 267428, 280200, 249616, 252273, 251790, 246599, 210499, 252597, 228890, 274236, 287020, 276332, 295045, 249656, 234510, 254575, 263859, 240168, 277771, 289212, 253061, 260931]::int[], ARRAY[330725, 306179, 392075, 380714, 346771, 369600, 303304]::int[], ARRAY[493492, 428392, 460998, 496516, 496454, 483789, 457636, 446645, 483810, 480548, 472874, 450497, 437749, 447323, 491723, 435493, 464008, 497166, 485647, 436295, 418028, 482982, 473902, 400229, 458235, 447960, 444048, 484496, 469717, 432115, 451927, 453178, 426858, 466909, 498539, 433942, 482662, 423374, 440661, 476001, 467711, 496083, 429300, 462030, 418133, 482260]::int[], '2024-10-01 10:23:00 UTC', '2024-10-26 06:47:00 UTC', '2024-08-28 08:12:00 UTC'),
+('ozella.orcnwjbk@example.net', '夏秋风', 'male','2020-01-26 03:40:00 UTC', '2024-10-15 02:31:00 UTC', '2024-10-07 23:13:00 UTC', ARRAY[157545, 190903, 136604, 186951, 118899, 127095, 128042, 108682, 147882, 197387, 170592, 134282, 161566, 172139, 162091, 117428, 196545, 193815, 145126]::int[], ARRAY[287524, 216435, 203871, 264226, 297708, 289199, 277118, 267180, 253685, 234950, 272404, 283401, 258999, 242757, 264806, 276096, 203961, 250318, 298852, 233016, 280039, 225932, 278725, 218221, 264144, 296972, 251377, 210606, 244228, 282266, 296656, 263204, 273511, 225852, 235865, 283727, 218913, 298710, 215105, 266257]::int[], ARRAY[333732, 352232, 311298, 354911, 320389, 362733, 374753, 337318, 327965, 354738, 376461, 327292, 384672, 348358, 385311, 301079, 368324, 397987, 318321, 308650, 310322, 382787, 312066, 355354, 347746, 341277, 353792, 341513, 385646, 357515, 368310, 353961, 363554, 329442, 365285, 375517, 394821, 375830, 366515, 375666, 311256, 390667, 321159, 384129, 329084, 361902, 306907, 359179, 337329]::int[], ARRAY[472692, 464334, 480564, 498011, 455517, 488472, 456542]::int[], '2024-10-20 09:03:00 UTC', '2024-10-21 15:01:00 UTC', '2024-10-01 04:06:00 UTC'),
+('lamont.mbp19vrw@example.net', '夏辰逸', 'female','2022-07-08 09:05:00 UTC', '2024-10-23 14:17:00 UTC', '2024-09-11 18:14:00 UTC', ARRAY[191998, 173044, 162970, 150343, 162567, 109166, 124186, 105649, 144427, 109247, 156771, 152646, 178435]::int[], ARRAY[214831, 204279, 205746, 205132, 273270, 279727, 290175]::int[], ARRAY[314849, 368693, 306688, 330792, 388532, 331122, 376527, 360197, 333406, 355603, 398734, 320607, 379783, 316373, 378957, 386099, 344606, 376732, 340750, 332067, 367970, 394334, 305059, 349812, 374188, 308732, 352123, 329508, 300954, 346111, 396120, 341440, 354505, 395255, 352212, 333403, 344841]::int[], ARRAY[470750, 478254, 441281, 490303, 455707, 472652, 421685, 447433, 435613, 409044, 437454, 493363, 477139, 420431, 426947, 453496, 429395, 466101, 469675, 417078, 479116, 480097, 415489, 498585, 485859, 489164, 403835, 441318, 447733, 415871, 452983, 457799, 410466, 414165, 493107, 407797, 420112]::int[], '2024-09-28 06:55:00 UTC', '2024-10-18 07:16:00 UTC', '2024-10-09 03:15:00 UTC'),
+('hilton.jqmfotgs@example.com', '谢丰瞻', 'unknown','2020-11-18 02:42:00 UTC', '2024-10-13 06:27:00 UTC', '2024-08-05 04:12:00 UTC', ARRAY[154401, 115872, 162545, 181836, 107763, 121950, 192311, 114728, 147815, 138495, 182744, 147091, 151291, 101505, 167213, 188063, 197578, 183738]::int[], ARRAY[228652, 248863, 298457, 278666, 218633, 275913, 203900, 219026, 265644, 278705, 223585, 261659, 233485, 211510, 272238, 213801, 237810, 205610, 208961, 245101, 275201, 250703, 286044, 283558, 252903, 293772, 292678, 274843, 235514, 216027, 281447, 205706, 228548]::int[], ARRAY[372459, 337978, 388322, 326634, 311766, 395875, 361938, 302899, 321545, 358241, 380072, 312672, 372647, 325498, 386656, 395982, 391265, 361597, 346221, 320753, 380630]::int[], ARRAY[410862, 469665, 498380, 455764, 410414, 446173, 401889, 458220, 495226, 481716, 481956, 440034, 414527, 493445, 493067, 400516, 477547, 472803, 483902, 415626, 444266, 479130, 426143, 415418, 489960, 454035, 448245, 404867, 457647, 492212, 450574, 447476, 420842, 445112, 459811, 424417]::int[], '2024-10-15 21:04:00 UTC', '2024-10-31 11:51:00 UTC', '2024-09-30 04:51:00 UTC'),
+('loma.cnhiu6oh@example.org', '覃瀚舟', 'female','2020-10-16 08:22:00 UTC', '2024-10-13 09:14:00 UTC', '2024-09-07 23:02:00 UTC', ARRAY[101361, 143391, 132540, 146010, 183039, 127699, 113248, 138180, 146900, 122518, 144810, 151608, 160392, 132970, 194312]::int[], ARRAY[222207, 203588, 288285, 297816, 276440, 238556, 210754, 277548, 221947, 205073, 201831, 273786, 250250, 204452, 246284, 237960]::int[], ARRAY[329648, 353998, 390172, 360335, 314114, 327967, 316938, 300321, 381079, 314006, 385029, 355211, 397035, 364400, 397219, 375853, 368948, 341440, 375966, 388713, 323677, 328292]::int[], ARRAY[418859, 474925, 446193, 486589, 455342, 428053, 411732, 460331, 459030, 497279, 466393, 475568, 499875, 419296, 484687, 422263, 451376, 413512, 402546, 457333, 449149, 413985, 479795, 408223, 451021, 400224, 475114, 447711, 451963, 431236, 492012, 457786, 452599, 433262, 489863, 417384]::int[], '2024-10-06 17:22:00 UTC', '2024-10-22 15:49:00 UTC', '2024-08-26 11:33:00 UTC'),
+('diana.oqvkezhg@example.com', '叶月虹', 'female','2020-09-19 16:53:00 UTC', '2024-10-09 01:48:00 UTC', '2024-08-14 09:58:00 UTC', ARRAY[164749, 105275, 131455, 198429, 131319, 107266, 191568, 176819, 196850, 194058, 187910, 191220, 167806, 140125, 132815, 192297, 178165, 166626, 106929, 110856, 190820, 197822, 136133, 115961, 165079, 182335, 185967, 106554, 114324, 164975, 133641, 103756, 101893, 127602]::int[], ARRAY[229293, 226867, 233856, 240988, 247657, 225086, 261272, 209318, 294618, 269204, 294356, 274705, 227458, 281835, 281008, 298749, 211942, 228646, 245108, 216983, 291309, 274280, 222888, 264696, 276612, 251762, 259546, 267391, 203492, 223807, 225440, 246737, 238801]::int[], ARRAY[303292, 311083, 308458, 393100, 355130, 315519, 317328, 362110, 300218, 372302, 325242, 379028, 331326, 363192, 371015, 384098, 397856, 395766, 367097, 357868, 340642, 331612, 332188, 300812, 375811, 336236, 345040, 396550, 337827, 389817, 387945, 393883, 380334, 313301, 332470, 302719, 385887, 300586, 320376, 346790, 309662, 316653, 380608, 309648]::int[], ARRAY[415694, 438304, 450832, 477448, 417826, 423645, 498643, 446397, 492992, 436736]::int[], '2024-09-22 15:13:00 UTC', '2024-10-17 16:15:00 UTC', '2024-09-15 10:11:00 UTC'),
+('naomi.iscgyb8p@example.org', '冯紫槐', 'female','2023-07-16 10:04:00 UTC', '2024-10-22 15:12:00 UTC', '2024-08-20 13:59:00 UTC', ARRAY[]::int[], ARRAY[200460, 260034, 284930, 249614, 208246, 207187, 263377, 203864, 228809, 272786, 294885, 292838, 254012, 250408, 228278, 264004, 213644, 276051, 232212, 222624, 217746, 264513, 255509, 244800]::int[], ARRAY[334350, 376117, 398008, 396741, 310125, 373601, 338793, 345700, 355914]::int[], ARRAY[422821, 433440, 497271, 401338, 487071, 434234, 413654, 462352, 480587, 453833, 456357, 469686, 439591, 454893, 480499, 470712, 413995, 494662, 488239, 451146, 458709, 466291, 464888, 481368, 437138, 422838, 438178, 455253, 413128, 444114, 493887, 413200, 465380, 448249, 487306, 459400, 491560, 433592, 422198, 420241, 455004, 420166, 432763, 493768]::int[], '2024-09-18 03:42:00 UTC', '2024-10-30 22:27:00 UTC', '2024-10-08 00:33:00 UTC'),
+('marlen.0r3zlu25@example.org', '郭弘耀', 'female','2023-06-07 18:29:00 UTC', '2024-10-14 09:14:00 UTC', '2024-10-24 21:46:00 UTC', ARRAY[180885, 124218, 176886, 124314, 149428, 173506, 188951, 147975, 189611, 149780, 189424, 181089, 179979, 104870, 101409, 101511, 122803, 103475, 199213, 103866, 170800, 182487, 159172, 179800, 190328, 170327, 115732, 118305, 148446, 195277, 125574, 138656, 155888, 158521, 125730, 132871, 121863, 158207, 135580, 166293, 199633, 101532, 104851, 123124, 192639, 153119]::int[], ARRAY[217981, 277776, 285094, 286957, 219844, 295859, 265888, 266739, 219240, 249676, 217652, 202711, 226664, 206913, 298517, 219174, 226605, 248088, 241089, 283501, 252799, 248010, 241001, 284988, 255635, 232181, 215941, 226170, 246541, 279325, 241205, 220174, 263299, 200564, 265238, 238107, 290372, 210190, 227510, 226635, 203025, 202369]::int[], ARRAY[323642, 389750, 389455, 340852, 359938, 335243, 344069, 351959, 368638, 374121, 351258, 317471, 375376, 330002, 389869, 321865, 327908, 301345, 305219, 370922, 325961]::int[], ARRAY[432342, 423054, 415233, 493181, 428028, 432748, 473224, 417886, 483201, 406476, 403676, 423489, 469542, 487817, 489564, 411276, 486154, 491570, 473633, 403162, 493846, 407353, 441215, 405129, 404265, 410097, 429383, 408139]::int[], '2024-10-23 09:06:00 UTC', '2024-10-20 02:09:00 UTC', '2024-10-09 06:18:00 UTC'),
+('bryce.vaaic14j@example.com', '萧弘玮', 'female','2022-12-14 06:13:00 UTC', '2024-10-07 00:12:00 UTC', '2024-09-09 03:14:00 UTC', ARRAY[163196, 158399, 128636, 187138]::int[], ARRAY[222516, 228615, 200651, 224384, 284403, 281793, 238033, 267342, 233223, 254014, 299715, 292170, 257479, 259339, 294658, 273636, 281466, 218735, 234996, 285119, 278805, 252578, 213045, 280265, 236037, 201428, 255729, 239556, 213931, 266324, 268935, 263885, 224001, 285935, 204003, 248015, 220531, 268679, 261614, 261097, 248074]::int[], ARRAY[343047, 364205, 375997, 374365, 307971, 305386, 392687, 341881, 389676, 334198, 301958, 342938, 363238, 358288, 329459, 314211, 365115, 349321, 370091, 368377, 396295, 387981, 373401, 384326, 347043, 349404, 350944, 378960, 314326, 302700, 340355, 356061, 385136, 319230, 360807, 345579, 361407, 333707, 385524, 315762, 363932, 310929, 364260, 371721, 367831, 363231]::int[], ARRAY[419521, 498413, 436425, 414446, 449111, 473278, 458497, 461160, 445715, 426917, 466236, 476815, 402643, 447212, 430008, 455593, 453903, 492765, 478662, 421484, 400192, 470803, 421072, 468993, 411459, 405627, 406361, 451778]::int[], '2024-10-21 01:40:00 UTC', '2024-10-19 17:28:00 UTC', '2024-09-15 13:05:00 UTC'),
+('april.hhw97a6x@example.org', '李婷莉', 'male','2024-05-21 05:38:00 UTC', '2024-10-17 01:58:00 UTC', '2024-10-18 04:38:00 UTC', ARRAY[111028, 185021, 177603, 182562, 119239, 124588, 164383, 172772, 146674, 186297, 128506, 142184, 188904, 125449, 178015, 182978, 161857, 111836, 106115, 119021, 167240, 113184, 115504, 123876, 158754, 148711, 181008]::int[], ARRAY[233054, 250684, 273224, 267613, 239668, 271215, 286545, 224450, 282483, 266761, 298831, 255989, 244888, 291909, 235760, 224955, 291871, 226907, 281893, 239996, 286617, 257213, 233016, 201446, 272872, 245045, 206956, 241605, 207562, 262556, 215381, 266933, 240250, 289483, 241434, 266563]::int[], ARRAY[362133, 348465, 357746, 348294, 349092, 353712, 368541, 392382, 317163, 331020, 389110, 359398, 365756, 319083, 358464, 376177, 305275, 396257, 302811, 332078, 303920, 300869, 358889, 371225, 315100, 346425, 347343, 346764, 376181, 364434, 398093, 352668, 304073, 325365, 365678, 323333, 392995, 356158, 351994]::int[], ARRAY[425723, 445344, 445057, 486129, 415278, 470103, 416900, 445140, 441399, 477925, 425256, 463477, 492218, 429868, 488962, 437528, 490418, 459221, 409978, 485479, 433209, 494161, 480321, 490776, 427244, 402614, 416651, 495526, 421314, 410284, 499006, 447141, 403860, 466386, 496263, 446847, 421570, 493853, 404445, 410030, 405570, 467277, 457949, 452355]::int[], '2024-10-04 22:00:00 UTC', '2024-10-31 06:56:00 UTC', '2024-10-27 12:41:00 UTC'),
+('santino.0l9mch62@example.net', '林远瑾', 'female','2019-11-11 22:47:00 UTC', '2024-10-04 04:04:00 UTC', '2024-08-24 20:54:00 UTC', ARRAY[124149, 111146, 190700, 187219, 181095, 189529, 105927, 156573, 176425, 115400, 133415, 186660, 183224, 109516, 106747, 112193, 126585, 192629, 153482, 175443, 130764, 133235, 122311, 146438, 138752, 140616, 145934, 132731, 172344, 122174, 196806, 187219, 132534]::int[], ARRAY[204052, 244817, 220037]::int[], ARRAY[322613, 336872, 318016, 347428, 329975, 371440, 374626, 396411, 317004, 306821, 344822, 336037, 375774, 362412, 321090, 323665, 335259, 391311, 313452, 330632, 372307, 305004, 376847, 314335, 349851, 326122]::int[], ARRAY[471934, 486020, 476825, 447136, 481314, 446111, 463427, 494411, 409973, 486002, 420413, 446294, 422866, 405021, 485444, 430886, 485717, 436839, 425475, 441869, 425037, 489044, 465785, 489839, 472728, 482439, 480817, 485347, 458071, 460226, 460163, 420086, 445425, 476178, 441837, 461482, 418824, 436737, 467769, 431140, 482843, 428665, 443444, 474051, 453449, 484444, 467114]::int[], '2024-09-21 08:29:00 UTC', '2024-10-19 10:34:00 UTC', '2024-09-18 18:22:00 UTC'),
+('therese.qpz12dpe@example.net', '武旭冠', 'unknown','2024-04-29 22:41:00 UTC', '2024-10-19 01:26:00 UTC', '2024-09-22 11:21:00 UTC', ARRAY[164034, 146354, 124415, 140580, 169197, 147458, 196266, 141292, 194109, 190539, 190277, 172181, 157417, 170006, 135616, 111093, 107223, 170238, 132212, 141839, 121794, 132306, 185980, 181610, 186646, 102114, 194418, 188248, 168111, 180974, 168574, 119258, 145985, 156736, 107141, 108590, 104733, 102389, 170594, 194100, 130406, 100649, 165356, 158955]::int[], ARRAY[283841, 257861, 260160, 218970, 267848, 211544, 224483, 218544, 206893, 268530, 263029, 293500, 274744, 273158, 218886, 287760, 266043, 258373, 232364, 214532, 281396, 276878, 223026, 204022, 235618, 276135]::int[], ARRAY[305481, 320998, 375529, 359727, 321837, 336515, 300986, 304219, 335111, 372241, 305629, 330844, 316663, 399678, 361078, 333652, 342200, 374892, 351071, 389796, 305214, 334052]::int[], ARRAY[476467, 407067, 426959, 450306, 418629, 439613, 473681, 435990, 410297, 429093, 473169, 441399, 400214, 481147, 403984, 474577, 465370, 428626, 456796, 410857, 493264, 487811, 442974, 482534, 442094, 459993, 413563, 479689]::int[], '2024-09-24 13:46:00 UTC', '2024-10-25 14:54:00 UTC', '2024-08-12 15:45:00 UTC'),
+('joanne.v2xd3abo@example.net', '熊鸿灿', 'unknown','2023-03-30 23:37:00 UTC', '2024-10-14 01:07:00 UTC', '2024-09-24 12:35:00 UTC', ARRAY[170281, 114211, 142794, 172534, 165470, 178711, 111837, 187981, 115950, 129463, 128797, 182985, 126770, 134003, 135235, 150197, 143685, 132307, 164389, 153278, 187739, 194760, 134719, 127005, 154058, 112273, 100076, 175318, 119696, 187918, 108198, 116756, 114408, 130886, 116780, 139834, 152774, 115717, 107630, 180395, 135558, 131439]::int[], ARRAY[261594, 221997, 222959, 268597, 247155, 219919, 262448, 255347, 227251, 216163, 241675, 293141, 202035, 270937, 298226, 247945]::int[], ARRAY[310259, 368789, 318207, 353806, 325580, 348476, 319929, 394219, 325742, 319022, 351379, 319323, 344810, 383753, 370104, 342364, 344146, 321468, 364251, 305447, 349385, 354421, 396913, 383057, 300211]::int[], ARRAY[462165, 447062, 452211, 480189, 475200, 447086, 418861, 486254, 442958, 452766, 415030, 457036, 487599, 495779, 464362, 487196, 470575, 401556, 477243, 416335, 428209, 494993, 487868, 408292, 426993, 428702, 481669, 467350, 413987, 409737, 446650, 442742, 460636, 484679, 409031, 474365, 416529, 489222, 410708, 458853, 487914, 492600, 452645, 423094, 401054]::int[], '2024-09-22 16:13:00 UTC', '2024-10-23 15:00:00 UTC', '2024-08-18 03:40:00 UTC'),
+('royal.rxo4cxap@example.org', '田昌云', 'female','2023-01-16 20:45:00 UTC', '2024-10-13 09:56:00 UTC', '2024-10-13 13:22:00 UTC', ARRAY[121232, 188985, 154936, 180598, 139208, 149800, 156937, 136347, 170261, 173504, 127865, 106215, 163162, 161383, 138090, 175363, 136109, 129845]::int[], ARRAY[203379, 240947, 285537, 289380, 241534, 274995, 296929, 245553, 280554, 222083]::int[], ARRAY[337536, 302357, 321130, 391418, 370573, 304688, 314549, 385494, 352390, 312158, 313744, 386076]::int[], ARRAY[469639, 440014, 468104, 473414, 428421, 486521, 400515, 427084, 401063, 410329, 448364, 489443, 400737, 421518, 471751, 486753, 440045, 470200, 420355, 490282, 416891, 498081, 478121, 456709, 490999, 434664, 463953, 416289, 446510, 417133]::int[], '2024-10-29 09:52:00 UTC', '2024-10-22 10:43:00 UTC', '2024-08-24 09:45:00 UTC'),
+('dewayne.438p8m07@example.org', '覃远乔', 'unknown','2020-10-14 12:07:00 UTC', '2024-10-04 14:32:00 UTC', '2024-08-19 08:53:00 UTC', ARRAY[135680, 139530, 124520, 167907, 195060, 187738, 193292, 102712, 199178, 157208, 175796, 185682, 147212, 105708, 158674, 186405, 186186, 117006, 122609, 141360, 108771, 145307, 106193, 101580, 158794, 139429, 121092, 195379, 111788, 189283, 186673, 135307, 163033, 139036, 112841, 160851, 167683, 190172, 125793, 186548, 131398, 101979, 198131, 181057, 145785, 158619]::int[], ARRAY[265628, 245111, 236387, 288375, 249620, 252055, 249689, 252813, 273311, 296837, 254558, 291907, 206950]::int[], ARRAY[351318, 357611, 336219, 350737, 306127, 311368, 304525, 352487, 386452, 337662, 398975, 394198, 395370, 376964, 337890, 387508, 359969, 306907, 332118, 303080, 387517, 317269, 338359, 345184, 362095, 354900, 373738, 381481, 329599, 334379, 350834, 305500, 336853, 372811, 361819, 329947, 372318, 342374, 346211, 371778, 364896]::int[], ARRAY[497634, 456676, 471427, 460147, 478847, 420801, 431276, 447830, 447073, 400788, 449371, 460473, 421551, 461708, 460352, 412947, 435856, 478305, 461494, 404836, 496307, 401005, 433350, 438947, 437848, 403135, 461237, 459217, 444565, 441397, 447875, 485863, 421891, 469078, 461660, 489419, 401861, 437805]::int[], '2024-10-13 13:39:00 UTC', '2024-10-19 01:46:00 UTC', '2024-10-01 01:38:00 UTC'),
+('elfrieda.nsmp821e@example.net', '蒋韵宇', 'female','2023-01-05 07:45:00 UTC', '2024-10-23 10:35:00 UTC', '2024-10-26 06:55:00 UTC', ARRAY[164736, 199845, 184501, 140337, 154232, 169167, 114776, 138315, 145473, 158328, 179745, 104314, 181748, 179696, 192050, 127106, 171704, 195386, 107662, 170947, 121045, 120343, 195128, 146076, 115952, 162373, 199634, 105540, 178685, 137800, 129946, 107979, 145263, 118864, 199655, 129128, 112906, 114376]::int[], ARRAY[296957, 276401, 266647, 204201, 221841, 287551, 239174]::int[], ARRAY[393812, 363088, 340828, 397944, 376466, 399486, 341122, 318083, 306407, 355500, 329005, 307307, 313364, 351079, 337780, 379485, 351996, 360127, 352927, 370903, 315452, 303949, 328303, 350636, 339419, 351877, 352160, 343875, 336930, 313704, 375497, 318744]::int[], ARRAY[447658, 448638, 411213, 456163, 479637, 425682, 447064, 418650, 427025, 457603, 488232, 442443, 472651, 467883, 437915, 458690, 474983, 483763, 455198, 496943, 476020, 437237, 434036, 400485, 463658, 417987, 420204, 432862, 443717, 494499, 483427, 441439, 428274, 435217, 443519, 407430, 400248, 441189, 431945, 467178]::int[], '2024-10-15 05:44:00 UTC', '2024-10-27 22:01:00 UTC', '2024-09-13 11:50:00 UTC'),
+('arden.621k3xou@example.com', '萧昌凯', 'female','2024-07-16 23:01:00 UTC', '2024-10-06 02:43:00 UTC', '2024-09-11 12:32:00 UTC', ARRAY[167480, 140752, 140505, 119390, 171409, 162239, 108130, 101627, 160352, 132471, 111419, 116805, 175966, 125500, 108700, 174009]::int[], ARRAY[286825, 204277, 221346, 279312, 213021, 206112, 200324, 236436]::int[], ARRAY[340213, 349911, 369496, 378331, 377575, 381164, 334246, 359925, 322322, 339695, 309358, 304237, 387686, 373317, 371630, 323068]::int[], ARRAY[]::int[], '2024-10-21 12:58:00 UTC', '2024-10-18 09:32:00 UTC', '2024-10-26 00:45:00 UTC'),
+('arden.mxutmk6s@example.net', '顾梦芹', 'male','2020-07-22 08:19:00 UTC', '2024-10-14 23:14:00 UTC', '2024-09-19 22:02:00 UTC', ARRAY[111829, 118772, 114651, 108562, 187983, 190385, 124168, 190176, 179713, 128388, 165374, 189535, 195030, 141291, 132378, 147041, 198274, 159192, 129708, 123241, 115009, 184295, 178098, 181572, 140704, 163807, 169239, 143983, 177263, 195909, 104468, 162466, 148859, 122698]::int[], ARRAY[]::int[], ARRAY[343048, 344634, 353529, 338521, 331177, 340427, 351359, 379021, 396636, 318392, 382543, 377683]::int[], ARRAY[483019, 473857, 493385, 450497, 436962, 417624, 409370, 409320, 404835, 433558, 402826, 484869, 451265, 416533, 415048, 409456, 449573, 471522, 415112, 445748, 402041, 436810, 443956, 423305, 457923, 405119, 419593, 466738, 491816]::int[], '2024-10-25 11:37:00 UTC', '2024-10-29 05:15:00 UTC', '2024-09-15 22:15:00 UTC'),
+('cydney.fekfb7iq@example.org', '魏文来', 'unknown','2024-08-07 03:54:00 UTC', '2024-10-22 15:09:00 UTC', '2024-10-06 08:00:00 UTC', ARRAY[119609, 113851, 108686, 118520, 111550, 159254, 108409, 189898, 199800, 193416, 170112, 116582, 119293, 185709, 181866, 105010, 155944, 141198, 148588, 140032, 109478, 171256, 110094, 101544, 178989, 168733, 155397, 198099, 177739, 183041, 179621, 131851, 171115, 141401, 159298]::int[], ARRAY[286759, 254398, 243123, 222098, 284142, 223712, 263513, 231736, 251597, 285735, 221528, 299749, 258328, 257569, 236097, 231359, 221833, 273871, 205392, 217691, 298850, 296796, 261626, 293775]::int[], ARRAY[382370, 367028, 374714, 334631, 358874, 360228, 387604, 372867, 368480, 316276, 370014, 358798, 397374, 389161, 344457, 355304, 346897, 338929, 375381, 390329, 364803, 373758]::int[], ARRAY[440013, 457756, 400015, 475066, 433094, 482341, 442189, 482262, 403397, 406809, 441137]::int[], '2024-10-19 21:19:00 UTC', '2024-10-24 05:55:00 UTC', '2024-10-05 21:03:00 UTC'),
+('ashtyn.tij6s4af@example.com', '龙明志', 'female','2020-09-18 01:19:00 UTC', '2024-10-12 18:24:00 UTC', '2024-08-20 23:21:00 UTC', ARRAY[144022, 112369, 109752, 163406, 148175, 115560, 128830, 171577, 159154, 174747, 175369, 116183, 157842, 154594, 132012, 190702, 140290, 178336, 153025, 113607, 167951, 109766, 181828, 118562, 183457, 113053, 105040, 141389, 189181, 172015, 174006, 177459, 158039, 162477, 100735, 161427, 106291, 193609, 149469, 122029]::int[], ARRAY[299548, 287117, 242966, 255800, 261042]::int[], ARRAY[389829, 391360, 368583, 376741, 313347, 332558, 378952, 387160, 395802, 300577, 318289, 359107, 308029, 331283, 323280, 330023, 358085, 328088, 321341, 393873, 308278, 385381, 314205, 382495, 372201, 362653, 380191, 370984, 380117, 393559, 386568, 345575, 349768, 328415]::int[], ARRAY[432355, 414925, 489591, 458508, 480513, 409225, 473208, 417435, 454086, 426848, 433570, 476354, 449918, 456006, 437179, 488337, 499284, 440565, 470858, 481584, 468557, 443137, 432679, 498412, 468897]::int[], '2024-10-17 05:40:00 UTC', '2024-10-24 00:18:00 UTC', '2024-08-14 17:22:00 UTC'),
+('ezequiel.npdtyor4@example.com', '卢新远', 'male','2023-01-21 13:06:00 UTC', '2024-10-15 00:48:00 UTC', '2024-10-07 19:36:00 UTC', ARRAY[121171, 193186, 156503, 128533, 186009, 171963]::int[], ARRAY[220541, 206213, 234346, 265834, 207507, 229733, 218978, 225125, 276926, 234028, 275431, 210427, 208452, 238983, 285571, 203056, 274986, 215262, 293479, 231005, 284655, 229990, 291750, 200116, 276154, 222751, 291945, 274197, 297966, 295707, 273203, 237411, 217226, 201366, 275303, 222271, 225707, 282282, 293473]::int[], ARRAY[377614, 374544, 342860, 311816, 370622, 338145, 383510, 346184, 303278, 348249, 308199, 308702, 358113, 305139, 300955, 398192, 336185, 371906, 311161, 362619, 386313, 318154, 303119, 387038, 332261, 339293, 388975, 351065, 356334, 306344, 380585, 311185, 374009, 367860, 384979, 365872, 302124, 332029, 306596, 396493, 372958, 339202, 303408, 356418, 307122, 382998]::int[], ARRAY[444491, 473404, 406756, 432473, 492494, 467754, 484117, 470852, 483391, 477175, 412326, 493482, 408717, 423941, 452008, 453012, 483044, 445734, 425701, 461489, 458077, 413097, 468087, 453789, 430335, 493694, 421523, 414222, 475027, 449084, 443030]::int[], '2024-10-26 09:06:00 UTC', '2024-10-17 17:08:00 UTC', '2024-09-28 02:16:00 UTC'),
+('ila.xocy19ac@example.com', '冯清云', 'unknown','2019-12-27 18:11:00 UTC', '2024-10-19 23:10:00 UTC', '2024-08-22 00:34:00 UTC', ARRAY[140056, 113881, 170700, 141226, 128056, 112390, 116541, 185909, 144886, 116582, 167654, 180937, 193878, 182619, 162616, 178024, 104976, 140076, 140819, 124874, 169283, 176780, 114113, 143110, 186262, 167775, 174695, 105793, 143016, 101447, 144650, 169948, 141822, 185614, 143725, 114328]::int[], ARRAY[268201, 237006, 268318, 209754, 234344, 299856, 284637, 284055, 215219, 226912, 236140, 268881, 217399, 234899, 299282, 232464, 298166, 268103, 283035, 279420, 247609, 266066, 265039, 234010, 209343, 228502, 219086, 294793, 215979, 217841, 243107, 256708, 249557, 292831, 270895]::int[], ARRAY[327842, 388304, 395589, 342645, 397495, 319643, 301883, 301821, 393819, 309277, 312601]::int[], ARRAY[]::int[], '2024-10-06 07:45:00 UTC', '2024-10-19 19:54:00 UTC', '2024-09-06 14:31:00 UTC'),
+('estell.gz9q03zh@example.net', '秦雨嘉', 'female','2023-02-16 11:58:00 UTC', '2024-10-17 09:07:00 UTC', '2024-08-19 10:48:00 UTC', ARRAY[135600, 134758, 149748, 134821, 105530, 164500, 127491, 108255, 199886, 163849, 177292, 153917, 151295, 104937, 191009, 133942, 174036, 102587, 171061, 141261, 186064, 163320, 109852, 132247, 172120, 170378, 137389, 148514, 147310, 108989, 100480, 142070, 145539, 128376, 159907]::int[], ARRAY[241460, 274294, 289741, 262058, 262819, 295852, 297385, 201307, 219468, 240430, 226755, 209904, 252912, 207824, 216195, 217312, 252098, 225129, 251375, 285583, 218278, 255498, 215121, 227957, 201983, 244764, 212387, 260581, 205492, 226633, 235257]::int[], ARRAY[310743, 315800, 333272, 327973, 357259, 398887, 364254, 308946, 331892, 313066, 389013, 301117, 346585, 346363, 307606, 305460, 354356, 386496, 373034, 349681, 381403, 342937, 319980, 396762, 382197, 328016, 387572, 321210, 326121, 397850, 333889, 377176, 312788, 354130, 310468, 369791, 335074, 343659, 351237]::int[], ARRAY[460286, 457258, 422104, 474434, 470384, 498448, 423176, 402253, 451715, 412535, 469549, 430275, 415686, 428505, 461552, 423416, 453286, 461494, 435132, 470840]::int[], '2024-09-21 03:31:00 UTC', '2024-10-23 22:57:00 UTC', '2024-10-11 14:36:00 UTC'),
+('jamil.qm3h772v@example.com', '苏柔雅', 'unknown','2021-07-07 12:51:00 UTC', '2024-10-25 03:39:00 UTC', '2024-08-22 06:23:00 UTC', ARRAY[128008, 153348, 185672, 150951, 173596, 127985, 167838, 180649, 137560, 119708, 151361, 155994, 156625, 143421, 165996, 198409, 186701, 129681, 171971, 134514, 131553, 179228, 140384, 115006, 163817, 114823, 120365, 152345, 127621, 164428, 106996, 146979, 176338, 188457, 153569, 175307, 167163, 128459, 141726, 113772, 175655]::int[], ARRAY[241642, 202079, 269115, 255370, 262623, 275729, 205507, 265810, 242160, 297333, 203534, 242624, 253466, 267096, 232568, 220444, 241336, 272838, 212213, 271648, 217271, 273369, 219826, 238716, 241081, 245028, 282920]::int[], ARRAY[352960, 396174, 342991, 361509, 376618, 334182, 309315, 369431, 366976, 331394, 335579, 363781]::int[], ARRAY[469782, 431093, 458274, 482667, 497488, 428767, 454413, 442713, 482185, 479512, 454372, 475364, 427098, 427988]::int[], '2024-09-29 23:03:00 UTC', '2024-10-27 02:36:00 UTC', '2024-08-19 03:09:00 UTC'),
+('stephon.xjgtmzmi@example.com', '林德宝', 'unknown','2021-02-09 19:08:00 UTC', '2024-10-31 09:50:00 UTC', '2024-09-15 06:29:00 UTC', ARRAY[167288, 130566, 106384, 140962, 125998, 155399, 154099, 181653, 107822, 170957, 145330, 142793]::int[], ARRAY[260644, 288511]::int[], ARRAY[397701, 352261, 363695, 337080, 358642, 360065, 328203, 324519, 327743, 389215, 340311, 344368, 380304, 303762, 349830, 303575, 322020, 357442, 303566, 319127, 309532, 362015, 341523, 330084]::int[], ARRAY[497925, 427873, 474585, 477673, 413468]::int[], '2024-10-27 07:50:00 UTC', '2024-10-28 05:52:00 UTC', '2024-10-26 23:56:00 UTC'),
+('tracey.mf0u27oa@example.net', '胡哲瀚', 'female','2022-06-27 09:12:00 UTC', '2024-10-24 09:41:00 UTC', '2024-10-13 02:07:00 UTC', ARRAY[187937, 158546]::int[], ARRAY[217218, 211912, 242073, 201772, 284751, 286628, 277267, 240832, 229569, 269815, 231221, 284153, 207632, 244861, 206079, 285865, 278822, 221600, 203527, 216234, 246853, 239564, 218729, 259638, 217227, 281058, 264139]::int[], ARRAY[330829, 371343, 369979, 335395, 323664, 354738, 313664, 361633, 356555, 313495]::int[], ARRAY[473515, 453718, 400523, 405494, 471125, 415070, 450927, 409391, 468033, 462675, 470034, 462379, 435035, 493859, 450440, 461153, 497895, 420503, 459987, 416954, 403268, 444226, 422767, 432291, 493681, 473532, 438853, 401810]::int[], '2024-09-30 23:48:00 UTC', '2024-10-23 11:52:00 UTC', '2024-09-14 20:52:00 UTC'),
+('nelle.7abf2lpe@example.com', '吴瀚德', 'female','2024-01-20 01:58:00 UTC', '2024-10-14 01:45:00 UTC', '2024-08-09 15:14:00 UTC', ARRAY[185785, 138926, 186931, 110654, 161922, 144281, 172965, 181055, 110272, 123183, 187348, 196965, 103057, 138119, 177506, 147204, 196134, 195482, 153490, 122160, 104051]::int[], ARRAY[285891, 202147, 262167, 295689, 283149, 235313, 254541, 287123, 219015, 274905, 294477, 278780, 278118, 206748, 272356, 288639, 233755, 210817, 239429, 237675, 217308, 295251, 255516, 235048, 266825, 211135, 276334, 260732, 225582, 209535, 285022, 219934]::int[], ARRAY[347592]::int[], ARRAY[456266, 456357, 447594, 467702, 433163, 446929, 453546]::int[], '2024-10-19 10:48:00 UTC', '2024-10-17 00:50:00 UTC', '2024-08-08 20:09:00 UTC'),
+('jolie.qejlqeip@example.net', '王桦楠', 'unknown','2022-10-01 09:16:00 UTC', '2024-10-31 13:56:00 UTC', '2024-08-10 21:01:00 UTC', ARRAY[104556, 116998, 141920, 148586, 166884, 121159, 112155, 143360, 199162, 189519, 100504, 128716, 175428, 140096, 102640, 149069, 121241, 112038, 130954, 130145, 135809, 185812, 156717, 103081, 111554, 105002, 146469, 114787, 118160, 151120, 185142, 106554, 154164, 131407, 104562, 103689, 142135, 138463, 109569, 178028, 104725, 180452, 113718, 124534, 152667, 186524, 155007, 105244, 150618]::int[], ARRAY[274432, 297036, 237046, 237550, 251469, 236960, 287519, 290645, 235569, 256208, 252817, 249120, 250380, 294554, 286346, 265867, 211420, 220360, 246086, 264792, 280370, 293539, 285092, 280104, 258230, 218943, 270502, 235437, 222725, 219636, 265647, 216679, 282286, 230449, 213095, 225679, 291305, 257801, 210808, 299013, 258658, 212524, 203299, 205685]::int[], ARRAY[301263, 312766, 385610, 388702, 316081, 314292, 349265, 332880, 351135, 306004, 380393, 387507, 367386, 307067, 308183, 392203, 339367, 320429, 368517, 391204, 396723, 315351, 381354, 320990, 377428, 392651, 330102, 330814, 315487]::int[], ARRAY[469387, 488377, 454451, 468277, 439545, 485867, 408838, 416719, 406895, 438967, 490608, 438556, 408541]::int[], '2024-09-20 19:59:00 UTC', '2024-10-27 04:15:00 UTC', '2024-09-23 21:01:00 UTC'),
+('margarett.arfu2zb2@example.com', '赵寒烟', 'female','2021-08-12 00:34:00 UTC', '2024-10-23 15:08:00 UTC', '2024-10-07 10:52:00 UTC', ARRAY[181976, 197188, 133355, 155308, 122078, 198154, 134592, 191780, 189007, 115384, 199044, 128577, 101641, 157713, 158947, 141601, 127579, 194444, 116136, 150385, 157208, 103467, 138398, 195899, 188610, 125472, 192706, 198819, 182388, 131679, 183922, 191524, 153137, 125908, 190798, 153226, 186182]::int[], ARRAY[258159, 234036, 280727, 237829, 299561, 214072, 237593, 219230, 211902, 228288, 224979, 277742, 222865, 250843, 208640, 247644, 244639, 243948, 209339, 292871, 293055, 286763, 262708, 293223, 240502, 294378, 221347, 204424, 235618, 261136, 221820, 253640, 288091, 225872, 268344, 272707, 229308, 295728, 225972, 210578, 267384, 292343]::int[], ARRAY[362092, 357886, 355399, 337366, 326938]::int[], ARRAY[423286, 445423, 450375, 447111, 437236, 478170, 422468, 426489, 439936, 403269, 489784, 467705, 470569, 412367, 484714, 487681, 485025, 484416, 455440, 426226, 440254, 440136, 498302, 439554, 473655, 471843, 440201, 493424, 413955, 430454, 418582, 410302, 422846, 411062, 495447, 473482, 439809, 439347, 462211, 474832, 489989, 433461, 425371, 486518]::int[], '2024-09-18 23:33:00 UTC', '2024-10-31 09:08:00 UTC', '2024-08-04 12:37:00 UTC'),
+('roel.6876x58n@example.com', '魏文驰', 'female','2020-08-29 23:49:00 UTC', '2024-10-24 07:25:00 UTC', '2024-10-15 14:41:00 UTC', ARRAY[161611, 169380, 194077, 158252, 172509, 169344, 102033, 186684, 187128, 114728, 189984, 140853, 131251, 152494, 122320, 133404, 195578, 132896, 176567, 150083, 124926, 172010, 190938, 178839, 196156, 147870, 155180, 102654, 106782, 147054, 161465, 199433, 126732, 110285, 166114, 114856, 194576, 143959, 111532, 171948, 143215, 109718]::int[], ARRAY[292608, 251153, 294561, 206548, 210261, 255352, 266380, 271002, 207224, 286346, 273693, 210322, 265547, 261328, 240419, 235212, 239600, 282370, 285950, 296985, 258833, 275147, 234739, 270288, 284954]::int[], ARRAY[394383, 357548, 399984, 359800, 331789, 311119, 351058, 340821, 372992, 364775, 356624, 312719, 370788, 370816, 350375, 391866, 384696, 329615, 352915, 337475, 380121, 332482, 398159, 358202, 345782, 330347]::int[], ARRAY[404431, 417718, 424257, 442257, 408035, 402192, 446154, 401325, 481591, 408180, 472813, 441692, 448461, 469814, 498635, 477551, 413647]::int[], '2024-09-19 04:54:00 UTC', '2024-10-17 15:09:00 UTC', '2024-10-16 01:52:00 UTC'),
+('mckenna.n8e9u51a@example.com', '夏杉晖', 'unknown','2024-05-22 02:32:00 UTC', '2024-10-28 18:50:00 UTC', '2024-10-03 05:29:00 UTC', ARRAY[138138, 150261, 141683, 122013, 183097, 120096, 157084, 195581, 164001, 168613, 197967, 100430, 172632, 133900, 185096, 142390, 162844, 184759, 199135, 136208, 128137, 169257, 137432, 166261, 141969, 166586]::int[], ARRAY[240813, 218863, 224446, 280090, 299703, 272139, 245133, 226948, 250342, 214812, 202443, 210624, 246396, 216709, 246658, 258795, 222700, 226750, 295793, 251009, 258727, 293485, 258575, 236623, 264740, 245112, 215704, 272751, 262479, 232957]::int[], ARRAY[]::int[], ARRAY[465449]::int[], '2024-10-12 04:28:00 UTC', '2024-10-21 14:16:00 UTC', '2024-09-23 18:37:00 UTC'),
+('moriah.k3g7opsm@example.com', '孟晓巧', 'female','2023-02-14 21:44:00 UTC', '2024-10-17 21:44:00 UTC', '2024-09-26 22:17:00 UTC', ARRAY[182556, 120802, 199006, 143638, 112946, 161367, 140952, 136531, 177419, 134027, 177490, 136455, 181425, 187977, 139798, 160684, 189936, 123338, 114886, 159752, 129217, 137853, 126301, 111666, 187581, 191946, 137451, 109246, 142145, 135617, 197591, 124898, 167926, 129167, 137939, 146029, 174080, 150105, 166090, 143601, 179121, 180070, 175791, 136012, 104712, 180767, 160816, 180820, 186955]::int[], ARRAY[287774, 299424, 265684, 295308, 216515, 244840, 278541, 222078, 221585, 223817, 205322, 289799, 298990, 204482, 234898, 226650, 214237, 290161, 262555, 217919, 210166, 219914]::int[], ARRAY[390630, 338047, 303318, 377412]::int[], ARRAY[434620, 480936, 472926, 447622]::int[], '2024-10-22 00:52:00 UTC', '2024-10-31 10:14:00 UTC', '2024-08-06 16:41:00 UTC'),
+('ryleigh.6c5qcebb@example.net', '徐文韵', 'male','2021-07-08 10:26:00 UTC', '2024-10-16 09:24:00 UTC', '2024-10-28 15:37:00 UTC', ARRAY[131464, 165700, 194257, 112293, 124252, 179622, 182532, 142799, 118132, 175964, 120800, 178491, 182836, 150344, 148486, 195202, 118097, 175842, 155376, 136741, 185099, 114924, 153651, 128983, 194295, 146710]::int[], ARRAY[283116, 258135, 250694, 243027, 274535, 206542, 275666, 235848, 213706, 229405, 286616, 210735, 277843, 277053, 221602, 235299, 201041, 240309, 257284, 251875, 274585, 244477, 297298, 243775, 214205, 220986, 216480, 219111, 224864, 265052, 293576, 208608, 265804, 233823, 216856, 208227, 267570, 235761, 244763, 220251, 291056, 225354, 200823, 252753, 206565, 288035, 215340, 221646, 299007]::int[], ARRAY[312541, 375984, 343290, 378079, 349368, 301873, 361292, 313886, 331532, 325856, 368572, 360669, 302194, 364863, 310729, 379438, 399192, 328501, 359385, 338918, 342647, 328431, 375810, 397875]::int[], ARRAY[490261, 474510, 484855, 489785, 459606]::int[], '2024-09-25 14:45:00 UTC', '2024-10-23 01:17:00 UTC', '2024-10-24 17:40:00 UTC'),
+('matilda.kaku3hsy@example.org', '唐花萍', 'male','2021-12-28 10:19:00 UTC', '2024-10-13 01:41:00 UTC', '2024-10-06 08:10:00 UTC', ARRAY[173487, 187355, 186534, 107215, 173528, 140936, 161824, 171481, 162367, 163328, 151298, 119851, 163990, 142302, 199283, 136413, 199512, 109947, 118429, 142603, 134882, 153110, 179743, 184988, 114551, 157187, 109921, 128761, 103181, 115614, 186594, 187988, 155244, 126721, 125515, 157987, 112002, 155727, 185390, 161880]::int[], ARRAY[225791, 214153, 293637, 246886, 219982, 274463, 247865, 244667, 232115, 294376]::int[], ARRAY[306462, 342721, 381799, 374729, 303655, 389685, 359005, 341657, 383180, 332716, 380409, 338403, 337969, 340981, 354689, 335272]::int[], ARRAY[454218, 460860, 470510, 442912, 455411, 477684, 402292, 494365, 475531, 443887, 437510, 400344, 453294, 404444, 421835, 478108, 460321, 452964, 402082, 413467, 466384, 464074, 406741, 425557, 451520, 492107, 432893, 498396, 413329, 451486, 489668, 432854, 438002, 421388, 414299, 443804, 496812, 462060, 424200, 417723, 448918, 407273, 473863, 492031, 495761]::int[], '2024-10-20 02:02:00 UTC', '2024-10-21 10:43:00 UTC', '2024-08-18 22:10:00 UTC'),
+('toni.l7gapsmk@example.net', '闫志伟', 'female','2021-12-15 15:52:00 UTC', '2024-10-08 22:22:00 UTC', '2024-09-09 16:32:00 UTC', ARRAY[181764, 136795, 125510, 115988, 122399]::int[], ARRAY[234725, 252129, 286540, 240502, 202179, 261835, 285144, 218569, 241257, 236990, 221323, 248325, 232203, 250699, 220369, 286971, 261200, 268381, 273002, 221396, 258763, 209969, 240744, 288602, 234220, 290287, 243538]::int[], ARRAY[385955, 340836, 384855, 397264, 321409, 315876, 340694, 383623, 367594, 399335, 304189, 386068, 345069, 358948, 321727, 304644, 393330, 334159, 370494, 358015, 330717, 350384, 307968, 387128, 318307, 341654, 308821, 344086, 395298, 374164, 334596, 340304, 356899, 352839]::int[], ARRAY[424399, 433086, 424037, 434448, 444847, 456236, 478217, 438236, 487559, 416642, 432130, 489780, 498858, 430760, 473735, 461970, 477328, 442947, 461285, 407327, 466684, 440398, 443261, 443229, 471603, 401185, 491156, 423440, 454277, 481426, 467230, 470671, 448371, 418277, 434376, 432025, 456503, 481155, 406711, 459170, 468364, 412778, 410057, 457713, 482416]::int[], '2024-10-21 03:47:00 UTC', '2024-10-31 11:16:00 UTC', '2024-09-16 14:36:00 UTC'),
+('erwin.5jyuyxnw@example.com', '严远鸿', 'unknown','2020-08-12 18:57:00 UTC', '2024-10-08 14:21:00 UTC', '2024-08-30 01:20:00 UTC', ARRAY[190899, 156253, 140956, 186820, 119611, 168613, 127256, 115886, 177193, 128086, 173613, 173549, 193451, 115514, 184765, 114352, 184598, 143955, 112415, 131770, 131841]::int[], ARRAY[282281, 268348, 219687, 291817, 291671, 247238, 252686, 294241, 235656, 240823, 221868, 296319, 273592, 227455, 232448, 212788, 298448, 240165, 254073, 217199, 211385, 272102, 237132, 254720, 283812, 233723, 253121, 208728, 218074, 234411, 260676, 249224, 228883, 297831, 214291]::int[], ARRAY[373921, 383307, 324634, 324075, 361733, 323471, 328564, 304981, 350965]::int[], ARRAY[403715]::int[], '2024-10-28 20:56:00 UTC', '2024-10-22 09:39:00 UTC', '2024-10-23 10:05:00 UTC'),
+('ariel.z991cfqc@example.net', '林昕怡', 'unknown','2021-04-21 16:23:00 UTC', '2024-10-20 03:34:00 UTC', '2024-09-22 06:03:00 UTC', ARRAY[139922, 129715, 185313, 102973, 167946, 109328, 136195, 166462, 179290, 119244, 166591, 100358, 141993, 185095, 134280, 168455, 153600, 126046, 135846, 182143, 119467]::int[], ARRAY[206711, 257258, 280488, 242721, 236904, 284333, 278529, 255430, 208196, 278566, 263248, 262265, 258175, 243398, 296804, 229935, 297925, 236930]::int[], ARRAY[]::int[], ARRAY[451593, 443562, 480935, 403450, 458066, 447474, 429853, 498878, 456331, 490797, 499614, 413613, 471146, 457411, 479300, 402341, 420147, 402909, 441031, 432299, 445069, 481980, 494872, 435944, 463450, 499148, 483162]::int[], '2024-10-10 00:32:00 UTC', '2024-10-22 23:50:00 UTC', '2024-10-30 10:27:00 UTC'),
+('thea.wtbyn3ad@example.com', '叶鼎宇', 'male','2022-08-22 16:10:00 UTC', '2024-10-16 00:08:00 UTC', '2024-08-11 19:10:00 UTC', ARRAY[151706, 122213, 118050, 183579, 146308, 185093, 133549, 169849, 140863, 176507, 177530, 174777, 101366, 115932, 119643, 167336, 196334, 173121, 108269, 179909, 155206, 158818, 113121, 180532, 106444, 101983, 128772, 197202, 164387, 168857]::int[], ARRAY[213269, 273724, 237229, 256613, 232855, 264024]::int[], ARRAY[341970, 380613, 366100, 326202, 329305, 338402, 368461, 398964, 394678, 353160]::int[], ARRAY[454877, 409831, 446394, 430389, 414505, 473911, 474954, 440475, 472266, 499470, 455149, 443899, 449179, 466152, 407416, 457809, 407329, 497589, 434020, 455807, 495894, 403060, 404432, 405534, 466748, 420623, 447281, 463482, 418513, 498183, 491534, 410204, 421827, 408558]::int[], '2024-09-24 18:38:00 UTC', '2024-10-23 04:05:00 UTC', '2024-09-23 09:18:00 UTC'),
+('delphine.jfkbmilz@example.com', '闫杰邦', 'female','2023-04-30 11:23:00 UTC', '2024-10-24 12:53:00 UTC', '2024-08-18 20:04:00 UTC', ARRAY[157779, 106440, 189107, 101333, 155077, 131335, 107728, 191726, 167168, 157388, 197377, 101239, 175876, 199235, 167990, 124227, 149038, 113162, 122046, 110763, 101137]::int[], ARRAY[282670, 277247, 219850, 274873, 257940, 238891, 274948, 261125, 276997, 210982, 287648, 251005, 277985, 286202, 287938, 206158, 204831, 227308, 259444, 252735, 222173, 205024, 217103, 283107, 244759, 208835, 278245]::int[], ARRAY[309085, 305074, 324740, 338737, 309617, 301963, 304079, 371178]::int[], ARRAY[443880, 410122, 408410, 460053, 417099, 416850, 461301, 419710, 402816, 478707, 414187, 482501, 420468, 437041, 440817, 445834, 407058, 425145, 446782, 445708, 484700, 454708, 404421, 416361, 404230, 495168, 458081, 476943]::int[], '2024-10-09 18:28:00 UTC', '2024-10-17 16:52:00 UTC', '2024-08-31 02:39:00 UTC'),
+('manley.kl4h2j44@example.com', '韩雁卉', 'female','2022-06-01 09:23:00 UTC', '2024-10-23 18:56:00 UTC', '2024-08-23 14:40:00 UTC', ARRAY[176816, 154738, 191605, 145897, 159955, 169917, 177192, 166289, 105244, 126304, 172236, 118694, 106414, 109094, 188013, 117424, 198774, 184159, 112109, 186226, 164550, 180915, 123443, 191586, 127143, 182064, 190941, 130407, 176025, 152376, 168102, 162510, 104503, 135457, 151061, 147275, 141346, 195652, 199688, 152114, 130358, 168904, 155174, 188983, 187774, 130663, 147970, 122968, 114102]::int[], ARRAY[248992, 299469, 256337, 271584, 262724, 249232, 252004, 258157, 273989, 271938, 211685, 263567, 238137]::int[], ARRAY[348325, 393239, 386266, 313793, 313084, 314300, 392176, 364223, 344997, 341387, 385391, 386022, 399814, 358144, 370703, 376840, 392916, 321672, 322266, 360401, 300998, 324805, 331145, 328527, 379350, 313743, 343871, 377036, 311617, 382979, 357102, 333206, 394032, 301446, 395160, 312854]::int[], ARRAY[430020, 462933, 401153, 410308, 470974, 453223, 453526, 410762, 470112, 493272, 453146, 426330, 456711, 402777, 454749, 407990]::int[], '2024-10-07 09:40:00 UTC', '2024-10-17 15:51:00 UTC', '2024-10-01 20:36:00 UTC'),
+('moshe.llyu6edd@example.net', '杨浩新', 'male','2023-09-13 12:28:00 UTC', '2024-10-20 16:46:00 UTC', '2024-10-12 06:38:00 UTC', ARRAY[133695, 171806, 187446, 195762, 106161, 143533, 135344, 193765, 106062, 134318, 103173, 101913, 147468, 137194, 148735, 121277, 117240, 184870, 187794, 115252, 130905, 127944, 141613, 108432, 137510, 165126, 134203, 101747, 179820, 191707, 194153, 166737]::int[], ARRAY[230828, 297629, 235940, 211611, 293030, 277101, 261382, 233460, 258651, 208576, 249413, 218197, 298730, 213975, 231138, 230630, 291710, 200914, 209095, 258865, 201153, 231756, 215359, 283700, 279000, 275616, 290801, 224941, 246541, 275920, 299474]::int[], ARRAY[329343, 367859, 327253, 335103, 322668, 382521, 379089, 322490, 376684, 399524, 381483, 374509, 364505, 302148, 380739, 336441, 373618, 326346, 372233, 383270, 361278, 301242, 390247, 321820, 396334, 367909, 332044, 364779, 374926, 356499, 385752, 361428, 364507, 391598, 327485, 348630, 360021, 387546, 366037, 300448, 349823, 354560]::int[], ARRAY[438809, 425555, 470397, 445926, 426765, 412043, 496878, 485244, 492759, 457229, 401996, 439982, 432928, 434362, 412911, 459345, 472077, 414086, 433122, 471817, 497202, 416579, 490237, 462658, 444019, 481390, 404842, 482592, 404591, 439002, 452397, 422955]::int[], '2024-10-04 17:42:00 UTC', '2024-10-29 05:30:00 UTC', '2024-09-14 00:15:00 UTC'),
+('allison.k4ai1i4u@example.org', '侯宇瀚', 'unknown','2021-05-12 01:58:00 UTC', '2024-10-18 16:45:00 UTC', '2024-10-22 14:51:00 UTC', ARRAY[168775, 154869, 125605, 180125, 159796, 158432, 107825, 144474, 171147, 188799, 154454, 176970, 168833, 127195, 144828, 162825, 171276, 185163, 154046, 151010, 181297, 111209, 123618, 190678, 162365, 123383, 173391, 120063, 109670, 139472, 129741, 139074, 103543, 110955, 139698, 158646, 149753, 146504, 159974, 190903, 122399, 175860, 185339]::int[], ARRAY[254642, 289955, 286421, 226473, 293159, 232746, 220939, 299673, 286993, 242946, 286068, 275743]::int[], ARRAY[320859, 383262, 326110, 353195, 300359, 361513, 322453, 315763, 396141, 338300, 393057, 325520, 372802, 347866, 346084, 323023, 339858, 333872]::int[], ARRAY[499286, 413878, 488917, 403427, 485951, 423149, 424541, 470025, 423374, 462389, 492884, 445807, 459570, 423007, 432736, 497011, 445708, 402010, 467592, 498999, 472469, 467352, 473365, 470148, 424596]::int[], '2024-09-20 21:13:00 UTC', '2024-10-21 00:38:00 UTC', '2024-09-21 14:54:00 UTC'),
+('adell.x2k3zq3u@example.com', '魏秋荷', 'male','2020-02-09 16:42:00 UTC', '2024-10-14 23:01:00 UTC', '2024-09-10 12:51:00 UTC', ARRAY[140231, 173355, 175238, 169600, 138856, 174384, 167916, 168436, 178722, 104847, 176500, 101003, 118327, 103404, 148907, 134013, 154804, 118329, 180934, 141319, 102310, 101019, 186192, 179219, 135916, 199617, 195162, 109717, 181082, 168415]::int[], ARRAY[220740, 207594, 218968, 272305, 223206, 232301, 268799, 255519, 281993, 297752, 204623, 240863, 230780, 215964, 222205, 286512, 283823, 212805, 223834, 221184, 280480, 235342, 287467, 212157, 238357, 268311, 252757, 294910, 221453, 258936, 283009, 220337, 230107]::int[], ARRAY[333604, 346431, 348612, 354096, 386325, 377261, 388450, 397632, 357122, 372431, 348751, 373864, 381273, 365307, 303954, 376971, 364754, 387363, 384760, 365276, 312900, 398005]::int[], ARRAY[472169, 485972, 481324, 435261, 438374, 495935, 436954, 463307, 452866, 494449, 421269, 495271, 411866, 455032, 482413, 452949, 434268, 418538, 465116, 437615, 414666, 413818, 439969, 455938, 496699, 456244, 463450, 489990, 475057, 412018, 491955, 484287, 404789, 462426]::int[], '2024-09-24 03:09:00 UTC', '2024-10-21 01:31:00 UTC', '2024-08-19 11:32:00 UTC'),
+('federico.acppmjm0@example.net', '马寻春', 'female','2024-01-24 04:35:00 UTC', '2024-10-18 13:54:00 UTC', '2024-08-22 19:02:00 UTC', ARRAY[147011, 104556, 175620, 176913, 126006, 109795, 122501, 115696, 184833, 116047, 189770, 174472, 190117, 161007, 118865, 151525, 147701, 157363, 195144, 115304]::int[], ARRAY[269025]::int[], ARRAY[351822, 366276, 323724, 392321, 305113, 381229, 396598, 346895, 383499, 328536]::int[], ARRAY[441412, 497142, 422416, 403813, 485611, 488309, 401216, 465778, 485387, 473421, 483862, 414884, 444995, 450170, 410524, 463976, 447202, 424369, 468437, 446608, 443290, 497994, 445462, 415363, 444736, 453995, 489274, 430496, 419559, 484326, 498337, 454587, 451369, 418742, 440313, 465809, 432902, 408478, 421279]::int[], '2024-09-17 02:42:00 UTC', '2024-10-27 09:27:00 UTC', '2024-10-18 09:01:00 UTC'),
+('blaise.zov9v91h@example.com', '曾彬锐', 'male','2022-11-02 05:47:00 UTC', '2024-10-27 21:33:00 UTC', '2024-09-28 17:15:00 UTC', ARRAY[105386, 104689, 126603, 186905, 117826, 106047, 170486, 106692, 143921, 136190, 194028, 114576, 125616, 177774, 154530, 133019, 180392, 134540, 176692, 187919, 151782, 179719, 171005, 130981, 160210, 135788, 141171, 128167, 137072, 163094, 147293, 172105, 184311]::int[], ARRAY[216735, 266809, 261750, 203098, 250114, 270346, 223084, 205898, 218856, 258671, 215077, 233933, 245726, 203077, 262642, 230301, 296752, 289111, 268660, 288514, 202469, 249957, 217042, 269916, 216191, 230012, 270039, 294124, 294853, 256915]::int[], ARRAY[301287, 324935, 372271, 386649, 351089, 391614, 301522, 336966, 347281, 324767, 310984, 302509, 332305]::int[], ARRAY[428430, 445106, 465641, 487740, 425962, 457910, 468097, 461580, 463932, 438786, 431031, 445696, 472651, 458786, 426940, 432931, 475383]::int[], '2024-10-17 07:01:00 UTC', '2024-10-27 07:56:00 UTC', '2024-08-16 15:12:00 UTC'),
+('finn.gwnaarvw@example.com', '徐向卉', 'unknown','2021-09-18 04:57:00 UTC', '2024-10-19 13:15:00 UTC', '2024-10-01 17:36:00 UTC', ARRAY[125979]::int[], ARRAY[236393, 271441, 270365, 289888, 268874, 296631, 233238, 287445, 258816, 288649, 258409, 260939, 285897, 279136, 278367, 244930, 226476, 291669, 250722]::int[], ARRAY[364445]::int[], ARRAY[490695, 403590, 433311, 475744, 465258, 403394, 469757, 415683, 416703, 441850, 423881, 470351, 441084, 482123, 410000, 427556, 421350, 497825, 401027, 460916, 434687, 499374, 464833, 445452, 453298, 419209, 468490, 476701, 451692, 475649, 452559, 441124, 403374, 412824, 404910, 411752, 419383, 408659, 418335, 487851, 409981, 419026, 451641, 483222, 417238]::int[], '2024-10-09 21:28:00 UTC', '2024-10-22 08:50:00 UTC', '2024-09-23 17:49:00 UTC'),
+('orion.46tzowlq@example.net', '龙雨瑞', 'unknown','2023-06-03 05:51:00 UTC', '2024-10-10 11:19:00 UTC', '2024-10-15 21:34:00 UTC', ARRAY[198264, 135172, 161920]::int[], ARRAY[250557, 254826, 230607, 255839, 235548, 263436, 212455, 212493, 268464, 277658, 231185, 211512, 284004, 282446, 271605, 255237, 232477, 272873, 240472, 292813, 233155, 228093, 281429, 209225, 220016, 227688, 294056, 273490, 213316, 245488, 271035, 269517, 207210, 240563, 232770, 214881, 226486, 291768, 281713, 201853, 243589]::int[], ARRAY[301926, 382692, 353519, 318235, 324069, 340238, 395167, 355514, 319952, 382918, 387871, 399483, 371198, 361283, 335912, 345141, 323553, 331002, 339381, 349283]::int[], ARRAY[454048, 463462, 430176, 482910, 404215, 473511, 429754]::int[], '2024-10-12 09:37:00 UTC', '2024-10-24 16:44:00 UTC', '2024-09-07 10:58:00 UTC'),
+('florine.cg0mexfi@example.com', '曾博伦', 'female','2020-08-31 15:14:00 UTC', '2024-10-07 20:23:00 UTC', '2024-08-24 16:01:00 UTC', ARRAY[177069, 162715, 171578, 160654, 144476, 147373, 197876, 189173, 152772, 139664, 103139, 118570, 111340, 111517, 106398, 122780, 118940, 178996, 181351, 128445, 123918, 113090, 182373]::int[], ARRAY[]::int[], ARRAY[377022, 354435, 329999, 337482, 320182, 317448, 399174, 391609, 387956, 388823, 377686, 335271, 329720]::int[], ARRAY[486524, 480908, 464013, 416888, 426437, 404149, 465277, 428532, 494138, 427024, 429021, 461391, 416775, 424104]::int[], '2024-10-03 01:25:00 UTC', '2024-10-24 20:47:00 UTC', '2024-08-25 15:06:00 UTC'),
+('sophia.ookashly@example.net', '赵翰辰', 'female','2021-11-30 04:56:00 UTC', '2024-10-16 11:46:00 UTC', '2024-10-19 23:05:00 UTC', ARRAY[108511, 184844, 118372, 137736, 146750, 156546]::int[], ARRAY[240209, 269682, 277032, 222613, 266864, 285978, 297028, 228327, 250319, 233398, 213638, 215159, 292152, 282998, 287720, 225101, 202693, 252822, 292803, 223410, 266975]::int[], ARRAY[396056, 386975, 353543, 301505, 353974, 315877, 331646, 351229, 311417, 393685, 304279, 351297, 305751, 330829, 380600, 353502, 342129, 329687, 371942, 345719, 344842, 394976, 352608, 300764, 367099]::int[], ARRAY[497303, 471992, 498897, 404331, 475774, 456440, 400316, 468805, 450102, 422021, 476351, 429862, 452222, 420873, 427911, 468791, 462300, 455687, 450803, 470389, 431379, 452865, 494097, 460570, 421609, 480473, 404158, 487201, 437138, 426598, 441194, 423892, 458679, 451069, 499961, 441960, 438039, 401270, 494552, 474026, 449518, 461933, 424603, 455137, 465151, 450672]::int[], '2024-10-11 01:43:00 UTC', '2024-10-21 23:50:00 UTC', '2024-10-14 00:08:00 UTC'),
+('narciso.2al327ot@example.org', '林涛羽', 'male','2024-03-11 01:32:00 UTC', '2024-10-28 21:48:00 UTC', '2024-10-26 09:35:00 UTC', ARRAY[163283, 105211, 197085, 156743, 195919, 154700, 143671, 100857, 129038, 112518]::int[], ARRAY[266252, 270889, 270132, 204161, 208724, 207578, 203052, 289079, 295914, 213482, 237126, 272026, 204737, 230449, 291676, 218753, 230916, 216167, 211141, 243671]::int[], ARRAY[320400, 354606, 311118, 374083, 378721, 351921, 361672, 382004, 348716, 395237, 329343, 396059, 335965, 338273, 373225, 349213, 307190, 370352, 367157, 320520, 375120, 332093, 327453, 373798, 319809, 336701, 320412, 348156, 325658, 374030, 386536, 362398, 326443, 310128, 330156, 383810, 330417, 337868]::int[], ARRAY[456050, 441739, 409082, 488739, 441090, 433485, 460283, 481788, 492717, 449123, 436536, 421442, 467064, 465189, 400785, 449230, 408438, 438099, 464415, 492977, 455023, 466612, 466491]::int[], '2024-09-17 17:27:00 UTC', '2024-10-25 04:26:00 UTC', '2024-10-02 20:33:00 UTC'),
+('macy.x7xdpxtc@example.org', '潘瑜昕', 'male','2022-04-27 04:58:00 UTC', '2024-10-14 20:21:00 UTC', '2024-08-14 16:31:00 UTC', ARRAY[110267, 114276, 136320, 194216, 112248, 135271, 168087, 183927, 184160, 120822, 110715, 168245, 125438, 135458, 141416, 160213, 136377, 113470, 132091, 106369, 164986, 155794, 126741, 126937, 180219, 158754, 148310, 161769, 145137, 132807, 113601, 136827, 189165, 175150, 147939, 138573, 158440, 109630]::int[], ARRAY[]::int[], ARRAY[348078, 342233, 392870, 345616, 376705, 398584, 367338, 364067, 326398, 349933, 314765, 321900, 399455, 374859, 307990, 303245, 324771, 345418, 309274, 352993, 323192, 333792, 303302, 307091, 383781, 315186, 303781, 338244, 340559, 366678, 352849, 381769, 366605, 368427, 350973, 383135, 356483, 388969, 314691, 335638, 394537, 355444, 360675, 325415]::int[], ARRAY[410544, 468598, 460095, 427976, 440656, 462375, 455109, 412569, 462499, 418138, 468161, 485415, 448707, 467877, 461955]::int[], '2024-10-30 02:34:00 UTC', '2024-10-23 03:33:00 UTC', '2024-10-27 02:41:00 UTC'),
+('adolfo.0dhc9784@example.com', '吕鸿祥', 'unknown','2019-11-05 06:15:00 UTC', '2024-10-26 01:00:00 UTC', '2024-08-10 10:48:00 UTC', ARRAY[109447, 189659, 101912, 114973, 116798, 180109, 115880, 124546, 137301, 161265, 189291, 172749, 196609, 184515, 179290, 127525, 100714, 119968, 107401, 184135]::int[], ARRAY[283004, 245650, 223867, 244783, 249738, 201268, 286871, 218485, 281738, 247165, 275376, 228987, 248673, 230713, 257541, 295248, 298819, 227454, 248730, 246526, 212027, 206878, 275019, 215996, 298087, 276407, 238430]::int[], ARRAY[345424, 308732, 394428, 372660, 383732, 328032, 314378, 389909, 354962]::int[], ARRAY[464217, 430098, 456283, 478164, 483659, 439085, 483304, 420567, 466724, 432505, 419873]::int[], '2024-09-21 17:55:00 UTC', '2024-10-20 09:19:00 UTC', '2024-10-27 08:12:00 UTC'),
+('jordy.lcdtrd8p@example.com', '覃瀚福', 'female','2024-02-03 17:11:00 UTC', '2024-10-04 14:27:00 UTC', '2024-09-04 21:34:00 UTC', ARRAY[166645, 130101, 161923, 157970, 101990, 197372, 103752, 184092, 118127, 167281, 198445, 181175, 170483, 122254, 141953, 131996, 142659, 173371, 119385, 140585, 141744, 113168, 155949]::int[], ARRAY[]::int[], ARRAY[341620, 327785, 300815, 309645, 336639]::int[], ARRAY[456995, 432721, 431280, 402549, 419967, 435746, 405708, 480000, 442703, 498983, 467763, 480902, 413206, 458699, 492077, 486525, 440305, 407863, 420494, 479091, 487252, 459389, 410182, 488224, 484741, 417273, 499157, 436395, 455071, 489551, 401307]::int[], '2024-10-11 11:06:00 UTC', '2024-10-17 14:44:00 UTC', '2024-10-15 05:45:00 UTC'),
+('alfredo.2eagugxj@example.net', '白鼎智', 'male','2022-08-23 07:23:00 UTC', '2024-10-20 01:42:00 UTC', '2024-08-11 12:51:00 UTC', ARRAY[184157, 164859, 144286, 145365, 190644, 149696, 101720, 183730, 193125, 108866, 170242, 145948, 152525, 122994, 159602, 189549, 140198, 177119, 150721, 179113, 124669, 138063, 123765, 148934, 146284, 142547, 150777, 189555, 175407, 160394, 145893, 181678, 173464, 153138, 104549, 164734, 100108, 165087, 192463, 183230]::int[], ARRAY[251427, 221476, 200127, 205837, 283562, 298233, 225458, 230249, 247359, 205800, 292283, 237344, 208232, 243152, 296356, 278552, 290077, 235007, 221884, 289164, 243416, 215484, 230724]::int[], ARRAY[314131, 318278, 323541, 316906, 372969, 397791, 347337, 310462, 384615, 395841, 323327, 314069, 342184, 390555, 376166, 332735, 343135, 342985, 315726, 334427, 360766, 348203, 348869, 395029, 397229, 382307]::int[], ARRAY[449772, 436747, 452027, 490372, 408810, 452432, 458594, 469723]::int[], '2024-10-30 18:09:00 UTC', '2024-10-17 00:27:00 UTC', '2024-09-15 18:47:00 UTC'),
+('paige.5wpaxha3@example.org', '史晓亦', 'male','2024-02-27 08:14:00 UTC', '2024-10-12 05:26:00 UTC', '2024-09-18 23:21:00 UTC', ARRAY[140540, 130358, 122711, 145880, 149328, 157716, 158245, 121701, 187675, 189399, 132410, 138079, 177715, 181450, 177996]::int[], ARRAY[210368, 266808, 218219, 279373, 232903, 286318, 247750, 259544, 202799, 226739, 222506, 206472, 260201, 225720, 221427, 256784, 228539, 293484, 229173, 222543, 271532, 278362, 287239, 234743, 240061, 283116, 210350, 247344, 209549, 234505, 257691, 285801, 239986, 210288, 268944, 262613, 270917, 239591, 207424, 251477]::int[], ARRAY[334190, 344185, 302907, 307279, 342139, 340493, 376932, 336684, 357721, 348098, 340651, 370805, 306559]::int[], ARRAY[472048, 433288, 450579, 497008, 442903, 405978, 415848, 493881, 469196, 437127, 492884, 455319, 478544, 485634, 496514, 479257, 478523, 458410, 436095, 434729, 454644, 494362, 418181, 465385, 496763, 469501, 468006, 487656, 440195, 412570, 495087, 448530, 427716, 441325, 496683, 436403, 403090]::int[], '2024-10-16 08:12:00 UTC', '2024-10-28 04:29:00 UTC', '2024-10-22 10:09:00 UTC'),
+('dave.2likq4dz@example.com', '付鼎宇', 'male','2020-04-25 00:13:00 UTC', '2024-10-20 09:39:00 UTC', '2024-10-24 18:26:00 UTC', ARRAY[193697, 138925, 162455, 174044, 137890, 139121, 179709, 194031, 139206, 193309, 128129, 153446, 150421, 169557, 148507, 197167, 185495, 113256, 146914, 153580, 197929, 149217, 160183, 137132, 115496, 182985, 160378, 138973, 135786, 158314, 146998, 145153, 157404, 178229, 109023, 138440, 112565, 144477, 131997, 107309, 194702, 198824, 102800, 128054, 133079, 148480]::int[], ARRAY[273573, 256269, 270827, 211907, 267612, 231340, 277581, 231974, 294846, 281898, 295794, 281037, 201289, 285848, 289158, 249466, 224869, 246634, 225336, 213440, 249061, 243394, 229740, 246590, 269770, 253127, 279449]::int[], ARRAY[309935, 382722, 300592, 329178, 334346, 371080, 317531, 389439, 345983, 396127, 343339, 370615, 379221, 335675, 379786, 356240, 307965, 388029, 331640, 392720, 342645, 300881, 390717, 362045, 313381, 366048, 359257, 314189, 381057, 390811, 372352, 367466, 397735, 366614]::int[], ARRAY[464666, 424113, 439859, 498108, 469177]::int[], '2024-09-17 13:01:00 UTC', '2024-10-19 18:04:00 UTC', '2024-10-09 09:28:00 UTC'),
+('laurine.zbdbrz2m@example.org', '欧阳安荷', 'female','2020-06-21 09:20:00 UTC', '2024-10-19 08:25:00 UTC', '2024-08-06 01:45:00 UTC', ARRAY[164329, 153840, 155557, 177474, 103743, 193130, 188788, 133882, 154986, 125900, 102353, 113524, 195392, 148957, 121834, 127007, 100678, 159806, 142413, 185134, 136145, 153219, 142063, 140838, 134158, 156409, 184182, 176923, 158299, 119567, 175371, 131194, 172451, 183479, 171817, 136879]::int[], ARRAY[202480, 244202, 296392, 262551, 218991, 226099, 265764, 221908, 293802, 238593, 242625, 274420, 251955, 215154, 278423, 283970, 286172, 214874, 201386, 278245, 276852, 267066, 252673, 264784, 266254, 270303, 240112, 215229, 271243, 256156, 208539, 203616, 259078]::int[], ARRAY[315635, 310126, 336912, 315381, 379649, 382882, 333510, 368074, 329767, 354259, 314007, 320468, 394638, 318799, 312964, 326283, 327593, 371129, 349313, 356989, 383388, 386815, 335677, 359444, 385290, 386661, 301339, 334911]::int[], ARRAY[493393, 434260, 486211, 409284, 430624, 461816, 428508, 436799, 435712, 480728, 406355, 448928, 403959, 406509, 464459, 482895, 453866, 427282, 427916, 435464, 462230, 487377, 482650, 425637, 487237, 450880, 404990, 411972, 493568, 455317, 417404, 404925, 493046, 476752, 471831, 457136, 441231, 428879, 421157, 487840]::int[], '2024-10-10 10:23:00 UTC', '2024-10-27 23:46:00 UTC', '2024-08-07 07:10:00 UTC'),
+('palma.5o9h9dsa@example.com', '付畅瑞', 'female','2021-12-13 16:41:00 UTC', '2024-10-30 00:38:00 UTC', '2024-09-15 12:05:00 UTC', ARRAY[156399, 161517, 189527, 190359, 110356, 131890, 137795, 110778, 157807, 165586, 150760, 150071, 135944, 117768, 196958, 161709, 166879, 133006, 103189, 127751]::int[], ARRAY[287817, 268292, 235756, 232366, 257228, 231688]::int[], ARRAY[344682, 367999, 379556, 383184, 380421, 331235, 375205, 351561, 317001, 372605, 344558, 383709, 344911, 320929, 352414, 396610, 352910, 343702, 341254, 360709, 314697, 382015, 340852, 385187]::int[], ARRAY[464351, 475648, 481239, 449250, 404062, 406659, 404507, 435355, 443200, 495607, 451605, 479181, 404808, 499116, 471027, 464573, 447639, 454170, 422951, 498193, 477375, 438719, 434931, 453118, 450255, 477054, 402928, 411761]::int[], '2024-10-03 14:50:00 UTC', '2024-10-21 00:50:00 UTC', '2024-08-21 17:24:00 UTC'),
+('giovanna.rmtr3i4y@example.org', '蔡若菲', 'unknown','2022-11-07 09:27:00 UTC', '2024-10-08 08:22:00 UTC', '2024-08-08 01:43:00 UTC', ARRAY[164900, 120322, 151445, 192897, 195115, 140247, 152415, 148994, 123983]::int[], ARRAY[256655, 232542, 219040, 237754, 283195, 223174, 276311, 229999, 202498, 258914, 240871, 295557, 284849, 219587, 223569, 221739]::int[], ARRAY[345943, 303151, 367832, 381169, 304454, 311562, 348018, 396250, 323334, 315619, 361758]::int[], ARRAY[402212, 424264, 407118, 466312, 490563, 443591, 431660, 451431, 415022, 420381, 492443, 489771]::int[], '2024-10-10 16:09:00 UTC', '2024-10-25 03:27:00 UTC', '2024-09-28 19:59:00 UTC'),
+('ike.y6ukxtfz@example.net', '顾泽信', 'female','2021-05-08 01:41:00 UTC', '2024-10-29 21:14:00 UTC', '2024-09-02 07:04:00 UTC', ARRAY[132689, 167097, 101124, 138360, 198920, 104680, 151033, 101679, 189782, 109803, 135133, 161315, 104391, 136535, 107089, 147890]::int[], ARRAY[299771, 259256, 250787, 270596, 243107, 263248, 206474, 293545, 233251, 291815, 236653, 212363, 273796, 211773, 296848, 244757, 227139, 231341, 289399, 267462]::int[], ARRAY[312792, 354842, 367193, 383576, 312854, 312414, 355332, 392850, 352595, 352571, 320199, 390084, 333866, 359433, 398298, 313807, 319201, 303733, 307348, 390507, 302222, 345430, 398118, 300439, 310500, 340861, 385819, 334015, 318460, 317365]::int[], ARRAY[402598, 437621, 459708, 424669, 466073, 466903, 408519, 496891]::int[], '2024-09-22 18:27:00 UTC', '2024-10-27 07:25:00 UTC', '2024-09-04 07:48:00 UTC'),
+('mohammad.5zfttwq5@example.org', '金雨珍', 'unknown','2022-10-20 16:12:00 UTC', '2024-10-26 12:41:00 UTC', '2024-08-22 21:08:00 UTC', ARRAY[162874, 101915, 184853, 119173, 158314, 174601, 136555, 106860, 191505, 117839, 162906, 128627, 174755, 139198, 141062, 173706, 141051, 167022, 188823, 185131, 112939, 136210, 190126, 129981, 178348, 156636]::int[], ARRAY[237173, 231067, 216861, 225605, 206355, 255236, 206073, 276888, 283960, 289857, 259569, 298048]::int[], ARRAY[389192, 390925, 328721, 323246, 318761, 364981, 360327, 367923, 381644, 326164, 332006, 376785, 319438, 306928, 380256, 382934, 354706, 318649, 360209, 391810, 346474, 365402, 346477, 374328, 397652, 374099, 314416, 352105, 380865, 380803]::int[], ARRAY[448259, 410775, 446027, 406210, 495648, 465643, 446622, 446443, 447398, 415296, 456032, 446942, 447739, 411872, 467298, 400817, 440202, 488748, 445084, 457400, 486615, 472831, 415894, 418983, 420093, 433292, 484384, 400371, 405081]::int[], '2024-10-03 00:07:00 UTC', '2024-10-26 10:45:00 UTC', '2024-08-30 23:52:00 UTC'),
+('dillan.ff1zehi6@example.org', '蒋采菡', 'unknown','2022-07-14 08:44:00 UTC', '2024-10-24 10:45:00 UTC', '2024-09-08 07:58:00 UTC', ARRAY[146623, 147692, 121354, 126538, 138528, 173543, 189854, 121741, 145369, 171628, 188436, 117570, 148918, 112474, 142831, 129321, 177317, 111751, 116223, 170911, 178555, 133824, 162991, 149762, 107994, 144637, 169642]::int[], ARRAY[246356, 214037, 276151, 216181, 224723, 273533, 259225, 296045, 223217, 221666, 232982, 278443, 263576, 297566, 274500, 271208, 207761, 207150, 264416, 269857, 236688, 210812, 225665, 281221, 247870, 281029, 254704, 265041, 258203, 295277, 227839, 295534, 239909, 262097, 288376, 230619, 239311, 216334, 221282, 278652]::int[], ARRAY[365193]::int[], ARRAY[468470, 487342, 494269, 454671, 493443, 448852, 481883, 489198, 486793, 414184, 486062, 443530, 487806, 451093, 474690, 422219, 471815, 450838, 425482, 442477, 478664, 420140, 468138, 422002, 447662, 404773, 480534, 445412, 499005, 431243, 482027, 432756, 446935, 407853, 461172, 461687, 448878, 488548, 443996, 463301, 439771, 482019, 403660, 480946, 405524, 453064, 422065, 447464, 463821]::int[], '2024-09-27 23:27:00 UTC', '2024-10-25 11:03:00 UTC', '2024-09-13 13:55:00 UTC'),
+('dexter.jch7ajdu@example.org', '许柔雅', 'male','2023-09-09 09:38:00 UTC', '2024-10-30 19:43:00 UTC', '2024-09-13 18:41:00 UTC', ARRAY[131521, 112815, 110293, 159404, 196892, 185213, 176461, 141528, 149491, 156481, 126260, 103438, 183547, 119275, 185740, 166544, 105795, 137694, 125688, 179795, 182198, 172378, 156811, 151270, 195012, 182204, 171152, 184133, 141559, 149604, 171489, 133753, 193865, 129572, 176818, 188337, 198024, 174111, 142009, 118594, 154890, 168516]::int[], ARRAY[288073, 228152, 250487, 275879, 283846, 206846, 241024, 203230, 232327, 235240, 292485, 299649, 297482, 221069, 262239, 273391, 288179, 266401, 265975, 219388, 210374, 255169, 282767, 271609, 207597, 294156, 255275, 288442, 260636, 298134, 264898, 282142]::int[], ARRAY[399796, 399081, 392478]::int[], ARRAY[488691, 477245, 471323, 498534, 414306, 466197, 418277, 411048]::int[], '2024-09-22 06:22:00 UTC', '2024-10-26 02:05:00 UTC', '2024-09-15 09:38:00 UTC'),
+('sabryna.awba5a41@example.com', '欧阳谷波', 'unknown','2022-04-04 02:25:00 UTC', '2024-10-19 22:38:00 UTC', '2024-09-29 11:12:00 UTC', ARRAY[198369, 182885, 106470, 160863, 175977, 109790, 156940, 118269, 189512, 101102, 103425, 194753, 149836, 160304, 114098, 190967, 174756, 133897, 168939, 126729, 168305, 104534, 170078, 172493, 190333, 121593, 123498, 186574, 102732, 158704, 191871, 100731, 139029, 141195, 108821, 144719, 141081, 153471, 136323, 176457, 124170, 174128, 144049, 149922, 126317, 188334]::int[], ARRAY[297633, 214751, 298620, 224468, 260778, 213989, 210293, 237041, 227355, 276417, 229603, 209130, 215490, 209583, 283998, 268549, 216025, 233135, 279059, 247808, 224748, 262614]::int[], ARRAY[380152, 398225, 385946, 357586, 345406, 350518, 366264, 369938, 343158, 305572, 316098, 322957, 340999, 387134, 342618, 384495, 353016, 326400, 356807, 365236, 341139, 336344, 396114, 379454, 370780, 389081, 332786, 387841, 354779, 399468, 341143, 320218, 352300, 332209, 341747, 386108, 397401, 363313, 376273, 388690, 310396, 385479]::int[], ARRAY[458065, 410793, 443804, 471437, 433532, 465909, 444372, 438502, 429753, 494438, 406782, 404537, 443133, 484495, 405094, 485927, 457378, 446782, 419919, 490328, 457479, 471055, 410510, 438152, 402024, 421541, 439890, 479175, 427264, 428680, 443462, 468604, 494407, 456502, 427275, 415162, 461945, 427847, 476568]::int[], '2024-10-17 17:51:00 UTC', '2024-10-21 07:26:00 UTC', '2024-09-26 08:11:00 UTC'),
+('agustina.18w11tca@example.com', '史紫薇', 'male','2024-08-17 16:05:00 UTC', '2024-10-26 18:57:00 UTC', '2024-09-29 01:45:00 UTC', ARRAY[188920, 128869, 153445, 120220, 106014, 161872, 188695, 101095, 111583, 154000, 119348, 113410, 177710, 136236, 117317, 103186, 132664, 146802, 114647, 153497, 184093, 125792, 122656, 114755, 157619, 188626, 158643, 118593, 141473, 162499, 175255, 105810, 172741, 116176, 188673, 183699, 179571, 114077, 159183, 161630, 178909]::int[], ARRAY[209967, 249170, 236629, 219987, 248899, 226682, 276095, 273271, 205983, 239386, 211308, 257910, 286133, 247986, 274082, 269875, 295485, 277944, 240227, 270993, 206606, 262454, 205173, 282860, 244969, 226241, 276723, 296944, 226526, 204730, 279632, 289461, 203488, 263678, 286238, 288398, 212998, 253590, 226828, 283326, 202402, 260918, 282566]::int[], ARRAY[341962, 379852, 317899, 370925, 395276, 363290, 333781, 326696, 367478, 399012, 335733, 304574, 320377, 304379, 358820, 358206, 378212, 370365, 302655, 392595, 329537, 351240, 329280, 392510, 383393, 369620, 356776]::int[], ARRAY[432238, 452247, 419592, 473834, 494738, 448282, 450847]::int[], '2024-09-18 01:28:00 UTC', '2024-10-18 20:04:00 UTC', '2024-10-27 15:06:00 UTC'),
+('shad.ttfojo9i@example.net', '戴旭翰', 'unknown','2022-05-22 12:47:00 UTC', '2024-10-19 22:38:00 UTC', '2024-09-13 19:12:00 UTC', ARRAY[104384, 117941, 133462, 101285, 124942, 189317, 154650, 140334, 190703, 160957, 134852, 102952, 181192, 184083, 127639, 183527, 162012, 157600, 181480, 109294, 159662, 100277, 191371, 157902, 101394, 148998, 115463, 117596, 149221, 106145, 112741, 168705, 111354, 138415, 129609, 121436, 179605, 199017, 151182]::int[], ARRAY[279517, 212545, 274908, 264217, 270937, 247378, 205189, 230019, 287470, 215582, 290120, 252094, 279060, 208399, 272919, 250940, 211379, 252982, 243862, 203734, 264688, 249296, 205073, 281572, 293363, 269039, 285329, 297370, 202824, 286323, 285435, 233634, 211026, 223112, 210001, 220406, 278759, 225451, 239742, 270909, 244427, 221684, 247590, 292990]::int[], ARRAY[366156, 336358, 352673, 344992, 338355, 390819, 364281, 321483, 390073, 329657, 350998, 336610, 311862]::int[], ARRAY[434811, 433522, 423520, 458455, 482437, 429492, 444106, 465841, 431928, 427499, 471903, 423994, 489767, 476269, 420644, 428205, 401544, 459862, 484681, 483626, 425554, 432907, 420158, 445434]::int[], '2024-10-28 02:48:00 UTC', '2024-10-26 02:36:00 UTC', '2024-09-07 18:47:00 UTC'),
+('florida.m3k1s6py@example.org', '闫畅瑞', 'unknown','2024-03-17 03:55:00 UTC', '2024-10-05 08:55:00 UTC', '2024-10-03 23:21:00 UTC', ARRAY[167571, 127895, 172356, 165068, 129222, 151458, 170123, 174248, 186503, 107617, 119745, 147400, 145863, 193395, 121523, 140700, 183132, 137056, 139186]::int[], ARRAY[221875, 246179, 286352, 230536, 260405, 258951, 261357, 253867, 214777, 260113, 284744, 250878, 257673, 240111, 295234, 207148, 246345, 214951, 251600, 248903, 247301, 224932]::int[], ARRAY[372435, 378380, 330313, 366858, 383318, 370253, 396642, 313963, 324746, 306912, 305706, 376234, 358168, 381310, 300016, 325717, 374832, 374574, 352931, 379094, 333429, 381005, 354470, 395456, 370339, 328623, 341923, 396320, 307884, 306512, 311397, 395243, 375964, 369570, 390660, 300421, 395566, 303250, 327061, 360784, 349413, 386080, 346251, 384005]::int[], ARRAY[488923, 444790, 431941, 406113, 485744, 444902, 459476, 418279, 466520, 429574, 443065, 409992, 401952, 411860, 408587, 490099, 469872, 455375, 410292, 414416, 463272, 483887, 467389, 421779, 428114, 409163, 485686, 414102, 425740, 493237, 497133, 400716, 419572, 443929, 435967, 441130, 437867, 469581, 451084, 423839, 487033, 415999, 428357, 453823, 440191, 498442, 406580]::int[], '2024-10-16 17:37:00 UTC', '2024-10-30 04:15:00 UTC', '2024-08-20 20:03:00 UTC'),
+('rupert.dauxksws@example.com', '陈玫雅', 'female','2020-10-22 03:29:00 UTC', '2024-10-24 17:16:00 UTC', '2024-09-17 11:35:00 UTC', ARRAY[100648, 154068, 158343, 140016, 149638, 192072, 157310, 172259, 150837, 122997, 195285, 108412, 116059, 103539, 119382]::int[], ARRAY[296467, 221234, 222482, 295103, 257453, 292991, 224778, 209604, 285716, 290109, 244818, 202512, 271423, 209211, 296750, 225628, 255020, 251888, 271591, 271444, 211721, 233843, 207737, 261036, 220948, 271121, 266157, 233940, 295364, 228776, 277197, 215512, 222280, 283879, 266616, 278970, 285954, 221659, 222318]::int[], ARRAY[328776, 324172, 321471, 320099, 396505, 306667, 368419, 309867, 369791, 392456, 380252, 322167, 326535, 399467, 311727, 318705, 388572, 379681, 394151, 330362, 304330, 355769, 353622, 327369, 309979, 338258, 351894, 349458, 359578, 362493, 309827, 394433, 380677, 320007, 301672, 316397, 381557, 344907, 343111, 329228, 302114, 365508, 383711, 345492, 345703, 340205, 386895]::int[], ARRAY[402304, 491717, 442389, 405914, 454988, 462487, 406637]::int[], '2024-10-25 12:58:00 UTC', '2024-10-17 18:43:00 UTC', '2024-08-20 14:48:00 UTC');
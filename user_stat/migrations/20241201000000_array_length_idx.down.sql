@@ -0,0 +1,6 @@
+-- Add down migration script here
+drop index if exists user_stats_recent_watched_len_idx;
+drop index if exists user_stats_viewed_but_not_started_len_idx;
+drop index if exists user_stats_started_but_not_finished_len_idx;
+drop index if exists user_stats_finished_len_idx;
+drop index if exists user_stats_finished_idx;
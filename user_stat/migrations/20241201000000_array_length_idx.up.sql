@@ -0,0 +1,8 @@
+-- Add up migration script here
+-- 数组长度条件 coalesce(cardinality(col), 0) 使用的表达式索引
+create index user_stats_recent_watched_len_idx on user_stats ((coalesce(cardinality(recent_watched), 0)));
+create index user_stats_viewed_but_not_started_len_idx on user_stats ((coalesce(cardinality(viewed_but_not_started), 0)));
+create index user_stats_started_but_not_finished_len_idx on user_stats ((coalesce(cardinality(started_but_not_finished), 0)));
+create index user_stats_finished_len_idx on user_stats ((coalesce(cardinality(finished), 0)));
+-- finished 同样可以使用 && @> <@ 条件
+create index user_stats_finished_idx on user_stats using gin (finished);
@@ -0,0 +1,190 @@
+use super::{
+    fetch_error,
+    sql::{column, SqlBuilder, TIME_COLUMNS},
+    SqlQuery,
+};
+use crate::{
+    pb::{
+        CountResponse, Gender, GenderCount, GenderCountResponse, HistogramBucket, HistogramRequest,
+        HistogramResponse, Interval, QueryRequest,
+    },
+    ServiceResult, UserStatsService,
+};
+use chrono::{DateTime, Utc};
+use crm_core::ToTimestamp;
+use tonic::{Response, Status};
+use tracing::info;
+
+// 实现统计相关的内部函数
+impl UserStatsService {
+    // 统计匹配的用户数量
+    pub async fn count(&self, query: QueryRequest) -> ServiceResult<CountResponse> {
+        let sql = query.to_count_sql()?;
+        info!("Generated SQL: {}", sql);
+        let (count,) = sql
+            .query_as::<(i64,)>()
+            .fetch_one(&self.inner.pool)
+            .await
+            .map_err(|e| fetch_error(e, &sql))?;
+
+        Ok(Response::new(CountResponse {
+            count: count as u64,
+        }))
+    }
+
+    // 按性别分组统计
+    pub async fn count_by_gender(&self, query: QueryRequest) -> ServiceResult<GenderCountResponse> {
+        let sql = query.to_gender_count_sql()?;
+        info!("Generated SQL: {}", sql);
+        let rows = sql
+            .query_as::<(Option<String>, i64)>()
+            .fetch_all(&self.inner.pool)
+            .await
+            .map_err(|e| fetch_error(e, &sql))?;
+
+        let counts = rows
+            .into_iter()
+            .map(|(gender, count)| GenderCount {
+                gender: Gender::from_db_str(gender.as_deref()) as i32,
+                count: count as u64,
+            })
+            .collect();
+        Ok(Response::new(GenderCountResponse { counts }))
+    }
+
+    // 按时间间隔统计分布
+    pub async fn histogram(&self, req: HistogramRequest) -> ServiceResult<HistogramResponse> {
+        let sql = req.to_sql()?;
+        info!("Generated SQL: {}", sql);
+        let rows = sql
+            .query_as::<(DateTime<Utc>, i64)>()
+            .fetch_all(&self.inner.pool)
+            .await
+            .map_err(|e| fetch_error(e, &sql))?;
+
+        let buckets = rows
+            .into_iter()
+            .map(|(start, count)| HistogramBucket {
+                start: Some(start.to_timestamp()),
+                count: count as u64,
+            })
+            .collect();
+        Ok(Response::new(HistogramResponse { buckets }))
+    }
+}
+
+impl QueryRequest {
+    // 生成数量统计SQL
+    pub fn to_count_sql(&self) -> Result<SqlQuery, Status> {
+        let mut builder = SqlBuilder::default();
+        let sql = format!(
+            "SELECT count(*) FROM user_stats WHERE {}",
+            self.where_clause(&mut builder)?
+        );
+        Ok(builder.build(sql))
+    }
+
+    // 生成按性别分组统计SQL
+    pub fn to_gender_count_sql(&self) -> Result<SqlQuery, Status> {
+        let mut builder = SqlBuilder::default();
+        let sql = format!(
+            "SELECT gender::text, count(*) FROM user_stats WHERE {} GROUP BY gender ORDER BY gender",
+            self.where_clause(&mut builder)?
+        );
+        Ok(builder.build(sql))
+    }
+}
+
+impl HistogramRequest {
+    // 生成直方图SQL 区间按UTC划分
+    pub fn to_sql(&self) -> Result<SqlQuery, Status> {
+        let name = column(&self.column, TIME_COLUMNS)?;
+        let interval = match Interval::try_from(self.interval) {
+            Ok(Interval::Day) => "day",
+            Ok(Interval::Week) => "week",
+            Err(_) => {
+                return Err(Status::invalid_argument(format!(
+                    "Invalid interval: {}",
+                    self.interval
+                )))
+            }
+        };
+
+        let mut builder = SqlBuilder::default();
+        let query = self.query.clone().unwrap_or_default();
+        let sql = format!(
+            "SELECT date_trunc('{interval}', {name}, 'UTC') AS bucket, count(*) FROM user_stats WHERE {} AND {name} IS NOT NULL GROUP BY bucket ORDER BY bucket",
+            query.where_clause(&mut builder)?
+        );
+        Ok(builder.build(sql))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pb::QueryRequestBuilder, test_utils::tq, AppConfig};
+    use anyhow::Result;
+    use crm_core::ConfigLoader;
+    use std::collections::HashMap;
+    use tonic::Code;
+
+    #[test]
+    fn histogram_request_to_sql_should_work() {
+        let req = HistogramRequest {
+            query: Some(
+                QueryRequestBuilder::default()
+                    .time_stamps(HashMap::from([(
+                        "created_at".to_string(),
+                        tq(Some(30), None),
+                    )]))
+                    .build()
+                    .unwrap(),
+            ),
+            column: "last_visited_at".to_string(),
+            interval: Interval::Week as i32,
+        };
+        let sql = req.to_sql().unwrap();
+        assert_eq!(
+            sql.sql,
+            "SELECT date_trunc('week', last_visited_at, 'UTC') AS bucket, count(*) FROM user_stats WHERE created_at >= $1 AND last_visited_at IS NOT NULL GROUP BY bucket ORDER BY bucket"
+        );
+
+        let req = HistogramRequest {
+            column: "email".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(req.to_sql().unwrap_err().code(), Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn aggregates_should_work() -> Result<()> {
+        let config = AppConfig::load().expect("Failed Load config");
+        let svc = UserStatsService::new(config).await;
+        let query = QueryRequestBuilder::default()
+            .time_stamps(HashMap::from([(
+                "created_at".to_string(),
+                tq(Some(30), None),
+            )]))
+            .build()?;
+
+        let count = svc.count(query.clone()).await?.into_inner().count;
+        let genders = svc.count_by_gender(query.clone()).await?.into_inner();
+        assert_eq!(genders.counts.iter().map(|c| c.count).sum::<u64>(), count);
+
+        let histogram = svc
+            .histogram(HistogramRequest {
+                query: Some(query),
+                column: "created_at".to_string(),
+                interval: Interval::Day as i32,
+            })
+            .await?
+            .into_inner();
+        assert_eq!(
+            histogram.buckets.iter().map(|b| b.count).sum::<u64>(),
+            count
+        );
+        assert!(histogram.buckets.len() <= 31);
+        Ok(())
+    }
+}
@@ -0,0 +1,255 @@
+use super::sql::{gender_query, ids_query, length_query, null_query, timestamp_query, SqlBuilder};
+use crate::pb::{
+    filter::Expr, ArrayLengthCondition, ArrayOp, Filter, FilterGroup, Gender, GenderCondition,
+    IdCondition, IdQuery, NullCondition, TimeCondition, TimeQuery,
+};
+use itertools::Itertools;
+use tonic::Status;
+
+// 过滤表达式允许的最大嵌套深度
+const MAX_DEPTH: usize = 32;
+
+impl Filter {
+    pub fn and(filters: impl IntoIterator<Item = Filter>) -> Self {
+        Self::new(Expr::And(FilterGroup {
+            filters: filters.into_iter().collect(),
+        }))
+    }
+
+    pub fn or(filters: impl IntoIterator<Item = Filter>) -> Self {
+        Self::new(Expr::Or(FilterGroup {
+            filters: filters.into_iter().collect(),
+        }))
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(filter: Filter) -> Self {
+        Self::new(Expr::Not(Box::new(filter)))
+    }
+
+    pub fn time(column: impl Into<String>, query: TimeQuery) -> Self {
+        Self::new(Expr::Time(TimeCondition {
+            column: column.into(),
+            query: Some(query),
+        }))
+    }
+
+    pub fn id(column: impl Into<String>, query: IdQuery) -> Self {
+        Self::id_with_op(column, query, ArrayOp::ContainsAll)
+    }
+
+    pub fn id_with_op(column: impl Into<String>, query: IdQuery, op: ArrayOp) -> Self {
+        Self::new(Expr::Id(IdCondition {
+            column: column.into(),
+            query: Some(query),
+            op: op as i32,
+        }))
+    }
+
+    pub fn length(column: impl Into<String>, min: Option<u32>, max: Option<u32>) -> Self {
+        Self::new(Expr::Length(ArrayLengthCondition {
+            column: column.into(),
+            min,
+            max,
+        }))
+    }
+
+    pub fn gender(genders: &[Gender]) -> Self {
+        Self::new(Expr::Gender(GenderCondition {
+            genders: genders.iter().map(|g| *g as i32).collect(),
+        }))
+    }
+
+    pub fn null(column: impl Into<String>, is_null: bool) -> Self {
+        Self::new(Expr::Null(NullCondition {
+            column: column.into(),
+            is_null,
+        }))
+    }
+
+    fn new(expr: Expr) -> Self {
+        Self { expr: Some(expr) }
+    }
+
+    // 递归编译过滤表达式
+    pub(crate) fn to_sql(&self, builder: &mut SqlBuilder) -> Result<String, Status> {
+        self.compile(builder, 0)
+    }
+
+    fn compile(&self, builder: &mut SqlBuilder, depth: usize) -> Result<String, Status> {
+        if depth > MAX_DEPTH {
+            return Err(Status::invalid_argument(format!(
+                "Filter is nested deeper than {}",
+                MAX_DEPTH
+            )));
+        }
+
+        let Some(expr) = &self.expr else {
+            return Err(Status::invalid_argument("Empty filter expression"));
+        };
+
+        let sql = match expr {
+            Expr::And(group) => group.compile(builder, depth, "AND", "TRUE")?,
+            Expr::Or(group) => group.compile(builder, depth, "OR", "FALSE")?,
+            // NOT NULL 仍为 NULL 先将 NULL 视为不满足再取反
+            Expr::Not(filter) => format!(
+                "NOT coalesce({}, FALSE)",
+                filter.compile(builder, depth + 1)?
+            ),
+            Expr::Time(cond) => {
+                let query = cond.query.unwrap_or_default();
+                timestamp_query(
+                    builder,
+                    &cond.column,
+                    query.lower.as_ref(),
+                    query.upper.as_ref(),
+                )?
+                .unwrap_or_else(|| "TRUE".to_string())
+            }
+            Expr::Id(cond) => {
+                let op = ArrayOp::try_from(cond.op).map_err(|_| {
+                    Status::invalid_argument(format!("Invalid array op: {}", cond.op))
+                })?;
+                let ids = cond.query.as_ref().map(|q| q.ids.as_slice()).unwrap_or(&[]);
+                ids_query(builder, &cond.column, ids, op)?.unwrap_or_else(|| "TRUE".to_string())
+            }
+            Expr::Length(cond) => length_query(builder, &cond.column, cond.min, cond.max)?
+                .unwrap_or_else(|| "TRUE".to_string()),
+            Expr::Gender(cond) => gender_query(builder, &cond.genders)?,
+            Expr::Null(cond) => null_query(&cond.column, cond.is_null)?,
+        };
+        Ok(sql)
+    }
+}
+
+impl FilterGroup {
+    // 空的 AND 组恒为真 空的 OR 组恒为假
+    fn compile(
+        &self,
+        builder: &mut SqlBuilder,
+        depth: usize,
+        op: &str,
+        empty: &str,
+    ) -> Result<String, Status> {
+        if self.filters.is_empty() {
+            return Ok(empty.to_string());
+        }
+
+        let conditions: Vec<_> = self
+            .filters
+            .iter()
+            .map(|f| f.compile(builder, depth + 1))
+            .try_collect()?;
+        Ok(format!("({})", conditions.join(&format!(" {} ", op))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pb::{QueryRequest, QueryRequestBuilder},
+        test_utils::{id, tq},
+        SqlArg,
+    };
+    use tonic::Code;
+
+    #[test]
+    fn filter_to_sql_should_work() {
+        let filter = Filter::and([
+            Filter::time("last_visited_at", tq(Some(7), None)),
+            Filter::or([
+                Filter::id("finished", id(&[42])),
+                Filter::id("started_but_not_finished", id(&[42])),
+            ]),
+            Filter::not(Filter::time("last_email_notification", tq(Some(3), None))),
+        ]);
+        let query = QueryRequestBuilder::default()
+            .filter(filter)
+            .build()
+            .unwrap();
+        let sql = query.to_sql().unwrap();
+        assert_eq!(
+            sql.sql,
+            "SELECT email, name FROM user_stats WHERE (last_visited_at >= $1 AND ($2 <@ finished OR $3 <@ started_but_not_finished) AND NOT coalesce(last_email_notification >= $4, FALSE))"
+        );
+        assert_eq!(sql.args.len(), 4);
+        assert_eq!(sql.args[1], SqlArg::IntArray(vec![42]));
+    }
+
+    #[test]
+    fn filter_should_combine_with_shorthand() {
+        let query = QueryRequestBuilder::default()
+            .id(("finished".to_string(), id(&[1])))
+            .filter(Filter::or([]))
+            .build()
+            .unwrap();
+        let sql = query.to_sql().unwrap();
+        assert_eq!(
+            sql.sql,
+            "SELECT email, name FROM user_stats WHERE $1 <@ finished AND FALSE"
+        );
+    }
+
+    #[test]
+    fn predicates_to_sql_should_work() {
+        let filter = Filter::and([
+            Filter::id_with_op("finished", id(&[1, 2]), ArrayOp::Overlap),
+            Filter::id_with_op("recent_watched", id(&[3]), ArrayOp::NotContains),
+            Filter::length("viewed_but_not_started", Some(1), Some(10)),
+            Filter::gender(&[Gender::Female, Gender::Unknown]),
+            Filter::null("last_watched_at", true),
+            Filter::null("last_visited_at", false),
+        ]);
+        let query = QueryRequestBuilder::default()
+            .filter(filter)
+            .build()
+            .unwrap();
+        let sql = query.to_sql().unwrap();
+        assert_eq!(
+            sql.sql,
+            "SELECT email, name FROM user_stats WHERE (finished && $1 AND NOT coalesce(recent_watched && $2, FALSE) AND coalesce(cardinality(viewed_but_not_started), 0) BETWEEN $3 AND $4 AND gender = ANY($5::gender[]) AND last_watched_at IS NULL AND last_visited_at IS NOT NULL)"
+        );
+        assert_eq!(
+            sql.args[4],
+            SqlArg::TextArray(vec!["female".to_string(), "unknown".to_string()])
+        );
+    }
+
+    #[test]
+    fn invalid_predicates_should_fail() {
+        let invalid = [
+            Filter::gender(&[Gender::Unspecified]),
+            Filter::null("created_at", true),
+            Filter::length("name", Some(1), None),
+            Filter::new(Expr::Id(IdCondition {
+                column: "finished".to_string(),
+                query: Some(id(&[1])),
+                op: 100,
+            })),
+        ];
+        for filter in invalid {
+            let query = QueryRequest {
+                filter: Some(filter),
+                ..Default::default()
+            };
+            assert_eq!(query.to_sql().unwrap_err().code(), Code::InvalidArgument);
+        }
+    }
+
+    #[test]
+    fn invalid_filter_should_fail() {
+        let mut query = QueryRequest {
+            filter: Some(Filter::not(Filter::default())),
+            ..Default::default()
+        };
+        assert_eq!(query.to_sql().unwrap_err().code(), Code::InvalidArgument);
+
+        let deep = (0..=MAX_DEPTH).fold(Filter::id("finished", id(&[1])), |f, _| Filter::not(f));
+        query.filter = Some(deep);
+        assert_eq!(query.to_sql().unwrap_err().code(), Code::InvalidArgument);
+
+        query.filter = Some(Filter::id("name", id(&[1])));
+        assert_eq!(query.to_sql().unwrap_err().code(), Code::InvalidArgument);
+    }
+}
@@ -0,0 +1,333 @@
+use super::sql::{to_int_array, SqlArg, SqlBuilder};
+use super::SqlQuery;
+use crate::{
+    pb::{ingest_event::Event, IngestEvent, IngestResponse, NotificationChannel},
+    ServiceResult, UserStatsService,
+};
+use chrono::Utc;
+use crm_core::ToDateTime;
+use futures::{Stream, StreamExt};
+use tonic::{Response, Status};
+use tracing::{info, warn};
+
+// 实现用户行为事件写入
+impl UserStatsService {
+    // 逐条写入事件 参数不合法的事件会被忽略并计数
+    pub async fn ingest(
+        &self,
+        mut events: impl Stream<Item = Result<IngestEvent, Status>> + Unpin,
+    ) -> ServiceResult<IngestResponse> {
+        let limit = self.inner.config.ingest.recent_watched_limit;
+        let mut res = IngestResponse::default();
+        while let Some(event) = events.next().await {
+            let sql = match event?.to_sql(limit) {
+                Ok(sql) => sql,
+                Err(e) => {
+                    warn!("Rejected event: {}", e.message());
+                    res.rejected += 1;
+                    continue;
+                }
+            };
+            // 每个事件是一条 upsert 语句 所有列在同一事务中更新
+            sql.query().execute(&self.inner.pool).await.map_err(|e| {
+                warn!("Failed to ingest event: {:?}", e);
+                Status::internal(format!(
+                    "Failed to ingest event after {} accepted: {}",
+                    res.accepted, sql
+                ))
+            })?;
+            res.accepted += 1;
+        }
+
+        info!(
+            "Ingested {} events, rejected {}",
+            res.accepted, res.rejected
+        );
+        Ok(Response::new(res))
+    }
+}
+
+impl IngestEvent {
+    pub fn new(email: impl Into<String>, event: Event) -> Self {
+        Self {
+            email: email.into(),
+            event: Some(event),
+            ..Default::default()
+        }
+    }
+
+    /// 生成 upsert SQL 用户不存在时创建
+    pub fn to_sql(&self, recent_watched_limit: u32) -> Result<SqlQuery, Status> {
+        if self.email.is_empty() {
+            return Err(Status::invalid_argument("Email is required"));
+        }
+        let Some(event) = &self.event else {
+            return Err(Status::invalid_argument("Event is required"));
+        };
+
+        let at = match &self.occurred_at {
+            Some(ts) => ts.to_datetime()?,
+            None => Utc::now(),
+        };
+        let mut builder = SqlBuilder::default();
+        let email = builder.bind(SqlArg::Text(self.email.clone()));
+        let name = builder.bind(SqlArg::Text(self.name.clone()));
+        let at = builder.bind(SqlArg::Timestamp(at));
+
+        // (列, 新用户的值, 已有用户的更新表达式)
+        let columns = match event {
+            Event::Visit(_) => vec![latest("last_visited_at", &at)],
+            Event::ContentViewed(e) => {
+                let id = content_id(&mut builder, e.content_id)?;
+                vec![
+                    latest("last_visited_at", &at),
+                    (
+                        "viewed_but_not_started",
+                        format!("ARRAY[{}]", id),
+                        // 已经浏览 开始或看完的内容不再加入
+                        format!(
+                            "CASE WHEN {id} = ANY(user_stats.viewed_but_not_started || user_stats.started_but_not_finished || user_stats.finished) THEN user_stats.viewed_but_not_started ELSE array_append(user_stats.viewed_but_not_started, {id}) END"
+                        ),
+                    ),
+                ]
+            }
+            Event::WatchStarted(e) => {
+                let id = content_id(&mut builder, e.content_id)?;
+                let limit = builder.bind(SqlArg::Int(recent_watched_limit as i32));
+                vec![
+                    latest("last_watched_at", &at),
+                    recent_watched(&id, &limit),
+                    remove("viewed_but_not_started", &id),
+                    (
+                        "started_but_not_finished",
+                        format!("ARRAY[{}]", id),
+                        // 重看已看完的内容 仍然保留在 finished 中
+                        format!(
+                            "CASE WHEN {id} = ANY(user_stats.finished) THEN user_stats.started_but_not_finished ELSE array_append(array_remove(user_stats.started_but_not_finished, {id}), {id}) END"
+                        ),
+                    ),
+                ]
+            }
+            Event::WatchFinished(e) => {
+                let id = content_id(&mut builder, e.content_id)?;
+                let limit = builder.bind(SqlArg::Int(recent_watched_limit as i32));
+                vec![
+                    latest("last_watched_at", &at),
+                    recent_watched(&id, &limit),
+                    remove("viewed_but_not_started", &id),
+                    remove("started_but_not_finished", &id),
+                    (
+                        "finished",
+                        format!("ARRAY[{}]", id),
+                        format!("array_append(array_remove(user_stats.finished, {id}), {id})"),
+                    ),
+                ]
+            }
+            Event::NotificationDelivered(e) => {
+                let column = match NotificationChannel::try_from(e.channel) {
+                    Ok(NotificationChannel::Email) => "last_email_notification",
+                    Ok(NotificationChannel::Sms) => "last_sms_notification",
+                    Ok(NotificationChannel::InApp) => "last_in_app_notification",
+                    _ => {
+                        return Err(Status::invalid_argument(format!(
+                            "Invalid notification channel: {}",
+                            e.channel
+                        )))
+                    }
+                };
+                vec![latest(column, &at)]
+            }
+        };
+
+        let mut names = vec![];
+        let mut values = vec![];
+        let mut updates = vec![];
+        for (col, value, update) in columns {
+            names.push(col);
+            values.push(value);
+            updates.push(format!("{} = {}", col, update));
+        }
+        let sql = format!(
+            "INSERT INTO user_stats (email, name, created_at, {}) VALUES ({}, {}, {}, {}) ON CONFLICT (email) DO UPDATE SET name = coalesce(nullif(user_stats.name, ''), EXCLUDED.name), {}",
+            names.join(", "),
+            email,
+            name,
+            at,
+            values.join(", "),
+            updates.join(", ")
+        );
+        Ok(builder.build(sql))
+    }
+}
+
+type Column = (&'static str, String, String);
+
+// 时间列只会向后推进 乱序到达的旧事件不会覆盖新的时间
+fn latest(column: &'static str, at: &str) -> Column {
+    (
+        column,
+        at.to_string(),
+        format!("greatest(user_stats.{col}, EXCLUDED.{col})", col = column),
+    )
+}
+
+// 将内容移到最近观看的最前面 并截断到最大长度
+fn recent_watched(id: &str, limit: &str) -> Column {
+    (
+        "recent_watched",
+        format!("ARRAY[{}]", id),
+        format!("(array_prepend({id}, array_remove(user_stats.recent_watched, {id})))[1:{limit}]"),
+    )
+}
+
+fn remove(column: &'static str, id: &str) -> Column {
+    (
+        column,
+        "NULL".to_string(),
+        format!("array_remove(user_stats.{}, {})", column, id),
+    )
+}
+
+fn content_id(builder: &mut SqlBuilder, id: u32) -> Result<String, Status> {
+    let id = to_int_array(&[id])?[0];
+    Ok(builder.bind(SqlArg::Int(id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pb::{
+            ContentViewedEvent, GetUserStatRequest, NotificationDeliveredEvent, VisitEvent,
+            WatchFinishedEvent, WatchStartedEvent,
+        },
+        test_utils::to_ts,
+        AppConfig,
+    };
+    use anyhow::Result;
+    use crm_core::ConfigLoader;
+    use futures::stream;
+    use tonic::Code;
+
+    #[test]
+    fn ingest_event_to_sql_should_work() {
+        let event = IngestEvent::new(
+            "a@b.c",
+            Event::NotificationDelivered(NotificationDeliveredEvent {
+                channel: NotificationChannel::Sms as i32,
+            }),
+        );
+        let sql = event.to_sql(10).unwrap();
+        assert_eq!(
+            sql.sql,
+            "INSERT INTO user_stats (email, name, created_at, last_sms_notification) VALUES ($1, $2, $3, $3) ON CONFLICT (email) DO UPDATE SET name = coalesce(nullif(user_stats.name, ''), EXCLUDED.name), last_sms_notification = greatest(user_stats.last_sms_notification, EXCLUDED.last_sms_notification)"
+        );
+
+        let event = IngestEvent::new(
+            "a@b.c",
+            Event::WatchFinished(WatchFinishedEvent { content_id: 7 }),
+        );
+        let sql = event.to_sql(10).unwrap();
+        assert!(sql.sql.contains(
+            "recent_watched = (array_prepend($4, array_remove(user_stats.recent_watched, $4)))[1:$5]"
+        ));
+        assert_eq!(sql.args[3], SqlArg::Int(7));
+        assert_eq!(sql.args[4], SqlArg::Int(10));
+    }
+
+    #[test]
+    fn invalid_ingest_event_should_fail() {
+        let invalid = [
+            IngestEvent::new("", Event::Visit(VisitEvent {})),
+            IngestEvent {
+                email: "a@b.c".to_string(),
+                ..Default::default()
+            },
+            IngestEvent::new(
+                "a@b.c",
+                Event::NotificationDelivered(NotificationDeliveredEvent::default()),
+            ),
+            IngestEvent::new(
+                "a@b.c",
+                Event::WatchStarted(WatchStartedEvent {
+                    content_id: u32::MAX,
+                }),
+            ),
+        ];
+        for event in invalid {
+            assert_eq!(event.to_sql(10).unwrap_err().code(), Code::InvalidArgument);
+        }
+    }
+
+    #[tokio::test]
+    async fn ingest_should_work() -> Result<()> {
+        let mut config = AppConfig::load().expect("Failed Load config");
+        config.ingest.recent_watched_limit = 2;
+        let (_tdb, svc) = UserStatsService::new_for_test_with_config(config).await?;
+        let email = "ingest@example.com";
+
+        let mut visit = IngestEvent::new(email, Event::Visit(VisitEvent {}));
+        visit.name = "Ingest".to_string();
+        visit.occurred_at = Some(to_ts(1));
+        let mut stale = visit.clone();
+        stale.occurred_at = Some(to_ts(10));
+        let events = vec![
+            visit,
+            stale,
+            IngestEvent::new(
+                email,
+                Event::ContentViewed(ContentViewedEvent { content_id: 1 }),
+            ),
+            IngestEvent::new(
+                email,
+                Event::ContentViewed(ContentViewedEvent { content_id: 2 }),
+            ),
+            IngestEvent::new(
+                email,
+                Event::WatchStarted(WatchStartedEvent { content_id: 1 }),
+            ),
+            IngestEvent::new(
+                email,
+                Event::WatchStarted(WatchStartedEvent { content_id: 3 }),
+            ),
+            IngestEvent::new(
+                email,
+                Event::WatchFinished(WatchFinishedEvent { content_id: 3 }),
+            ),
+            IngestEvent::new(
+                email,
+                Event::WatchStarted(WatchStartedEvent { content_id: 4 }),
+            ),
+            IngestEvent::new(
+                email,
+                Event::NotificationDelivered(NotificationDeliveredEvent {
+                    channel: NotificationChannel::InApp as i32,
+                }),
+            ),
+            IngestEvent::new("", Event::Visit(VisitEvent {})),
+        ];
+
+        let res = svc
+            .ingest(stream::iter(events.into_iter().map(Ok)))
+            .await?
+            .into_inner();
+        assert_eq!(res.accepted, 9);
+        assert_eq!(res.rejected, 1);
+
+        let user = svc
+            .get_user_stat(GetUserStatRequest::new(email))
+            .await?
+            .into_inner();
+        assert_eq!(user.name, "Ingest");
+        // 乱序到达的旧访问不会覆盖最新访问时间
+        assert!(user.last_visited_at.unwrap().seconds > to_ts(2).seconds);
+        assert_eq!(user.recent_watched, vec![4, 3]);
+        assert_eq!(user.viewed_but_not_started, vec![2]);
+        assert_eq!(user.started_but_not_finished, vec![1, 4]);
+        assert_eq!(user.finished, vec![3]);
+        assert!(user.last_watched_at.is_some());
+        assert!(user.last_in_app_notification.is_some());
+        assert!(user.last_email_notification.is_none());
+        Ok(())
+    }
+}
@@ -1,71 +1,181 @@
+mod aggregate;
+mod filter;
+mod ingest;
+mod page;
+mod profile;
+mod raw;
+mod row;
+mod sql;
+
 use crate::{
     pb::{QueryRequest, QueryRequestBuilder, RawQueryRequest, TimeQuery, User},
-    ResponseStream, ServiceResult, UserStatsService,
+    RawQueryConfig, ResponseStream, ServiceResult, UserStatsService,
 };
-use chrono::{DateTime, TimeZone, Utc};
-use core::fmt;
-use itertools::Itertools;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use metrics::{counter, histogram};
 use prost_types::Timestamp;
+pub use row::{DbGender, DbIds, DbTimestamp};
+pub use sql::{SqlArg, SqlQuery};
 use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::{Response, Status};
-use tracing::info;
+use tracing::{field::Empty, info, info_span, warn, Instrument, Span};
+
+// 服务端缓冲的最大行数 超过后等待客户端消费
+const CHANNEL_SIZE: usize = 128;
 
 // 实现UserStatsService内部函数
 impl UserStatsService {
     // 条件查询
     pub async fn query(&self, query: QueryRequest) -> ServiceResult<ResponseStream> {
-        let sql = query.to_string();
-        // 调用raw_query
-        self.raw_query(RawQueryRequest { query: sql }).await
+        let sql = query.to_sql()?;
+        info!("Generated SQL: {}", sql);
+        self.fetch_users(sql, None).await
     }
 
-    // 原始SQL查询
+    // 原始SQL查询 在只读事务中执行
     pub async fn raw_query(&self, req: RawQueryRequest) -> ServiceResult<ResponseStream> {
-        // SQLX 拿到列表
-        let Ok(ret) = sqlx::query_as::<_, User>(&req.query)
-            .fetch_all(&self.inner.pool)
+        let sandbox = &self.inner.config.raw_query;
+        if !sandbox.enabled {
+            return Err(Status::unimplemented("RawQuery is disabled"));
+        }
+        raw::validate_select(&req.query)?;
+        self.fetch_users(SqlQuery::raw(req.query), Some(sandbox.clone()))
             .await
-        else {
-            return Err(Status::internal(format!(
-                "Failed to fetch data with query:{}",
-                req.query
-            )));
-        };
-
-        // 将 Iterator 转换为 Stream
-        Ok(Response::new(Box::pin(futures::stream::iter(
-            ret.into_iter().map(Ok),
-        ))))
     }
-}
 
-/// 实现 Display 将 QueryRequest 转换为SQL
-impl fmt::Display for QueryRequest {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut sql = "SELECT email, name FROM user_stats WHERE ".to_string();
+    // 执行SQL 通过游标逐行返回用户流
+    async fn fetch_users(
+        &self,
+        sql: SqlQuery,
+        sandbox: Option<RawQueryConfig>,
+    ) -> ServiceResult<ResponseStream> {
+        let pool = self.inner.pool.clone();
+        let mut conn = pool.acquire().await.map_err(|e| {
+            warn!("Failed to acquire connection: {:?}", e);
+            Status::unavailable("Failed to acquire db connection")
+        })?;
+        // 记录后端进程ID 客户端断开时用于取消查询
+        let pid: i32 = sqlx::query_scalar("SELECT pg_backend_pid()")
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(|e| {
+                warn!("Failed to get backend pid: {:?}", e);
+                Status::internal("Failed to prepare query")
+            })?;
 
-        let time_conditions = self
-            .time_stamps
-            .iter()
-            .map(|(k, v)| timestamp_query(k, v.lower.as_ref(), v.upper.as_ref()))
-            .join(" AND ");
+        // 沙箱模式: 只读事务 + 语句超时
+        if let Some(sandbox) = &sandbox {
+            let statements = [
+                "BEGIN READ ONLY".to_string(),
+                format!(
+                    "SET LOCAL statement_timeout = {}",
+                    sandbox.statement_timeout
+                ),
+            ];
+            for stmt in statements {
+                if let Err(e) = sqlx::query(&stmt).execute(&mut *conn).await {
+                    warn!("Failed to prepare read-only transaction: {:?}", e);
+                    // 事务可能已经开启 关闭连接 不放回连接池
+                    let _ = conn.close().await;
+                    return Err(Status::internal("Failed to prepare query"));
+                }
+            }
+        }
+        let max_rows = sandbox.as_ref().map(|s| s.max_rows);
+        let method = match sandbox {
+            Some(_) => "RawQuery",
+            None => "Query",
+        };
 
-        sql.push_str(&time_conditions);
+        let (tx, rx) = mpsc::channel(CHANNEL_SIZE);
+        let span = info_span!("user_stats.fetch", method, rows = Empty);
+        let task = async move {
+            let mut cancelled = false;
+            let mut count = 0;
+            {
+                let mut rows = sql.query_as::<User>().fetch(&mut *conn);
+                loop {
+                    // 等待下一行时同时关注客户端是否已断开
+                    let row = tokio::select! {
+                        _ = tx.closed() => {
+                            cancelled = true;
+                            break;
+                        }
+                        row = rows.next() => row,
+                    };
+                    let Some(row) = row else {
+                        break;
+                    };
+                    // 超过最大行数 返回错误并取消查询
+                    if max_rows.is_some_and(|max| count >= max) {
+                        let _ = tx
+                            .send(Err(Status::resource_exhausted(format!(
+                                "Query returned more than {} rows",
+                                count
+                            ))))
+                            .await;
+                        cancelled = true;
+                        break;
+                    }
+                    count += 1;
 
-        let id_conditions = self
-            .ids
-            .iter()
-            .map(|(k, v)| ids_query(k, &v.ids))
-            .join(" AND ");
+                    let row = row.map_err(|e| fetch_error(e, &sql));
+                    let failed = row.is_err();
+                    // 通道有界 客户端消费慢时在此等待 形成背压
+                    if tx.send(row).await.is_err() {
+                        cancelled = true;
+                        break;
+                    }
+                    if failed {
+                        break;
+                    }
+                }
+            }
+            Span::current().record("rows", count);
+            counter!("user_stats_rows_streamed_total", "method" => method).increment(count as u64);
+            histogram!("user_stats_stream_rows", "method" => method).record(count as f64);
 
-        if !id_conditions.is_empty() {
-            sql.push_str(" AND ");
-            sql.push_str(&id_conditions);
-        }
+            if cancelled {
+                info!("Stop streaming, cancel query on backend {}", pid);
+                if let Err(e) = sqlx::query("SELECT pg_cancel_backend($1)")
+                    .bind(pid)
+                    .execute(&pool)
+                    .await
+                {
+                    warn!("Failed to cancel query: {:?}", e);
+                }
+                // 连接上可能还有未读完的数据 直接关闭不再放回连接池
+                let _ = conn.close().await;
+            } else if sandbox.is_some() {
+                // 只读事务不需要提交
+                if let Err(e) = sqlx::query("ROLLBACK").execute(&mut *conn).await {
+                    warn!("Failed to rollback read-only transaction: {:?}", e);
+                    let _ = conn.close().await;
+                }
+            }
+        };
+        tokio::spawn(task.instrument(span));
 
-        info!("Generated SQL: {}", sql);
+        Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
+    }
+}
 
-        write!(f, "{}", sql)
+// 将数据库错误转换为 Status
+fn fetch_error(e: sqlx::Error, sql: &SqlQuery) -> Status {
+    warn!("Failed to fetch row: {:?}", e);
+    let code = e
+        .as_database_error()
+        .and_then(|e| e.code())
+        .map(|c| c.into_owned());
+    match code.as_deref() {
+        // query_canceled: 超过 statement_timeout
+        Some("57014") => Status::deadline_exceeded("Query exceeded statement timeout"),
+        // read_only_sql_transaction: 只读事务中尝试写入
+        Some("25006") => Status::permission_denied("Query is not allowed to modify data"),
+        _ => Status::internal(format!("Failed to fetch data with query:{}", sql)),
     }
 }
 
@@ -96,68 +206,20 @@ impl QueryRequest {
     }
 }
 
-// 组ID条件
-fn ids_query(name: &str, ids: &Vec<u32>) -> String {
-    if ids.is_empty() {
-        return "TRUE".to_string();
-    }
-
-    format!("array{:?} <@ {}", ids, name)
-}
-
-// 组时间戳条件
-fn timestamp_query(name: &str, lower: Option<&Timestamp>, upper: Option<&Timestamp>) -> String {
-    if lower.is_none() && upper.is_none() {
-        return "TRUE".to_string();
-    }
-
-    if lower.is_none() {
-        let upper = ts_to_utc(upper.unwrap());
-        return format!("{} <= '{}'", name, upper.to_rfc3339());
-    }
-
-    if upper.is_none() {
-        let lower = ts_to_utc(lower.unwrap());
-        return format!("{} >= '{}'", name, lower.to_rfc3339());
-    }
-
-    format!(
-        "{} BETWEEN '{}' AND '{}'",
-        name,
-        ts_to_utc(lower.unwrap()).to_rfc3339(),
-        ts_to_utc(upper.unwrap()).to_rfc3339()
-    )
-}
-
-// 将时间戳 转换为UTC时间
-fn ts_to_utc(ts: &Timestamp) -> DateTime<Utc> {
-    Utc.timestamp_opt(ts.seconds, ts.nanos as _).unwrap()
-}
-
 #[cfg(test)]
-
 mod tests {
     use anyhow::Result;
+    use crm_core::ConfigLoader;
     use futures::StreamExt;
+    use tonic::Code;
 
     use super::*;
     use crate::{
-        pb::QueryRequestBuilder,
+        pb::{ArrayOp, Filter, Gender, QueryRequestBuilder},
         test_utils::{id, tq},
-        AppConfig,
+        AppConfig, UserStatsServiceInner,
     };
-    #[test]
-    fn query_request_to_string_should_work() {
-        let d1 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
-        let d2 = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
-        let query = QueryRequest::new_with_dt("created_at", d1, d2);
-        let sql = query.to_string();
-        assert_eq!(
-            sql,
-            "SELECT email, name FROM user_stats WHERE created_at BETWEEN '2024-01-01T00:00:00+00:00' AND '2024-01-02T00:00:00+00:00'"
-        );
-    }
-
+    use std::sync::Arc;
     #[tokio::test]
     async fn raw_query_should_work() -> Result<()> {
         let config = AppConfig::load().expect("Failed Load config");
@@ -177,6 +239,127 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn raw_query_should_cancel_when_client_dropped() -> Result<()> {
+        let config = AppConfig::load().expect("Failed Load config");
+        let svc = UserStatsService::new(config).await;
+        let sql = "select email, name from user_stats, pg_sleep(5) as cancel_probe";
+        let stream = svc
+            .raw_query(RawQueryRequest {
+                query: sql.to_string(),
+            })
+            .await?
+            .into_inner();
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        drop(stream);
+
+        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        let running: i64 = sqlx::query_scalar(
+            "select count(*) from pg_stat_activity where state = 'active' and query = $1",
+        )
+        .bind(sql)
+        .fetch_one(&svc.pool)
+        .await?;
+        assert_eq!(running, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn raw_query_should_reject_non_select() -> Result<()> {
+        let config = AppConfig::load().expect("Failed Load config");
+        let svc = UserStatsService::new(config).await;
+        let ret = svc
+            .raw_query(RawQueryRequest {
+                query: "delete from user_stats".to_string(),
+            })
+            .await;
+        assert_eq!(ret.err().map(|e| e.code()), Some(Code::InvalidArgument));
+
+        // 能通过解析的写操作由只读事务拦截
+        let mut stream = svc
+            .raw_query(RawQueryRequest {
+                query: "select email, name from user_stats limit 1 for update".to_string(),
+            })
+            .await?
+            .into_inner();
+        let ret = stream.next().await.expect("should return an error");
+        assert_eq!(ret.unwrap_err().code(), Code::PermissionDenied);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn raw_query_should_enforce_limits() -> Result<()> {
+        let mut config = AppConfig::load().expect("Failed Load config");
+        config.raw_query.max_rows = 3;
+        config.raw_query.statement_timeout = 100;
+        let svc = UserStatsService::new(config).await;
+
+        let stream = svc
+            .raw_query(RawQueryRequest {
+                query: "select email, name from user_stats limit 10".to_string(),
+            })
+            .await?
+            .into_inner();
+        let ret = stream.collect::<Vec<_>>().await;
+        assert_eq!(ret.len(), 4);
+        assert!(ret[..3].iter().all(|r| r.is_ok()));
+        assert_eq!(ret[3].as_ref().unwrap_err().code(), Code::ResourceExhausted);
+
+        let mut stream = svc
+            .raw_query(RawQueryRequest {
+                query: "select email, name from user_stats, pg_sleep(1) limit 1".to_string(),
+            })
+            .await?
+            .into_inner();
+        let ret = stream.next().await.expect("should return an error");
+        assert_eq!(ret.unwrap_err().code(), Code::DeadlineExceeded);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_sandbox_should_not_leak_transaction() -> Result<()> {
+        let (_tdb, svc) = UserStatsService::new_for_test().await?;
+        let mut config = AppConfig::load()?;
+        // 超出 int 范围 SET LOCAL 会失败
+        config.raw_query.statement_timeout = u64::MAX;
+        let svc = UserStatsService {
+            inner: Arc::new(UserStatsServiceInner {
+                config,
+                pool: svc.pool.clone(),
+            }),
+        };
+
+        let ret = svc
+            .raw_query(RawQueryRequest {
+                query: "select email, name from user_stats limit 1".to_string(),
+            })
+            .await;
+        assert_eq!(ret.err().map(|e| e.code()), Some(Code::Internal));
+
+        // 连接不能带着未结束的事务回到连接池
+        let leaked: i64 = sqlx::query_scalar(
+            "select count(*) from pg_stat_activity where datname = current_database() and state like 'idle in transaction%'",
+        )
+        .fetch_one(&svc.pool)
+        .await?;
+        assert_eq!(leaked, 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn raw_query_should_fail_when_disabled() -> Result<()> {
+        let mut config = AppConfig::load().expect("Failed Load config");
+        config.raw_query.enabled = false;
+        let svc = UserStatsService::new(config).await;
+        let ret = svc
+            .raw_query(RawQueryRequest {
+                query: "select email, name from user_stats limit 1".to_string(),
+            })
+            .await;
+        assert_eq!(ret.err().map(|e| e.code()), Some(Code::Unimplemented));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn query_should_work() -> Result<()> {
         let config = AppConfig::load().expect("Failed Load config");
@@ -199,4 +382,88 @@ mod tests {
         }
         Ok(())
     }
+
+    #[tokio::test]
+    async fn not_filter_should_match_null_column() -> Result<()> {
+        let (_tdb, svc) = UserStatsService::new_for_test().await?;
+        sqlx::query("INSERT INTO user_stats(email, name) VALUES ('never@acme.org', 'Never')")
+            .execute(&svc.pool)
+            .await?;
+
+        // 从未发送过邮件的用户也满足 "最近3天没有发送过邮件"
+        let filter = Filter::not(Filter::time("last_email_notification", tq(Some(3), None)));
+        let query = QueryRequestBuilder::default().filter(filter).build()?;
+        let ret = svc
+            .query(query)
+            .await?
+            .into_inner()
+            .collect::<Vec<_>>()
+            .await;
+        assert!(ret
+            .iter()
+            .any(|r| r.as_ref().is_ok_and(|u| u.email == "never@acme.org")));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn array_filters_should_use_index() -> Result<()> {
+        let (_tdb, svc) = UserStatsService::new_for_test().await?;
+        // 测试数据较少 禁用顺序扫描后检查是否能用到索引
+        let mut conn = svc.pool.acquire().await?;
+        sqlx::query("SET enable_seqscan = off")
+            .execute(&mut *conn)
+            .await?;
+
+        let filters = [
+            (
+                Filter::length("finished", Some(3), None),
+                "user_stats_finished_len_idx",
+            ),
+            (
+                Filter::id_with_op("finished", id(&[400042]), ArrayOp::Overlap),
+                "user_stats_finished_idx",
+            ),
+        ];
+        for (filter, index) in filters {
+            let query = QueryRequestBuilder::default().filter(filter).build()?;
+            let mut sql = query.to_sql()?;
+            sql.sql = format!("EXPLAIN {}", sql.sql);
+            let plan: Vec<(String,)> = sql.query_as().fetch_all(&mut *conn).await?;
+            assert!(plan.iter().any(|(line,)| line.contains(index)), "{}", index);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn query_with_filter_should_work() -> Result<()> {
+        let config = AppConfig::load().expect("Failed Load config");
+        let svc = UserStatsService::new(config).await;
+
+        let filter = Filter::and([
+            Filter::time("last_visited_at", tq(Some(7), None)),
+            Filter::or([
+                Filter::id("finished", id(&[400042])),
+                Filter::id("started_but_not_finished", id(&[300042])),
+            ]),
+            Filter::not(Filter::time("last_email_notification", tq(Some(3), None))),
+            Filter::id_with_op("recent_watched", id(&[100001, 100002]), ArrayOp::Overlap),
+            Filter::id_with_op(
+                "viewed_but_not_started",
+                id(&[200001]),
+                ArrayOp::NotContains,
+            ),
+            Filter::length("finished", Some(1), None),
+            Filter::gender(&[Gender::Female, Gender::Male]),
+            Filter::null("last_watched_at", false),
+        ]);
+        let query = QueryRequestBuilder::default().filter(filter).build()?;
+        let ret = svc
+            .query(query)
+            .await?
+            .into_inner()
+            .collect::<Vec<_>>()
+            .await;
+        assert!(ret.iter().all(|r| r.is_ok()));
+        Ok(())
+    }
 }
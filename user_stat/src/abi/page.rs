@@ -0,0 +1,346 @@
+use super::{
+    fetch_error,
+    sql::{column, SqlArg, SqlBuilder},
+    SqlQuery,
+};
+use crate::{
+    pb::{OrderBy, PageRequest, UserStat, UserStatPage},
+    ServiceResult, UserStatsService,
+};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use crm_core::ToDateTime;
+use serde::{Deserialize, Serialize};
+use tonic::{Response, Status};
+use tracing::info;
+
+/// UserStat 中可以返回的列
+pub const USER_STAT_COLUMNS: &[&str] = &[
+    "email",
+    "name",
+    "gender",
+    "created_at",
+    "last_visited_at",
+    "last_watched_at",
+    "recent_watched",
+    "viewed_but_not_started",
+    "started_but_not_finished",
+    "finished",
+    "last_email_notification",
+    "last_in_app_notification",
+    "last_sms_notification",
+];
+
+/// 允许排序的列 均有索引
+pub const ORDER_COLUMNS: &[&str] = &[
+    "email",
+    "created_at",
+    "last_visited_at",
+    "last_watched_at",
+    "last_email_notification",
+    "last_in_app_notification",
+    "last_sms_notification",
+];
+
+const DEFAULT_PAGE_SIZE: u32 = 100;
+const MAX_PAGE_SIZE: u32 = 1000;
+
+/// 分页游标 记录上一页最后一行的排序值
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Cursor {
+    column: String,
+    desc: bool,
+    value: Option<DateTime<Utc>>,
+    email: String,
+}
+
+// 实现分页查询
+impl UserStatsService {
+    pub async fn query_page(&self, req: PageRequest) -> ServiceResult<UserStatPage> {
+        let limit = req.page_size();
+        let sql = req.to_sql()?;
+        info!("Generated SQL: {}", sql);
+        let mut users = sql
+            .query_as::<UserStat>()
+            .fetch_all(&self.inner.pool)
+            .await
+            .map_err(|e| fetch_error(e, &sql))?;
+
+        // 多查询一行 用于判断是否还有下一页
+        let next_cursor = if users.len() > limit as usize {
+            users.truncate(limit as usize);
+            let last = users.last().expect("page should not be empty");
+            req.order().cursor_after(last)?.encode()?
+        } else {
+            String::new()
+        };
+
+        Ok(Response::new(UserStatPage { users, next_cursor }))
+    }
+}
+
+impl PageRequest {
+    /// 编译分页查询SQL
+    pub fn to_sql(&self) -> Result<SqlQuery, Status> {
+        let order = self.order();
+        let desc = order.desc;
+        let order_column = column(&order.column, ORDER_COLUMNS)?;
+        let projection = self.projection(order_column)?;
+
+        let mut builder = SqlBuilder::default();
+        let query = self.query.clone().unwrap_or_default();
+        let mut conditions = vec![query.where_clause(&mut builder)?];
+        if !self.cursor.is_empty() {
+            let cursor = Cursor::decode(&self.cursor)?;
+            if cursor.column != order_column || cursor.desc != desc {
+                return Err(Status::invalid_argument(
+                    "Cursor does not match the requested order",
+                ));
+            }
+            conditions.push(cursor.keyset(&mut builder));
+        }
+
+        let dir = if desc { "DESC" } else { "ASC" };
+        let order_by = if order_column == "email" {
+            format!("email {}", dir)
+        } else {
+            format!("{} {} NULLS LAST, email {}", order_column, dir, dir)
+        };
+        let limit = builder.bind(SqlArg::Int(self.page_size() as i32 + 1));
+        let sql = format!(
+            "SELECT {} FROM user_stats WHERE {} ORDER BY {} LIMIT {}",
+            projection.join(", "),
+            conditions.join(" AND "),
+            order_by,
+            limit
+        );
+        Ok(builder.build(sql))
+    }
+
+    // 每页数量
+    fn page_size(&self) -> u32 {
+        match self.limit {
+            0 => DEFAULT_PAGE_SIZE,
+            n => n.min(MAX_PAGE_SIZE),
+        }
+    }
+
+    // 排序条件 默认按 email 升序
+    fn order(&self) -> OrderBy {
+        self.order_by.clone().unwrap_or_else(|| OrderBy {
+            column: "email".to_string(),
+            desc: false,
+        })
+    }
+
+    // 需要查询的列 email 与排序列总会被查询
+    fn projection<'a>(&'a self, order_column: &'a str) -> Result<Vec<&'a str>, Status> {
+        let mut columns = vec!["email"];
+        match &self.fields {
+            Some(mask) if !mask.paths.is_empty() => {
+                for path in &mask.paths {
+                    columns.push(column(path, USER_STAT_COLUMNS)?);
+                }
+            }
+            _ => columns.push("name"),
+        }
+        columns.push(order_column);
+        // 保持列的顺序 去除重复
+        let mut seen = vec![];
+        columns.retain(|c| {
+            let first = !seen.contains(c);
+            seen.push(*c);
+            first
+        });
+        Ok(columns)
+    }
+}
+
+impl OrderBy {
+    // 根据当前页最后一行生成游标
+    fn cursor_after(&self, user: &UserStat) -> Result<Cursor, Status> {
+        let value = match self.column.as_str() {
+            "email" => None,
+            "created_at" => user.created_at.as_ref(),
+            "last_visited_at" => user.last_visited_at.as_ref(),
+            "last_watched_at" => user.last_watched_at.as_ref(),
+            "last_email_notification" => user.last_email_notification.as_ref(),
+            "last_in_app_notification" => user.last_in_app_notification.as_ref(),
+            "last_sms_notification" => user.last_sms_notification.as_ref(),
+            name => {
+                return Err(Status::invalid_argument(format!(
+                    "Unknown column: {}",
+                    name
+                )))
+            }
+        };
+
+        Ok(Cursor {
+            column: self.column.clone(),
+            desc: self.desc,
+            value: value.map(ToDateTime::to_datetime).transpose()?,
+            email: user.email.clone(),
+        })
+    }
+}
+
+impl Cursor {
+    fn encode(&self) -> Result<String, Status> {
+        let data = serde_json::to_vec(self)
+            .map_err(|e| Status::internal(format!("Failed to encode cursor: {}", e)))?;
+        Ok(URL_SAFE_NO_PAD.encode(data))
+    }
+
+    fn decode(cursor: &str) -> Result<Self, Status> {
+        URL_SAFE_NO_PAD
+            .decode(cursor)
+            .ok()
+            .and_then(|data| serde_json::from_slice(&data).ok())
+            .ok_or_else(|| Status::invalid_argument("Invalid cursor"))
+    }
+
+    // 生成 keyset 条件 NULL 值排在最后
+    fn keyset(&self, builder: &mut SqlBuilder) -> String {
+        let op = if self.desc { "<" } else { ">" };
+        let email = builder.bind(SqlArg::Text(self.email.clone()));
+        if self.column == "email" {
+            return format!("email {} {}", op, email);
+        }
+
+        match self.value {
+            Some(value) => {
+                let value = builder.bind(SqlArg::Timestamp(value));
+                format!(
+                    "(({col}, email) {op} ({value}, {email}) OR {col} IS NULL)",
+                    col = self.column
+                )
+            }
+            None => format!("({} IS NULL AND email {} {})", self.column, op, email),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pb::{QueryRequest, QueryRequestBuilder},
+        test_utils::tq,
+        AppConfig,
+    };
+    use anyhow::Result;
+    use crm_core::ConfigLoader;
+    use prost_types::FieldMask;
+    use std::collections::HashMap;
+    use tonic::Code;
+
+    fn page_request(cursor: &str) -> PageRequest {
+        PageRequest {
+            query: Some(
+                QueryRequestBuilder::default()
+                    .time_stamps(HashMap::from([(
+                        "created_at".to_string(),
+                        tq(Some(30), None),
+                    )]))
+                    .build()
+                    .unwrap(),
+            ),
+            order_by: Some(OrderBy {
+                column: "last_visited_at".to_string(),
+                desc: true,
+            }),
+            limit: 5,
+            cursor: cursor.to_string(),
+            fields: Some(FieldMask {
+                paths: vec!["gender".to_string(), "finished".to_string()],
+            }),
+        }
+    }
+
+    #[test]
+    fn page_request_to_sql_should_work() {
+        let sql = page_request("").to_sql().unwrap();
+        assert_eq!(
+            sql.sql,
+            "SELECT email, gender, finished, last_visited_at FROM user_stats WHERE created_at >= $1 ORDER BY last_visited_at DESC NULLS LAST, email DESC LIMIT $2"
+        );
+        assert_eq!(sql.args[1], SqlArg::Int(6));
+
+        let cursor = Cursor {
+            column: "last_visited_at".to_string(),
+            desc: true,
+            value: None,
+            email: "a@b.c".to_string(),
+        };
+        let sql = page_request(&cursor.encode().unwrap()).to_sql().unwrap();
+        assert!(sql
+            .sql
+            .contains("AND (last_visited_at IS NULL AND email < $2) ORDER BY"));
+    }
+
+    #[test]
+    fn invalid_page_request_should_fail() {
+        let mut req = page_request("invalid");
+        assert_eq!(req.to_sql().unwrap_err().code(), Code::InvalidArgument);
+
+        let cursor = Cursor {
+            column: "created_at".to_string(),
+            desc: true,
+            value: None,
+            email: "a@b.c".to_string(),
+        };
+        req.cursor = cursor.encode().unwrap();
+        assert_eq!(req.to_sql().unwrap_err().code(), Code::InvalidArgument);
+
+        let req = PageRequest {
+            order_by: Some(OrderBy {
+                column: "name".to_string(),
+                desc: false,
+            }),
+            ..Default::default()
+        };
+        assert_eq!(req.to_sql().unwrap_err().code(), Code::InvalidArgument);
+
+        let req = PageRequest {
+            query: Some(QueryRequest::default()),
+            fields: Some(FieldMask {
+                paths: vec!["password".to_string()],
+            }),
+            ..Default::default()
+        };
+        assert_eq!(req.to_sql().unwrap_err().code(), Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn query_page_should_work() -> Result<()> {
+        let config = AppConfig::load().expect("Failed Load config");
+        let svc = UserStatsService::new(config).await;
+
+        let first = svc.query_page(page_request("")).await?.into_inner();
+        assert_eq!(first.users.len(), 5);
+        assert!(!first.next_cursor.is_empty());
+        // 未请求的列为默认值
+        assert!(first.users.iter().all(|u| u.name.is_empty()));
+        assert!(first.users.iter().all(|u| u.gender != 0));
+
+        let second = svc
+            .query_page(page_request(&first.next_cursor))
+            .await?
+            .into_inner();
+        assert_eq!(second.users.len(), 5);
+
+        // 两页按 last_visited_at 降序连续
+        let visited: Vec<_> = first
+            .users
+            .iter()
+            .chain(second.users.iter())
+            .map(|u| u.last_visited_at.map(|ts| (ts.seconds, ts.nanos)))
+            .collect();
+        assert!(visited.windows(2).all(|w| w[0] >= w[1]));
+        assert!(first
+            .users
+            .iter()
+            .all(|u| second.users.iter().all(|v| v.email != u.email)));
+        Ok(())
+    }
+}
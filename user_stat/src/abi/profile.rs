@@ -0,0 +1,155 @@
+use super::{
+    fetch_error,
+    page::USER_STAT_COLUMNS,
+    sql::{SqlArg, SqlBuilder},
+    SqlQuery,
+};
+use crate::{
+    pb::{BatchGetUserStatsRequest, BatchGetUserStatsResponse, GetUserStatRequest, UserStat},
+    ServiceResult, UserStatsService,
+};
+use tonic::{Response, Status};
+
+// 批量查询的最大数量
+const MAX_BATCH_SIZE: usize = 1000;
+
+// 实现用户信息查询
+impl UserStatsService {
+    // 查询单个用户
+    pub async fn get_user_stat(&self, req: GetUserStatRequest) -> ServiceResult<UserStat> {
+        let sql = req.to_sql()?;
+        let user = sql
+            .query_as::<UserStat>()
+            .fetch_optional(&self.inner.pool)
+            .await
+            .map_err(|e| fetch_error(e, &sql))?
+            .ok_or_else(|| Status::not_found(format!("User not found: {}", req.email)))?;
+
+        Ok(Response::new(user))
+    }
+
+    // 批量查询用户
+    pub async fn batch_get_user_stats(
+        &self,
+        req: BatchGetUserStatsRequest,
+    ) -> ServiceResult<BatchGetUserStatsResponse> {
+        let sql = req.to_sql()?;
+        let users = sql
+            .query_as::<UserStat>()
+            .fetch_all(&self.inner.pool)
+            .await
+            .map_err(|e| fetch_error(e, &sql))?;
+
+        Ok(Response::new(BatchGetUserStatsResponse { users }))
+    }
+}
+
+impl GetUserStatRequest {
+    pub fn new(email: impl Into<String>) -> Self {
+        Self {
+            email: email.into(),
+        }
+    }
+
+    // 生成查询SQL
+    pub fn to_sql(&self) -> Result<SqlQuery, Status> {
+        if self.email.is_empty() {
+            return Err(Status::invalid_argument("Email is required"));
+        }
+
+        let mut builder = SqlBuilder::default();
+        let sql = format!(
+            "SELECT {} FROM user_stats WHERE email = {}",
+            USER_STAT_COLUMNS.join(", "),
+            builder.bind(SqlArg::Text(self.email.clone()))
+        );
+        Ok(builder.build(sql))
+    }
+}
+
+impl BatchGetUserStatsRequest {
+    pub fn new(emails: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            emails: emails.into_iter().map(|e| e.into()).collect(),
+        }
+    }
+
+    // 生成批量查询SQL
+    pub fn to_sql(&self) -> Result<SqlQuery, Status> {
+        if self.emails.len() > MAX_BATCH_SIZE {
+            return Err(Status::invalid_argument(format!(
+                "At most {} emails are allowed in a batch",
+                MAX_BATCH_SIZE
+            )));
+        }
+
+        let mut builder = SqlBuilder::default();
+        let sql = format!(
+            "SELECT {} FROM user_stats WHERE email = ANY({}) ORDER BY email",
+            USER_STAT_COLUMNS.join(", "),
+            builder.bind(SqlArg::TextArray(self.emails.clone()))
+        );
+        Ok(builder.build(sql))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{pb::PageRequest, AppConfig};
+    use anyhow::Result;
+    use crm_core::ConfigLoader;
+    use tonic::Code;
+
+    #[test]
+    fn get_user_stat_to_sql_should_work() {
+        let sql = GetUserStatRequest::new("a@b.c").to_sql().unwrap();
+        assert!(sql
+            .sql
+            .starts_with("SELECT email, name, gender, created_at"));
+        assert!(sql.sql.ends_with("FROM user_stats WHERE email = $1"));
+
+        let err = GetUserStatRequest::default().to_sql().unwrap_err();
+        assert_eq!(err.code(), Code::InvalidArgument);
+
+        let req = BatchGetUserStatsRequest::new((0..=MAX_BATCH_SIZE).map(|i| i.to_string()));
+        assert_eq!(req.to_sql().unwrap_err().code(), Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn get_user_stat_should_work() -> Result<()> {
+        let config = AppConfig::load().expect("Failed Load config");
+        let svc = UserStatsService::new(config).await;
+        let page = svc
+            .query_page(PageRequest {
+                limit: 3,
+                ..Default::default()
+            })
+            .await?
+            .into_inner();
+        let emails: Vec<_> = page.users.into_iter().map(|u| u.email).collect();
+
+        let user = svc
+            .get_user_stat(GetUserStatRequest::new(&emails[0]))
+            .await?
+            .into_inner();
+        assert_eq!(user.email, emails[0]);
+        assert!(!user.name.is_empty());
+        assert!(user.created_at.is_some());
+
+        let err = svc
+            .get_user_stat(GetUserStatRequest::new("not-exists@example.com"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), Code::NotFound);
+
+        let mut req = BatchGetUserStatsRequest::new(emails.clone());
+        req.emails.push("not-exists@example.com".to_string());
+        let users = svc.batch_get_user_stats(req).await?.into_inner().users;
+        assert_eq!(
+            users.into_iter().map(|u| u.email).collect::<Vec<_>>(),
+            emails
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use sqlparser::{ast::Statement, dialect::PostgreSqlDialect, parser::Parser};
+use tonic::Status;
+
+/// 校验原始查询 仅允许单条 SELECT 语句
+pub(crate) fn validate_select(sql: &str) -> Result<(), Status> {
+    let statements = Parser::parse_sql(&PostgreSqlDialect {}, sql)
+        .map_err(|e| Status::invalid_argument(format!("Failed to parse query: {}", e)))?;
+
+    match statements.as_slice() {
+        [Statement::Query(_)] => Ok(()),
+        [_] => Err(Status::invalid_argument("Only SELECT statement is allowed")),
+        _ => Err(Status::invalid_argument(
+            "Exactly one statement is allowed in a query",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_select_should_work() {
+        assert!(validate_select("SELECT * FROM user_stats LIMIT 5").is_ok());
+        assert!(validate_select("with t as (select 1) select * from t").is_ok());
+    }
+
+    #[test]
+    fn validate_select_should_reject_other_statements() {
+        assert!(validate_select("DELETE FROM user_stats").is_err());
+        assert!(validate_select("SELECT 1; DROP TABLE user_stats").is_err());
+        assert!(validate_select("UPDATE user_stats SET name = 'x'").is_err());
+        assert!(validate_select("not sql at all").is_err());
+    }
+}
@@ -0,0 +1,96 @@
+use crate::pb::Gender;
+use chrono::{DateTime, Utc};
+use crm_core::ToTimestamp;
+use prost_types::Timestamp;
+use sqlx::{
+    error::BoxDynError,
+    postgres::{PgTypeInfo, PgValueRef},
+    Decode, Postgres, Type, ValueRef,
+};
+
+/// 数据库 gender 枚举 NULL 时为 GENDER_UNSPECIFIED
+pub struct DbGender(Gender);
+
+/// 可为空的 timestamptz 列
+pub struct DbTimestamp(Option<Timestamp>);
+
+/// 可为空的 int[] 列 NULL 时为空数组
+pub struct DbIds(Vec<u32>);
+
+impl Type<Postgres> for DbGender {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("gender")
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == Self::type_info() || <&str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for DbGender {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        if value.is_null() {
+            return Ok(Self(Gender::Unspecified));
+        }
+        let value = <&str as Decode<Postgres>>::decode(value)?;
+        Ok(Self(Gender::from_db_str(Some(value))))
+    }
+}
+
+impl From<DbGender> for i32 {
+    fn from(value: DbGender) -> Self {
+        value.0 as i32
+    }
+}
+
+impl Type<Postgres> for DbTimestamp {
+    fn type_info() -> PgTypeInfo {
+        <DateTime<Utc> as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <DateTime<Utc> as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for DbTimestamp {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let value = <Option<DateTime<Utc>> as Decode<Postgres>>::decode(value)?;
+        Ok(Self(value.map(|dt| dt.to_timestamp())))
+    }
+}
+
+impl From<DbTimestamp> for Option<Timestamp> {
+    fn from(value: DbTimestamp) -> Self {
+        value.0
+    }
+}
+
+impl Type<Postgres> for DbIds {
+    fn type_info() -> PgTypeInfo {
+        <Vec<i32> as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <Vec<i32> as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for DbIds {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let value = <Option<Vec<i32>> as Decode<Postgres>>::decode(value)?;
+        Ok(Self(
+            value
+                .unwrap_or_default()
+                .into_iter()
+                .map(|id| id as u32)
+                .collect(),
+        ))
+    }
+}
+
+impl From<DbIds> for Vec<u32> {
+    fn from(value: DbIds) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,380 @@
+use crate::pb::{ArrayOp, Gender, QueryRequest};
+use chrono::{DateTime, Utc};
+use core::fmt;
+use crm_core::ToDateTime;
+use prost_types::Timestamp;
+use sqlx::{
+    postgres::PgArguments,
+    query::{Query, QueryAs},
+    Postgres,
+};
+use tonic::Status;
+
+/// user_stats 表中允许作为时间条件的列
+pub const TIME_COLUMNS: &[&str] = &[
+    "created_at",
+    "last_visited_at",
+    "last_watched_at",
+    "last_email_notification",
+    "last_in_app_notification",
+    "last_sms_notification",
+];
+
+/// user_stats 表中可以为空的时间列
+pub const NULLABLE_TIME_COLUMNS: &[&str] = &[
+    "last_visited_at",
+    "last_watched_at",
+    "last_email_notification",
+    "last_in_app_notification",
+    "last_sms_notification",
+];
+
+/// user_stats 表中允许作为ID条件的数组列
+pub const ID_COLUMNS: &[&str] = &[
+    "recent_watched",
+    "viewed_but_not_started",
+    "started_but_not_finished",
+    "finished",
+];
+
+/// 绑定到SQL中的参数
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlArg {
+    Int(i32),
+    Text(String),
+    Timestamp(DateTime<Utc>),
+    IntArray(Vec<i32>),
+    TextArray(Vec<String>),
+}
+
+/// 编译后的SQL语句，所有的值都以 $n 参数的方式绑定
+#[derive(Debug, Clone, PartialEq)]
+pub struct SqlQuery {
+    pub sql: String,
+    pub args: Vec<SqlArg>,
+}
+
+/// SQL 构建器，负责分配参数序号
+#[derive(Debug, Default)]
+pub(crate) struct SqlBuilder {
+    args: Vec<SqlArg>,
+}
+
+impl SqlBuilder {
+    // 添加一个参数 返回其占位符
+    pub(crate) fn bind(&mut self, arg: SqlArg) -> String {
+        self.args.push(arg);
+        format!("${}", self.args.len())
+    }
+
+    pub(crate) fn build(self, sql: String) -> SqlQuery {
+        SqlQuery {
+            sql,
+            args: self.args,
+        }
+    }
+}
+
+impl SqlQuery {
+    // 不带参数的SQL
+    pub fn raw(sql: impl Into<String>) -> Self {
+        Self {
+            sql: sql.into(),
+            args: vec![],
+        }
+    }
+
+    // 生成 sqlx 查询并按顺序绑定参数
+    pub fn query_as<O>(&self) -> QueryAs<'_, Postgres, O, PgArguments>
+    where
+        O: for<'r> sqlx::FromRow<'r, sqlx::postgres::PgRow>,
+    {
+        self.args
+            .iter()
+            .fold(sqlx::query_as(&self.sql), |q, arg| match arg {
+                SqlArg::Int(v) => q.bind(*v),
+                SqlArg::Text(v) => q.bind(v.clone()),
+                SqlArg::Timestamp(v) => q.bind(*v),
+                SqlArg::IntArray(v) => q.bind(v.clone()),
+                SqlArg::TextArray(v) => q.bind(v.clone()),
+            })
+    }
+
+    // 生成不返回行的 sqlx 查询 用于写入
+    pub fn query(&self) -> Query<'_, Postgres, PgArguments> {
+        self.args
+            .iter()
+            .fold(sqlx::query(&self.sql), |q, arg| match arg {
+                SqlArg::Int(v) => q.bind(*v),
+                SqlArg::Text(v) => q.bind(v.clone()),
+                SqlArg::Timestamp(v) => q.bind(*v),
+                SqlArg::IntArray(v) => q.bind(v.clone()),
+                SqlArg::TextArray(v) => q.bind(v.clone()),
+            })
+    }
+}
+
+/// 用于日志输出的可读形式
+impl fmt::Display for SqlQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.sql)?;
+        if !self.args.is_empty() {
+            write!(f, " -- {:?}", self.args)?;
+        }
+        Ok(())
+    }
+}
+
+impl QueryRequest {
+    /// 将 QueryRequest 编译为参数化SQL，未知列返回 invalid_argument
+    pub fn to_sql(&self) -> Result<SqlQuery, Status> {
+        let mut builder = SqlBuilder::default();
+        let sql = format!(
+            "SELECT email, name FROM user_stats WHERE {}",
+            self.where_clause(&mut builder)?
+        );
+        Ok(builder.build(sql))
+    }
+
+    // 生成 WHERE 子句 供查询与统计共用
+    pub(crate) fn where_clause(&self, builder: &mut SqlBuilder) -> Result<String, Status> {
+        Ok(self.conditions(builder)?.join(" AND "))
+    }
+
+    // 生成所有条件 按列名排序 保证生成的SQL稳定
+    fn conditions(&self, builder: &mut SqlBuilder) -> Result<Vec<String>, Status> {
+        let mut conditions = vec![];
+
+        let mut time_stamps: Vec<_> = self.time_stamps.iter().collect();
+        time_stamps.sort_by_key(|(k, _)| k.as_str());
+        for (name, tq) in time_stamps {
+            if let Some(cond) =
+                timestamp_query(builder, name, tq.lower.as_ref(), tq.upper.as_ref())?
+            {
+                conditions.push(cond);
+            }
+        }
+
+        let mut ids: Vec<_> = self.ids.iter().collect();
+        ids.sort_by_key(|(k, _)| k.as_str());
+        for (name, iq) in ids {
+            if let Some(cond) = ids_query(builder, name, &iq.ids, ArrayOp::ContainsAll)? {
+                conditions.push(cond);
+            }
+        }
+
+        if let Some(filter) = &self.filter {
+            conditions.push(filter.to_sql(builder)?);
+        }
+
+        if conditions.is_empty() {
+            conditions.push("TRUE".to_string());
+        }
+        Ok(conditions)
+    }
+}
+
+// 校验列名是否在白名单中
+pub(crate) fn column<'a>(name: &'a str, allowed: &[&str]) -> Result<&'a str, Status> {
+    if allowed.contains(&name) {
+        Ok(name)
+    } else {
+        Err(Status::invalid_argument(format!(
+            "Unknown column: {}",
+            name
+        )))
+    }
+}
+
+// 组ID条件 GIN 索引支持 <@ 与 && 操作符
+pub(crate) fn ids_query(
+    builder: &mut SqlBuilder,
+    name: &str,
+    ids: &[u32],
+    op: ArrayOp,
+) -> Result<Option<String>, Status> {
+    let name = column(name, ID_COLUMNS)?;
+    if ids.is_empty() {
+        // 空集合与任何数组都没有交集
+        return Ok((op == ArrayOp::Overlap).then(|| "FALSE".to_string()));
+    }
+
+    let ids = builder.bind(SqlArg::IntArray(to_int_array(ids)?));
+    let cond = match op {
+        ArrayOp::ContainsAll => format!("{} <@ {}", ids, name),
+        ArrayOp::Overlap => format!("{} && {}", name, ids),
+        // 数组为 NULL 时视为不包含
+        ArrayOp::NotContains => format!("NOT coalesce({} && {}, FALSE)", name, ids),
+    };
+    Ok(Some(cond))
+}
+
+// 组数组长度条件
+pub(crate) fn length_query(
+    builder: &mut SqlBuilder,
+    name: &str,
+    min: Option<u32>,
+    max: Option<u32>,
+) -> Result<Option<String>, Status> {
+    let name = column(name, ID_COLUMNS)?;
+    // 与迁移中的表达式索引保持一致 否则只能顺序扫描
+    let len = format!("coalesce(cardinality({}), 0)", name);
+    let mut bind = |v: u32| -> Result<String, Status> {
+        let v = i32::try_from(v)
+            .map_err(|_| Status::invalid_argument(format!("Invalid length: {}", v)))?;
+        Ok(builder.bind(SqlArg::Int(v)))
+    };
+    let cond = match (min, max) {
+        (None, None) => return Ok(None),
+        (Some(min), None) => format!("{} >= {}", len, bind(min)?),
+        (None, Some(max)) => format!("{} <= {}", len, bind(max)?),
+        (Some(min), Some(max)) => format!("{} BETWEEN {} AND {}", len, bind(min)?, bind(max)?),
+    };
+    Ok(Some(cond))
+}
+
+// 组性别条件
+pub(crate) fn gender_query(builder: &mut SqlBuilder, genders: &[i32]) -> Result<String, Status> {
+    if genders.is_empty() {
+        return Ok("FALSE".to_string());
+    }
+
+    let values = genders
+        .iter()
+        .map(|g| {
+            Gender::try_from(*g)
+                .ok()
+                .and_then(|g| g.as_db_str())
+                .map(|g| g.to_string())
+                .ok_or_else(|| Status::invalid_argument(format!("Invalid gender: {}", g)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(format!(
+        "gender = ANY({}::gender[])",
+        builder.bind(SqlArg::TextArray(values))
+    ))
+}
+
+// 组空值条件
+pub(crate) fn null_query(name: &str, is_null: bool) -> Result<String, Status> {
+    let name = column(name, NULLABLE_TIME_COLUMNS)?;
+    let op = if is_null { "IS NULL" } else { "IS NOT NULL" };
+    Ok(format!("{} {}", name, op))
+}
+
+impl Gender {
+    // 数据库枚举中的取值
+    pub fn as_db_str(&self) -> Option<&'static str> {
+        match self {
+            Gender::Unspecified => None,
+            Gender::Female => Some("female"),
+            Gender::Male => Some("male"),
+            Gender::Unknown => Some("unknown"),
+        }
+    }
+
+    // 由数据库枚举取值转换
+    pub fn from_db_str(value: Option<&str>) -> Self {
+        match value {
+            Some("female") => Gender::Female,
+            Some("male") => Gender::Male,
+            Some("unknown") => Gender::Unknown,
+            _ => Gender::Unspecified,
+        }
+    }
+}
+
+// 组时间戳条件
+pub(crate) fn timestamp_query(
+    builder: &mut SqlBuilder,
+    name: &str,
+    lower: Option<&Timestamp>,
+    upper: Option<&Timestamp>,
+) -> Result<Option<String>, Status> {
+    let name = column(name, TIME_COLUMNS)?;
+    let cond = match (lower, upper) {
+        (None, None) => return Ok(None),
+        (None, Some(upper)) => format!(
+            "{} <= {}",
+            name,
+            builder.bind(SqlArg::Timestamp(upper.to_datetime()?))
+        ),
+        (Some(lower), None) => format!(
+            "{} >= {}",
+            name,
+            builder.bind(SqlArg::Timestamp(lower.to_datetime()?))
+        ),
+        (Some(lower), Some(upper)) => format!(
+            "{} BETWEEN {} AND {}",
+            name,
+            builder.bind(SqlArg::Timestamp(lower.to_datetime()?)),
+            builder.bind(SqlArg::Timestamp(upper.to_datetime()?))
+        ),
+    };
+    Ok(Some(cond))
+}
+
+// 数据库中ID为 int 类型
+pub(crate) fn to_int_array(ids: &[u32]) -> Result<Vec<i32>, Status> {
+    ids.iter()
+        .map(|id| {
+            i32::try_from(*id).map_err(|_| Status::invalid_argument(format!("Invalid id: {}", id)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        pb::QueryRequestBuilder,
+        test_utils::{id, tq},
+    };
+    use chrono::TimeZone;
+    use tonic::Code;
+
+    #[test]
+    fn query_request_to_sql_should_work() {
+        let d1 = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let d2 = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+        let query = QueryRequest::new_with_dt("created_at", d1, d2);
+        let sql = query.to_sql().unwrap();
+        assert_eq!(
+            sql.sql,
+            "SELECT email, name FROM user_stats WHERE created_at BETWEEN $1 AND $2"
+        );
+        assert_eq!(sql.args, vec![SqlArg::Timestamp(d1), SqlArg::Timestamp(d2)]);
+    }
+
+    #[test]
+    fn query_request_with_ids_to_sql_should_work() {
+        let query = QueryRequestBuilder::default()
+            .id(("finished".to_string(), id(&[1, 2])))
+            .id(("recent_watched".to_string(), id(&[])))
+            .build()
+            .unwrap();
+        let sql = query.to_sql().unwrap();
+        assert_eq!(
+            sql.sql,
+            "SELECT email, name FROM user_stats WHERE $1 <@ finished"
+        );
+        assert_eq!(sql.args, vec![SqlArg::IntArray(vec![1, 2])]);
+    }
+
+    #[test]
+    fn query_request_with_unknown_column_should_fail() {
+        let query = QueryRequestBuilder::default()
+            .id(("finished; DELETE FROM user_stats; --".to_string(), id(&[1])))
+            .build()
+            .unwrap();
+        let err = query.to_sql().unwrap_err();
+        assert_eq!(err.code(), Code::InvalidArgument);
+
+        let mut query = QueryRequest::default();
+        query
+            .time_stamps
+            .insert("email".to_string(), tq(Some(1), None));
+        let err = query.to_sql().unwrap_err();
+        assert_eq!(err.code(), Code::InvalidArgument);
+    }
+}
@@ -1,7 +1,9 @@
-use std::{env, fs::File};
-
-use anyhow::{bail, Result};
+use crm_core::{
+    config::{field, Issues, Validate},
+    AuthConfig, ConfigLoader, MetricsConfig, PolicyConfig, ServerTls, TracingConfig,
+};
 use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
 
 /// 服务配置
 #[derive(Debug, Serialize, Deserialize)]
@@ -10,38 +12,122 @@ pub struct AppConfig {
     pub server: ServerConfig,
     // 身份认证相关
     pub auth: AuthConfig,
-}
-
-/// 身份认证配置 暂未启用
-#[derive(Debug, Serialize, Deserialize)]
-pub struct AuthConfig {
-    // 私钥
-    pub pk: String,
+    // 按方法授权相关
+    #[serde(default)]
+    pub policy: PolicyConfig,
+    // 配置后提供 /metrics
+    #[serde(default)]
+    pub metrics: Option<MetricsConfig>,
+    // 配置后通过 OTLP 导出 trace
+    #[serde(default)]
+    pub tracing: Option<TracingConfig>,
+    // 原始查询相关
+    #[serde(default)]
+    pub raw_query: RawQueryConfig,
+    // 事件写入相关
+    #[serde(default)]
+    pub ingest: IngestConfig,
 }
 
 // 服务配置
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ServerConfig {
+    // 监听地址
+    #[serde(default = "crm_core::server::default_host")]
+    pub host: IpAddr,
     // 监听端口
     pub port: u16,
+    // 收到退出信号后 等待进行中请求完成的最长秒数
+    #[serde(default = "crm_core::server::default_drain_timeout")]
+    pub drain_timeout: u64,
+    // 未配置时使用明文
+    pub tls: Option<ServerTls>,
     // 数据连接
     pub db_url: String,
 }
 
-impl AppConfig {
-    // 加载配置
-    pub fn load() -> Result<Self> {
-        let ret = match (
-            File::open("user_stat.yml"),
-            File::open("/etc/config/user_stat.yml"),
-            env::var("USER_STAT_CONFIG"),
-        ) {
-            (Ok(reader), _, _) => serde_yaml::from_reader(reader),
-            (_, Ok(reader), _) => serde_yaml::from_reader(reader),
-            (_, _, Ok(path)) => serde_yaml::from_reader(File::open(path)?),
-            _ => bail!("User Stat Config file not found"),
-        };
-
-        Ok(ret?)
+/// RawQuery 配置 生产环境可关闭
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawQueryConfig {
+    // 是否启用 RawQuery
+    pub enabled: bool,
+    // 语句超时时间 单位毫秒
+    pub statement_timeout: u64,
+    // 最多返回的行数
+    pub max_rows: usize,
+}
+
+impl Default for RawQueryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            statement_timeout: 5000,
+            max_rows: 10000,
+        }
+    }
+}
+
+/// Ingest 配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngestConfig {
+    // recent_watched 保留的最大数量
+    pub recent_watched_limit: u32,
+}
+
+impl Default for IngestConfig {
+    fn default() -> Self {
+        Self {
+            recent_watched_limit: 50,
+        }
+    }
+}
+
+impl ConfigLoader for AppConfig {
+    const FILE: &'static str = "user_stat.yml";
+    const ENV: &'static str = "USER_STAT_CONFIG";
+    const PREFIX: &'static str = "USER_STAT";
+}
+
+impl Validate for AppConfig {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        self.server.validate(&field(path, "server"), issues);
+        self.auth.validate(&field(path, "auth"), issues);
+        self.policy.validate(&field(path, "policy"), issues);
+        if let Some(metrics) = &self.metrics {
+            metrics.validate(&field(path, "metrics"), issues);
+        }
+        if let Some(tracing) = &self.tracing {
+            tracing.validate(&field(path, "tracing"), issues);
+        }
+        let raw_query = field(path, "raw_query");
+        issues.check(
+            self.raw_query.statement_timeout > 0,
+            field(&raw_query, "statement_timeout"),
+            "must be greater than 0",
+        );
+        issues.check(
+            self.raw_query.max_rows > 0,
+            field(&raw_query, "max_rows"),
+            "must be greater than 0",
+        );
+        issues.check(
+            self.ingest.recent_watched_limit > 0,
+            field(&field(path, "ingest"), "recent_watched_limit"),
+            "must be greater than 0",
+        );
+    }
+}
+
+impl Validate for ServerConfig {
+    fn validate(&self, path: &str, issues: &mut Issues) {
+        issues.check(self.port != 0, field(path, "port"), "must not be 0");
+        if let Some(tls) = &self.tls {
+            tls.validate(&field(path, "tls"), issues);
+        }
+        issues.check(
+            self.db_url.starts_with("postgres://") || self.db_url.starts_with("postgresql://"),
+            field(path, "db_url"),
+            "must be a postgres:// url",
+        );
     }
 }
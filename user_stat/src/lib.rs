@@ -1,17 +1,23 @@
+#![allow(clippy::result_large_err)]
+
 mod abi;
 mod config;
 pub mod pb;
 
-pub use config::AppConfig;
+pub use abi::{SqlArg, SqlQuery};
+pub use config::{AppConfig, IngestConfig, RawQueryConfig};
 
+use crm_core::HealthCheck;
 use futures::Stream;
 use pb::{
     user_stats_server::{UserStats, UserStatsServer},
-    QueryRequest, RawQueryRequest, User,
+    BatchGetUserStatsRequest, BatchGetUserStatsResponse, CountResponse, GenderCountResponse,
+    GetUserStatRequest, HistogramRequest, HistogramResponse, IngestEvent, IngestResponse,
+    PageRequest, QueryRequest, RawQueryRequest, User, UserStat, UserStatPage,
 };
 use sqlx::PgPool;
 use std::{ops::Deref, pin::Pin, sync::Arc};
-use tonic::{async_trait, Request, Response, Status};
+use tonic::{async_trait, Request, Response, Status, Streaming};
 
 type ServiceResult<T> = Result<Response<T>, Status>;
 type ResponseStream = Pin<Box<dyn Stream<Item = Result<User, Status>> + Send>>;
@@ -40,6 +46,36 @@ impl UserStats for UserStatsService {
         self.query(query).await
     }
 
+    // QueryPage
+    async fn query_page(&self, request: Request<PageRequest>) -> ServiceResult<UserStatPage> {
+        let req = request.into_inner();
+        self.query_page(req).await
+    }
+
+    // GetUserStat
+    async fn get_user_stat(&self, request: Request<GetUserStatRequest>) -> ServiceResult<UserStat> {
+        let req = request.into_inner();
+        self.get_user_stat(req).await
+    }
+
+    // BatchGetUserStats
+    async fn batch_get_user_stats(
+        &self,
+        request: Request<BatchGetUserStatsRequest>,
+    ) -> ServiceResult<BatchGetUserStatsResponse> {
+        let req = request.into_inner();
+        self.batch_get_user_stats(req).await
+    }
+
+    // Ingest
+    async fn ingest(
+        &self,
+        request: Request<Streaming<IngestEvent>>,
+    ) -> ServiceResult<IngestResponse> {
+        let events = request.into_inner();
+        self.ingest(events).await
+    }
+
     // 实现RawQueryStream
     type RawQueryStream = ResponseStream;
     // RawQuert
@@ -50,6 +86,30 @@ impl UserStats for UserStatsService {
         let query = request.into_inner();
         self.raw_query(query).await
     }
+
+    // Count
+    async fn count(&self, request: Request<QueryRequest>) -> ServiceResult<CountResponse> {
+        let query = request.into_inner();
+        self.count(query).await
+    }
+
+    // CountByGender
+    async fn count_by_gender(
+        &self,
+        request: Request<QueryRequest>,
+    ) -> ServiceResult<GenderCountResponse> {
+        let query = request.into_inner();
+        self.count_by_gender(query).await
+    }
+
+    // Histogram
+    async fn histogram(
+        &self,
+        request: Request<HistogramRequest>,
+    ) -> ServiceResult<HistogramResponse> {
+        let req = request.into_inner();
+        self.histogram(req).await
+    }
 }
 
 impl UserStatsService {
@@ -58,6 +118,7 @@ impl UserStatsService {
         let pool = PgPool::connect(&config.server.db_url)
             .await
             .expect("Failed Connect to DB");
+        crm_core::metrics::record_pool("user_stats", pool.clone());
         let inner = UserStatsServiceInner { config, pool };
 
         Self {
@@ -71,6 +132,14 @@ impl UserStatsService {
     }
 }
 
+// 能从连接池取得连接时服务可用
+impl HealthCheck for UserStatsService {
+    async fn check(&self) -> anyhow::Result<()> {
+        self.pool.acquire().await?;
+        Ok(())
+    }
+}
+
 impl Deref for UserStatsService {
     type Target = UserStatsServiceInner;
 
@@ -81,48 +150,55 @@ impl Deref for UserStatsService {
 
 #[cfg(feature = "test_utils")]
 pub mod test_utils {
-    use crate::pb::{IdQuery, TimeQuery};
+    use crate::{
+        pb::{IdQuery, TimeQuery},
+        AppConfig, UserStatsService, UserStatsServiceInner,
+    };
+    use anyhow::Result;
     use chrono::Utc;
+    use crm_core::{ConfigLoader, ToTimestamp};
     use prost_types::Timestamp;
+    use sqlx::PgPool;
+    use sqlx_db_tester::TestPg;
+    use std::{path::Path, sync::Arc};
+
+    impl UserStatsService {
+        // 创建临时数据库并加载测试数据 TestPg 被 drop 时数据库会被删除
+        pub async fn new_for_test() -> Result<(TestPg, Self)> {
+            Self::new_for_test_with_config(AppConfig::load()?).await
+        }
 
-    // impl UserStatsService {
-    //     pub async fn new_for_test() -> Result<(TestPg, Self)> {
-    //         let config = AppConfig::load()?;
-    //         let post = config.server.db_url.rfind("/").expect("invalid db_url");
-    //         let server_url = &config.server.db_url[..post];
-    //         let (tdb, pool) = get_test_pool(Some(server_url)).await;
-    //         let svc = Self {
-    //             inner: Arc::new(UserStatsServiceInner { config, pool }),
-    //         };
-    //
-    //         Ok((tdb, svc))
-    //     }
-    // }
-    //
-    // pub async fn get_test_pool(url: Option<&str>) -> (TestPg, PgPool) {
-    //     let url = match url {
-    //         Some(url) => url.to_string(),
-    //         None => "postgres://:123456@localhost:5432".to_string(),
-    //     };
-    //
-    //     let p = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("migrations");
-    //     let tdb = TestPg::new(url, p);
-    //     let pool = tdb.get_pool().await;
-    //
-    //     let sql = include_str!("../fixtures/data.sql").split(";");
-    //     let mut ts = pool.begin().await.expect("begin transaction failed");
-    //     for s in sql {
-    //         if s.trim().is_empty() {
-    //             continue;
-    //         } else {
-    //             ts.execute(s).await.expect("execute sql failed");
-    //         }
-    //     }
-    //
-    //     ts.commit().await.expect("commit transaction failed");
-    //
-    //     (tdb, pool)
-    // }
+        // 使用指定配置创建临时数据库
+        pub async fn new_for_test_with_config(config: AppConfig) -> Result<(TestPg, Self)> {
+            let post = config.server.db_url.rfind('/').expect("invalid db_url");
+            let server_url = &config.server.db_url[..post];
+            let (tdb, pool) = get_test_pool(Some(server_url)).await;
+            let svc = Self {
+                inner: Arc::new(UserStatsServiceInner { config, pool }),
+            };
+
+            Ok((tdb, svc))
+        }
+    }
+
+    pub async fn get_test_pool(url: Option<&str>) -> (TestPg, PgPool) {
+        let url = match url {
+            Some(url) => url.to_string(),
+            None => "postgres://:123456@localhost:5432".to_string(),
+        };
+
+        let p = Path::new(env!("CARGO_MANIFEST_DIR")).join("migrations");
+        let tdb = TestPg::new(url, p);
+        let pool = tdb.get_pool().await;
+
+        // fixture 由 `cargo run --example gen -- fixture` 生成
+        sqlx::raw_sql(include_str!("../fixtures/data.sql"))
+            .execute(&pool)
+            .await
+            .expect("load fixture failed");
+
+        (tdb, pool)
+    }
 
     pub fn id(id: &[u32]) -> IdQuery {
         IdQuery { ids: id.to_vec() }
@@ -135,12 +211,9 @@ pub mod test_utils {
     }
 
     pub fn to_ts(days: i64) -> Timestamp {
-        let dt = Utc::now()
+        Utc::now()
             .checked_sub_signed(chrono::Duration::days(days))
-            .unwrap();
-        Timestamp {
-            seconds: dt.timestamp(),
-            nanos: dt.timestamp_subsec_nanos() as i32,
-        }
+            .unwrap()
+            .to_timestamp()
     }
 }
@@ -1,23 +1,33 @@
 use anyhow::Result;
-use tonic::transport::Server;
-use tracing::{info, level_filters::LevelFilter};
-use tracing_subscriber::{fmt::Layer, layer::SubscriberExt, util::SubscriberInitExt, Layer as _};
-use user_stat::{AppConfig, UserStatsService};
+use crm_core::{init_tracing, metrics, Bootstrap, ConfigLoader};
+use std::{env, net::SocketAddr, time::Duration};
+use tonic::{server::NamedService, service::Routes};
+use tracing::info;
+use user_stat::{
+    pb::{user_stats_server::UserStatsServer, FILE_DESCRIPTOR_SET},
+    AppConfig, UserStatsService,
+};
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let layer = Layer::new().with_filter(LevelFilter::INFO);
-    tracing_subscriber::registry().with(layer).init();
-    let config = AppConfig::load().expect("Failed to load config");
-    let addr = config.server.port;
-    let addr = format!("[::1]:{}", addr)
-        .parse()
-        .expect("Failed to parse addr");
-    info!("UserStatService listening on {}", addr);
+    let config = AppConfig::load_with_args(env::args().skip(1))?;
+    let _guard = init_tracing("user_stat", config.tracing.as_ref())?;
+    if let Some(config) = &config.metrics {
+        metrics::install(config)?;
+    }
+    let addr = SocketAddr::new(config.server.host, config.server.port);
+    let server = Bootstrap::try_new(
+        addr,
+        config.server.tls.as_ref(),
+        &config.auth,
+        &config.policy,
+    )?
+    .drain_timeout(Duration::from_secs(config.server.drain_timeout));
+    info!("UserStatService listening on {}", server.addr());
 
-    let svc = UserStatsService::new(config).await.into_server();
-
-    Server::builder().add_service(svc).serve(addr).await?;
-
-    Ok(())
+    let svc = UserStatsService::new(config).await;
+    let server = server
+        .health(&[UserStatsServer::<UserStatsService>::NAME], svc.clone())
+        .reflection(FILE_DESCRIPTOR_SET);
+    server.serve(Routes::new(svc.into_server())).await
 }
@@ -2,3 +2,5 @@
 mod user_stats;
 
 pub use user_stats::*;
+
+pub const FILE_DESCRIPTOR_SET: &[u8] = tonic::include_file_descriptor_set!("user_stats");
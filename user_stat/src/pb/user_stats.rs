@@ -14,6 +14,49 @@ pub struct User {
     #[builder(setter(into))]
     pub name: ::prost::alloc::string::String,
 }
+/// 用户完整统计信息 对应 user_stats 表
+#[derive(sqlx::FromRow)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UserStat {
+    #[prost(string, tag = "1")]
+    pub email: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    #[sqlx(default)]
+    pub name: ::prost::alloc::string::String,
+    #[prost(enumeration = "Gender", tag = "3")]
+    #[sqlx(default, try_from = "crate::abi::DbGender")]
+    pub gender: i32,
+    #[prost(message, optional, tag = "4")]
+    #[sqlx(default, try_from = "crate::abi::DbTimestamp")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "5")]
+    #[sqlx(default, try_from = "crate::abi::DbTimestamp")]
+    pub last_visited_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "6")]
+    #[sqlx(default, try_from = "crate::abi::DbTimestamp")]
+    pub last_watched_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint32, repeated, tag = "7")]
+    #[sqlx(default, try_from = "crate::abi::DbIds")]
+    pub recent_watched: ::prost::alloc::vec::Vec<u32>,
+    #[prost(uint32, repeated, tag = "8")]
+    #[sqlx(default, try_from = "crate::abi::DbIds")]
+    pub viewed_but_not_started: ::prost::alloc::vec::Vec<u32>,
+    #[prost(uint32, repeated, tag = "9")]
+    #[sqlx(default, try_from = "crate::abi::DbIds")]
+    pub started_but_not_finished: ::prost::alloc::vec::Vec<u32>,
+    #[prost(uint32, repeated, tag = "10")]
+    #[sqlx(default, try_from = "crate::abi::DbIds")]
+    pub finished: ::prost::alloc::vec::Vec<u32>,
+    #[prost(message, optional, tag = "11")]
+    #[sqlx(default, try_from = "crate::abi::DbTimestamp")]
+    pub last_email_notification: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "12")]
+    #[sqlx(default, try_from = "crate::abi::DbTimestamp")]
+    pub last_in_app_notification: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "13")]
+    #[sqlx(default, try_from = "crate::abi::DbTimestamp")]
+    pub last_sms_notification: ::core::option::Option<::prost_types::Timestamp>,
+}
 /// 时间查询条件
 #[derive(derive_builder::Builder)]
 #[builder(setter(into, strip_option), default)]
@@ -32,6 +75,82 @@ pub struct IdQuery {
     #[prost(uint32, repeated, tag = "1")]
     pub ids: ::prost::alloc::vec::Vec<u32>,
 }
+/// 单列时间条件
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeCondition {
+    #[prost(string, tag = "1")]
+    pub column: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub query: ::core::option::Option<TimeQuery>,
+}
+/// 单列ID条件
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IdCondition {
+    #[prost(string, tag = "1")]
+    pub column: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub query: ::core::option::Option<IdQuery>,
+    #[prost(enumeration = "ArrayOp", tag = "3")]
+    pub op: i32,
+}
+/// 数组长度范围 包含边界
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ArrayLengthCondition {
+    #[prost(string, tag = "1")]
+    pub column: ::prost::alloc::string::String,
+    #[prost(uint32, optional, tag = "2")]
+    pub min: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "3")]
+    pub max: ::core::option::Option<u32>,
+}
+/// 性别条件 匹配其中任意一个
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenderCondition {
+    #[prost(enumeration = "Gender", repeated, tag = "1")]
+    pub genders: ::prost::alloc::vec::Vec<i32>,
+}
+/// 空值条件
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NullCondition {
+    #[prost(string, tag = "1")]
+    pub column: ::prost::alloc::string::String,
+    #[prost(bool, tag = "2")]
+    pub is_null: bool,
+}
+/// 条件组
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FilterGroup {
+    #[prost(message, repeated, tag = "1")]
+    pub filters: ::prost::alloc::vec::Vec<Filter>,
+}
+/// 过滤表达式 支持 AND/OR/NOT 嵌套
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Filter {
+    #[prost(oneof = "filter::Expr", tags = "1, 2, 3, 4, 5, 6, 7, 8")]
+    pub expr: ::core::option::Option<filter::Expr>,
+}
+/// Nested message and enum types in `Filter`.
+pub mod filter {
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Expr {
+        #[prost(message, tag = "1")]
+        And(super::FilterGroup),
+        #[prost(message, tag = "2")]
+        Or(super::FilterGroup),
+        #[prost(message, tag = "3")]
+        Not(::prost::alloc::boxed::Box<super::Filter>),
+        #[prost(message, tag = "4")]
+        Time(super::TimeCondition),
+        #[prost(message, tag = "5")]
+        Id(super::IdCondition),
+        #[prost(message, tag = "6")]
+        Length(super::ArrayLengthCondition),
+        #[prost(message, tag = "7")]
+        Gender(super::GenderCondition),
+        #[prost(message, tag = "8")]
+        Null(super::NullCondition),
+    }
+}
 /// 查询请求参数
 #[derive(derive_builder::Builder)]
 #[builder(setter(into, strip_option), default)]
@@ -39,6 +158,7 @@ pub struct IdQuery {
 pub struct QueryRequest {
     /// 时间查询条件 例如 created_at, last_visited_at等 -> Option<HashMap<String,TimeQuery>>
     #[prost(map = "string, message", tag = "1")]
+    #[builder(setter(each(name = "timestamp", into)))]
     pub time_stamps: ::std::collections::HashMap<
         ::prost::alloc::string::String,
         TimeQuery,
@@ -47,6 +167,131 @@ pub struct QueryRequest {
     #[prost(map = "string, message", tag = "2")]
     #[builder(setter(each(name = "id", into)))]
     pub ids: ::std::collections::HashMap<::prost::alloc::string::String, IdQuery>,
+    /// 过滤表达式 与上面的条件以 AND 组合
+    #[prost(message, optional, tag = "3")]
+    pub filter: ::core::option::Option<Filter>,
+}
+/// 排序条件
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderBy {
+    /// 排序列 仅支持带索引的列 email 与时间列
+    #[prost(string, tag = "1")]
+    pub column: ::prost::alloc::string::String,
+    #[prost(bool, tag = "2")]
+    pub desc: bool,
+}
+/// 分页查询请求
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PageRequest {
+    /// 过滤条件
+    #[prost(message, optional, tag = "1")]
+    pub query: ::core::option::Option<QueryRequest>,
+    /// 排序条件 为空时按 email 升序 始终以 email 作为第二排序列
+    #[prost(message, optional, tag = "2")]
+    pub order_by: ::core::option::Option<OrderBy>,
+    /// 每页数量 为0时默认100 最大1000
+    #[prost(uint32, tag = "3")]
+    pub limit: u32,
+    /// 上一页返回的游标 为空时从第一页开始
+    #[prost(string, tag = "4")]
+    pub cursor: ::prost::alloc::string::String,
+    /// 需要返回的 UserStat 字段 为空时返回 email 与 name
+    #[prost(message, optional, tag = "5")]
+    pub fields: ::core::option::Option<::prost_types::FieldMask>,
+}
+/// 分页查询响应
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UserStatPage {
+    #[prost(message, repeated, tag = "1")]
+    pub users: ::prost::alloc::vec::Vec<UserStat>,
+    /// 下一页的游标 为空表示没有更多数据
+    #[prost(string, tag = "2")]
+    pub next_cursor: ::prost::alloc::string::String,
+}
+/// 按 email 查询单个用户
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetUserStatRequest {
+    #[prost(string, tag = "1")]
+    pub email: ::prost::alloc::string::String,
+}
+/// 按 email 批量查询用户 最多1000个
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchGetUserStatsRequest {
+    #[prost(string, repeated, tag = "1")]
+    pub emails: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// 批量查询响应 不存在的 email 会被忽略
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BatchGetUserStatsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub users: ::prost::alloc::vec::Vec<UserStat>,
+}
+/// 用户访问
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct VisitEvent {}
+/// 用户浏览了内容 但还没有开始观看
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ContentViewedEvent {
+    #[prost(uint32, tag = "1")]
+    pub content_id: u32,
+}
+/// 用户开始观看内容
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct WatchStartedEvent {
+    #[prost(uint32, tag = "1")]
+    pub content_id: u32,
+}
+/// 用户看完了内容
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct WatchFinishedEvent {
+    #[prost(uint32, tag = "1")]
+    pub content_id: u32,
+}
+/// 通知已送达用户
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct NotificationDeliveredEvent {
+    #[prost(enumeration = "NotificationChannel", tag = "1")]
+    pub channel: i32,
+}
+/// 用户行为事件
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct IngestEvent {
+    #[prost(string, tag = "1")]
+    pub email: ::prost::alloc::string::String,
+    /// 用户名 仅在用户不存在时用于创建
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// 事件发生时间 为空时使用服务器时间
+    #[prost(message, optional, tag = "3")]
+    pub occurred_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(oneof = "ingest_event::Event", tags = "4, 5, 6, 7, 8")]
+    pub event: ::core::option::Option<ingest_event::Event>,
+}
+/// Nested message and enum types in `IngestEvent`.
+pub mod ingest_event {
+    #[derive(Clone, Copy, PartialEq, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag = "4")]
+        Visit(super::VisitEvent),
+        #[prost(message, tag = "5")]
+        ContentViewed(super::ContentViewedEvent),
+        #[prost(message, tag = "6")]
+        WatchStarted(super::WatchStartedEvent),
+        #[prost(message, tag = "7")]
+        WatchFinished(super::WatchFinishedEvent),
+        #[prost(message, tag = "8")]
+        NotificationDelivered(super::NotificationDeliveredEvent),
+    }
+}
+/// 事件写入结果
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct IngestResponse {
+    /// 成功写入的事件数量
+    #[prost(uint64, tag = "1")]
+    pub accepted: u64,
+    /// 参数不合法被忽略的事件数量
+    #[prost(uint64, tag = "2")]
+    pub rejected: u64,
 }
 /// 原始查询请求
 #[derive(derive_builder::Builder)]
@@ -63,6 +308,179 @@ pub struct QueryResponse {
     #[prost(message, repeated, tag = "1")]
     pub users: ::prost::alloc::vec::Vec<User>,
 }
+/// 数量统计响应
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CountResponse {
+    #[prost(uint64, tag = "1")]
+    pub count: u64,
+}
+/// 单个性别的数量
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GenderCount {
+    /// 数据库中为 NULL 时返回 GENDER_UNSPECIFIED
+    #[prost(enumeration = "Gender", tag = "1")]
+    pub gender: i32,
+    #[prost(uint64, tag = "2")]
+    pub count: u64,
+}
+/// 按性别分组统计响应
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GenderCountResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub counts: ::prost::alloc::vec::Vec<GenderCount>,
+}
+/// 时间直方图请求
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HistogramRequest {
+    /// 过滤条件
+    #[prost(message, optional, tag = "1")]
+    pub query: ::core::option::Option<QueryRequest>,
+    /// 统计的时间列 例如 created_at, last_visited_at
+    #[prost(string, tag = "2")]
+    pub column: ::prost::alloc::string::String,
+    #[prost(enumeration = "Interval", tag = "3")]
+    pub interval: i32,
+}
+/// 直方图中的一个区间 起始时间为UTC
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct HistogramBucket {
+    #[prost(message, optional, tag = "1")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(uint64, tag = "2")]
+    pub count: u64,
+}
+/// 时间直方图响应 按时间升序 忽略该列为 NULL 的用户
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct HistogramResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub buckets: ::prost::alloc::vec::Vec<HistogramBucket>,
+}
+/// 性别 对应数据库中的 gender 枚举
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Gender {
+    Unspecified = 0,
+    Female = 1,
+    Male = 2,
+    Unknown = 3,
+}
+impl Gender {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "GENDER_UNSPECIFIED",
+            Self::Female => "GENDER_FEMALE",
+            Self::Male => "GENDER_MALE",
+            Self::Unknown => "GENDER_UNKNOWN",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "GENDER_UNSPECIFIED" => Some(Self::Unspecified),
+            "GENDER_FEMALE" => Some(Self::Female),
+            "GENDER_MALE" => Some(Self::Male),
+            "GENDER_UNKNOWN" => Some(Self::Unknown),
+            _ => None,
+        }
+    }
+}
+/// 数组比较方式
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ArrayOp {
+    /// 包含全部ID
+    ContainsAll = 0,
+    /// 包含任意一个ID
+    Overlap = 1,
+    /// 不包含其中任何一个ID
+    NotContains = 2,
+}
+impl ArrayOp {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::ContainsAll => "ARRAY_OP_CONTAINS_ALL",
+            Self::Overlap => "ARRAY_OP_OVERLAP",
+            Self::NotContains => "ARRAY_OP_NOT_CONTAINS",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ARRAY_OP_CONTAINS_ALL" => Some(Self::ContainsAll),
+            "ARRAY_OP_OVERLAP" => Some(Self::Overlap),
+            "ARRAY_OP_NOT_CONTAINS" => Some(Self::NotContains),
+            _ => None,
+        }
+    }
+}
+/// 通知渠道
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NotificationChannel {
+    Unspecified = 0,
+    Email = 1,
+    Sms = 2,
+    InApp = 3,
+}
+impl NotificationChannel {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "NOTIFICATION_CHANNEL_UNSPECIFIED",
+            Self::Email => "NOTIFICATION_CHANNEL_EMAIL",
+            Self::Sms => "NOTIFICATION_CHANNEL_SMS",
+            Self::InApp => "NOTIFICATION_CHANNEL_IN_APP",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NOTIFICATION_CHANNEL_UNSPECIFIED" => Some(Self::Unspecified),
+            "NOTIFICATION_CHANNEL_EMAIL" => Some(Self::Email),
+            "NOTIFICATION_CHANNEL_SMS" => Some(Self::Sms),
+            "NOTIFICATION_CHANNEL_IN_APP" => Some(Self::InApp),
+            _ => None,
+        }
+    }
+}
+/// 直方图时间间隔
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum Interval {
+    Day = 0,
+    Week = 1,
+}
+impl Interval {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Day => "INTERVAL_DAY",
+            Self::Week => "INTERVAL_WEEK",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "INTERVAL_DAY" => Some(Self::Day),
+            "INTERVAL_WEEK" => Some(Self::Week),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod user_stats_client {
     #![allow(
@@ -179,6 +597,97 @@ pub mod user_stats_client {
                 .insert(GrpcMethod::new("user_stats.UserStats", "Query"));
             self.inner.server_streaming(req, path, codec).await
         }
+        /// 分页查询 支持排序与字段选择
+        pub async fn query_page(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PageRequest>,
+        ) -> std::result::Result<tonic::Response<super::UserStatPage>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/user_stats.UserStats/QueryPage",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("user_stats.UserStats", "QueryPage"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 查询单个用户的完整信息
+        pub async fn get_user_stat(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetUserStatRequest>,
+        ) -> std::result::Result<tonic::Response<super::UserStat>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/user_stats.UserStats/GetUserStat",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("user_stats.UserStats", "GetUserStat"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 批量查询用户的完整信息
+        pub async fn batch_get_user_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BatchGetUserStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BatchGetUserStatsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/user_stats.UserStats/BatchGetUserStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("user_stats.UserStats", "BatchGetUserStats"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 写入用户行为事件 更新 user_stats
+        pub async fn ingest(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::IngestEvent>,
+        ) -> std::result::Result<tonic::Response<super::IngestResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/user_stats.UserStats/Ingest",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("user_stats.UserStats", "Ingest"));
+            self.inner.client_streaming(req, path, codec).await
+        }
         /// 原始字符串查询
         pub async fn raw_query(
             &mut self,
@@ -204,6 +713,78 @@ pub mod user_stats_client {
                 .insert(GrpcMethod::new("user_stats.UserStats", "RawQuery"));
             self.inner.server_streaming(req, path, codec).await
         }
+        /// 统计匹配的用户数量
+        pub async fn count(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryRequest>,
+        ) -> std::result::Result<tonic::Response<super::CountResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/user_stats.UserStats/Count",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("user_stats.UserStats", "Count"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 按性别分组统计匹配的用户数量
+        pub async fn count_by_gender(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GenderCountResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/user_stats.UserStats/CountByGender",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("user_stats.UserStats", "CountByGender"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 按天或周统计时间列的分布
+        pub async fn histogram(
+            &mut self,
+            request: impl tonic::IntoRequest<super::HistogramRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::HistogramResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/user_stats.UserStats/Histogram",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("user_stats.UserStats", "Histogram"));
+            self.inner.unary(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -230,6 +811,29 @@ pub mod user_stats_server {
             &self,
             request: tonic::Request<super::QueryRequest>,
         ) -> std::result::Result<tonic::Response<Self::QueryStream>, tonic::Status>;
+        /// 分页查询 支持排序与字段选择
+        async fn query_page(
+            &self,
+            request: tonic::Request<super::PageRequest>,
+        ) -> std::result::Result<tonic::Response<super::UserStatPage>, tonic::Status>;
+        /// 查询单个用户的完整信息
+        async fn get_user_stat(
+            &self,
+            request: tonic::Request<super::GetUserStatRequest>,
+        ) -> std::result::Result<tonic::Response<super::UserStat>, tonic::Status>;
+        /// 批量查询用户的完整信息
+        async fn batch_get_user_stats(
+            &self,
+            request: tonic::Request<super::BatchGetUserStatsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::BatchGetUserStatsResponse>,
+            tonic::Status,
+        >;
+        /// 写入用户行为事件 更新 user_stats
+        async fn ingest(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::IngestEvent>>,
+        ) -> std::result::Result<tonic::Response<super::IngestResponse>, tonic::Status>;
         /// Server streaming response type for the RawQuery method.
         type RawQueryStream: tonic::codegen::tokio_stream::Stream<
                 Item = std::result::Result<super::User, tonic::Status>,
@@ -241,6 +845,27 @@ pub mod user_stats_server {
             &self,
             request: tonic::Request<super::RawQueryRequest>,
         ) -> std::result::Result<tonic::Response<Self::RawQueryStream>, tonic::Status>;
+        /// 统计匹配的用户数量
+        async fn count(
+            &self,
+            request: tonic::Request<super::QueryRequest>,
+        ) -> std::result::Result<tonic::Response<super::CountResponse>, tonic::Status>;
+        /// 按性别分组统计匹配的用户数量
+        async fn count_by_gender(
+            &self,
+            request: tonic::Request<super::QueryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GenderCountResponse>,
+            tonic::Status,
+        >;
+        /// 按天或周统计时间列的分布
+        async fn histogram(
+            &self,
+            request: tonic::Request<super::HistogramRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::HistogramResponse>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
     pub struct UserStatsServer<T> {
@@ -364,6 +989,185 @@ pub mod user_stats_server {
                     };
                     Box::pin(fut)
                 }
+                "/user_stats.UserStats/QueryPage" => {
+                    #[allow(non_camel_case_types)]
+                    struct QueryPageSvc<T: UserStats>(pub Arc<T>);
+                    impl<T: UserStats> tonic::server::UnaryService<super::PageRequest>
+                    for QueryPageSvc<T> {
+                        type Response = super::UserStatPage;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PageRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UserStats>::query_page(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QueryPageSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/user_stats.UserStats/GetUserStat" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetUserStatSvc<T: UserStats>(pub Arc<T>);
+                    impl<
+                        T: UserStats,
+                    > tonic::server::UnaryService<super::GetUserStatRequest>
+                    for GetUserStatSvc<T> {
+                        type Response = super::UserStat;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetUserStatRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UserStats>::get_user_stat(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetUserStatSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/user_stats.UserStats/BatchGetUserStats" => {
+                    #[allow(non_camel_case_types)]
+                    struct BatchGetUserStatsSvc<T: UserStats>(pub Arc<T>);
+                    impl<
+                        T: UserStats,
+                    > tonic::server::UnaryService<super::BatchGetUserStatsRequest>
+                    for BatchGetUserStatsSvc<T> {
+                        type Response = super::BatchGetUserStatsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BatchGetUserStatsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UserStats>::batch_get_user_stats(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = BatchGetUserStatsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/user_stats.UserStats/Ingest" => {
+                    #[allow(non_camel_case_types)]
+                    struct IngestSvc<T: UserStats>(pub Arc<T>);
+                    impl<
+                        T: UserStats,
+                    > tonic::server::ClientStreamingService<super::IngestEvent>
+                    for IngestSvc<T> {
+                        type Response = super::IngestResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::IngestEvent>>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UserStats>::ingest(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = IngestSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/user_stats.UserStats/RawQuery" => {
                     #[allow(non_camel_case_types)]
                     struct RawQuerySvc<T: UserStats>(pub Arc<T>);
@@ -410,6 +1214,137 @@ pub mod user_stats_server {
                     };
                     Box::pin(fut)
                 }
+                "/user_stats.UserStats/Count" => {
+                    #[allow(non_camel_case_types)]
+                    struct CountSvc<T: UserStats>(pub Arc<T>);
+                    impl<T: UserStats> tonic::server::UnaryService<super::QueryRequest>
+                    for CountSvc<T> {
+                        type Response = super::CountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UserStats>::count(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CountSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/user_stats.UserStats/CountByGender" => {
+                    #[allow(non_camel_case_types)]
+                    struct CountByGenderSvc<T: UserStats>(pub Arc<T>);
+                    impl<T: UserStats> tonic::server::UnaryService<super::QueryRequest>
+                    for CountByGenderSvc<T> {
+                        type Response = super::GenderCountResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UserStats>::count_by_gender(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CountByGenderSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/user_stats.UserStats/Histogram" => {
+                    #[allow(non_camel_case_types)]
+                    struct HistogramSvc<T: UserStats>(pub Arc<T>);
+                    impl<
+                        T: UserStats,
+                    > tonic::server::UnaryService<super::HistogramRequest>
+                    for HistogramSvc<T> {
+                        type Response = super::HistogramResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::HistogramRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UserStats>::histogram(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = HistogramSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(empty_body());
@@ -0,0 +1,59 @@
+use anyhow::{anyhow, Result};
+use crm_core::{auth::test_utils::TestKey, Bootstrap, HealthCheck, PolicyConfig};
+use std::time::Duration;
+use tokio::time::sleep;
+use tonic::{
+    server::NamedService,
+    service::Routes,
+    transport::{server::TcpIncoming, Channel},
+};
+use tonic_health::pb::{
+    health_check_response::ServingStatus, health_client::HealthClient, HealthCheckRequest,
+};
+use user_stat::{pb::user_stats_server::UserStatsServer, UserStatsService};
+
+const PORT: u16 = 60100;
+const USER_STATS: &str = UserStatsServer::<UserStatsService>::NAME;
+
+#[tokio::test]
+async fn health_should_be_not_serving_when_db_is_unreachable() -> Result<()> {
+    let (tdb, svc) = UserStatsService::new_for_test().await?;
+    // 删除测试数据库 连接池再也取不到连接
+    drop(tdb);
+    assert!(svc.check().await.is_err());
+
+    let addr = format!("[::1]:{}", PORT).parse()?;
+    let server = Bootstrap::try_new(
+        addr,
+        None,
+        &TestKey::new().config(),
+        &PolicyConfig::default(),
+    )?
+    .health(&[USER_STATS], svc.clone());
+    // 先绑定端口 再启动服务 避免客户端连接时服务尚未就绪
+    let incoming = TcpIncoming::new(addr, true, None).map_err(|e| anyhow!(e))?;
+    tokio::spawn(server.serve_with_incoming(Routes::new(svc.into_server()), incoming));
+    let channel = Channel::from_shared(format!("http://{}", addr))?
+        .connect()
+        .await?;
+    let mut client = HealthClient::new(channel);
+
+    // 等待首次检查完成 启动时注册的状态同样是 NOT_SERVING
+    sleep(Duration::from_millis(500)).await;
+    assert_eq!(check(&mut client, "").await?, ServingStatus::NotServing);
+    assert_eq!(
+        check(&mut client, USER_STATS).await?,
+        ServingStatus::NotServing
+    );
+    Ok(())
+}
+
+async fn check(
+    client: &mut HealthClient<Channel>,
+    service: &str,
+) -> Result<ServingStatus, tonic::Status> {
+    let req = HealthCheckRequest {
+        service: service.to_string(),
+    };
+    Ok(client.check(req).await?.into_inner().status())
+}
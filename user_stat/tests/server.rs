@@ -1,38 +1,45 @@
-use std::{net::SocketAddr, time::Duration};
+use std::net::SocketAddr;
 
 use anyhow::Result;
 use chrono::{TimeZone, Utc};
 use futures::StreamExt;
+use prost_types::Timestamp;
 use sqlx_db_tester::TestPg;
-use tokio::time::sleep;
-use tonic::transport::Server;
+use tonic::transport::{server::TcpIncoming, Server};
 use user_stat::{
-    pb::{user_stats_client::UserStatsClient, QueryRequestBuilder, RawQueryRequestBuilder},
-    test_utils::{id, tq},
+    pb::{
+        user_stats_client::UserStatsClient, QueryRequestBuilder, RawQueryRequestBuilder, TimeQuery,
+    },
+    test_utils::id,
     UserStatsService,
 };
 
 const PORT_BASE: u32 = 60000;
 
-/// 这个测试 会随着时间推移产生问题
+/// fixture 数据是固定的 因此使用绝对时间查询
 #[tokio::test]
 async fn query_should_work() -> Result<()> {
     let (_tdb, addr) = start_server(PORT_BASE).await?;
     let mut client = UserStatsClient::connect(format!("http://{}", addr)).await?;
 
-    // 减去额定时间 保证用例可用
-    let created_date = Utc.with_ymd_and_hms(2023, 8, 1, 0, 0, 0).unwrap();
-    let days = Utc::now().signed_duration_since(created_date).num_days();
+    let created_date = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    let tq = TimeQuery {
+        lower: Some(Timestamp {
+            seconds: created_date.timestamp(),
+            nanos: 0,
+        }),
+        upper: None,
+    };
 
     let query = QueryRequestBuilder::default()
-        .timestamp(("created_at".to_string(), tq(Some(days), None)))
-        .id(("viewed_but_not_started".to_string(), id(&[252790])))
+        .timestamp(("created_at".to_string(), tq))
+        .id(("viewed_but_not_started".to_string(), id(&[247799])))
         .build()
         .unwrap();
 
     let stream = client.query(query).await?.into_inner();
     let ret = stream.collect::<Vec<_>>().await;
-    assert_eq!(ret.len(), 16);
+    assert_eq!(ret.len(), 2);
     Ok(())
 }
 
@@ -53,19 +60,31 @@ async fn raw_query_should_work() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn count_should_work() -> Result<()> {
+    let (_tdb, addr) = start_server(PORT_BASE + 2).await?;
+    let mut client = UserStatsClient::connect(format!("http://{}", addr)).await?;
+
+    let total = client
+        .count(QueryRequestBuilder::default().build()?)
+        .await?;
+    assert_eq!(total.into_inner().count, 300);
+    Ok(())
+}
+
 async fn start_server(port: u32) -> Result<(TestPg, SocketAddr)> {
     let addr = format!("[::1]:{}", port).parse()?;
     let (tdb, svc) = UserStatsService::new_for_test().await?;
+    // 先绑定端口 再启动服务 避免客户端连接时服务尚未就绪
+    let incoming = TcpIncoming::new(addr, true, None).map_err(|e| anyhow::anyhow!(e))?;
 
     tokio::spawn(async move {
         Server::builder()
             .add_service(svc.into_server())
-            .serve(addr)
+            .serve_with_incoming(incoming)
             .await
             .unwrap();
     });
 
-    sleep(Duration::from_micros(1)).await;
-
     Ok((tdb, addr))
 }